@@ -0,0 +1,217 @@
+//! Network adapters of GigE cameras.
+//!
+//! The driver reports the network adapter a GigE camera is connected to along with the device
+//! information of the camera. [`AdapterConfig::query`] reads it, including the link speed and the
+//! packet filter, which keeps GigE image packets away from the network stack of the operating
+//! system. The adapter is addressed by an [`AdapterId`], which only such a query yields, so that
+//! the packet filter cannot be set for an adapter the driver does not know.
+//!
+//! # Documentation
+//! * [is_GetEthDeviceInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getethdeviceinfo.html)
+//! * [is_SetPacketFilter](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setpacketfilter.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    ptr,
+};
+
+use ueye_sys::{
+    camera::IS_USE_DEVICE_ID,
+    eth::{is_SetPacketFilter, UEYE_ETH_PACKETFILTER_SETUP},
+    types::{DWORD, HIDS, INT},
+};
+
+use crate::{
+    camera::{Camera, CameraInfo},
+    error::{check, Error, Result},
+    eth,
+    observer::call,
+};
+
+/// Filter of the packets a network adapter passes to the operating system.
+///
+/// ARP and ICMP (ping) packets are always passed.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PacketFilter {
+    /// Pass all packets.
+    PassAll,
+
+    /// Block the image packets of GigE cameras (_recommended_).
+    #[default]
+    BlockCameraData,
+
+    /// Block all packets.
+    BlockAll,
+}
+
+impl PacketFilter {
+    /// Every packet filter.
+    pub const ALL: [PacketFilter; 3] =
+        [PacketFilter::PassAll, PacketFilter::BlockCameraData, PacketFilter::BlockAll];
+}
+
+impl From<PacketFilter> for UEYE_ETH_PACKETFILTER_SETUP {
+    #[inline]
+    fn from(filter: PacketFilter) -> Self {
+        match filter {
+            PacketFilter::PassAll => UEYE_ETH_PACKETFILTER_SETUP::IS_ETH_PCKTFLT_PASSALL,
+            PacketFilter::BlockCameraData => UEYE_ETH_PACKETFILTER_SETUP::IS_ETH_PCKTFLT_BLOCKUEGET,
+            PacketFilter::BlockAll => UEYE_ETH_PACKETFILTER_SETUP::IS_ETH_PCKTFLT_BLOCKALL,
+        }
+    }
+}
+
+impl TryFrom<u16> for PacketFilter {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self> {
+        PacketFilter::ALL
+            .into_iter()
+            .find(|&filter| UEYE_ETH_PACKETFILTER_SETUP::from(filter) as u16 == value)
+            .ok_or(Error::UnexpectedValue(value as INT))
+    }
+}
+
+impl Display for PacketFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PacketFilter::PassAll => "pass all",
+            PacketFilter::BlockCameraData => "block camera data",
+            PacketFilter::BlockAll => "block all",
+        })
+    }
+}
+
+/// Link speed of a network adapter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinkSpeed {
+    /// 100 Mbit/s.
+    Mb100,
+
+    /// 1000 Mbit/s.
+    Mb1000,
+}
+
+impl LinkSpeed {
+    /// Bandwidth of the link, in Mbit/s.
+    #[inline]
+    pub const fn megabits(self) -> u32 {
+        match self {
+            LinkSpeed::Mb100 => 100,
+            LinkSpeed::Mb1000 => 1000,
+        }
+    }
+}
+
+impl TryFrom<u32> for LinkSpeed {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            100 => Ok(LinkSpeed::Mb100),
+            1000 => Ok(LinkSpeed::Mb1000),
+            _ => Err(Error::UnexpectedValue(value as INT)),
+        }
+    }
+}
+
+impl Display for LinkSpeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Mbit/s", self.megabits())
+    }
+}
+
+/// ID of a network adapter, as defined internally by the driver.
+///
+/// Only an [`AdapterConfig`] query yields adapter IDs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AdapterId(DWORD);
+
+impl AdapterId {
+    /// Raw ID of the adapter.
+    #[inline]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// Set the packet filter of network adapter `adapter`.
+pub fn set_packet_filter(adapter: AdapterId, filter: PacketFilter) -> Result<()> {
+    check(unsafe { call!(is_SetPacketFilter(adapter.0 as INT, filter.into())) })
+}
+
+/// Configuration of the network adapter a GigE camera is connected to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AdapterConfig {
+    /// ID of the adapter.
+    pub id: AdapterId,
+
+    /// Link speed set up for the camera.
+    pub link_speed: LinkSpeed,
+
+    /// Whether the adapter is configured for DHCP.
+    pub dhcp: bool,
+
+    /// Number of cameras detected at the adapter.
+    pub devices_known: u32,
+
+    /// Number of cameras initialized through the adapter.
+    pub devices_paired: u32,
+
+    /// Filter of incoming packets.
+    pub packet_filter: PacketFilter,
+}
+
+impl AdapterConfig {
+    /// Query the network adapter of `camera`.
+    ///
+    /// Fails with [`Error::NotSupported`] for cameras other than GigE cameras.
+    #[inline]
+    pub fn query(camera: &Camera) -> Result<Self> {
+        Self::query_handle(camera.handle())
+    }
+
+    /// Query the network adapter of the camera listed as `info`, without opening it.
+    ///
+    /// Fails with [`Error::NotSupported`] for cameras other than GigE cameras.
+    #[inline]
+    pub fn query_device(info: &CameraInfo) -> Result<Self> {
+        Self::query_handle(info.device_id as HIDS | IS_USE_DEVICE_ID)
+    }
+
+    fn query_handle(hcam: HIDS) -> Result<Self> {
+        let info = eth::device_info(hcam)?;
+
+        // fields are read one by one, as raw values for the enumerations, since the driver may
+        // report values which they lack
+        let adapter = unsafe { ptr::addr_of!((*info.as_ptr()).infoAdapter) };
+        let (id, link_speed, dhcp, devices_known, devices_paired, packet_filter) = unsafe {
+            (
+                ptr::addr_of!((*adapter).dwAdapterID).read_unaligned(),
+                ptr::addr_of!((*adapter).dwDeviceLinkspeed).cast::<u32>().read_unaligned(),
+                ptr::addr_of!((*adapter).bIsEnabledDHCP).read_unaligned(),
+                ptr::addr_of!((*adapter).dwCntDevicesKnown).read_unaligned(),
+                ptr::addr_of!((*adapter).dwCntDevicesPaired).read_unaligned(),
+                ptr::addr_of!((*adapter).wPacketFilter).cast::<u16>().read_unaligned(),
+            )
+        };
+
+        Ok(Self {
+            id: AdapterId(id),
+            link_speed: LinkSpeed::try_from(link_speed)?,
+            dhcp: dhcp != 0,
+            devices_known,
+            devices_paired,
+            packet_filter: PacketFilter::try_from(packet_filter)?,
+        })
+    }
+
+    /// Set the packet filter of the adapter.
+    pub fn set_packet_filter(&mut self, filter: PacketFilter) -> Result<()> {
+        set_packet_filter(self.id, filter)?;
+        self.packet_filter = filter;
+        Ok(())
+    }
+}
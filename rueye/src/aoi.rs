@@ -0,0 +1,423 @@
+//! Area of interest (AOI).
+//!
+//! The position and size of an AOI must be multiples of the increments of the sensor and lie
+//! within its limits. [`AoiLimits`] computes valid AOIs from them, e.g. to center an AOI or to fit
+//! an aspect ratio, and [`AoiBuilder`] snaps a requested AOI to them before setting it.
+//!
+//! # Documentation
+//! [is_AOI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_aoi.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    aoi::{is_AOI, IS_AOI_CMD},
+    types::{void, IS_POINT_2D, IS_RECT, IS_SIZE_2D, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Area of interest, in pixels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aoi {
+    /// Horizontal position of the top left corner.
+    pub x: i32,
+
+    /// Vertical position of the top left corner.
+    pub y: i32,
+
+    /// Width of the area.
+    pub width: i32,
+
+    /// Height of the area.
+    pub height: i32,
+}
+
+impl From<IS_RECT> for Aoi {
+    #[inline]
+    fn from(rect: IS_RECT) -> Self {
+        Self { x: rect.s32X, y: rect.s32Y, width: rect.s32Width, height: rect.s32Height }
+    }
+}
+
+impl From<Aoi> for IS_RECT {
+    #[inline]
+    fn from(aoi: Aoi) -> Self {
+        Self { s32X: aoi.x, s32Y: aoi.y, s32Width: aoi.width, s32Height: aoi.height }
+    }
+}
+
+/// Query the current image AOI.
+pub fn current(camera: &Camera) -> Result<IS_RECT> {
+    let mut rect = IS_RECT { s32X: 0, s32Y: 0, s32Width: 0, s32Height: 0 };
+    check(unsafe {
+        command!(is_AOI(
+            camera.handle(),
+            IS_AOI_CMD::IS_AOI_IMAGE_GET_AOI,
+            &mut rect as *mut IS_RECT as *mut void,
+            size_of::<IS_RECT>() as UINT,
+        ))
+    })?;
+
+    Ok(rect)
+}
+
+/// Set the image AOI.
+///
+/// The position and size must match the increments of the sensor.
+pub fn set(camera: &Camera, aoi: &Aoi) -> Result<()> {
+    let mut rect = IS_RECT::from(*aoi);
+    check(unsafe {
+        command!(is_AOI(
+            camera.handle(),
+            IS_AOI_CMD::IS_AOI_IMAGE_SET_AOI,
+            &mut rect as *mut IS_RECT as *mut void,
+            size_of::<IS_RECT>() as UINT,
+        ))
+    })
+}
+
+/// Query an AOI property of the size type with `command`.
+fn query_size(camera: &Camera, command: IS_AOI_CMD) -> Result<IS_SIZE_2D> {
+    let mut size = IS_SIZE_2D { s32Width: 0, s32Height: 0 };
+    check(unsafe {
+        command!(is_AOI(
+            camera.handle(),
+            command,
+            &mut size as *mut IS_SIZE_2D as *mut void,
+            size_of::<IS_SIZE_2D>() as UINT,
+        ))
+    })?;
+
+    Ok(size)
+}
+
+/// Query an AOI property of the point type with `command`.
+fn query_point(camera: &Camera, command: IS_AOI_CMD) -> Result<IS_POINT_2D> {
+    let mut point = IS_POINT_2D { s32X: 0, s32Y: 0 };
+    check(unsafe {
+        command!(is_AOI(
+            camera.handle(),
+            command,
+            &mut point as *mut IS_POINT_2D as *mut void,
+            size_of::<IS_POINT_2D>() as UINT,
+        ))
+    })?;
+
+    Ok(point)
+}
+
+/// Limits and increments of the AOI of a sensor, in pixels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AoiLimits {
+    /// Smallest width and height.
+    pub min_size: (i32, i32),
+
+    /// Largest width and height, the size of the sensor.
+    pub max_size: (i32, i32),
+
+    /// Increment of the width and height.
+    pub size_increment: (i32, i32),
+
+    /// Increment of the position.
+    pub position_increment: (i32, i32),
+}
+
+/// Round `value` down to a multiple of `increment`.
+#[inline]
+const fn round_down(value: i32, increment: i32) -> i32 {
+    if increment > 1 {
+        value - value.rem_euclid(increment)
+    } else {
+        value
+    }
+}
+
+/// Round `value` up to a multiple of `increment`, or `None` on overflow.
+#[inline]
+const fn round_up(value: i32, increment: i32) -> Option<i32> {
+    if increment > 1 && value.rem_euclid(increment) != 0 {
+        value.checked_add(increment - value.rem_euclid(increment))
+    } else {
+        Some(value)
+    }
+}
+
+/// Multiple of `increment` within `min`…`max` closest to `value`, rounded down.
+///
+/// Fails with [`Error::InvalidParameter`] if no multiple of `increment` lies within the limits.
+fn fit(value: i32, min: i32, max: i32, increment: i32) -> Result<i32> {
+    let low = round_up(min, increment).ok_or(Error::InvalidParameter)?;
+    let high = round_down(max, increment);
+    if low > high {
+        return Err(Error::InvalidParameter);
+    }
+
+    Ok(round_down(value.clamp(low, high), increment))
+}
+
+impl AoiLimits {
+    /// Query the AOI limits of the sensor with its current binning and subsampling.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        let min = query_size(camera, IS_AOI_CMD::IS_AOI_IMAGE_GET_SIZE_MIN)?;
+        let max = query_size(camera, IS_AOI_CMD::IS_AOI_IMAGE_GET_SIZE_MAX)?;
+        let size_inc = query_size(camera, IS_AOI_CMD::IS_AOI_IMAGE_GET_SIZE_INC)?;
+        let pos_inc = query_point(camera, IS_AOI_CMD::IS_AOI_IMAGE_GET_POS_INC)?;
+
+        Ok(Self {
+            min_size: (min.s32Width, min.s32Height),
+            max_size: (max.s32Width, max.s32Height),
+            size_increment: (size_inc.s32Width, size_inc.s32Height),
+            position_increment: (pos_inc.s32X, pos_inc.s32Y),
+        })
+    }
+
+    /// Valid size closest to `width` × `height`, rounded down to the increments and clamped to
+    /// the largest and smallest multiples of the increments within the limits.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if no multiple of the increments lies within the
+    /// limits, e.g. if the smallest size exceeds the largest one.
+    pub fn fit_size(&self, width: i32, height: i32) -> Result<(i32, i32)> {
+        Ok((
+            fit(width, self.min_size.0, self.max_size.0, self.size_increment.0)?,
+            fit(height, self.min_size.1, self.max_size.1, self.size_increment.1)?,
+        ))
+    }
+
+    /// Valid AOI of about `width` × `height`, centered on the sensor.
+    ///
+    /// The size is fitted with [`AoiLimits::fit_size`], and the position rounded down to the
+    /// increments, so the AOI may be off center by less than one increment.
+    pub fn centered(&self, width: i32, height: i32) -> Result<Aoi> {
+        let (width, height) = self.fit_size(width, height)?;
+
+        Ok(Aoi {
+            x: round_down((self.max_size.0 - width) / 2, self.position_increment.0),
+            y: round_down((self.max_size.1 - height) / 2, self.position_increment.1),
+            width,
+            height,
+        })
+    }
+
+    /// Valid AOI closest to `aoi`.
+    ///
+    /// The size is fitted with [`AoiLimits::fit_size`], then the position is clamped so that the
+    /// AOI lies on the sensor and rounded down to the increments.
+    pub fn snap(&self, aoi: &Aoi) -> Result<Aoi> {
+        let (width, height) = self.fit_size(aoi.width, aoi.height)?;
+
+        Ok(Aoi {
+            x: round_down(aoi.x.clamp(0, self.max_size.0 - width), self.position_increment.0),
+            y: round_down(aoi.y.clamp(0, self.max_size.1 - height), self.position_increment.1),
+            width,
+            height,
+        })
+    }
+
+    /// Whether `aoi` lies on the sensor and matches the limits and increments.
+    #[inline]
+    pub fn is_valid(&self, aoi: &Aoi) -> bool {
+        self.snap(aoi).is_ok_and(|snapped| snapped == *aoi)
+    }
+
+    /// Largest valid AOI with the aspect ratio `ratio` (width / height), centered on the sensor.
+    ///
+    /// The aspect ratio of the AOI deviates from `ratio` by at most the increments. Fails with
+    /// [`Error::InvalidParameter`] if `ratio` is not a positive number.
+    pub fn with_aspect(&self, ratio: f64) -> Result<Aoi> {
+        if !(ratio.is_finite() && ratio > 0.0) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let (max_width, max_height) = self.max_size;
+        let (width, height) = if f64::from(max_width) / f64::from(max_height) > ratio {
+            ((f64::from(max_height) * ratio) as i32, max_height)
+        } else {
+            (max_width, (f64::from(max_width) / ratio) as i32)
+        };
+
+        self.centered(width, height)
+    }
+}
+
+/// Builder of an AOI that is checked against the [limits](AoiLimits) of the sensor when
+/// applied.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AoiBuilder {
+    aoi: Aoi,
+    strict: bool,
+}
+
+impl AoiBuilder {
+    /// AOI of `width` × `height` at the top left corner of the sensor.
+    #[inline]
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self { aoi: Aoi { x: 0, y: 0, width, height }, strict: false }
+    }
+
+    /// Set the position of the top left corner.
+    #[inline]
+    pub const fn position(mut self, x: i32, y: i32) -> Self {
+        self.aoi.x = x;
+        self.aoi.y = y;
+        self
+    }
+
+    /// Set the size.
+    #[inline]
+    pub const fn size(mut self, width: i32, height: i32) -> Self {
+        self.aoi.width = width;
+        self.aoi.height = height;
+        self
+    }
+
+    /// Fail with [`Error::InvalidParameter`] instead of snapping an invalid AOI to the closest
+    /// valid one.
+    #[inline]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Valid AOI for `limits`, see [`AoiLimits::snap`].
+    pub fn build(&self, limits: &AoiLimits) -> Result<Aoi> {
+        let aoi = limits.snap(&self.aoi)?;
+        if self.strict && aoi != self.aoi {
+            return Err(Error::InvalidParameter);
+        }
+
+        Ok(aoi)
+    }
+
+    /// Query the limits of the sensor and set the built AOI in a single call, returning the AOI
+    /// in effect afterwards.
+    pub fn apply(&self, camera: &Camera) -> Result<IS_RECT> {
+        let aoi = self.build(&AoiLimits::query(camera)?)?;
+        set(camera, &aoi)?;
+        current(camera)
+    }
+}
+
+impl From<Aoi> for AoiBuilder {
+    #[inline]
+    fn from(aoi: Aoi) -> Self {
+        Self { aoi, strict: false }
+    }
+}
+
+impl Camera {
+    /// Set an AOI of about `width` × `height`, centered on the sensor, returning the AOI set.
+    ///
+    /// See [`AoiLimits::centered`].
+    pub fn center_aoi(&self, width: i32, height: i32) -> Result<Aoi> {
+        let aoi = AoiLimits::query(self)?.centered(width, height)?;
+        set(self, &aoi)?;
+        Ok(aoi)
+    }
+
+    /// Set the largest AOI with the aspect ratio `ratio` (width / height), centered on the sensor,
+    /// returning the AOI set.
+    ///
+    /// See [`AoiLimits::with_aspect`].
+    pub fn aoi_with_aspect(&self, ratio: f64) -> Result<Aoi> {
+        let aoi = AoiLimits::query(self)?.with_aspect(ratio)?;
+        set(self, &aoi)?;
+        Ok(aoi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Limits of a 1280 × 1024 sensor with size increments that do not divide the minimum.
+    const LIMITS: AoiLimits = AoiLimits {
+        min_size: (30, 6),
+        max_size: (1280, 1024),
+        size_increment: (8, 4),
+        position_increment: (4, 2),
+    };
+
+    #[test]
+    fn fit_size_rounds_to_increments() {
+        assert_eq!(LIMITS.fit_size(645, 483), Ok((640, 480)));
+        assert_eq!(LIMITS.fit_size(1280, 1024), Ok((1280, 1024)));
+        assert_eq!(LIMITS.fit_size(2000, 2000), Ok((1280, 1024)));
+    }
+
+    #[test]
+    fn fit_size_stays_within_limits() {
+        // the smallest multiples of the increments at least the minimum
+        assert_eq!(LIMITS.fit_size(0, 0), Ok((32, 8)));
+        assert_eq!(LIMITS.fit_size(31, 7), Ok((32, 8)));
+
+        // the largest multiples of the increments at most the maximum
+        let limits = AoiLimits { max_size: (1277, 1023), ..LIMITS };
+        assert_eq!(limits.fit_size(1280, 1024), Ok((1272, 1020)));
+        assert_eq!(limits.fit_size(1277, 1023), Ok((1272, 1020)));
+    }
+
+    #[test]
+    fn fit_size_rejects_empty_limits() {
+        let inverted = AoiLimits { min_size: (100, 6), max_size: (50, 1024), ..LIMITS };
+        assert_eq!(inverted.fit_size(64, 64), Err(Error::InvalidParameter));
+
+        let between = AoiLimits { min_size: (33, 6), max_size: (39, 1024), ..LIMITS };
+        assert_eq!(between.fit_size(36, 64), Err(Error::InvalidParameter));
+
+        let overflow = AoiLimits { min_size: (i32::MAX, 6), max_size: (i32::MAX, 1024), ..LIMITS };
+        assert_eq!(overflow.fit_size(64, 64), Err(Error::InvalidParameter));
+    }
+
+    #[test]
+    fn snap_clamps_to_sensor() {
+        let aoi = Aoi { x: 13, y: 7, width: 645, height: 483 };
+        assert_eq!(LIMITS.snap(&aoi), Ok(Aoi { x: 12, y: 6, width: 640, height: 480 }));
+
+        let aoi = Aoi { x: 1000, y: -5, width: 640, height: 480 };
+        assert_eq!(LIMITS.snap(&aoi), Ok(Aoi { x: 640, y: 0, width: 640, height: 480 }));
+
+        let limits = AoiLimits { max_size: (1277, 1023), ..LIMITS };
+        let aoi = Aoi { x: 100, y: 100, width: 1280, height: 1024 };
+        assert_eq!(limits.snap(&aoi), Ok(Aoi { x: 4, y: 2, width: 1272, height: 1020 }));
+
+        let inverted = AoiLimits { min_size: (100, 6), max_size: (50, 1024), ..LIMITS };
+        assert_eq!(inverted.snap(&aoi), Err(Error::InvalidParameter));
+    }
+
+    #[test]
+    fn is_valid_matches_snap() {
+        assert!(LIMITS.is_valid(&Aoi { x: 12, y: 6, width: 640, height: 480 }));
+        assert!(!LIMITS.is_valid(&Aoi { x: 13, y: 6, width: 640, height: 480 }));
+        assert!(!LIMITS.is_valid(&Aoi { x: 0, y: 0, width: 30, height: 8 }));
+    }
+
+    #[test]
+    fn centered_on_sensor() {
+        assert_eq!(LIMITS.centered(640, 480), Ok(Aoi { x: 320, y: 272, width: 640, height: 480 }));
+
+        // off center by less than one position increment
+        assert_eq!(LIMITS.centered(632, 476), Ok(Aoi { x: 324, y: 274, width: 632, height: 476 }));
+    }
+
+    #[test]
+    fn with_aspect_fits_sensor() {
+        // wider than the sensor: full width
+        assert_eq!(
+            LIMITS.with_aspect(16.0 / 9.0),
+            Ok(Aoi { x: 0, y: 152, width: 1280, height: 720 })
+        );
+
+        // narrower than the sensor: full height
+        assert_eq!(LIMITS.with_aspect(1.0), Ok(Aoi { x: 128, y: 0, width: 1024, height: 1024 }));
+
+        for ratio in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(LIMITS.with_aspect(ratio), Err(Error::InvalidParameter));
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! In-camera automatic controls.
+//!
+//! [`AutoControl`] switches auto exposure, auto gain, auto white balance and auto frame rate in
+//! one go, together with the brightness reference, speed and hysteresis shared by the controls.
+//! The driver does not report when the controls reached their target, so
+//! [`AutoControl::wait_settled`] polls the exposure time and gains until they stop changing.
+//!
+//! For control on the host side, e.g. in triggered operation, see
+//! [`BrightnessController`](crate::brightness_control::BrightnessController).
+//!
+//! # Documentation
+//! [is_SetAutoParameter](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setautoparameter.html)
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use ueye_sys::{
+    auto_parameter::{
+        is_SetAutoParameter, IS_GET_AUTO_HYSTERESIS, IS_GET_AUTO_REFERENCE, IS_GET_AUTO_SPEED,
+        IS_GET_ENABLE_AUTO_FRAMERATE, IS_GET_ENABLE_AUTO_GAIN, IS_GET_ENABLE_AUTO_SHUTTER,
+        IS_GET_ENABLE_AUTO_WHITEBALANCE, IS_SET_AUTO_HYSTERESIS, IS_SET_AUTO_REFERENCE,
+        IS_SET_AUTO_SPEED, IS_SET_ENABLE_AUTO_FRAMERATE, IS_SET_ENABLE_AUTO_GAIN,
+        IS_SET_ENABLE_AUTO_SHUTTER, IS_SET_ENABLE_AUTO_WHITEBALANCE,
+    },
+    gain::{
+        is_SetHardwareGain, IS_GET_BLUE_GAIN, IS_GET_GREEN_GAIN, IS_GET_RED_GAIN,
+        IS_IGNORE_PARAMETER,
+    },
+    types::{double, INT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    exposure, gain,
+    observer::call,
+};
+
+/// Time between two samples in [`AutoControl::wait_settled`].
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Number of consecutive unchanged samples after which the controls are considered settled.
+const SETTLED_SAMPLES: usize = 5;
+
+/// Switches and parameters of the automatic controls.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoControl {
+    /// Auto exposure shutter.
+    pub exposure: bool,
+
+    /// Auto gain.
+    pub gain: bool,
+
+    /// Auto white balance.
+    pub white_balance: bool,
+
+    /// Auto frame rate, adapting the frame rate to the exposure time.
+    pub frame_rate: bool,
+
+    /// Brightness reference value (`0`…`255`), or [`None`] to leave it unchanged.
+    pub reference: Option<f64>,
+
+    /// Control speed (`0`…`100`), or [`None`] to leave it unchanged.
+    pub speed: Option<f64>,
+
+    /// Deviation from the reference tolerated without adjustment, or [`None`] to leave it
+    /// unchanged.
+    pub hysteresis: Option<f64>,
+}
+
+impl AutoControl {
+    /// All controls disabled, leaving the parameters unchanged.
+    pub const OFF: Self = Self {
+        exposure: false,
+        gain: false,
+        white_balance: false,
+        frame_rate: false,
+        reference: None,
+        speed: None,
+        hysteresis: None,
+    };
+
+    /// Auto exposure and auto gain enabled, controlling the image brightness.
+    pub const BRIGHTNESS: Self = Self { exposure: true, gain: true, ..Self::OFF };
+
+    /// Set the brightness reference value (`0`…`255`).
+    #[inline]
+    pub const fn reference(mut self, reference: f64) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+
+    /// Set the control speed (`0`…`100`).
+    #[inline]
+    pub const fn speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Set the hysteresis.
+    #[inline]
+    pub const fn hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Query the switches and parameters of the camera.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        Ok(Self {
+            exposure: get(camera, IS_GET_ENABLE_AUTO_SHUTTER)? != 0.0,
+            gain: get(camera, IS_GET_ENABLE_AUTO_GAIN)? != 0.0,
+            white_balance: get(camera, IS_GET_ENABLE_AUTO_WHITEBALANCE)? != 0.0,
+            frame_rate: get(camera, IS_GET_ENABLE_AUTO_FRAMERATE)? != 0.0,
+            reference: Some(get(camera, IS_GET_AUTO_REFERENCE)?),
+            speed: Some(get(camera, IS_GET_AUTO_SPEED)?),
+            hysteresis: Some(get(camera, IS_GET_AUTO_HYSTERESIS)?),
+        })
+    }
+
+    /// Apply the parameters, then the switches, to `camera`.
+    pub fn apply(&self, camera: &Camera) -> Result<()> {
+        let parameters = [
+            (IS_SET_AUTO_REFERENCE, self.reference),
+            (IS_SET_AUTO_SPEED, self.speed),
+            (IS_SET_AUTO_HYSTERESIS, self.hysteresis),
+        ];
+        for (param, value) in parameters {
+            if let Some(value) = value {
+                set(camera, param, value)?;
+            }
+        }
+
+        let switches = [
+            (IS_SET_ENABLE_AUTO_SHUTTER, self.exposure),
+            (IS_SET_ENABLE_AUTO_GAIN, self.gain),
+            (IS_SET_ENABLE_AUTO_WHITEBALANCE, self.white_balance),
+            (IS_SET_ENABLE_AUTO_FRAMERATE, self.frame_rate),
+        ];
+        for (param, enable) in switches {
+            set(camera, param, if enable { 1.0 } else { 0.0 })?;
+        }
+
+        Ok(())
+    }
+
+    /// Disable all controls of `camera`.
+    #[inline]
+    pub fn disable(camera: &Camera) -> Result<()> {
+        Self::OFF.apply(camera)
+    }
+
+    /// Wait until the exposure time and gains of `camera` stopped changing.
+    ///
+    /// The camera must be capturing for the controls to act. Fails with [`Error::TimedOut`] if
+    /// the controls did not settle within `timeout`.
+    pub fn wait_settled(camera: &Camera, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        let mut last = Settings::sample(camera)?;
+        let mut unchanged = 0;
+
+        while unchanged < SETTLED_SAMPLES {
+            if Instant::now() + POLL_INTERVAL > deadline {
+                return Err(Error::TimedOut);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+            let settings = Settings::sample(camera)?;
+            unchanged = if settings == last { unchanged + 1 } else { 0 };
+            last = settings;
+        }
+
+        Ok(())
+    }
+}
+
+/// Settings changed by the controls, sampled to detect when they settled.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Settings {
+    exposure: f64,
+    gains: [INT; 4],
+}
+
+impl Settings {
+    fn sample(camera: &Camera) -> Result<Self> {
+        Ok(Self {
+            exposure: exposure::current(camera)?,
+            gains: [
+                gain::current(camera)?,
+                channel_gain(camera, IS_GET_RED_GAIN)?,
+                channel_gain(camera, IS_GET_GREEN_GAIN)?,
+                channel_gain(camera, IS_GET_BLUE_GAIN)?,
+            ],
+        })
+    }
+}
+
+fn channel_gain(camera: &Camera, param: INT) -> Result<INT> {
+    match unsafe {
+        call!(is_SetHardwareGain(
+            camera.handle(),
+            param,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+        ))
+    } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
+
+fn get(camera: &Camera, param: INT) -> Result<f64> {
+    let (mut value, mut unused): (double, double) = (0.0, 0.0);
+    check(unsafe { call!(is_SetAutoParameter(camera.handle(), param, &mut value, &mut unused)) })?;
+
+    Ok(value)
+}
+
+fn set(camera: &Camera, param: INT, value: f64) -> Result<()> {
+    let (mut value, mut unused): (double, double) = (value, 0.0);
+    check(unsafe { call!(is_SetAutoParameter(camera.handle(), param, &mut value, &mut unused)) })
+}
@@ -0,0 +1,230 @@
+//! Recording of frames into AVI files.
+//!
+//! A [`VideoRecorder`] compresses frames as Motion JPEG into an AVI file, using the uEye tools
+//! library. The image format of the file is taken from the first frame added, so all frames must
+//! share its geometry; frames arriving while the previous one is still being compressed are
+//! dropped and [counted](VideoRecorder::dropped_frames). The file is finalized when the recorder
+//! is [finished](VideoRecorder::finish) or dropped.
+//!
+//! # Documentation
+//! [AVI functions](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_avi_funktionen.html)
+
+use std::{ffi::CString, io::ErrorKind, marker::PhantomData, path::Path};
+
+use ueye_sys::{
+    avi::{
+        isavi_AddFrame, isavi_CloseAVI, isavi_ExitAVI, isavi_GetAVISize,
+        isavi_GetnCompressedFrames, isavi_GetnLostFrames, isavi_InitAVI, isavi_OpenAVI,
+        isavi_ResetFrameCounters, isavi_SetFrameRate, isavi_SetImageQuality, isavi_SetImageSize,
+        isavi_StartAVI, isavi_StopAVI, IS_AVI_CM_RGB24, IS_AVI_CM_RGB32, IS_AVI_CM_Y8,
+        IS_AVI_ERR_ALLOC_MEMORY, IS_AVI_ERR_CREATE_STREAM, IS_AVI_ERR_INVALID_CM,
+        IS_AVI_ERR_INVALID_FILE, IS_AVI_ERR_INVALID_POSITION, IS_AVI_ERR_INVALID_SIZE,
+        IS_AVI_ERR_INVALID_VALUE, IS_AVI_ERR_NEW_FILE, IS_AVI_ERR_NO_CODEC_AVAIL,
+        IS_AVI_ERR_PARAMETER, IS_AVI_ERR_WRITE_INFO, IS_AVI_NO_ERR,
+    },
+    types::{char, float, long, INT, ULONG},
+};
+
+use crate::{
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{Error, Result},
+    frame::Frame,
+    observer::call,
+};
+
+/// Configuration of a [`VideoRecorder`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VideoOptions {
+    /// Color mode of the frames: [`ColorMode::Mono8`], [`ColorMode::Bgr8Packed`] or
+    /// [`ColorMode::Bgra8Packed`].
+    pub color_mode: ColorMode,
+
+    /// JPEG quality of the compression (`1`…`100`).
+    pub quality: INT,
+
+    /// Frame rate the file is played back at, in frames per second.
+    pub frame_rate: f64,
+}
+
+impl Default for VideoOptions {
+    fn default() -> Self {
+        Self { color_mode: ColorMode::Mono8, quality: 75, frame_rate: 25.0 }
+    }
+}
+
+/// Convert a return value of the uEye tools library into a [`Result`].
+fn check_avi(code: INT) -> Result<()> {
+    match code {
+        IS_AVI_NO_ERR => Ok(()),
+        IS_AVI_ERR_PARAMETER
+        | IS_AVI_ERR_INVALID_VALUE
+        | IS_AVI_ERR_INVALID_CM
+        | IS_AVI_ERR_INVALID_SIZE
+        | IS_AVI_ERR_INVALID_POSITION => Err(Error::InvalidParameter),
+        IS_AVI_ERR_NO_CODEC_AVAIL => Err(Error::NotSupported),
+        IS_AVI_ERR_ALLOC_MEMORY => Err(Error::OutOfMemory),
+        IS_AVI_ERR_INVALID_FILE => Err(Error::Io(ErrorKind::InvalidInput)),
+        IS_AVI_ERR_NEW_FILE | IS_AVI_ERR_CREATE_STREAM | IS_AVI_ERR_WRITE_INFO => {
+            Err(Error::Io(ErrorKind::Other))
+        },
+        code => Err(Error::Unknown(code)),
+    }
+}
+
+/// Geometry of the frames of a recording.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Geometry {
+    width: usize,
+    height: usize,
+    pitch: usize,
+}
+
+/// Recording of frames into an AVI file, finalized when dropped.
+#[derive(Debug)]
+pub struct VideoRecorder<'c> {
+    id: INT,
+    path: CString,
+    options: VideoOptions,
+    geometry: Option<Geometry>,
+    released: bool,
+    _camera: PhantomData<&'c Camera>,
+}
+
+impl<'c> VideoRecorder<'c> {
+    /// Prepare a recording of frames of `camera` into the AVI file at `path`.
+    ///
+    /// The file is created along with the first frame added. Fails with [`Error::Io`] if `path`
+    /// is not valid UTF-8, and with [`Error::InvalidParameter`] if the color mode cannot be
+    /// recorded.
+    pub fn create(
+        camera: &'c Camera,
+        path: impl AsRef<Path>,
+        options: &VideoOptions,
+    ) -> Result<Self> {
+        avi_color_mode(options.color_mode)?;
+        let path = path
+            .as_ref()
+            .to_str()
+            .and_then(|path| CString::new(path).ok())
+            .ok_or(Error::Io(ErrorKind::InvalidInput))?;
+
+        let mut id: INT = 0;
+        check_avi(unsafe { call!(isavi_InitAVI(&mut id, camera.handle())) })?;
+
+        // from here on, `Drop` releases the instance
+        let recorder = Self {
+            id,
+            path,
+            options: *options,
+            geometry: None,
+            released: false,
+            _camera: PhantomData,
+        };
+        check_avi(unsafe { call!(isavi_SetImageQuality(recorder.id, options.quality)) })?;
+
+        Ok(recorder)
+    }
+
+    /// Create the file in the format of `frame` and start recording.
+    fn start(&self, frame: &Frame<'_>) -> Result<Geometry> {
+        let mode = avi_color_mode(self.options.color_mode)?;
+        let line = self.options.color_mode.line_bytes(frame.width());
+        let [width, height, padding] = [frame.width(), frame.height(), frame.pitch() - line]
+            .map(|value| long::try_from(value).map_err(|_| Error::InvalidParameter));
+
+        check_avi(unsafe {
+            call!(isavi_SetImageSize(self.id, mode, width?, height?, 0, 0, padding?))
+        })?;
+        check_avi(unsafe { call!(isavi_OpenAVI(self.id, self.path.as_ptr())) })?;
+        check_avi(unsafe { call!(isavi_SetFrameRate(self.id, self.options.frame_rate)) })?;
+        check_avi(unsafe { call!(isavi_StartAVI(self.id)) })?;
+
+        Ok(Geometry { width: frame.width(), height: frame.height(), pitch: frame.pitch() })
+    }
+
+    /// Compress `frame` and add it to the file, e.g. a frame of the image memory last captured
+    /// into.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `frame` does not match the color mode of the
+    /// recording or the geometry of the first frame.
+    pub fn add_frame(&mut self, frame: &Frame<'_>) -> Result<()> {
+        if frame.bits_per_pixel() != self.options.color_mode.bits_per_pixel() {
+            return Err(Error::InvalidParameter);
+        }
+
+        let geometry =
+            Geometry { width: frame.width(), height: frame.height(), pitch: frame.pitch() };
+        match self.geometry {
+            Some(recorded) if recorded != geometry => return Err(Error::InvalidParameter),
+            Some(_) => {},
+            None => self.geometry = Some(self.start(frame)?),
+        }
+
+        // the frame is only read, and copied before the call returns
+        let data = frame.as_bytes().as_ptr() as *mut char;
+        check_avi(unsafe { call!(isavi_AddFrame(self.id, data)) })
+    }
+
+    /// Number of frames added to the file.
+    pub fn compressed_frames(&self) -> Result<u64> {
+        let mut frames: ULONG = 0;
+        check_avi(unsafe { call!(isavi_GetnCompressedFrames(self.id, &mut frames)) })?;
+        Ok(frames as u64)
+    }
+
+    /// Number of frames dropped because the previous frame was still being compressed.
+    pub fn dropped_frames(&self) -> Result<u64> {
+        let mut frames: ULONG = 0;
+        check_avi(unsafe { call!(isavi_GetnLostFrames(self.id, &mut frames)) })?;
+        Ok(frames as u64)
+    }
+
+    /// Reset the counters of compressed and dropped frames.
+    pub fn reset_counters(&self) -> Result<()> {
+        check_avi(unsafe { call!(isavi_ResetFrameCounters(self.id)) })
+    }
+
+    /// Size of the file written so far, in bytes.
+    pub fn file_size(&self) -> Result<u64> {
+        let mut size: float = 0.0;
+        check_avi(unsafe { call!(isavi_GetAVISize(self.id, &mut size)) })?;
+        Ok((size as f64 * 1024.0) as u64)
+    }
+
+    /// Stop recording and finalize the file, reporting failures that dropping would ignore.
+    #[inline]
+    pub fn finish(mut self) -> Result<()> {
+        self.close()
+    }
+
+    /// Stop recording, close the file and release the instance.
+    fn close(&mut self) -> Result<()> {
+        let mut result = Ok(());
+        if self.geometry.take().is_some() {
+            result = check_avi(unsafe { call!(isavi_StopAVI(self.id)) })
+                .and(check_avi(unsafe { call!(isavi_CloseAVI(self.id)) }));
+        }
+
+        self.released = true;
+        result.and(check_avi(unsafe { call!(isavi_ExitAVI(self.id)) }))
+    }
+}
+
+impl Drop for VideoRecorder<'_> {
+    fn drop(&mut self) {
+        if !self.released {
+            let _ = self.close();
+        }
+    }
+}
+
+/// Color mode of the uEye tools library for `mode`.
+fn avi_color_mode(mode: ColorMode) -> Result<INT> {
+    match mode {
+        ColorMode::Mono8 => Ok(IS_AVI_CM_Y8),
+        ColorMode::Bgr8Packed => Ok(IS_AVI_CM_RGB24),
+        ColorMode::Bgra8Packed => Ok(IS_AVI_CM_RGB32),
+        _ => Err(Error::InvalidParameter),
+    }
+}
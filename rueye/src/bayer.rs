@@ -0,0 +1,231 @@
+//! Bayer patterns of color sensors.
+//!
+//! The driver reports the color of the top left pixel of the full sensor. An AOI starting at an
+//! odd column or row shifts the phase of the pattern, so raw images of such an AOI start with a
+//! different color; [`pattern`] accounts for the offset of the current AOI.
+//!
+//! The pattern feeds the software [debayering](crate::debayer); the driver conversion of
+//! [`convert`](crate::convert) aligns frames with the sensor pattern by their offset instead.
+//!
+//! # Documentation
+//! [is_GetSensorInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getsensorinfo.html)
+
+use std::fmt::{Display, Formatter};
+
+use ueye_sys::{
+    camera_info::{
+        BAYER_PIXEL_BLUE, BAYER_PIXEL_GREEN, BAYER_PIXEL_RED, IS_COLORMODE_BAYER, SENSORINFO,
+    },
+    types::INT,
+};
+
+use crate::{
+    aoi,
+    camera::Camera,
+    device_info,
+    error::{Error, Result},
+};
+
+/// Color filter of a pixel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BayerColor {
+    /// Red filter, one pixel of each cell.
+    Red,
+
+    /// Green filter, two diagonal pixels of each cell.
+    Green,
+
+    /// Blue filter, one pixel of each cell.
+    Blue,
+}
+
+/// Arrangement of the 2×2 color filter cell, named by its colors in row-major order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BayerPattern {
+    /// Red at the top left, blue at the bottom right.
+    Rggb,
+
+    /// Green at the top left, red to its right and blue below it.
+    Grbg,
+
+    /// Green at the top left, blue to its right and red below it.
+    Gbrg,
+
+    /// Blue at the top left, red at the bottom right.
+    Bggr,
+}
+
+impl BayerPattern {
+    /// Pattern of a sensor, or `None` for sensors without Bayer filter.
+    ///
+    /// The driver only reports the top left color, which does not tell [`Grbg`](Self::Grbg) from
+    /// [`Gbrg`](Self::Gbrg). Fails with [`Error::NotSupported`] for sensors starting with green,
+    /// whose pattern has to be given explicitly.
+    pub fn of_sensor(info: &SENSORINFO) -> Result<Option<Self>> {
+        if info.nColorMode != IS_COLORMODE_BAYER {
+            return Ok(None);
+        }
+
+        match info.nUpperLeftBayerPixel {
+            BAYER_PIXEL_RED => Ok(Some(Self::Rggb)),
+            BAYER_PIXEL_GREEN => Err(Error::NotSupported),
+            BAYER_PIXEL_BLUE => Ok(Some(Self::Bggr)),
+            other => Err(Error::UnexpectedValue(other as INT)),
+        }
+    }
+
+    /// Colors of the 2×2 cell, as `[[top left, top right], [bottom left, bottom right]]`.
+    pub const fn colors(self) -> [[BayerColor; 2]; 2] {
+        use BayerColor::*;
+
+        match self {
+            Self::Rggb => [[Red, Green], [Green, Blue]],
+            Self::Grbg => [[Green, Red], [Blue, Green]],
+            Self::Gbrg => [[Green, Blue], [Red, Green]],
+            Self::Bggr => [[Blue, Green], [Green, Red]],
+        }
+    }
+
+    /// Color of the pixel at `(x, y)`.
+    #[inline]
+    pub const fn color_at(self, x: u32, y: u32) -> BayerColor {
+        self.colors()[(y % 2) as usize][(x % 2) as usize]
+    }
+
+    /// Pattern of an image starting at `(x, y)` of an image with this pattern.
+    ///
+    /// An odd `x` swaps the columns of the cell, an odd `y` swaps its rows.
+    pub const fn shifted(self, x: i32, y: i32) -> Self {
+        let pattern = if x % 2 != 0 {
+            match self {
+                Self::Rggb => Self::Grbg,
+                Self::Grbg => Self::Rggb,
+                Self::Gbrg => Self::Bggr,
+                Self::Bggr => Self::Gbrg,
+            }
+        } else {
+            self
+        };
+
+        if y % 2 != 0 {
+            match pattern {
+                Self::Rggb => Self::Gbrg,
+                Self::Grbg => Self::Bggr,
+                Self::Gbrg => Self::Rggb,
+                Self::Bggr => Self::Grbg,
+            }
+        } else {
+            pattern
+        }
+    }
+
+    /// Pattern of an AOI of a sensor with this pattern.
+    #[inline]
+    pub const fn for_aoi(self, aoi: &aoi::Aoi) -> Self {
+        self.shifted(aoi.x, aoi.y)
+    }
+
+    /// Name of the pattern, e.g. `"RGGB"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rggb => "RGGB",
+            Self::Grbg => "GRBG",
+            Self::Gbrg => "GBRG",
+            Self::Bggr => "BGGR",
+        }
+    }
+}
+
+impl Display for BayerPattern {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Query the Bayer pattern of the sensor, or `None` for sensors without Bayer filter.
+#[inline]
+pub fn sensor_pattern(camera: &Camera) -> Result<Option<BayerPattern>> {
+    BayerPattern::of_sensor(&device_info::sensor_info(camera)?)
+}
+
+/// Query the Bayer pattern of images of the current AOI, or `None` for sensors without Bayer
+/// filter.
+pub fn pattern(camera: &Camera) -> Result<Option<BayerPattern>> {
+    let Some(pattern) = sensor_pattern(camera)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(pattern.for_aoi(&aoi::Aoi::from(aoi::current(camera)?))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [BayerPattern; 4] =
+        [BayerPattern::Rggb, BayerPattern::Grbg, BayerPattern::Gbrg, BayerPattern::Bggr];
+
+    #[test]
+    fn shifted_even_offsets_keep_pattern() {
+        for pattern in ALL {
+            for (x, y) in [(0, 0), (2, 0), (0, 4), (-2, 6), (640, 480)] {
+                assert_eq!(pattern.shifted(x, y), pattern, "{pattern} by ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn shifted_odd_column_swaps_columns() {
+        assert_eq!(BayerPattern::Rggb.shifted(1, 0), BayerPattern::Grbg);
+        assert_eq!(BayerPattern::Grbg.shifted(3, 0), BayerPattern::Rggb);
+        assert_eq!(BayerPattern::Gbrg.shifted(-1, 2), BayerPattern::Bggr);
+        assert_eq!(BayerPattern::Bggr.shifted(5, 2), BayerPattern::Gbrg);
+    }
+
+    #[test]
+    fn shifted_odd_row_swaps_rows() {
+        assert_eq!(BayerPattern::Rggb.shifted(0, 1), BayerPattern::Gbrg);
+        assert_eq!(BayerPattern::Grbg.shifted(2, 3), BayerPattern::Bggr);
+        assert_eq!(BayerPattern::Gbrg.shifted(0, -1), BayerPattern::Rggb);
+        assert_eq!(BayerPattern::Bggr.shifted(4, 7), BayerPattern::Grbg);
+    }
+
+    #[test]
+    fn shifted_matches_colors_at_offset() {
+        for pattern in ALL {
+            for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1), (3, 2), (2, 5)] {
+                let shifted = pattern.shifted(x, y);
+                for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    assert_eq!(
+                        shifted.color_at(dx, dy),
+                        pattern.color_at(x as u32 + dx, y as u32 + dy),
+                        "{pattern} by ({x}, {y}) at ({dx}, {dy})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn for_aoi_uses_offset() {
+        let aoi = aoi::Aoi { x: 101, y: 50, width: 640, height: 480 };
+        assert_eq!(BayerPattern::Rggb.for_aoi(&aoi), BayerPattern::Grbg);
+    }
+
+    #[test]
+    fn of_sensor_rejects_green_first() {
+        let mut info = unsafe { std::mem::zeroed::<SENSORINFO>() };
+        info.nColorMode = IS_COLORMODE_BAYER;
+
+        info.nUpperLeftBayerPixel = BAYER_PIXEL_RED;
+        assert_eq!(BayerPattern::of_sensor(&info), Ok(Some(BayerPattern::Rggb)));
+
+        info.nUpperLeftBayerPixel = BAYER_PIXEL_BLUE;
+        assert_eq!(BayerPattern::of_sensor(&info), Ok(Some(BayerPattern::Bggr)));
+
+        info.nUpperLeftBayerPixel = BAYER_PIXEL_GREEN;
+        assert_eq!(BayerPattern::of_sensor(&info), Err(Error::NotSupported));
+    }
+}
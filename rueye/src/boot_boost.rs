@@ -0,0 +1,171 @@
+//! Boot boost of GigE cameras.
+//!
+//! With boot boost, the driver opens the listed cameras at system start, so that opening them in
+//! the application is faster. The configuration is system-wide: cameras are listed by their
+//! camera ID, and a changed camera ID only takes effect once the camera is reconnected.
+//! [`wait_until_booted`] blocks until all listed cameras applied the configuration.
+//!
+//! # Documentation
+//! * [is_BootBoost](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_bootboost.html)
+//! * [Boot boost](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/cm_addfunc_boot-boost.html)
+
+use std::{mem::size_of, ptr, time::Duration};
+
+use ueye_sys::{
+    boot_boost::{
+        is_BootBoost, IS_BOOTBOOST_CMD, IS_BOOTBOOST_ID, IS_BOOTBOOST_IDLIST_ELEMENTSIZE,
+        IS_BOOTBOOST_IDLIST_HEADERSIZE, IS_BOOTBOOST_ID_MAX, IS_BOOTBOOST_ID_MIN,
+    },
+    types::{void, DWORD, HIDS, UINT},
+};
+
+use crate::{
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// The configuration is not specific to a camera.
+const NO_CAMERA: HIDS = 0;
+
+fn boot_boost<T>(cmd: IS_BOOTBOOST_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_BootBoost(NO_CAMERA, cmd, param as *mut T as *mut void, size_of::<T>() as UINT))
+    })
+}
+
+fn trigger(cmd: IS_BOOTBOOST_CMD) -> Result<()> {
+    check(unsafe { command!(is_BootBoost(NO_CAMERA, cmd, ptr::null_mut(), 0)) })
+}
+
+/// Fail with [`Error::InvalidParameter`] unless `id` is a valid camera ID.
+fn check_id(id: IS_BOOTBOOST_ID) -> Result<()> {
+    match (IS_BOOTBOOST_ID_MIN..=IS_BOOTBOOST_ID_MAX).contains(&id) {
+        true => Ok(()),
+        false => Err(Error::InvalidParameter),
+    }
+}
+
+/// Query whether boot boost is enabled.
+pub fn enabled() -> Result<bool> {
+    let mut enabled: DWORD = 0;
+    boot_boost(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_GET_ENABLED, &mut enabled)?;
+
+    Ok(enabled != 0)
+}
+
+/// Enable boot boost for the listed cameras, without waiting for them.
+#[inline]
+pub fn enable() -> Result<()> {
+    trigger(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_ENABLE)
+}
+
+/// Disable boot boost, without waiting for the listed cameras.
+#[inline]
+pub fn disable() -> Result<()> {
+    trigger(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_DISABLE)
+}
+
+/// Wait until all listed cameras applied the boot boost configuration, enabled or disabled.
+///
+/// The driver waits in whole seconds, so `timeout` is rounded up. Fails with [`Error::TimedOut`]
+/// if `timeout` elapses.
+pub fn wait_until_booted(timeout: Duration) -> Result<()> {
+    let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+    let mut secs = UINT::try_from(secs).unwrap_or(UINT::MAX).max(1);
+    boot_boost(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_WAIT, &mut secs)
+}
+
+/// Add the camera ID `id` (`1`…`254`) to the boot boost list.
+pub fn add_camera_id(id: IS_BOOTBOOST_ID) -> Result<()> {
+    check_id(id)?;
+    let mut id = id;
+    boot_boost(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_ADD_ID, &mut id)
+}
+
+/// Remove the camera ID `id` (`1`…`254`) from the boot boost list.
+pub fn remove_camera_id(id: IS_BOOTBOOST_ID) -> Result<()> {
+    check_id(id)?;
+    let mut id = id;
+    boot_boost(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_REMOVE_ID, &mut id)
+}
+
+/// Remove all camera IDs from the boot boost list.
+#[inline]
+pub fn clear_camera_ids() -> Result<()> {
+    trigger(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_CLEAR_IDLIST)
+}
+
+/// Query the camera IDs of the boot boost list.
+pub fn camera_ids() -> Result<Vec<IS_BOOTBOOST_ID>> {
+    let mut count: DWORD = 0;
+    boot_boost(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_GET_IDLIST_SIZE, &mut count)?;
+
+    let mut list = IdList::new(&vec![0; count as usize]);
+    list.command(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_GET_IDLIST)?;
+
+    Ok(list.ids())
+}
+
+/// Replace the boot boost list with the camera IDs `ids` (`1`…`254` each).
+pub fn set_camera_ids(ids: &[IS_BOOTBOOST_ID]) -> Result<()> {
+    ids.iter().try_for_each(|&id| check_id(id))?;
+    IdList::new(ids).command(IS_BOOTBOOST_CMD::IS_BOOTBOOST_CMD_SET_IDLIST)
+}
+
+/// Variable-length [`IS_BOOTBOOST_IDLIST`](ueye_sys::boot_boost::IS_BOOTBOOST_IDLIST): the number
+/// of entries, followed by the entries.
+struct IdList {
+    // DWORD-aligned storage of the header and the entries
+    words: Vec<DWORD>,
+}
+
+impl IdList {
+    fn new(ids: &[IS_BOOTBOOST_ID]) -> Self {
+        let entries = ids.len() * IS_BOOTBOOST_IDLIST_ELEMENTSIZE;
+        let mut words = vec![0; 1 + entries.div_ceil(size_of::<DWORD>())];
+        words[0] = ids.len() as DWORD;
+
+        let mut list = Self { words };
+        list.entries_mut().copy_from_slice(ids);
+        list
+    }
+
+    /// Number of entries, limited to the capacity in case the driver reports more.
+    fn len(&self) -> usize {
+        let capacity =
+            (self.words.len() - 1) * size_of::<DWORD>() / IS_BOOTBOOST_IDLIST_ELEMENTSIZE;
+        (self.words[0] as usize).min(capacity)
+    }
+
+    fn size(&self) -> usize {
+        IS_BOOTBOOST_IDLIST_HEADERSIZE + self.len() * IS_BOOTBOOST_IDLIST_ELEMENTSIZE
+    }
+
+    fn entries_mut(&mut self) -> &mut [IS_BOOTBOOST_ID] {
+        let len = self.len();
+
+        // SAFETY: the storage holds at least `len` entries behind the header
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.words[1..].as_mut_ptr() as *mut IS_BOOTBOOST_ID,
+                len,
+            )
+        }
+    }
+
+    fn ids(mut self) -> Vec<IS_BOOTBOOST_ID> {
+        self.entries_mut().to_vec()
+    }
+
+    fn command(&mut self, cmd: IS_BOOTBOOST_CMD) -> Result<()> {
+        let size = self.size();
+        check(unsafe {
+            command!(is_BootBoost(
+                NO_CAMERA,
+                cmd,
+                self.words.as_mut_ptr() as *mut void,
+                size as UINT,
+            ))
+        })
+    }
+}
@@ -0,0 +1,101 @@
+//! Automatic exposure bracketing.
+//!
+//! [`Bracketing`] captures one frame per programmed exposure step using the software trigger and
+//! [`is_FreezeVideo`], and returns the frames labelled with their exposure times, e.g. as input
+//! for HDR fusion.
+//!
+//! The exposure time and trigger mode in effect before the capture are restored afterwards, also
+//! when the capture fails.
+
+use ueye_sys::{
+    types::INT,
+    video::{is_FreezeVideo, IS_WAIT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    exposure,
+    frame::FrameBuf,
+    observer::call,
+    trigger::TriggerMode,
+};
+
+/// Frame captured as part of a bracket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketedFrame {
+    /// Position of the step within the bracket.
+    pub index: usize,
+
+    /// Requested exposure time (in ms).
+    pub requested_exposure: f64,
+
+    /// Exposure time actually set by the camera (in ms).
+    pub exposure: f64,
+
+    /// Captured image.
+    pub frame: FrameBuf,
+}
+
+/// Exposure bracketing configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bracketing {
+    steps: Vec<f64>,
+}
+
+impl Bracketing {
+    /// Bracket with the given exposure times (in ms), captured in order.
+    pub fn new(steps: impl IntoIterator<Item = f64>) -> Self {
+        Self { steps: steps.into_iter().collect() }
+    }
+
+    /// Bracket of `count` exposures centered on `base` (in ms), spaced `ev` stops apart.
+    ///
+    /// E.g. `Bracketing::stops(10.0, 3, 1.0)` yields 5 ms, 10 ms and 20 ms.
+    pub fn stops(base: f64, count: usize, ev: f64) -> Self {
+        let center = (count as f64 - 1.0) / 2.0;
+        Self::new((0..count).map(|i| base * (ev * (i as f64 - center)).exp2()))
+    }
+
+    /// Programmed exposure times (in ms).
+    #[inline]
+    pub fn steps(&self) -> &[f64] {
+        &self.steps
+    }
+
+    /// Capture one frame per exposure step.
+    ///
+    /// Image memory must already be allocated and set active on the camera.
+    pub fn capture(&self, camera: &Camera) -> Result<Vec<BracketedFrame>> {
+        let previous_exposure = exposure::current(camera)?;
+        let previous_trigger = TriggerMode::current(camera)?;
+
+        let result = TriggerMode::Software.apply(camera).and_then(|_| self.capture_steps(camera));
+
+        let restored = previous_trigger
+            .apply(camera)
+            .and_then(|_| exposure::set(camera, previous_exposure).map(|_| ()));
+
+        let frames = result?;
+        restored?;
+        Ok(frames)
+    }
+
+    fn capture_steps(&self, camera: &Camera) -> Result<Vec<BracketedFrame>> {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, &requested_exposure)| {
+                let exposure = exposure::set(camera, requested_exposure)?;
+                check(unsafe { call!(is_FreezeVideo(camera.handle(), IS_WAIT as INT)) })?;
+
+                Ok(BracketedFrame {
+                    index,
+                    requested_exposure,
+                    exposure,
+                    frame: FrameBuf::copy_active(camera)?,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,248 @@
+//! Host-side brightness control.
+//!
+//! [`BrightnessController`] keeps the mean image brightness at a target level by adjusting exposure
+//! time and gain from the histogram of each captured frame. Exposure is preferred, since it adds no
+//! noise; once the exposure time reaches the latency budget, the remaining correction is applied
+//! as gain. When darkening, gain is reduced first.
+//!
+//! Unlike the in-camera auto modes, the controller only acts when it is fed a frame, which makes it
+//! suitable for triggered operation: the new settings take effect from the next trigger on.
+
+use ueye_sys::types::INT;
+
+use crate::{
+    camera::Camera,
+    error::{Error, Result},
+    exposure,
+    frame::Frame,
+    gain,
+};
+
+/// Gain multiplication factor of `1.00`.
+const UNITY_GAIN: INT = 100;
+
+/// Exposure and gain settings produced by a [`BrightnessController`].
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Adjustment {
+    /// Brightness measured in the frame (`0.0`…`1.0`).
+    pub brightness: f64,
+
+    /// Exposure time (in ms).
+    pub exposure: f64,
+
+    /// Master gain multiplication factor (`100` = `1.00`).
+    pub gain_factor: INT,
+
+    /// Whether the brightness was within tolerance, leaving the settings unchanged.
+    pub settled: bool,
+}
+
+/// Brightness controller handing off from exposure to gain.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BrightnessController {
+    /// Target mean brightness, as a fraction of the largest sample value (`0.0`…`1.0`).
+    pub target: f64,
+
+    /// Deviation from the target that is tolerated without adjustment (`0.0`…`1.0`).
+    pub tolerance: f64,
+
+    /// Longest exposure time before gain is used (in ms).
+    pub exposure_budget: f64,
+
+    /// Largest master gain multiplication factor to use (`100` = `1.00`), or [`None`] for the
+    /// sensor maximum.
+    pub max_gain_factor: Option<INT>,
+
+    /// Fraction of the correction applied per frame (`0.0`…`1.0`), to avoid oscillation.
+    pub damping: f64,
+}
+
+impl Default for BrightnessController {
+    fn default() -> Self {
+        Self {
+            target: 0.5,
+            tolerance: 0.02,
+            exposure_budget: 33.0,
+            max_gain_factor: None,
+            damping: 0.7,
+        }
+    }
+}
+
+impl BrightnessController {
+    /// Controller aiming at `target` brightness (`0.0`…`1.0`) with default settings.
+    pub fn new(target: f64) -> Self {
+        Self { target, ..Self::default() }
+    }
+
+    /// Mean brightness of the frame as a fraction of the largest sample value, averaged over
+    /// all channels.
+    pub fn brightness(frame: &Frame<'_>) -> f64 {
+        let means = frame.mean();
+        if means.is_empty() {
+            return 0.0;
+        }
+
+        means.iter().sum::<f64>() / means.len() as f64 / frame.max_sample() as f64
+    }
+
+    /// Compute the settings that bring `brightness`, measured with `exposure` (in ms) and
+    /// `gain_factor`, to the target.
+    ///
+    /// `exposure_range` bounds the exposure time (in ms) and `max_gain_factor` is the sensor
+    /// maximum.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if the exposure range is empty.
+    pub fn plan(
+        &self,
+        brightness: f64,
+        exposure: f64,
+        gain_factor: INT,
+        exposure_range: (f64, f64),
+        max_gain_factor: INT,
+    ) -> Result<Adjustment> {
+        let (min_exposure, max_exposure) = exposure_range;
+        if min_exposure.is_nan() || max_exposure.is_nan() || min_exposure > max_exposure {
+            return Err(Error::InvalidParameter);
+        }
+
+        let mut adjustment = Adjustment { brightness, exposure, gain_factor, settled: false };
+        if (brightness - self.target).abs() <= self.tolerance {
+            adjustment.settled = true;
+            return Ok(adjustment);
+        }
+
+        // a black frame carries no information on the required correction; step up by 4x
+        let ratio = if brightness > 0.0 { self.target / brightness } else { 4.0 };
+        let ratio = ratio.powf(self.damping.clamp(0.0, 1.0));
+
+        let max_gain = self.max_gain_factor.map_or(max_gain_factor, |max| max.min(max_gain_factor));
+        let max_gain = max_gain.max(UNITY_GAIN);
+        let budget = self.exposure_budget.max(min_exposure).min(max_exposure);
+
+        // total sensitivity, in ms at unity gain
+        let product = exposure * gain_factor.max(UNITY_GAIN) as f64 / UNITY_GAIN as f64 * ratio;
+
+        adjustment.exposure = product.clamp(min_exposure, budget);
+        adjustment.gain_factor = ((product / adjustment.exposure * UNITY_GAIN as f64).round()
+            as INT)
+            .clamp(UNITY_GAIN, max_gain);
+        Ok(adjustment)
+    }
+
+    /// Measure the frame and apply the resulting exposure and gain to the camera.
+    ///
+    /// # Return values
+    /// The settings actually applied by the camera.
+    pub fn update(&self, camera: &Camera, frame: &Frame<'_>) -> Result<Adjustment> {
+        let range = exposure::range(camera)?;
+        let planned = self.plan(
+            Self::brightness(frame),
+            exposure::current(camera)?,
+            gain::factor(camera)?,
+            (range.min, range.max),
+            gain::max_factor(camera)?,
+        )?;
+        if planned.settled {
+            return Ok(planned);
+        }
+
+        Ok(Adjustment {
+            exposure: exposure::set(camera, planned.exposure)?,
+            gain_factor: gain::set_factor(camera, planned.gain_factor)?,
+            ..planned
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RANGE: (f64, f64) = (0.1, 100.0);
+
+    /// Controller aiming at `0.5` with the full correction applied per frame.
+    fn controller() -> BrightnessController {
+        BrightnessController { damping: 1.0, ..BrightnessController::default() }
+    }
+
+    fn assert_plan(adjustment: Adjustment, exposure: f64, gain_factor: INT) {
+        assert!(
+            (adjustment.exposure - exposure).abs() < 1e-9,
+            "exposure {} instead of {exposure}",
+            adjustment.exposure
+        );
+        assert_eq!(adjustment.gain_factor, gain_factor);
+        assert!(!adjustment.settled);
+    }
+
+    #[test]
+    fn within_tolerance_settles() {
+        let adjustment = controller().plan(0.51, 10.0, 150, RANGE, 400).unwrap();
+        assert!(adjustment.settled);
+        assert_eq!((adjustment.exposure, adjustment.gain_factor), (10.0, 150));
+    }
+
+    #[test]
+    fn brightening_within_budget_uses_exposure() {
+        let adjustment = controller().plan(0.25, 10.0, UNITY_GAIN, RANGE, 400).unwrap();
+        assert_plan(adjustment, 20.0, UNITY_GAIN);
+    }
+
+    #[test]
+    fn brightening_past_budget_hands_off_to_gain() {
+        let adjustment = controller().plan(0.125, 20.0, UNITY_GAIN, RANGE, 400).unwrap();
+        assert_plan(adjustment, 33.0, 242);
+    }
+
+    #[test]
+    fn gain_is_limited_by_controller_and_sensor() {
+        let limited = BrightnessController { max_gain_factor: Some(200), ..controller() };
+        assert_plan(limited.plan(0.05, 33.0, UNITY_GAIN, RANGE, 400).unwrap(), 33.0, 200);
+        assert_plan(limited.plan(0.05, 33.0, UNITY_GAIN, RANGE, 150).unwrap(), 33.0, 150);
+    }
+
+    #[test]
+    fn darkening_reduces_gain_first() {
+        let adjustment = controller().plan(1.0, 33.0, 300, RANGE, 400).unwrap();
+        assert_plan(adjustment, 33.0, 150);
+    }
+
+    #[test]
+    fn darkening_clamps_to_shortest_exposure() {
+        let dark = BrightnessController { target: 0.01, ..controller() };
+        assert_plan(dark.plan(1.0, 1.0, UNITY_GAIN, RANGE, 400).unwrap(), RANGE.0, UNITY_GAIN);
+    }
+
+    #[test]
+    fn black_frame_steps_up() {
+        let adjustment = controller().plan(0.0, 5.0, UNITY_GAIN, RANGE, 400).unwrap();
+        assert_plan(adjustment, 20.0, UNITY_GAIN);
+    }
+
+    #[test]
+    fn damping_applies_part_of_the_correction() {
+        let damped = BrightnessController { damping: 0.5, ..controller() };
+        assert_plan(damped.plan(0.125, 5.0, UNITY_GAIN, RANGE, 400).unwrap(), 10.0, UNITY_GAIN);
+    }
+
+    #[test]
+    fn budget_is_bounded_by_exposure_range() {
+        let adjustment = controller().plan(0.125, 20.0, UNITY_GAIN, (0.1, 25.0), 400).unwrap();
+        assert_plan(adjustment, 25.0, 320);
+
+        let short = BrightnessController { exposure_budget: 0.01, ..controller() };
+        assert_plan(short.plan(0.25, 0.1, UNITY_GAIN, RANGE, 400).unwrap(), 0.1, 200);
+    }
+
+    #[test]
+    fn invalid_exposure_range_fails() {
+        let controller = controller();
+        for range in [(10.0, 1.0), (f64::NAN, 1.0), (0.1, f64::NAN)] {
+            assert_eq!(
+                controller.plan(0.25, 10.0, UNITY_GAIN, range, 400),
+                Err(Error::InvalidParameter)
+            );
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Rust closures as driver callbacks.
+//!
+//! The driver signals events either through event objects or through window messages
+//! (`is_EnableMessage`, Windows only), neither of which can call Rust code directly. An
+//! [`EventCallback`] bridges event objects to a closure, which is called on a background thread
+//! for every signaled event until the callback is dropped.
+//!
+//! Functions that take a C callback with a context pointer, like the
+//! [autofocus callback](crate::focus::Focus::on_autofocus) of `is_Focus`, pass a [`Context`] as
+//! context to an `extern "C"` trampoline, which forwards to the closure with [`Context::invoke`].
+//! Panics of the closure are caught there, since unwinding into the driver is undefined behavior.
+//!
+//! # Documentation
+//! * [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+//! * [Event/message handling](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_funktionsbloecke_event_handling.html)
+
+use std::{
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
+    ptr::NonNull,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use ueye_sys::types::{void, HIDS, UINT};
+
+use crate::{
+    camera::Camera,
+    error::{Error, Result},
+    event::{exit_events, init_events, wait_any, EventKind},
+};
+
+/// Longest time the callback thread waits before checking whether it should stop.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Closure called on a background thread for every signaled event, unregistered on drop.
+#[derive(Debug)]
+pub struct EventCallback<'c> {
+    hcam: HIDS,
+    events: Vec<UINT>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    camera: PhantomData<&'c Camera>,
+}
+
+impl<'c> EventCallback<'c> {
+    /// Register and enable `kinds` on `camera`, calling `callback` for every signaled event.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for
+    /// [device-independent](EventKind::is_device_independent) events, see
+    /// [`DeviceWatcher`](crate::hotplug::DeviceWatcher) for those. A panic of `callback` is
+    /// caught, so that later events are still delivered.
+    pub fn new(
+        camera: &'c Camera,
+        kinds: &[EventKind],
+        mut callback: impl FnMut(EventKind) + Send + 'static,
+    ) -> Result<Self> {
+        if kinds.iter().any(|kind| kind.is_device_independent()) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let hcam = camera.handle();
+        let mut events: Vec<UINT> = kinds.iter().map(|kind| kind.bits()).collect();
+        events.sort_unstable();
+        events.dedup();
+        init_events(hcam, &events)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = thread::spawn({
+            let stop = Arc::clone(&stop);
+            let events = events.clone();
+            move || {
+                while !stop.load(Ordering::Acquire) {
+                    let Ok(kind) =
+                        wait_any(hcam, &events, POLL_INTERVAL).and_then(EventKind::try_from)
+                    else {
+                        continue;
+                    };
+
+                    let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(kind)));
+                }
+            }
+        });
+
+        Ok(Self { hcam, events, stop, thread: Some(thread), camera: PhantomData })
+    }
+}
+
+impl Drop for EventCallback<'_> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+
+        exit_events(self.hcam, &self.events);
+    }
+}
+
+/// Closure passed as context pointer to a C callback, released on drop.
+///
+/// The context must outlive its registration with the driver, i.e. the owner unregisters the
+/// callback before dropping the context.
+pub struct Context<F: ?Sized> {
+    // Owned, from `Box::into_raw`, so that the driver may mutate the closure through it
+    closure: NonNull<Box<F>>,
+}
+
+// SAFETY: the context owns the closure like a `Box` does
+unsafe impl<F: ?Sized + Send> Send for Context<F> {}
+// SAFETY: shared references only hand out the pointer, never the closure
+unsafe impl<F: ?Sized + Sync> Sync for Context<F> {}
+
+impl<F: ?Sized> std::fmt::Debug for Context<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Context").field(&self.as_ptr()).finish()
+    }
+}
+
+impl<F: ?Sized> Context<F> {
+    /// Box `closure` at a stable address.
+    pub fn new(closure: Box<F>) -> Self {
+        // SAFETY: `Box::into_raw` never returns null
+        Self { closure: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(closure))) } }
+    }
+
+    /// Context pointer to hand to the driver.
+    pub fn as_ptr(&self) -> *mut void {
+        self.closure.as_ptr().cast()
+    }
+
+    /// Call the closure behind `context` from a trampoline, returning `None` if it panicked.
+    ///
+    /// # Safety
+    /// `context` must be the [pointer](Context::as_ptr) of a live context of the same type, which
+    /// is not invoked concurrently.
+    pub unsafe fn invoke<R>(context: *mut void, f: impl FnOnce(&mut F) -> R) -> Option<R> {
+        let closure = unsafe { &mut *(context as *mut Box<F>) };
+        panic::catch_unwind(AssertUnwindSafe(|| f(closure))).ok()
+    }
+}
+
+impl<F: ?Sized> Drop for Context<F> {
+    fn drop(&mut self) {
+        // SAFETY: the pointer comes from `Box::into_raw` in `Context::new` and is freed only here
+        drop(unsafe { Box::from_raw(self.closure.as_ptr()) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invoke_mutates_closure() {
+        let mut calls = 0;
+        let context: Context<dyn FnMut() -> i32> = Context::new(Box::new(move || {
+            calls += 1;
+            calls
+        }));
+
+        for expected in 1..=3 {
+            let calls = unsafe { Context::<dyn FnMut() -> i32>::invoke(context.as_ptr(), |f| f()) };
+            assert_eq!(calls, Some(expected));
+        }
+    }
+
+    #[test]
+    fn invoke_catches_panic() {
+        let context: Context<dyn FnMut()> = Context::new(Box::new(|| panic!("callback")));
+        assert_eq!(unsafe { Context::<dyn FnMut()>::invoke(context.as_ptr(), |f| f()) }, None);
+    }
+}
@@ -0,0 +1,316 @@
+//! Camera handle ownership.
+//!
+//! A [`Camera`] is initialized with [`is_InitCamera`] on construction and released with
+//! [`is_ExitCamera`] when dropped, so that a handle can neither leak nor be released twice. The
+//! raw handle remains available through [`Camera::handle`] for use with [`ueye_sys`] directly.
+//!
+//! [`list_cameras`] enumerates the connected cameras without opening them.
+//!
+//! Only one process can open a camera at a time. [`Camera::open_by_serial`] looks the camera up
+//! in the camera list first, so that a camera in use is reported with its [`Availability`]
+//! instead of the bare [`Error::AllDevicesBusy`], and can optionally wait for it to become free.
+//!
+//! # Documentation
+//! * [is_InitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_initcamera.html)
+//! * [is_ExitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_exitcamera.html)
+//! * [is_GetCameraList](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+//! * [is_GetEthDeviceInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getethdeviceinfo.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    mem::{self, size_of},
+    ptr, thread,
+    time::{Duration, Instant},
+};
+
+use ueye_sys::{
+    camera::{
+        is_ExitCamera, is_GetCameraList, is_GetNumberOfCameras, is_InitCamera, IS_USE_DEVICE_ID,
+        UEYE_CAMERA_INFO, UEYE_CAMERA_LIST,
+    },
+    eth::UEYE_ETH_CONTROLSTATUS,
+    types::{DWORD, HIDS, INT, ULONG},
+};
+
+use crate::{
+    device_info::c_string,
+    error::{check, Error, Result},
+    eth,
+    observer::call,
+};
+
+/// Whether a camera can be opened, as seen from this process.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Availability {
+    /// The camera is free to be opened.
+    Available,
+
+    /// The camera is in use, by a process on this or another PC.
+    InUse,
+
+    /// _GigE only:_ the camera is paired with the driver on this PC, but not opened.
+    Paired,
+
+    /// _GigE only:_ the camera is opened by a process on this PC.
+    Opened,
+}
+
+impl Display for Availability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Availability::Available => "available",
+            Availability::InUse => "in use",
+            Availability::Paired => "paired with this PC",
+            Availability::Opened => "opened on this PC",
+        })
+    }
+}
+
+impl Availability {
+    /// Availability of the camera listed as `info`.
+    fn of(info: &CameraInfo) -> Self {
+        if !info.in_use {
+            return Availability::Available;
+        }
+
+        let Ok(eth) = eth::device_info(info.device_id as HIDS | IS_USE_DEVICE_ID) else {
+            return Availability::InUse;
+        };
+
+        // the status is read as raw bits, since the driver may set bits the flags lack
+        let status = UEYE_ETH_CONTROLSTATUS::from_bits_retain(unsafe {
+            ptr::addr_of!((*eth.as_ptr()).infoDevControl.dwControlStatus)
+                .cast::<u32>()
+                .read_unaligned()
+        });
+        if status.contains(UEYE_ETH_CONTROLSTATUS::IS_ETH_CTRLSTATUS_OPENED) {
+            Availability::Opened
+        } else if status.contains(UEYE_ETH_CONTROLSTATUS::IS_ETH_CTRLSTATUS_PAIRED) {
+            Availability::Paired
+        } else {
+            Availability::InUse
+        }
+    }
+}
+
+/// Configuration of [`Camera::open_by_serial`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OpenOptions {
+    /// Longest time to wait for a camera in use to become free, or `None` to fail immediately.
+    pub timeout: Option<Duration>,
+
+    /// Delay before checking a camera in use again, doubled after every attempt.
+    pub backoff: Duration,
+
+    /// Upper bound of the delay between attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Camera connected to the system, as listed by [`list_cameras`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CameraInfo {
+    /// Customizable camera ID.
+    pub camera_id: DWORD,
+
+    /// Internal device ID.
+    pub device_id: DWORD,
+
+    /// Sensor ID.
+    pub sensor_id: DWORD,
+
+    /// Serial number.
+    pub serial_number: String,
+
+    /// Camera model.
+    pub model: String,
+
+    /// Full camera model name.
+    pub full_model_name: String,
+
+    /// Whether the camera is in use by any process.
+    pub in_use: bool,
+}
+
+impl CameraInfo {
+    /// Open the camera by its device ID.
+    #[inline]
+    pub fn open(&self) -> Result<Camera> {
+        Camera::open_device(self.device_id as HIDS)
+    }
+}
+
+impl Display for CameraInfo {
+    /// Single line for camera listings, e.g. `1: UI-3240CP-C-HQ #4102812345 (in use)`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} #{}", self.camera_id, self.model, self.serial_number)?;
+        if self.in_use {
+            f.write_str(" (in use)")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&UEYE_CAMERA_INFO> for CameraInfo {
+    fn from(info: &UEYE_CAMERA_INFO) -> Self {
+        Self {
+            camera_id: info.dwCameraID,
+            device_id: info.dwDeviceID,
+            sensor_id: info.dwSensorID,
+            serial_number: c_string(&info.SerNo),
+            model: c_string(&info.Model),
+            full_model_name: c_string(&info.FullModelName),
+            in_use: info.dwInUse != 0,
+        }
+    }
+}
+
+/// Number of attempts of [`list_cameras`] while cameras are connected or removed.
+const LIST_ATTEMPTS: usize = 3;
+
+/// List the cameras connected to the system.
+///
+/// Fails with [`Error::AccessViolation`] if the number of cameras keeps changing while listing.
+pub fn list_cameras() -> Result<Vec<CameraInfo>> {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+
+        let mut count: INT = 0;
+        check(unsafe { call!(is_GetNumberOfCameras(&mut count)) })?;
+        let count = count.max(0) as usize;
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        // `UEYE_CAMERA_LIST` is a count followed by a variable-length array of entries
+        let size = mem::offset_of!(UEYE_CAMERA_LIST, uci) + count * size_of::<UEYE_CAMERA_INFO>();
+        let words = size.div_ceil(size_of::<UEYE_CAMERA_LIST>());
+        let mut buffer = vec![UEYE_CAMERA_LIST::default(); words];
+        buffer[0].dwCount = count as ULONG;
+
+        let list = buffer.as_mut_ptr();
+        match check(unsafe { call!(is_GetCameraList(list)) }) {
+            // a camera was connected or removed in between
+            Err(Error::AccessViolation) if attempts < LIST_ATTEMPTS => continue,
+            result => result?,
+        }
+
+        let entries = unsafe { ptr::addr_of!((*list).uci) as *const UEYE_CAMERA_INFO };
+        let listed = (unsafe { (*list).dwCount } as usize).min(count);
+        let entries = unsafe { std::slice::from_raw_parts(entries, listed) };
+        return Ok(entries.iter().map(CameraInfo::from).collect());
+    }
+}
+
+/// Initialized camera, released on drop.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Camera {
+    hcam: HIDS,
+}
+
+impl Camera {
+    /// Open the first available camera.
+    #[inline]
+    pub fn open_first() -> Result<Self> {
+        Self::init(0)
+    }
+
+    /// Open the camera with the given camera ID (`1`…`254`).
+    #[inline]
+    pub fn open(camera_id: HIDS) -> Result<Self> {
+        Self::init(camera_id)
+    }
+
+    /// Open the camera with the given device ID.
+    #[inline]
+    pub fn open_device(device_id: HIDS) -> Result<Self> {
+        Self::init(device_id | IS_USE_DEVICE_ID)
+    }
+
+    /// Open the camera with the given serial number.
+    ///
+    /// # Errors
+    /// * [`Error::CameraNotFound`] if no connected camera has the serial number.
+    /// * [`Error::CameraBusy`] if the camera is still in use after [`OpenOptions::timeout`].
+    pub fn open_by_serial(serial: &str, options: &OpenOptions) -> Result<Self> {
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+        let mut backoff = options.backoff;
+
+        loop {
+            let info = list_cameras()?
+                .into_iter()
+                .find(|info| info.serial_number == serial)
+                .ok_or(Error::CameraNotFound)?;
+
+            let availability = match Availability::of(&info) {
+                Availability::Available => match info.open() {
+                    // another process opened it since the list was read
+                    Err(Error::AllDevicesBusy | Error::DeviceInUse) => Availability::InUse,
+                    result => return result,
+                },
+                availability => availability,
+            };
+
+            match deadline.map(|deadline| deadline.saturating_duration_since(Instant::now())) {
+                Some(remaining) if !remaining.is_zero() => {
+                    thread::sleep(backoff.min(remaining));
+                    backoff = (backoff * 2).min(options.max_backoff);
+                },
+                _ => return Err(Error::CameraBusy(availability)),
+            }
+        }
+    }
+
+    pub(crate) fn init(mut hcam: HIDS) -> Result<Self> {
+        check(unsafe { call!(is_InitCamera(&mut hcam, ptr::null_mut())) })?;
+        Ok(Self { hcam })
+    }
+
+    /// Take ownership of a handle obtained from [`is_InitCamera`].
+    ///
+    /// # Safety
+    /// `hcam` must be a valid, initialized camera handle that is not owned elsewhere, since it is
+    /// released when the returned [`Camera`] is dropped.
+    #[inline]
+    pub const unsafe fn from_raw(hcam: HIDS) -> Self {
+        Self { hcam }
+    }
+
+    /// Release ownership of the handle without closing the camera.
+    ///
+    /// The caller becomes responsible for calling [`is_ExitCamera`].
+    #[inline]
+    pub fn into_raw(self) -> HIDS {
+        let hcam = self.hcam;
+        mem::forget(self);
+        hcam
+    }
+
+    /// Raw camera handle, for use with the [`ueye_sys`] functions.
+    #[inline]
+    pub const fn handle(&self) -> HIDS {
+        self.hcam
+    }
+
+    /// Close the camera, reporting failures that are otherwise ignored on drop.
+    pub fn close(self) -> Result<()> {
+        check(unsafe { call!(is_ExitCamera(self.into_raw())) })
+    }
+}
+
+impl Drop for Camera {
+    fn drop(&mut self) {
+        unsafe { call!(is_ExitCamera(self.hcam)) };
+    }
+}
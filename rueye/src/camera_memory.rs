@@ -0,0 +1,106 @@
+//! Image memory of GigE cameras.
+//!
+//! GigE cameras with a memory board of their own can keep the captured images there instead of
+//! transferring them right away, e.g. to capture bursts faster than the network carries them.
+//! Once [activated](activate), each capture started fills a new _iteration_ of images in the
+//! camera memory. [`iterations`] lists the iterations available, [`iteration`] the images of one,
+//! and [`transfer_image`] transfers an image to the active image memory of the PC. Iterations
+//! occupy the camera memory until they are [released](release_iterations).
+//!
+//! Operations requiring the other memory mode fail with [`Error::MemoryBoardActivated`] or
+//! [`Error::MemoryBoardDeactivated`], and images no longer in the camera memory with
+//! [`Error::ImageNotPresent`].
+//!
+//! # Documentation
+//! * [Using the camera memory](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_gige_cameramemory.html)
+//! * [is_ImageBuffer](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagebuffer.html)
+
+use std::{
+    mem::{size_of, MaybeUninit},
+    ops::RangeInclusive,
+};
+
+use ueye_sys::{
+    image_buffer::{is_ImageBuffer, ID_RANGE, IMGBUF_CMD, IMGBUF_ITEM, IMGBUF_ITERATION_INFO},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+    transfer::{self, ImageDestination},
+};
+
+fn image_buffer<T>(camera: &Camera, cmd: IMGBUF_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_ImageBuffer(
+            camera.handle(),
+            cmd,
+            param as *mut T as *mut void,
+            size_of::<T>() as UINT,
+        ))
+    })
+}
+
+/// Whether the camera keeps its images in the camera memory.
+#[inline]
+pub fn is_active(camera: &Camera) -> Result<bool> {
+    transfer::image_destination(camera)
+        .map(|destination| destination == ImageDestination::DeviceMemory)
+}
+
+/// Keep the images of subsequent captures in the camera memory.
+///
+/// Succeeds if the camera memory is already active.
+pub fn activate(camera: &Camera) -> Result<()> {
+    match transfer::set_image_destination(camera, ImageDestination::DeviceMemory) {
+        Err(Error::MemoryBoardActivated) => Ok(()),
+        result => result,
+    }
+}
+
+/// Transfer the images of subsequent captures to the PC again.
+///
+/// Succeeds if the camera memory is already inactive.
+pub fn deactivate(camera: &Camera) -> Result<()> {
+    match transfer::set_image_destination(camera, ImageDestination::UserMemory) {
+        Err(Error::MemoryBoardDeactivated) => Ok(()),
+        result => result,
+    }
+}
+
+/// Query the IDs of the iterations in the camera memory, or [`None`] if there are none.
+pub fn iterations(camera: &Camera) -> Result<Option<RangeInclusive<u32>>> {
+    let mut range = ID_RANGE { s32First: 0, s32Last: 0 };
+    image_buffer(camera, IMGBUF_CMD::IS_IMGBUF_DEVMEM_CMD_GET_AVAILABLE_ITERATIONS, &mut range)?;
+    Ok(id_range(range).map(|ids| *ids.start() as u32..=*ids.end() as u32))
+}
+
+/// IDs of `range`, or [`None`] if it is empty.
+#[inline]
+fn id_range(range: ID_RANGE) -> Option<RangeInclusive<INT>> {
+    (range.s32Last > 0 && range.s32Last >= range.s32First).then_some(range.s32First..=range.s32Last)
+}
+
+/// Query the IDs of the images of iteration `iteration`, or [`None`] if it has none.
+pub fn iteration(camera: &Camera, iteration: u32) -> Result<Option<RangeInclusive<INT>>> {
+    // SAFETY: IMGBUF_ITERATION_INFO is plain data, for which all-zero is a valid value.
+    let mut info = unsafe { MaybeUninit::<IMGBUF_ITERATION_INFO>::zeroed().assume_init() };
+    info.u32IterationID = iteration;
+    image_buffer(camera, IMGBUF_CMD::IS_IMGBUF_DEVMEM_CMD_GET_ITERATION_INFO, &mut info)?;
+    Ok(id_range(info.rangeImageID))
+}
+
+/// Transfer image `image` of iteration `iteration` from the camera memory to the active image
+/// memory.
+pub fn transfer_image(camera: &Camera, iteration: u32, image: INT) -> Result<()> {
+    let mut item = IMGBUF_ITEM { u32IterationID: iteration, s32ImageID: image };
+    image_buffer(camera, IMGBUF_CMD::IS_IMGBUF_DEVMEM_CMD_TRANSFER_IMAGE, &mut item)
+}
+
+/// Release all iterations up to and including `iteration`, freeing their camera memory.
+pub fn release_iterations(camera: &Camera, iteration: u32) -> Result<()> {
+    let mut iteration = INT::try_from(iteration).map_err(|_| Error::InvalidParameter)?;
+    image_buffer(camera, IMGBUF_CMD::IS_IMGBUF_DEVMEM_CMD_RELEASE_ITERATIONS, &mut iteration)
+}
@@ -0,0 +1,211 @@
+//! Capability report across all camera functions.
+//!
+//! Each function of the API reports its capabilities through its own command, bitmask and
+//! parameter type. [`CameraCapabilities`] gathers them in one struct, so that applications can
+//! adapt to the connected model with a single call to [`Camera::capabilities`]. Functions the
+//! camera does not support at all are reported as [`None`].
+//!
+//! # Documentation
+//! * [is_Focus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus.html)
+//! * [is_Zoom](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_zoom.html)
+//! * [is_DeviceFeature](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature.html)
+//! * [Using GPIO](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_iogpio.html)
+//! * [is_IpConfig](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_ipconfig.html)
+//! * [is_AutoParameter](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_autoparameter.html)
+
+use std::{mem::size_of, ptr};
+
+use ueye_sys::{
+    auto_parameter::{is_AutoParameter, AES_MODE, AWB_MODE, IS_AUTOPARAMETER_CMD},
+    device_feature::DEVICE_FEATURE_MODE_CAPS,
+    eth::{is_IpConfig, IPCONFIG_CAPABILITY_FLAGS, IPCONFIG_CMD},
+    focus::FOCUS_CAPABILITY_FLAGS,
+    io::IO_GPIO,
+    types::{void, UINT},
+    zoom::ZOOM_CAPABILITY_FLAGS,
+};
+
+use crate::{
+    camera::Camera,
+    command::{device_command, focus::FOC_CMD_GET_CAPABILITIES},
+    error::{check, optional, Error, Result},
+    eth,
+    exposure::{self, ExposureCaps},
+    gpio,
+    observer::{call, command},
+    zoom,
+};
+
+/// GPIOs of the camera, by function.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoCapabilities {
+    /// GPIOs of the camera.
+    pub gpios: IO_GPIO,
+
+    /// GPIOs that can be used as output.
+    pub outputs: IO_GPIO,
+
+    /// GPIOs that can be used as flash output.
+    pub flash: IO_GPIO,
+
+    /// GPIOs that can be used as PWM output.
+    pub pwm: IO_GPIO,
+}
+
+impl IoCapabilities {
+    /// Number of GPIOs.
+    #[inline]
+    pub const fn gpio_count(&self) -> u32 {
+        self.gpios.bits().count_ones()
+    }
+
+    /// Number of GPIOs that can be used as flash output.
+    #[inline]
+    pub const fn flash_count(&self) -> u32 {
+        self.flash.bits().count_ones()
+    }
+
+    /// Number of GPIOs that can be used as PWM output.
+    #[inline]
+    pub const fn pwm_count(&self) -> u32 {
+        self.pwm.bits().count_ones()
+    }
+}
+
+/// IP configuration modes supported by a GigE camera.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpConfigCapabilities {
+    /// A persistent IP address can be set.
+    pub persistent_ip: bool,
+
+    /// The IP address can be obtained from a DHCP server.
+    pub dhcp: bool,
+
+    /// The IP address can be configured automatically by the network adapter.
+    pub autoconfig_ip: bool,
+}
+
+impl From<UINT> for IpConfigCapabilities {
+    fn from(caps: UINT) -> Self {
+        let has = |cap: IPCONFIG_CAPABILITY_FLAGS| caps & cap as UINT != 0;
+        Self {
+            persistent_ip: has(IPCONFIG_CAPABILITY_FLAGS::IPCONFIG_CAP_PERSISTENT_IP_SUPPORTED),
+            dhcp: has(IPCONFIG_CAPABILITY_FLAGS::IPCONFIG_CAP_DHCP_SUPPORTED),
+            autoconfig_ip: has(IPCONFIG_CAPABILITY_FLAGS::IPCONFIG_CAP_AUTOCONFIG_IP_SUPPORTED),
+        }
+    }
+}
+
+/// Capabilities of a camera, with [`None`] for functions it does not support.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CameraCapabilities {
+    /// Focus functions, for cameras with a focus lens.
+    pub focus: Option<FOCUS_CAPABILITY_FLAGS>,
+
+    /// Zoom functions.
+    pub zoom: Option<ZOOM_CAPABILITY_FLAGS>,
+
+    /// Special functions of the camera model.
+    pub device_features: Option<DEVICE_FEATURE_MODE_CAPS>,
+
+    /// Exposure functions.
+    pub exposure: Option<ExposureCaps>,
+
+    /// GPIOs, for cameras with GPIOs.
+    pub io: Option<IoCapabilities>,
+
+    /// IP configuration modes, for GigE cameras.
+    pub ip_config: Option<IpConfigCapabilities>,
+
+    /// Modes of the auto exposure control.
+    pub auto_exposure: Option<AES_MODE>,
+
+    /// Modes of the auto white balance.
+    pub auto_white_balance: Option<AWB_MODE>,
+}
+
+impl CameraCapabilities {
+    /// Query the capabilities of `camera`.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        Ok(Self {
+            focus: optional(focus(camera))?,
+            zoom: optional(zoom::capabilities(camera))?,
+            device_features: optional(camera.device_features().supported_features())?,
+            exposure: optional(exposure::caps(camera))?,
+            io: optional(io(camera))?,
+            ip_config: optional(ip_config(camera))?,
+            auto_exposure: optional(auto_parameter(
+                camera,
+                IS_AUTOPARAMETER_CMD::IS_AES_CMD_GET_SUPPORTED_TYPES,
+            ))?
+            .map(AES_MODE::from_bits_retain),
+            auto_white_balance: optional(auto_parameter(
+                camera,
+                IS_AUTOPARAMETER_CMD::IS_AWB_CMD_GET_SUPPORTED_TYPES,
+            ))?
+            .map(AWB_MODE::from_bits_retain),
+        })
+    }
+}
+
+impl Camera {
+    /// Query the capabilities of the camera across all functions.
+    #[inline]
+    pub fn capabilities(&self) -> Result<CameraCapabilities> {
+        CameraCapabilities::query(self)
+    }
+}
+
+fn focus(camera: &Camera) -> Result<FOCUS_CAPABILITY_FLAGS> {
+    let mut caps = FOCUS_CAPABILITY_FLAGS::empty();
+    device_command::<FOC_CMD_GET_CAPABILITIES>(camera, &mut caps)?;
+    Ok(caps)
+}
+
+fn io(camera: &Camera) -> Result<IoCapabilities> {
+    let gpios = gpio::supported(camera)?;
+    if gpios.is_empty() {
+        return Err(Error::NotSupported);
+    }
+
+    Ok(IoCapabilities {
+        gpios,
+        outputs: optional(gpio::supported_outputs(camera))?.unwrap_or(IO_GPIO::empty()),
+        flash: optional(gpio::supported_flash(camera))?.unwrap_or(IO_GPIO::empty()),
+        pwm: optional(gpio::supported_pwm(camera))?.unwrap_or(IO_GPIO::empty()),
+    })
+}
+
+fn ip_config(camera: &Camera) -> Result<IpConfigCapabilities> {
+    let info = eth::device_info(camera.handle())?;
+    let mac =
+        unsafe { ptr::addr_of!((*info.as_ptr()).infoDevHeartbeat.macDevice).read_unaligned() };
+
+    let mut caps: UINT = 0;
+    check(unsafe {
+        call!(is_IpConfig(
+            -1,
+            mac,
+            IPCONFIG_CMD::IPCONFIG_CMD_QUERY_CAPABILITIES,
+            &mut caps as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(IpConfigCapabilities::from(caps))
+}
+
+fn auto_parameter(camera: &Camera, cmd: IS_AUTOPARAMETER_CMD) -> Result<UINT> {
+    let mut modes: UINT = 0;
+    check(unsafe {
+        command!(is_AutoParameter(
+            camera.handle(),
+            cmd,
+            &mut modes as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(modes)
+}
@@ -0,0 +1,294 @@
+//! Continuous capture into a ring buffer sequence.
+//!
+//! [`FrameIterator`] allocates a [`RingBuffer`] of image memories, starts live capture and yields
+//! each newly captured frame as a [`LockedFrame`]. While a frame is alive, its image memory is
+//! [locked](RingBuffer::lock) so the driver skips it; dropping the frame unlocks it again. Each
+//! frame carries its [`FrameInfo`], recorded as it is received.
+//!
+//! If the consumer holds on to every image memory at once, the driver has nowhere to store new
+//! images and frames are lost until one is released. To hand frames to a consumer on another
+//! thread with an explicit [`DropPolicy`](crate::queue::DropPolicy) instead,
+//! [`FrameIterator::forward`] copies each frame into a [`FrameQueue`], whose
+//! [statistics](FrameQueue::stats) count the frames the policy discarded.
+//!
+//! A [hook](FrameIterator::with_hook) can change exposure, gain or flash settings between frames.
+//!
+//! # Documentation
+//! [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+
+use std::{
+    rc::Rc,
+    time::{Duration, SystemTime},
+};
+
+use ueye_sys::{
+    constants::event::IS_SET_EVENT_FRAME,
+    types::{HIDS, INT},
+    video::{is_CaptureVideo, is_StopLiveVideo, IS_DONT_WAIT, IS_FORCE_VIDEO_STOP},
+};
+
+use crate::{
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{check, Result},
+    event::{exit_events, init_events, wait_one},
+    exposure,
+    flash::{self, FlashParams},
+    frame::{Frame, FrameBuf},
+    gain,
+    image_info::FrameInfo,
+    metadata::ImageMetadata,
+    observer::call,
+    queue::FrameQueue,
+    ring_buffer::RingBuffer,
+};
+
+/// Configuration of a [`FrameIterator`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CaptureOptions {
+    /// Number of image memories in the sequence (at least one).
+    pub buffers: usize,
+
+    /// Color mode of the image memories, matching the color mode set on the camera.
+    pub color_mode: ColorMode,
+
+    /// Longest time to wait for a frame.
+    pub timeout: Duration,
+}
+
+impl Default for CaptureOptions {
+    fn default() -> Self {
+        Self { buffers: 4, color_mode: ColorMode::Mono8, timeout: Duration::from_secs(1) }
+    }
+}
+
+/// Frame held in a locked image memory of the sequence.
+///
+/// The image memory is unlocked when the frame is dropped.
+#[derive(Debug)]
+pub struct LockedFrame<'c> {
+    ring: Rc<RingBuffer<'c>>,
+    position: usize,
+    info: FrameInfo,
+}
+
+impl LockedFrame<'_> {
+    /// ID of the image memory holding the frame.
+    #[inline]
+    pub fn mem_id(&self) -> INT {
+        self.ring.id(self.position).expect("locked positions are in range")
+    }
+
+    /// Information of the frame, recorded when it was received.
+    #[inline]
+    pub fn info(&self) -> &FrameInfo {
+        &self.info
+    }
+
+    /// Query the metadata of the frame.
+    #[inline]
+    pub fn metadata(&self) -> Result<ImageMetadata> {
+        self.ring.metadata(self.position)
+    }
+
+    /// Borrow the image data.
+    ///
+    /// The image memory is locked against writes by the driver until this frame is dropped.
+    #[inline]
+    pub fn as_frame(&self) -> Result<Frame<'_>> {
+        // SAFETY: the memory is only unlocked when this frame is dropped, after the borrow ends.
+        unsafe { self.ring.frame(self.position) }
+    }
+}
+
+impl Drop for LockedFrame<'_> {
+    fn drop(&mut self) {
+        let _ = self.ring.unlock(self.position);
+    }
+}
+
+/// Register and enable the frame event, then start live capture.
+pub(crate) fn start(camera: &Camera) -> Result<()> {
+    init_events(camera.handle(), &[IS_SET_EVENT_FRAME])?;
+    check(unsafe { call!(is_CaptureVideo(camera.handle(), IS_DONT_WAIT as INT)) })
+}
+
+/// Stop live capture and release the frame event, undoing [`start`].
+pub(crate) fn stop(camera: &Camera) {
+    unsafe { call!(is_StopLiveVideo(camera.handle(), IS_FORCE_VIDEO_STOP as INT)) };
+    exit_events(camera.handle(), &[IS_SET_EVENT_FRAME]);
+}
+
+/// Block until the frame event is signaled or `timeout` elapses.
+#[inline]
+pub(crate) fn wait_frame(hcam: HIDS, timeout: Duration) -> Result<()> {
+    wait_one(hcam, IS_SET_EVENT_FRAME, timeout)
+}
+
+/// Settings that a [frame hook](FrameIterator::with_hook) may change between two frames.
+#[derive(Debug)]
+pub struct FrameSettings<'c> {
+    camera: &'c Camera,
+    frame: u64,
+}
+
+impl<'c> FrameSettings<'c> {
+    /// Camera the settings are applied to.
+    #[inline]
+    pub fn camera(&self) -> &'c Camera {
+        self.camera
+    }
+
+    /// Index of the frame the settings apply to, counting from `0` since capture started.
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Set the exposure time (in ms), returning the exposure time actually set.
+    #[inline]
+    pub fn set_exposure(&self, ms: f64) -> Result<f64> {
+        exposure::set(self.camera, ms)
+    }
+
+    /// Set the master gain (`0`…`100`).
+    #[inline]
+    pub fn set_gain(&self, master: INT) -> Result<()> {
+        gain::set(self.camera, master)
+    }
+
+    /// Set the flash delay and duration.
+    #[inline]
+    pub fn set_flash(&self, params: FlashParams) -> Result<()> {
+        flash::set_params(self.camera, params)
+    }
+}
+
+/// Hook run by a [`FrameIterator`] on every frame boundary.
+type FrameHook<'c> = Box<dyn FnMut(&FrameSettings<'c>) -> Result<()> + 'c>;
+
+/// Blocking iterator over frames captured in live mode.
+///
+/// The iterator never ends; failures such as [`Error::TimedOut`](crate::Error::TimedOut) are
+/// yielded as items, leaving it to the caller whether to continue. Live capture is stopped when the
+/// iterator is dropped.
+pub struct FrameIterator<'c> {
+    camera: &'c Camera,
+    ring: Rc<RingBuffer<'c>>,
+    timeout: Duration,
+    hook: Option<FrameHook<'c>>,
+    frames: u64,
+}
+
+impl std::fmt::Debug for FrameIterator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrameIterator")
+            .field("camera", &self.camera)
+            .field("ring", &self.ring)
+            .field("timeout", &self.timeout)
+            .field("hook", &self.hook.is_some())
+            .field("frames", &self.frames)
+            .finish()
+    }
+}
+
+impl<'c> FrameIterator<'c> {
+    /// Allocate the sequence for the current AOI and start live capture.
+    pub fn new(camera: &'c Camera, options: &CaptureOptions) -> Result<Self> {
+        Self::begin(camera, options, None)
+    }
+
+    /// Allocate the sequence for the current AOI and start live capture, running `hook` before
+    /// every frame to change exposure, gain or flash settings, e.g. to alternate bright and dark
+    /// frames.
+    ///
+    /// The hook runs for frame `0` before capture starts, then for each following frame as soon as
+    /// the previous one arrived. In triggered mode, changes therefore land on frame boundaries as
+    /// long as the next trigger arrives after the hook returned; in freerun mode, the next
+    /// exposure may already be running. If the hook fails, the frame that just arrived is released
+    /// and the error yielded in its place.
+    pub fn with_hook(
+        camera: &'c Camera,
+        options: &CaptureOptions,
+        hook: impl FnMut(&FrameSettings<'c>) -> Result<()> + 'c,
+    ) -> Result<Self> {
+        Self::begin(camera, options, Some(Box::new(hook)))
+    }
+
+    fn begin(
+        camera: &'c Camera,
+        options: &CaptureOptions,
+        hook: Option<FrameHook<'c>>,
+    ) -> Result<Self> {
+        let ring = Rc::new(RingBuffer::alloc(camera, options.buffers, options.color_mode)?);
+
+        // from here on, `Drop` undoes the event registration and the live capture
+        let mut iterator = Self { camera, ring, timeout: options.timeout, hook, frames: 0 };
+        iterator.run_hook()?;
+        start(camera)?;
+
+        Ok(iterator)
+    }
+
+    /// Run the hook for the next frame, if any.
+    fn run_hook(&mut self) -> Result<()> {
+        match &mut self.hook {
+            Some(hook) => hook(&FrameSettings { camera: self.camera, frame: self.frames }),
+            None => Ok(()),
+        }
+    }
+
+    /// Wait for the next frame and lock it.
+    pub fn next_frame(&mut self) -> Result<LockedFrame<'c>> {
+        wait_frame(self.camera.handle(), self.timeout)?;
+        let received = SystemTime::now();
+        let position = self.ring.lock_last()?;
+
+        // unlocked again if querying its information fails
+        let info = self.ring.frame_info(position, received).inspect_err(|_| {
+            let _ = self.ring.unlock(position);
+        })?;
+        let frame = LockedFrame { ring: Rc::clone(&self.ring), position, info };
+
+        self.frames += 1;
+        self.run_hook()?;
+
+        Ok(frame)
+    }
+
+    /// Copy each frame into `queue` until it is closed, applying the
+    /// [`DropPolicy`](crate::queue::DropPolicy) of the queue when the consumer falls behind.
+    ///
+    /// The image memory of each frame is released as soon as it is copied. While a
+    /// [blocking](crate::queue::DropPolicy::Block) queue is full, no frame is taken out of the
+    /// ring buffer, so the driver drops frames once every image memory is filled.
+    ///
+    /// Returns once the queue is [closed](FrameQueue::close), or with the first failure to
+    /// capture, e.g. [`Error::TimedOut`](crate::error::Error::TimedOut).
+    pub fn forward(&mut self, queue: &FrameQueue<(FrameInfo, FrameBuf)>) -> Result<()> {
+        while !queue.is_closed() {
+            let frame = self.next_frame()?;
+            let copy = (frame.info, FrameBuf::copy_from(&frame.as_frame()?));
+            drop(frame);
+
+            queue.push(copy);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'c> Iterator for FrameIterator<'c> {
+    type Item = Result<LockedFrame<'c>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_frame())
+    }
+}
+
+impl Drop for FrameIterator<'_> {
+    fn drop(&mut self) {
+        stop(self.camera);
+    }
+}
@@ -0,0 +1,192 @@
+//! Capture state machine.
+//!
+//! A [`CaptureControl`] tracks whether a camera is stopped, capturing live in freerun mode, armed
+//! for triggered capture or in standby, and only allows the transitions between them that the
+//! driver supports. Each transition issues the driver calls in the order they are required, e.g.
+//! the trigger mode is set before live capture is started, and live capture is stopped before the
+//! camera enters standby.
+//!
+//! ```text
+//!            start_live              enter_standby
+//!   Live  <-------------  Stopped  ---------------->  Standby
+//!         -------------->    ^ |   <----------------
+//!              stop          | |         wake
+//!                       stop | | arm
+//!                            | v
+//!                           Armed
+//! ```
+//!
+//! Transitions that are not allowed from the current state fail with
+//! [`Error::InvalidCaptureMode`], or [`Error::CaptureRunning`] when capture has to be stopped
+//! first, without calling the driver.
+//!
+//! # Documentation
+//! * [is_CaptureVideo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturevideo.html)
+//! * [is_StopLiveVideo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_stoplivevideo.html)
+
+use ueye_sys::{
+    types::{INT, TRUE},
+    video::{is_CaptureVideo, is_StopLiveVideo, IS_DONT_WAIT, IS_FORCE_VIDEO_STOP, IS_GET_LIVE},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+    operating_mode,
+    trigger::TriggerMode,
+};
+
+/// Capture state of a camera.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum CaptureState {
+    /// No capture is running.
+    #[default]
+    Stopped,
+
+    /// Images are captured continuously (_freerun live_).
+    Live,
+
+    /// Images are captured on every trigger of the given mode.
+    Armed(TriggerMode),
+
+    /// The sensor is powered down.
+    Standby,
+}
+
+impl CaptureState {
+    /// Query the capture state of the camera.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        if operating_mode::is_standby(camera)? {
+            return Ok(Self::Standby);
+        }
+
+        if unsafe { call!(is_CaptureVideo(camera.handle(), IS_GET_LIVE as INT)) } != TRUE as INT {
+            return Ok(Self::Stopped);
+        }
+
+        Ok(match TriggerMode::current(camera)? {
+            TriggerMode::Off => Self::Live,
+            mode => Self::Armed(mode),
+        })
+    }
+
+    /// Whether images are being captured, live or on triggers.
+    #[inline]
+    pub const fn is_capturing(self) -> bool {
+        matches!(self, Self::Live | Self::Armed(_))
+    }
+}
+
+/// Capture state machine of a camera.
+#[derive(Debug)]
+pub struct CaptureControl<'c> {
+    camera: &'c Camera,
+    state: CaptureState,
+}
+
+impl<'c> CaptureControl<'c> {
+    /// Take control of the capture of `camera`, starting from its current state.
+    pub fn new(camera: &'c Camera) -> Result<Self> {
+        Ok(Self { camera, state: CaptureState::current(camera)? })
+    }
+
+    /// Current state.
+    #[inline]
+    pub fn state(&self) -> CaptureState {
+        self.state
+    }
+
+    /// Fail unless the camera is stopped.
+    fn expect_stopped(&self) -> Result<()> {
+        match self.state {
+            CaptureState::Stopped => Ok(()),
+            CaptureState::Live | CaptureState::Armed(_) => Err(Error::CaptureRunning),
+            CaptureState::Standby => Err(Error::InvalidCaptureMode),
+        }
+    }
+
+    /// _Stopped → Live:_ disable the trigger and start live capture.
+    pub fn start_live(&mut self) -> Result<()> {
+        self.expect_stopped()?;
+
+        TriggerMode::Off.apply(self.camera)?;
+        check(unsafe { call!(is_CaptureVideo(self.camera.handle(), IS_DONT_WAIT as INT)) })?;
+
+        self.state = CaptureState::Live;
+        Ok(())
+    }
+
+    /// _Stopped → Armed:_ set the trigger `mode` and start capturing on every trigger.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for [`TriggerMode::Off`]; use
+    /// [`CaptureControl::start_live`] instead.
+    pub fn arm(&mut self, mode: TriggerMode) -> Result<()> {
+        self.expect_stopped()?;
+        if mode == TriggerMode::Off {
+            return Err(Error::InvalidParameter);
+        }
+
+        mode.apply(self.camera)?;
+        check(unsafe { call!(is_CaptureVideo(self.camera.handle(), IS_DONT_WAIT as INT)) })?;
+
+        self.state = CaptureState::Armed(mode);
+        Ok(())
+    }
+
+    /// _Live or Armed → Stopped:_ stop capturing.
+    pub fn stop(&mut self) -> Result<()> {
+        if !self.state.is_capturing() {
+            return Err(Error::InvalidCaptureMode);
+        }
+
+        check(unsafe {
+            call!(is_StopLiveVideo(self.camera.handle(), IS_FORCE_VIDEO_STOP as INT))
+        })?;
+
+        self.state = CaptureState::Stopped;
+        Ok(())
+    }
+
+    /// _Stopped → Standby:_ power down the sensor.
+    pub fn enter_standby(&mut self) -> Result<()> {
+        self.expect_stopped()?;
+
+        operating_mode::enter_standby(self.camera)?;
+
+        self.state = CaptureState::Standby;
+        Ok(())
+    }
+
+    /// _Standby → Stopped:_ power up the sensor.
+    pub fn wake(&mut self) -> Result<()> {
+        if self.state != CaptureState::Standby {
+            return Err(Error::InvalidCaptureMode);
+        }
+
+        operating_mode::leave_standby(self.camera)?;
+
+        self.state = CaptureState::Stopped;
+        Ok(())
+    }
+
+    /// Reach `target` from the current state through the allowed transitions.
+    pub fn transition(&mut self, target: CaptureState) -> Result<()> {
+        if self.state == target {
+            return Ok(());
+        }
+
+        match self.state {
+            CaptureState::Live | CaptureState::Armed(_) => self.stop()?,
+            CaptureState::Standby => self.wake()?,
+            CaptureState::Stopped => {},
+        }
+
+        match target {
+            CaptureState::Stopped => Ok(()),
+            CaptureState::Live => self.start_live(),
+            CaptureState::Armed(mode) => self.arm(mode),
+            CaptureState::Standby => self.enter_standby(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Capture errors counted by the driver.
+//!
+//! The driver counts every failed or discarded capture by cause since the camera was opened or
+//! the counters were [reset]. [`CaptureStats`] holds the counters in named fields; the
+//! driver signals [`EventKind::CaptureStatus`](crate::event::EventKind::CaptureStatus) whenever
+//! one of them increases.
+//!
+//! # Documentation
+//! [is_CaptureStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturestatus.html)
+
+use std::{mem::size_of, ptr};
+
+use ueye_sys::{
+    capture_status::{
+        is_CaptureStatus, IS_CAPTURE_STATUS_CMD, UEYE_CAPTURE_STATUS, UEYE_CAPTURE_STATUS_INFO,
+    },
+    types::{void, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    observer::command,
+};
+
+use UEYE_CAPTURE_STATUS::*;
+
+/// Capture error counters, by cause.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureStats {
+    /// Sum of all counters.
+    pub total: u32,
+
+    /// No destination memory was available for the image.
+    pub no_dest_mem: u32,
+
+    /// The image could not be converted to the color format.
+    pub conversion_failed: u32,
+
+    /// All destination memories were locked.
+    pub image_locked: u32,
+
+    /// The driver ran out of internal buffers.
+    pub out_of_buffers: u32,
+
+    /// The camera was not ready when the image was requested.
+    pub device_not_ready: u32,
+
+    /// The image was not transferred completely, e.g. because the USB bandwidth was exceeded.
+    pub transfer_failed: u32,
+
+    /// The camera could not transfer images fast enough and skipped them.
+    pub missed_images: u32,
+
+    /// The camera did not deliver the image within the timeout.
+    pub device_timeout: u32,
+
+    /// The camera failed to capture the image.
+    pub frame_capture_failed: u32,
+
+    /// _GigE only:_ the network buffer of the host overflowed.
+    pub eth_buffer_overrun: u32,
+}
+
+impl CaptureStats {
+    /// Every cause counted by the driver.
+    pub const CAUSES: [UEYE_CAPTURE_STATUS; 10] = [
+        IS_CAP_STATUS_API_NO_DEST_MEM,
+        IS_CAP_STATUS_API_CONVERSION_FAILED,
+        IS_CAP_STATUS_API_IMAGE_LOCKED,
+        IS_CAP_STATUS_DRV_OUT_OF_BUFFERS,
+        IS_CAP_STATUS_DRV_DEVICE_NOT_READY,
+        IS_CAP_STATUS_TRANSFER_FAILED,
+        IS_CAP_STATUS_DEV_MISSED_IMAGES,
+        IS_CAP_STATUS_DEV_TIMEOUT,
+        IS_CAP_STATUS_DEV_FRAME_CAPTURE_FAILED,
+        IS_CAP_STATUS_ETH_BUFFER_OVERRUN,
+    ];
+
+    /// Query the counters of `camera`.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        let mut info = UEYE_CAPTURE_STATUS_INFO::default();
+        check(unsafe {
+            command!(is_CaptureStatus(
+                camera.handle(),
+                IS_CAPTURE_STATUS_CMD::IS_CAPTURE_STATUS_INFO_CMD_GET,
+                &mut info as *mut UEYE_CAPTURE_STATUS_INFO as *mut void,
+                size_of::<UEYE_CAPTURE_STATUS_INFO>() as UINT,
+            ))
+        })?;
+
+        Ok(Self::from(info))
+    }
+
+    /// Counter of `cause`.
+    pub const fn count(&self, cause: UEYE_CAPTURE_STATUS) -> u32 {
+        match cause {
+            IS_CAP_STATUS_API_NO_DEST_MEM => self.no_dest_mem,
+            IS_CAP_STATUS_API_CONVERSION_FAILED => self.conversion_failed,
+            IS_CAP_STATUS_API_IMAGE_LOCKED => self.image_locked,
+            IS_CAP_STATUS_DRV_OUT_OF_BUFFERS => self.out_of_buffers,
+            IS_CAP_STATUS_DRV_DEVICE_NOT_READY => self.device_not_ready,
+            IS_CAP_STATUS_TRANSFER_FAILED => self.transfer_failed,
+            IS_CAP_STATUS_DEV_MISSED_IMAGES => self.missed_images,
+            IS_CAP_STATUS_DEV_TIMEOUT => self.device_timeout,
+            IS_CAP_STATUS_DEV_FRAME_CAPTURE_FAILED => self.frame_capture_failed,
+            IS_CAP_STATUS_ETH_BUFFER_OVERRUN => self.eth_buffer_overrun,
+        }
+    }
+
+    /// Causes with a non-zero counter, along with the counter.
+    pub fn errors(&self) -> impl Iterator<Item = (UEYE_CAPTURE_STATUS, u32)> + '_ {
+        Self::CAUSES
+            .into_iter()
+            .map(|cause| (cause, self.count(cause)))
+            .filter(|&(_, count)| count != 0)
+    }
+
+    /// Whether any capture failed.
+    #[inline]
+    pub const fn has_errors(&self) -> bool {
+        self.total != 0
+    }
+
+    /// Counters accumulated since `earlier`, an earlier query of the same camera.
+    ///
+    /// Counters that decreased in between, because they were [reset], count from `0`.
+    pub const fn since(&self, earlier: &CaptureStats) -> Self {
+        Self {
+            total: self.total.saturating_sub(earlier.total),
+            no_dest_mem: self.no_dest_mem.saturating_sub(earlier.no_dest_mem),
+            conversion_failed: self.conversion_failed.saturating_sub(earlier.conversion_failed),
+            image_locked: self.image_locked.saturating_sub(earlier.image_locked),
+            out_of_buffers: self.out_of_buffers.saturating_sub(earlier.out_of_buffers),
+            device_not_ready: self.device_not_ready.saturating_sub(earlier.device_not_ready),
+            transfer_failed: self.transfer_failed.saturating_sub(earlier.transfer_failed),
+            missed_images: self.missed_images.saturating_sub(earlier.missed_images),
+            device_timeout: self.device_timeout.saturating_sub(earlier.device_timeout),
+            frame_capture_failed: self
+                .frame_capture_failed
+                .saturating_sub(earlier.frame_capture_failed),
+            eth_buffer_overrun: self.eth_buffer_overrun.saturating_sub(earlier.eth_buffer_overrun),
+        }
+    }
+}
+
+impl From<UEYE_CAPTURE_STATUS_INFO> for CaptureStats {
+    fn from(info: UEYE_CAPTURE_STATUS_INFO) -> Self {
+        let count = |cause: UEYE_CAPTURE_STATUS| info.adwCapStatusCnt_Detail[cause as usize];
+        Self {
+            total: info.dwCapStatusCnt_Total,
+            no_dest_mem: count(IS_CAP_STATUS_API_NO_DEST_MEM),
+            conversion_failed: count(IS_CAP_STATUS_API_CONVERSION_FAILED),
+            image_locked: count(IS_CAP_STATUS_API_IMAGE_LOCKED),
+            out_of_buffers: count(IS_CAP_STATUS_DRV_OUT_OF_BUFFERS),
+            device_not_ready: count(IS_CAP_STATUS_DRV_DEVICE_NOT_READY),
+            transfer_failed: count(IS_CAP_STATUS_TRANSFER_FAILED),
+            missed_images: count(IS_CAP_STATUS_DEV_MISSED_IMAGES),
+            device_timeout: count(IS_CAP_STATUS_DEV_TIMEOUT),
+            frame_capture_failed: count(IS_CAP_STATUS_DEV_FRAME_CAPTURE_FAILED),
+            eth_buffer_overrun: count(IS_CAP_STATUS_ETH_BUFFER_OVERRUN),
+        }
+    }
+}
+
+impl Camera {
+    /// Query the capture error counters of the camera, see [`CaptureStats::query`].
+    #[inline]
+    pub fn capture_stats(&self) -> Result<CaptureStats> {
+        CaptureStats::query(self)
+    }
+}
+
+/// Reset all capture error counters of `camera`.
+pub fn reset(camera: &Camera) -> Result<()> {
+    check(unsafe {
+        command!(is_CaptureStatus(
+            camera.handle(),
+            IS_CAPTURE_STATUS_CMD::IS_CAPTURE_STATUS_INFO_CMD_RESET,
+            ptr::null_mut(),
+            0
+        ))
+    })
+}
+
+/// Query the number of CRC errors detected in transferred images.
+pub fn crc_error_count(camera: &Camera) -> Result<u32> {
+    let mut count: UINT = 0;
+    check(unsafe {
+        command!(is_CaptureStatus(
+            camera.handle(),
+            IS_CAPTURE_STATUS_CMD::IS_CAPTURE_STATUS_CRC_ERROR_COUNT_GET,
+            &mut count as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(count)
+}
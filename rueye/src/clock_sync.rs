@@ -0,0 +1,302 @@
+//! Device clock synchronization across multiple cameras.
+//!
+//! Each camera stamps its frames with its own free-running device clock. To compare timestamps of
+//! several GigE cameras, [`TimestampReset::arm_all`] configures every camera to reset its clock to
+//! `0` on the next edge of a shared trigger signal. A [`SkewEstimator`] then collects the device
+//! timestamps of frames captured on the same subsequent triggers and reports the residual offset,
+//! jitter and drift of each camera against the first one.
+//!
+//! # Documentation
+//! * [Configuring the timestamp](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeaturetimestamp.html)
+//! * [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+use ueye_sys::{
+    device_feature::{
+        IS_TIMESTAMP_CONFIGURATION, TIMESTAMP_CONFIGURATION_EDGE, TIMESTAMP_CONFIGURATION_MODE,
+        TIMESTAMP_CONFIGURATION_PIN,
+    },
+    types::INT,
+};
+
+use crate::{
+    camera::Camera,
+    command::{device_command, device_feature::IS_DEVICE_FEATURE_CMD_SET_TIMESTAMP_CONFIGURATION},
+    error::{Error, Result},
+    image_info,
+};
+
+/// Device timestamp ticks per microsecond.
+const TICKS_PER_US: f64 = 10.0;
+
+/// One-time reset of the device timestamp on an external signal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TimestampReset {
+    /// Input pin carrying the shared reset signal.
+    pub pin: TIMESTAMP_CONFIGURATION_PIN,
+
+    /// Signal edge resetting the timestamp.
+    pub edge: TIMESTAMP_CONFIGURATION_EDGE,
+}
+
+impl Default for TimestampReset {
+    fn default() -> Self {
+        Self {
+            pin: TIMESTAMP_CONFIGURATION_PIN::TIMESTAMP_CONFIGURATION_PIN_TRIGGER,
+            edge: TIMESTAMP_CONFIGURATION_EDGE::TIMESTAMP_CONFIGURATION_EDGE_RISING,
+        }
+    }
+}
+
+impl TimestampReset {
+    /// Configuration passed to [`IS_DEVICE_FEATURE_CMD_SET_TIMESTAMP_CONFIGURATION`].
+    #[inline]
+    pub const fn configuration(self) -> IS_TIMESTAMP_CONFIGURATION {
+        IS_TIMESTAMP_CONFIGURATION {
+            s32Mode: TIMESTAMP_CONFIGURATION_MODE::IS_RESET_TIMESTAMP_ONCE,
+            s32Pin: self.pin,
+            s32Edge: self.edge,
+        }
+    }
+
+    /// Arm the timestamp reset on the camera.
+    pub fn arm(self, camera: &Camera) -> Result<()> {
+        let mut configuration = self.configuration();
+        device_command::<IS_DEVICE_FEATURE_CMD_SET_TIMESTAMP_CONFIGURATION>(
+            camera,
+            &mut configuration,
+        )
+    }
+
+    /// Arm the timestamp reset on all cameras, stopping at the first failure.
+    ///
+    /// The reset happens on the next signal edge, so the shared trigger must not fire before this
+    /// returns.
+    pub fn arm_all(self, cameras: &[&Camera]) -> Result<()> {
+        cameras.iter().try_for_each(|camera| self.arm(camera))
+    }
+}
+
+/// Residual clock error of one camera against the reference camera.
+///
+/// All times are in µs.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct CameraSkew {
+    /// Index of the camera, as passed to [`SkewEstimator::record`].
+    pub camera: usize,
+
+    /// Mean offset of the device timestamp against the reference camera.
+    pub mean_offset: f64,
+
+    /// Largest absolute offset against the reference camera.
+    pub max_offset: f64,
+
+    /// Standard deviation of the offset.
+    pub jitter: f64,
+
+    /// Rate at which the offset grows (in µs per second, i.e. ppm).
+    pub drift_ppm: f64,
+}
+
+/// Estimates per-camera clock skew from frames captured on shared triggers.
+///
+/// The first camera serves as reference.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkewEstimator {
+    cameras: usize,
+    events: Vec<Vec<u64>>,
+}
+
+impl SkewEstimator {
+    /// Estimator for `cameras` cameras.
+    pub fn new(cameras: usize) -> Self {
+        Self { cameras, events: Vec::new() }
+    }
+
+    /// Number of cameras.
+    #[inline]
+    pub const fn cameras(&self) -> usize {
+        self.cameras
+    }
+
+    /// Number of recorded trigger events.
+    #[inline]
+    pub fn events(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Record the device timestamps (in 0.1 µs steps) of the frames captured on one trigger,
+    /// in camera order.
+    ///
+    /// # Panics
+    /// If the number of timestamps does not match [`SkewEstimator::cameras`].
+    pub fn record(&mut self, timestamps: &[u64]) {
+        assert_eq!(timestamps.len(), self.cameras, "one timestamp per camera expected");
+        self.events.push(timestamps.to_vec());
+    }
+
+    /// Record the device timestamps of the frames captured on one trigger, given as camera
+    /// handle and image memory ID in camera order.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if the number of frames does not match
+    /// [`SkewEstimator::cameras`].
+    pub fn record_frames(&mut self, frames: &[(&Camera, INT)]) -> Result<()> {
+        if frames.len() != self.cameras {
+            return Err(Error::InvalidParameter);
+        }
+
+        let timestamps = frames
+            .iter()
+            .map(|&(camera, mem_id)| image_info::device_timestamp(camera, mem_id))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.record(&timestamps);
+        Ok(())
+    }
+
+    /// Discard all recorded events, e.g. after re-arming the timestamp reset.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Skew of every camera against the reference camera.
+    ///
+    /// The reference camera is included with zero skew. Drift requires at least two events
+    /// spread over time and is `0` otherwise.
+    pub fn report(&self) -> Vec<CameraSkew> {
+        (0..self.cameras).map(|camera| self.skew(camera)).collect()
+    }
+
+    fn skew(&self, camera: usize) -> CameraSkew {
+        let mut skew = CameraSkew { camera, ..CameraSkew::default() };
+        if self.events.is_empty() || camera == 0 {
+            return skew;
+        }
+
+        // (reference time, offset) per event, in µs
+        let points: Vec<(f64, f64)> = self
+            .events
+            .iter()
+            .map(|timestamps| {
+                let reference = timestamps[0];
+                let offset = timestamps[camera] as i128 - reference as i128;
+                (reference as f64 / TICKS_PER_US, offset as f64 / TICKS_PER_US)
+            })
+            .collect();
+
+        let n = points.len() as f64;
+        let mean_time = points.iter().map(|&(t, _)| t).sum::<f64>() / n;
+        skew.mean_offset = points.iter().map(|&(_, o)| o).sum::<f64>() / n;
+        skew.max_offset = points.iter().map(|&(_, o)| o.abs()).fold(0.0, f64::max);
+        skew.jitter =
+            (points.iter().map(|&(_, o)| (o - skew.mean_offset).powi(2)).sum::<f64>() / n).sqrt();
+
+        // least-squares slope of offset over time; µs/µs scaled to ppm
+        let (covariance, variance) = points.iter().fold((0.0, 0.0), |(c, v), &(t, o)| {
+            let dt = t - mean_time;
+            (c + dt * (o - skew.mean_offset), v + dt * dt)
+        });
+        if variance > 0.0 {
+            skew.drift_ppm = covariance / variance * 1e6;
+        }
+
+        skew
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Device ticks per second.
+    const TICKS_PER_S: u64 = 10_000_000;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-6, "{actual} instead of {expected}");
+    }
+
+    /// Estimator fed with one trigger per second for six seconds, with camera 1 starting 50 µs
+    /// ahead and gaining 20 ppm, and camera 2 starting 30 µs behind and losing 10 ppm.
+    fn drifting() -> SkewEstimator {
+        let mut estimator = SkewEstimator::new(3);
+        for second in 0..6 {
+            let reference = 1_000 + second * TICKS_PER_S;
+            estimator.record(&[
+                reference,
+                reference + 500 + second * 200,
+                reference - 300 - second * 100,
+            ]);
+        }
+        estimator
+    }
+
+    #[test]
+    fn reference_has_no_skew() {
+        assert_eq!(drifting().report()[0], CameraSkew { camera: 0, ..CameraSkew::default() });
+    }
+
+    #[test]
+    fn drift_of_fast_camera() {
+        let skew = drifting().report()[1];
+        assert_eq!(skew.camera, 1);
+        assert_close(skew.drift_ppm, 20.0);
+        assert_close(skew.mean_offset, 100.0);
+        assert_close(skew.max_offset, 150.0);
+        assert_close(skew.jitter, 20.0 * (35.0f64 / 12.0).sqrt());
+    }
+
+    #[test]
+    fn drift_of_slow_camera() {
+        let skew = drifting().report()[2];
+        assert_close(skew.drift_ppm, -10.0);
+        assert_close(skew.mean_offset, -55.0);
+        assert_close(skew.max_offset, 80.0);
+    }
+
+    #[test]
+    fn constant_offset_has_no_drift_or_jitter() {
+        let mut estimator = SkewEstimator::new(2);
+        for second in 0..4 {
+            let reference = second * TICKS_PER_S;
+            estimator.record(&[reference, reference + 25]);
+        }
+
+        let skew = estimator.report()[1];
+        assert_close(skew.mean_offset, 2.5);
+        assert_close(skew.jitter, 0.0);
+        assert_close(skew.drift_ppm, 0.0);
+    }
+
+    #[test]
+    fn single_event_has_no_drift() {
+        let mut estimator = SkewEstimator::new(2);
+        estimator.record(&[TICKS_PER_S, TICKS_PER_S + 40]);
+
+        let skew = estimator.report()[1];
+        assert_close(skew.mean_offset, 4.0);
+        assert_close(skew.drift_ppm, 0.0);
+    }
+
+    #[test]
+    fn offsets_beyond_i64_do_not_overflow() {
+        let mut estimator = SkewEstimator::new(2);
+        estimator.record(&[u64::MAX, 0]);
+
+        assert_close(estimator.report()[1].mean_offset, -(u64::MAX as f64) / TICKS_PER_US);
+    }
+
+    #[test]
+    fn clear_discards_events() {
+        let mut estimator = drifting();
+        estimator.clear();
+
+        assert_eq!(estimator.events(), 0);
+        assert_eq!(estimator.report()[1], CameraSkew { camera: 1, ..CameraSkew::default() });
+    }
+
+    #[test]
+    #[should_panic(expected = "one timestamp per camera expected")]
+    fn record_rejects_missing_timestamps() {
+        SkewEstimator::new(2).record(&[0]);
+    }
+}
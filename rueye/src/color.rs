@@ -0,0 +1,212 @@
+//! Color correction of color cameras.
+//!
+//! The color correction compensates the crosstalk between the color channels of the sensor with a
+//! 3×3 matrix, whose effect is scaled by a strength from `0.0` (_none_) to `1.0` (_full_). The
+//! matrix itself is chosen by the [`ColorCorrectionMode`], matching the optical filter of the
+//! camera.
+//!
+//! # Documentation
+//! [is_SetColorCorrection](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setcolorcorrection.html)
+
+use ueye_sys::{
+    color::{
+        is_SetColorCorrection, IS_CCOR_DISABLE, IS_CCOR_ENABLE_BG40_ENHANCED,
+        IS_CCOR_ENABLE_HQ_ENHANCED, IS_CCOR_ENABLE_NORMAL, IS_CCOR_SET_IR_AUTOMATIC,
+        IS_GET_CCOR_FACTOR, IS_GET_CCOR_FACTOR_DEFAULT, IS_GET_CCOR_FACTOR_MAX,
+        IS_GET_CCOR_FACTOR_MIN, IS_GET_CCOR_MODE, IS_GET_DEFAULT_CCOR_MODE,
+        IS_GET_SUPPORTED_CCOR_MODE,
+    },
+    types::{double, INT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Color correction matrix, chosen for the optical filter of the camera.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorCorrectionMode {
+    /// Matrix of the sensor, for cameras without IR filter glass.
+    Normal,
+
+    /// Matrix enhanced for cameras with BG40 optical filter.
+    Bg40Enhanced,
+
+    /// Matrix enhanced for cameras with HQ optical filter.
+    HqEnhanced,
+
+    /// Matrix selected automatically for the IR filter of the camera.
+    IrAutomatic,
+}
+
+impl ColorCorrectionMode {
+    /// Every mode, in the order of their bits.
+    pub const ALL: [Self; 4] =
+        [Self::Normal, Self::Bg40Enhanced, Self::HqEnhanced, Self::IrAutomatic];
+
+    /// Raw value of the mode.
+    #[inline]
+    pub const fn bits(self) -> INT {
+        match self {
+            Self::Normal => IS_CCOR_ENABLE_NORMAL,
+            Self::Bg40Enhanced => IS_CCOR_ENABLE_BG40_ENHANCED,
+            Self::HqEnhanced => IS_CCOR_ENABLE_HQ_ENHANCED,
+            Self::IrAutomatic => IS_CCOR_SET_IR_AUTOMATIC,
+        }
+    }
+
+    /// Mode of a raw value, or `None` if the color correction is disabled.
+    fn from_raw(value: INT) -> Result<Option<Self>> {
+        match value {
+            IS_CCOR_DISABLE => Ok(None),
+            value => Self::try_from(value).map(Some),
+        }
+    }
+}
+
+impl TryFrom<INT> for ColorCorrectionMode {
+    type Error = Error;
+
+    fn try_from(value: INT) -> Result<Self> {
+        Self::ALL.into_iter().find(|mode| mode.bits() == value).ok_or(Error::UnexpectedValue(value))
+    }
+}
+
+impl From<ColorCorrectionMode> for INT {
+    #[inline]
+    fn from(value: ColorCorrectionMode) -> Self {
+        value.bits()
+    }
+}
+
+/// Color correction of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct ColorCorrection<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the color correction of the camera.
+    #[inline]
+    pub fn color_correction(&self) -> ColorCorrection<'_> {
+        ColorCorrection { camera: self }
+    }
+}
+
+impl ColorCorrection<'_> {
+    /// Call [`is_SetColorCorrection`] with a query returning its result in the return value.
+    fn query(&self, command: INT) -> Result<INT> {
+        // some drivers write the strength along with any query
+        let mut factor: double = 0.0;
+        match unsafe { call!(is_SetColorCorrection(self.camera.handle(), command, &mut factor)) } {
+            value if value < 0 => Err(Error::from_code(value)),
+            value => Ok(value),
+        }
+    }
+
+    /// Call [`is_SetColorCorrection`] with a query returning its result in the factor.
+    fn factor(&self, command: INT) -> Result<f64> {
+        let mut factor: double = 0.0;
+        check(unsafe { call!(is_SetColorCorrection(self.camera.handle(), command, &mut factor)) })?;
+        Ok(factor)
+    }
+
+    /// Query the current mode, or `None` if the color correction is disabled.
+    #[inline]
+    pub fn mode(&self) -> Result<Option<ColorCorrectionMode>> {
+        ColorCorrectionMode::from_raw(self.query(IS_GET_CCOR_MODE)?)
+    }
+
+    /// Query the default mode, or `None` if the color correction is disabled by default.
+    #[inline]
+    pub fn default_mode(&self) -> Result<Option<ColorCorrectionMode>> {
+        ColorCorrectionMode::from_raw(self.query(IS_GET_DEFAULT_CCOR_MODE)?)
+    }
+
+    /// Query the modes supported by the camera.
+    pub fn supported_modes(&self) -> Result<Vec<ColorCorrectionMode>> {
+        let supported = self.query(IS_GET_SUPPORTED_CCOR_MODE)?;
+        Ok(ColorCorrectionMode::ALL
+            .into_iter()
+            .filter(|mode| supported & mode.bits() != 0)
+            .collect())
+    }
+
+    /// Whether the color correction is enabled.
+    #[inline]
+    pub fn is_enabled(&self) -> Result<bool> {
+        self.mode().map(|mode| mode.is_some())
+    }
+
+    /// Enable the color correction in `mode` at `strength` (_range: `0.0`…`1.0`_).
+    pub fn enable(&self, mode: ColorCorrectionMode, strength: f64) -> Result<()> {
+        let mut factor: double = strength;
+        check(unsafe {
+            call!(is_SetColorCorrection(self.camera.handle(), mode.bits(), &mut factor))
+        })
+    }
+
+    /// Disable the color correction.
+    pub fn disable(&self) -> Result<()> {
+        let mut factor: double = 0.0;
+        check(unsafe {
+            call!(is_SetColorCorrection(self.camera.handle(), IS_CCOR_DISABLE, &mut factor))
+        })
+    }
+
+    /// Query the current strength.
+    #[inline]
+    pub fn strength(&self) -> Result<f64> {
+        self.factor(IS_GET_CCOR_FACTOR)
+    }
+
+    /// Query the default strength.
+    #[inline]
+    pub fn default_strength(&self) -> Result<f64> {
+        self.factor(IS_GET_CCOR_FACTOR_DEFAULT)
+    }
+
+    /// Query the range of the strength, as `(min, max)`.
+    #[inline]
+    pub fn strength_range(&self) -> Result<(f64, f64)> {
+        Ok((self.factor(IS_GET_CCOR_FACTOR_MIN)?, self.factor(IS_GET_CCOR_FACTOR_MAX)?))
+    }
+
+    /// Set the strength, keeping the current mode.
+    ///
+    /// Fails with [`Error::NoSuccess`] while the color correction is disabled.
+    pub fn set_strength(&self, strength: f64) -> Result<()> {
+        let mode = self.mode()?.ok_or(Error::NoSuccess)?;
+        self.enable(mode, strength)
+    }
+
+    /// Query the effective 3×3 color correction matrix, in row-major order, applied to
+    /// `[r, g, b]` column vectors.
+    ///
+    /// While the color correction is disabled, this is the identity matrix. Otherwise, the matrix
+    /// is queried from the factors of the driver, which only some driver versions report; the
+    /// others fail with [`Error::NotSupported`].
+    pub fn matrix(&self) -> Result<[[f32; 3]; 3]> {
+        if !self.is_enabled()? {
+            return Ok([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        }
+
+        // drivers reporting only the strength leave the remaining factors untouched
+        let mut factors: [double; 9] = [double::NAN; 9];
+        check(unsafe {
+            call!(is_SetColorCorrection(
+                self.camera.handle(),
+                IS_GET_CCOR_FACTOR,
+                factors.as_mut_ptr()
+            ))
+        })?;
+
+        if factors.iter().any(|factor| factor.is_nan()) {
+            return Err(Error::NotSupported);
+        }
+
+        Ok([0, 1, 2].map(|row| [0, 1, 2].map(|column| factors[3 * row + column] as f32)))
+    }
+}
@@ -0,0 +1,367 @@
+//! Color modes, i.e. the pixel formats the driver delivers images in.
+//!
+//! The color mode set on the camera determines the memory layout of each pixel, so image memories
+//! are [allocated](crate::image_mem::ImageMemory::alloc) for a [`ColorMode`] rather than a bare
+//! bit depth. Raw sensor data of color cameras is a Bayer pattern, which the driver only converts
+//! in the other modes.
+//!
+//! [Frames](crate::frame::Frame) describe lines of interleaved pixels, so image memories are not
+//! allocated in [planar](PixelKind::Planar) modes, whose channels follow each other plane by
+//! plane.
+//!
+//! # Documentation
+//! * [is_SetColorMode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setcolormode.html)
+//! * [Color and memory formats](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_allgemeines_farbformate.html)
+
+use ueye_sys::{
+    color::{
+        is_SetColorMode, IS_CM_BGR10_PACKED, IS_CM_BGR10_UNPACKED, IS_CM_BGR12_UNPACKED,
+        IS_CM_BGR565_PACKED, IS_CM_BGR5_PACKED, IS_CM_BGR8_PACKED, IS_CM_BGRA12_UNPACKED,
+        IS_CM_BGRA8_PACKED, IS_CM_BGRY8_PACKED, IS_CM_CBYCRY_PACKED, IS_CM_JPEG, IS_CM_MONO10,
+        IS_CM_MONO12, IS_CM_MONO16, IS_CM_MONO8, IS_CM_PREFER_PACKED_SOURCE_FORMAT,
+        IS_CM_RGB10_PACKED, IS_CM_RGB10_UNPACKED, IS_CM_RGB12_UNPACKED, IS_CM_RGB8_PACKED,
+        IS_CM_RGB8_PLANAR, IS_CM_RGBA12_UNPACKED, IS_CM_RGBA8_PACKED, IS_CM_RGBY8_PACKED,
+        IS_CM_SENSOR_RAW10, IS_CM_SENSOR_RAW12, IS_CM_SENSOR_RAW16, IS_CM_SENSOR_RAW8,
+        IS_CM_UYVY_BAYER_PACKED, IS_CM_UYVY_MONO_PACKED, IS_CM_UYVY_PACKED, IS_GET_COLOR_MODE,
+    },
+    types::INT,
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Arrangement of the samples of a color mode in memory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PixelKind {
+    /// Raw sensor data, a Bayer pattern on color cameras.
+    Bayer,
+
+    /// Grayscale, one sample per pixel.
+    Mono,
+
+    /// Several channels interleaved in each pixel.
+    Packed,
+
+    /// Each channel in a plane of its own.
+    Planar,
+
+    /// Compressed image data.
+    Compressed,
+}
+
+/// Color mode (pixel format) of the images delivered by the driver.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorMode {
+    /// Raw sensor data, 8 bits.
+    SensorRaw8,
+
+    /// Raw sensor data, 10 bits in 16.
+    SensorRaw10,
+
+    /// Raw sensor data, 12 bits in 16.
+    SensorRaw12,
+
+    /// Raw sensor data, 16 bits.
+    SensorRaw16,
+
+    /// Grayscale, 8 bits.
+    #[default]
+    Mono8,
+
+    /// Grayscale, 10 bits in 16.
+    Mono10,
+
+    /// Grayscale, 12 bits in 16.
+    Mono12,
+
+    /// Grayscale, 16 bits.
+    Mono16,
+
+    /// Blue, green and red, 5 bits each in 16.
+    Bgr5Packed,
+
+    /// Blue, green and red, 5, 6 and 5 bits in 16.
+    Bgr565Packed,
+
+    /// Red, green and blue, 8 bits each.
+    Rgb8Packed,
+
+    /// Blue, green and red, 8 bits each.
+    Bgr8Packed,
+
+    /// Red, green, blue and alpha, 8 bits each.
+    Rgba8Packed,
+
+    /// Blue, green, red and alpha, 8 bits each.
+    Bgra8Packed,
+
+    /// Red, green, blue and luminance, 8 bits each.
+    Rgby8Packed,
+
+    /// Blue, green, red and luminance, 8 bits each.
+    Bgry8Packed,
+
+    /// Red, green and blue, 10 bits each in 32.
+    Rgb10Packed,
+
+    /// Blue, green and red, 10 bits each in 32.
+    Bgr10Packed,
+
+    /// Red, green and blue, 10 bits each in 16.
+    Rgb10Unpacked,
+
+    /// Blue, green and red, 10 bits each in 16.
+    Bgr10Unpacked,
+
+    /// Red, green and blue, 12 bits each in 16.
+    Rgb12Unpacked,
+
+    /// Blue, green and red, 12 bits each in 16.
+    Bgr12Unpacked,
+
+    /// Red, green, blue and alpha, 12 bits each in 16.
+    Rgba12Unpacked,
+
+    /// Blue, green, red and alpha, 12 bits each in 16.
+    Bgra12Unpacked,
+
+    /// JPEG compressed (_USB uEye XS_).
+    Jpeg,
+
+    /// YUV 4:2:2, 8 bits each.
+    UyvyPacked,
+
+    /// YUV 4:2:2 of the grayscale image, 8 bits each.
+    UyvyMonoPacked,
+
+    /// YUV 4:2:2 of the Bayer pattern, 8 bits each.
+    UyvyBayerPacked,
+
+    /// YCbCr 4:2:2, 8 bits each.
+    CbYCrYPacked,
+
+    /// Red, green and blue planes, 8 bits each.
+    Rgb8Planar,
+}
+
+impl ColorMode {
+    /// All color modes.
+    pub const ALL: [ColorMode; 30] = [
+        ColorMode::SensorRaw8,
+        ColorMode::SensorRaw10,
+        ColorMode::SensorRaw12,
+        ColorMode::SensorRaw16,
+        ColorMode::Mono8,
+        ColorMode::Mono10,
+        ColorMode::Mono12,
+        ColorMode::Mono16,
+        ColorMode::Bgr5Packed,
+        ColorMode::Bgr565Packed,
+        ColorMode::Rgb8Packed,
+        ColorMode::Bgr8Packed,
+        ColorMode::Rgba8Packed,
+        ColorMode::Bgra8Packed,
+        ColorMode::Rgby8Packed,
+        ColorMode::Bgry8Packed,
+        ColorMode::Rgb10Packed,
+        ColorMode::Bgr10Packed,
+        ColorMode::Rgb10Unpacked,
+        ColorMode::Bgr10Unpacked,
+        ColorMode::Rgb12Unpacked,
+        ColorMode::Bgr12Unpacked,
+        ColorMode::Rgba12Unpacked,
+        ColorMode::Bgra12Unpacked,
+        ColorMode::Jpeg,
+        ColorMode::UyvyPacked,
+        ColorMode::UyvyMonoPacked,
+        ColorMode::UyvyBayerPacked,
+        ColorMode::CbYCrYPacked,
+        ColorMode::Rgb8Planar,
+    ];
+
+    /// Raw value of the color mode.
+    pub const fn bits(self) -> INT {
+        match self {
+            ColorMode::SensorRaw8 => IS_CM_SENSOR_RAW8,
+            ColorMode::SensorRaw10 => IS_CM_SENSOR_RAW10,
+            ColorMode::SensorRaw12 => IS_CM_SENSOR_RAW12,
+            ColorMode::SensorRaw16 => IS_CM_SENSOR_RAW16,
+            ColorMode::Mono8 => IS_CM_MONO8,
+            ColorMode::Mono10 => IS_CM_MONO10,
+            ColorMode::Mono12 => IS_CM_MONO12,
+            ColorMode::Mono16 => IS_CM_MONO16,
+            ColorMode::Bgr5Packed => IS_CM_BGR5_PACKED,
+            ColorMode::Bgr565Packed => IS_CM_BGR565_PACKED,
+            ColorMode::Rgb8Packed => IS_CM_RGB8_PACKED,
+            ColorMode::Bgr8Packed => IS_CM_BGR8_PACKED,
+            ColorMode::Rgba8Packed => IS_CM_RGBA8_PACKED,
+            ColorMode::Bgra8Packed => IS_CM_BGRA8_PACKED,
+            ColorMode::Rgby8Packed => IS_CM_RGBY8_PACKED,
+            ColorMode::Bgry8Packed => IS_CM_BGRY8_PACKED,
+            ColorMode::Rgb10Packed => IS_CM_RGB10_PACKED,
+            ColorMode::Bgr10Packed => IS_CM_BGR10_PACKED,
+            ColorMode::Rgb10Unpacked => IS_CM_RGB10_UNPACKED,
+            ColorMode::Bgr10Unpacked => IS_CM_BGR10_UNPACKED,
+            ColorMode::Rgb12Unpacked => IS_CM_RGB12_UNPACKED,
+            ColorMode::Bgr12Unpacked => IS_CM_BGR12_UNPACKED,
+            ColorMode::Rgba12Unpacked => IS_CM_RGBA12_UNPACKED,
+            ColorMode::Bgra12Unpacked => IS_CM_BGRA12_UNPACKED,
+            ColorMode::Jpeg => IS_CM_JPEG,
+            ColorMode::UyvyPacked => IS_CM_UYVY_PACKED,
+            ColorMode::UyvyMonoPacked => IS_CM_UYVY_MONO_PACKED,
+            ColorMode::UyvyBayerPacked => IS_CM_UYVY_BAYER_PACKED,
+            ColorMode::CbYCrYPacked => IS_CM_CBYCRY_PACKED,
+            ColorMode::Rgb8Planar => IS_CM_RGB8_PLANAR,
+        }
+    }
+
+    /// Image bit depth (bits per pixel) of the color mode, as passed to `is_AllocImageMem`.
+    pub const fn bits_per_pixel(self) -> usize {
+        match self {
+            ColorMode::SensorRaw8 | ColorMode::Mono8 | ColorMode::Jpeg => 8,
+            ColorMode::SensorRaw10
+            | ColorMode::SensorRaw12
+            | ColorMode::SensorRaw16
+            | ColorMode::Mono10
+            | ColorMode::Mono12
+            | ColorMode::Mono16
+            | ColorMode::Bgr5Packed
+            | ColorMode::Bgr565Packed
+            | ColorMode::UyvyPacked
+            | ColorMode::UyvyMonoPacked
+            | ColorMode::UyvyBayerPacked
+            | ColorMode::CbYCrYPacked => 16,
+            ColorMode::Rgb8Packed | ColorMode::Bgr8Packed | ColorMode::Rgb8Planar => 24,
+            ColorMode::Rgba8Packed
+            | ColorMode::Bgra8Packed
+            | ColorMode::Rgby8Packed
+            | ColorMode::Bgry8Packed
+            | ColorMode::Rgb10Packed
+            | ColorMode::Bgr10Packed => 32,
+            ColorMode::Rgb10Unpacked
+            | ColorMode::Bgr10Unpacked
+            | ColorMode::Rgb12Unpacked
+            | ColorMode::Bgr12Unpacked => 48,
+            ColorMode::Rgba12Unpacked | ColorMode::Bgra12Unpacked => 64,
+        }
+    }
+
+    /// Number of channels per pixel; YUV modes alternate the chroma channels between pixels, so
+    /// they count two.
+    pub const fn channels(self) -> usize {
+        match self {
+            ColorMode::SensorRaw8
+            | ColorMode::SensorRaw10
+            | ColorMode::SensorRaw12
+            | ColorMode::SensorRaw16
+            | ColorMode::Mono8
+            | ColorMode::Mono10
+            | ColorMode::Mono12
+            | ColorMode::Mono16 => 1,
+            ColorMode::UyvyPacked
+            | ColorMode::UyvyMonoPacked
+            | ColorMode::UyvyBayerPacked
+            | ColorMode::CbYCrYPacked => 2,
+            ColorMode::Bgr5Packed
+            | ColorMode::Bgr565Packed
+            | ColorMode::Rgb8Packed
+            | ColorMode::Bgr8Packed
+            | ColorMode::Rgb10Packed
+            | ColorMode::Bgr10Packed
+            | ColorMode::Rgb10Unpacked
+            | ColorMode::Bgr10Unpacked
+            | ColorMode::Rgb12Unpacked
+            | ColorMode::Bgr12Unpacked
+            | ColorMode::Jpeg
+            | ColorMode::Rgb8Planar => 3,
+            ColorMode::Rgba8Packed
+            | ColorMode::Bgra8Packed
+            | ColorMode::Rgby8Packed
+            | ColorMode::Bgry8Packed
+            | ColorMode::Rgba12Unpacked
+            | ColorMode::Bgra12Unpacked => 4,
+        }
+    }
+
+    /// Arrangement of the samples in memory.
+    pub const fn kind(self) -> PixelKind {
+        match self {
+            ColorMode::SensorRaw8
+            | ColorMode::SensorRaw10
+            | ColorMode::SensorRaw12
+            | ColorMode::SensorRaw16 => PixelKind::Bayer,
+            ColorMode::Mono8 | ColorMode::Mono10 | ColorMode::Mono12 | ColorMode::Mono16 => {
+                PixelKind::Mono
+            },
+            ColorMode::Jpeg => PixelKind::Compressed,
+            ColorMode::Rgb8Planar => PixelKind::Planar,
+            _ => PixelKind::Packed,
+        }
+    }
+
+    /// Whether the color mode holds raw sensor data, a Bayer pattern on color cameras.
+    #[inline]
+    pub const fn is_bayer(self) -> bool {
+        matches!(self.kind(), PixelKind::Bayer)
+    }
+
+    /// Whether the color mode interleaves several channels in each pixel.
+    #[inline]
+    pub const fn is_packed(self) -> bool {
+        matches!(self.kind(), PixelKind::Packed)
+    }
+
+    /// Whether the color mode stores each channel in a plane of its own.
+    #[inline]
+    pub const fn is_planar(self) -> bool {
+        matches!(self.kind(), PixelKind::Planar)
+    }
+
+    /// Number of bytes of a line of `width` pixels, without padding; for planar modes, the bytes
+    /// of the line in all planes together.
+    #[inline]
+    pub const fn line_bytes(self, width: usize) -> usize {
+        width * self.bits_per_pixel().div_ceil(8)
+    }
+}
+
+impl TryFrom<INT> for ColorMode {
+    type Error = Error;
+
+    fn try_from(value: INT) -> Result<Self> {
+        let mode = value & !IS_CM_PREFER_PACKED_SOURCE_FORMAT;
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.bits() == mode)
+            .ok_or(Error::UnexpectedValue(value))
+    }
+}
+
+impl From<ColorMode> for INT {
+    #[inline]
+    fn from(mode: ColorMode) -> Self {
+        mode.bits()
+    }
+}
+
+impl Camera {
+    /// Query the color mode of the camera.
+    pub fn color_mode(&self) -> Result<ColorMode> {
+        match unsafe { call!(is_SetColorMode(self.handle(), IS_GET_COLOR_MODE)) } {
+            value if value < 0 => Err(Error::from_code(value)),
+            value => ColorMode::try_from(value),
+        }
+    }
+
+    /// Set the color mode of the camera.
+    ///
+    /// Image memories allocated for another color mode must be reallocated.
+    pub fn set_color_mode(&self, mode: ColorMode) -> Result<()> {
+        check(unsafe { call!(is_SetColorMode(self.handle(), mode.bits())) })
+    }
+}
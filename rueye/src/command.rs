@@ -0,0 +1,238 @@
+//! Commands of the command interface, coupled with their parameter types at compile time.
+//!
+//! Functions such as [`is_DeviceFeature`], [`is_IO`] and [`is_Focus`] take a command along with an
+//! untyped parameter pointer and its size, so nothing keeps a command from being passed the wrong
+//! structure or size. Each command below is a type of its own, whose [`CommandParam::Param`] is
+//! the documented parameter type; [`device_command`] exchanges exactly that type with the driver.
+//!
+//! Enumerations of the driver are exchanged as their raw [`UINT`], since the driver may report
+//! values that the enumeration does not know. Commands without parameter take `()`.
+//!
+//! # Documentation
+//! * [is_DeviceFeature](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature.html)
+//! * [is_Focus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus.html)
+//! * [is_IO](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_io.html)
+
+use std::{mem::size_of, ptr};
+
+use ueye_sys::{
+    device_feature::{
+        is_DeviceFeature, DEVICE_FEATURE_CMD, DEVICE_FEATURE_MODE_CAPS,
+        IS_MULTI_INTEGRATION_CYCLES, IS_MULTI_INTEGRATION_SCOPE, IS_TIMESTAMP_CONFIGURATION,
+    },
+    focus::{is_Focus, AUTOFOCUS_CALLBACK, FOCUS_CAPABILITY_FLAGS, FOCUS_CMD, FOCUS_STATUS},
+    io::{is_IO, IO_CMD, IO_FLASH_PARAMS, IO_GPIO},
+    types::{void, BOOL, INT, IS_RANGE_S32, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    observer::command,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Command of the command interface, along with the type of its parameter.
+pub trait CommandParam: sealed::Sealed {
+    /// Documented parameter type of the command.
+    type Param;
+
+    /// Run the command, exchanging `param`, and return the raw result.
+    #[doc(hidden)]
+    fn exchange(camera: &Camera, param: &mut Self::Param) -> INT;
+}
+
+/// Run command `C`, exchanging `param` with a size matching its type.
+#[inline]
+pub fn device_command<C: CommandParam>(camera: &Camera, param: &mut C::Param) -> Result<()> {
+    check(C::exchange(camera, param))
+}
+
+/// Pointer and size of `param`, or a null pointer for commands without parameter.
+fn raw<T>(param: &mut T) -> (*mut void, UINT) {
+    match size_of::<T>() {
+        0 => (ptr::null_mut(), 0),
+        size => (param as *mut T as *mut void, size as UINT),
+    }
+}
+
+/// Declare a module of command types for `$function`, one for each listed variant of `$cmd`.
+macro_rules! commands {
+    ($(
+        $(#[$attr:meta])*
+        mod $module:ident = $function:ident($cmd:ident) {
+            $($variant:ident: $param:ty),* $(,)?
+        }
+    )*) => {$(
+        $(#[$attr])*
+        pub mod $module {
+            use super::*;
+
+            $(
+                #[doc = concat!(
+                    "Command [`", stringify!($cmd), "::", stringify!($variant), "`] of [`",
+                    stringify!($function), "`], exchanging `", stringify!($param), "`."
+                )]
+                #[allow(non_camel_case_types)]
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+                pub struct $variant;
+
+                impl sealed::Sealed for $variant {}
+
+                impl CommandParam for $variant {
+                    type Param = $param;
+
+                    #[inline]
+                    fn exchange(camera: &Camera, param: &mut $param) -> INT {
+                        let (param, size) = raw(param);
+                        unsafe { command!($function(camera.handle(), $cmd::$variant, param, size)) }
+                    }
+                }
+            )*
+        }
+    )*};
+}
+
+commands! {
+    /// Commands of [`is_DeviceFeature`].
+    mod device_feature = is_DeviceFeature(DEVICE_FEATURE_CMD) {
+        IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES: DEVICE_FEATURE_MODE_CAPS,
+        IS_DEVICE_FEATURE_CMD_SET_LINESCAN_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LINESCAN_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_LINESCAN_NUMBER: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LINESCAN_NUMBER: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_SHUTTER_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_SHUTTER_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_LOG_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_LOG_MODE_MANUAL_VALUE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_LOG_MODE_MANUAL_GAIN: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_VERTICAL_AOI_MERGE_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION_DEFAULT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION: INT,
+        IS_DEVICE_FEATURE_CMD_SET_VERTICAL_AOI_MERGE_POSITION: INT,
+        IS_DEVICE_FEATURE_CMD_GET_FPN_CORRECTION_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_FPN_CORRECTION_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_FPN_CORRECTION_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_SOURCE_GAIN_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_SOURCE_GAIN_DEFAULT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_SOURCE_GAIN: INT,
+        IS_DEVICE_FEATURE_CMD_SET_SENSOR_SOURCE_GAIN: INT,
+        IS_DEVICE_FEATURE_CMD_GET_BLACK_REFERENCE_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_BLACK_REFERENCE_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_BLACK_REFERENCE_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_ALLOW_RAW_WITH_LUT: BOOL,
+        IS_DEVICE_FEATURE_CMD_SET_ALLOW_RAW_WITH_LUT: BOOL,
+        IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_SENSOR_BIT_DEPTHS: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_BIT_DEPTH_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_BIT_DEPTH: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_SENSOR_BIT_DEPTH: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION: INT,
+        IS_DEVICE_FEATURE_CMD_SET_JPEG_COMPRESSION: INT,
+        IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION_DEFAULT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_NOISE_REDUCTION_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_NOISE_REDUCTION_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_NOISE_REDUCTION_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_TIMESTAMP_CONFIGURATION: IS_TIMESTAMP_CONFIGURATION,
+        IS_DEVICE_FEATURE_CMD_SET_TIMESTAMP_CONFIGURATION: IS_TIMESTAMP_CONFIGURATION,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_HEIGHT_DEFAULT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_HEIGHT_NUMBER: INT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_HEIGHT: INT,
+        IS_DEVICE_FEATURE_CMD_SET_VERTICAL_AOI_MERGE_HEIGHT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION_DEFAULT: INT,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION_RANGE: IS_RANGE_S32,
+        IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION: INT,
+        IS_DEVICE_FEATURE_CMD_SET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION: INT,
+        IS_DEVICE_FEATURE_CMD_GET_SENSOR_TEMPERATURE_NUMERICAL_VALUE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_IMAGE_EFFECT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_IMAGE_EFFECT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_IMAGE_EFFECT_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_EXTENDED_PIXELCLOCK_RANGE_ENABLE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_EXTENDED_PIXELCLOCK_RANGE_ENABLE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_EXTENDED_PIXELCLOCK_RANGE_ENABLE: UINT,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_GET_SCOPE: IS_MULTI_INTEGRATION_SCOPE,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_GET_PARAMS: IS_MULTI_INTEGRATION_CYCLES,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_SET_PARAMS: IS_MULTI_INTEGRATION_CYCLES,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_GET_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_GET_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_MULTI_INTEGRATION_SET_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_LEVEL_CONTROLLED_TRIGGER_INPUT_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LEVEL_CONTROLLED_TRIGGER_INPUT_MODE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_LEVEL_CONTROLLED_TRIGGER_INPUT_MODE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_REPEATED_START_CONDITION_I2C: BOOL,
+        IS_DEVICE_FEATURE_CMD_GET_REPEATED_START_CONDITION_I2C: BOOL,
+        IS_DEVICE_FEATURE_CMD_GET_REPEATED_START_CONDITION_I2C_DEFAULT: BOOL,
+        IS_DEVICE_FEATURE_CMD_GET_TEMPERATURE_STATUS: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_MEMORY_MODE_ENABLE: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_MEMORY_MODE_ENABLE: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_MEMORY_MODE_ENABLE_DEFAULT: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_MEMORY_MODE_ENABLE_SUPPORTED: BOOL,
+        IS_DEVICE_FEATURE_CMD_GET_FPN_CORRECTION_IS_CALIBRATED: UINT,
+        IS_DEVICE_FEATURE_CMD_SET_FPN_CORRECTION_DATA_LOADING: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_FPN_CORRECTION_DATA_LOADING: UINT,
+        IS_DEVICE_FEATURE_CMD_GET_FPN_CORRECTION_DATA_LOADING_DEFAULT: UINT,
+    }
+
+    /// Commands of [`is_IO`].
+    mod io = is_IO(IO_CMD) {
+        IS_IO_CMD_GPIOS_GET_SUPPORTED: IO_GPIO,
+        IS_IO_CMD_GPIOS_GET_SUPPORTED_INPUTS: IO_GPIO,
+        IS_IO_CMD_GPIOS_GET_SUPPORTED_OUTPUTS: IO_GPIO,
+        IS_IO_CMD_GPIOS_GET_DIRECTION: IO_GPIO,
+        IS_IO_CMD_GPIOS_SET_DIRECTION: IO_GPIO,
+        IS_IO_CMD_GPIOS_GET_STATE: IO_GPIO,
+        IS_IO_CMD_GPIOS_SET_STATE: IO_GPIO,
+        IS_IO_CMD_LED_GET_STATE: UINT,
+        IS_IO_CMD_LED_SET_STATE: UINT,
+        IS_IO_CMD_LED_TOGGLE_STATE: (),
+        IS_IO_CMD_FLASH_GET_GLOBAL_PARAMS: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_APPLY_GLOBAL_PARAMS: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_SUPPORTED_GPIOS: IO_GPIO,
+        IS_IO_CMD_FLASH_GET_PARAMS_MIN: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_PARAMS_MAX: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_PARAMS_INC: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_PARAMS: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_SET_PARAMS: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_MODE: UINT,
+        IS_IO_CMD_FLASH_SET_MODE: UINT,
+        IS_IO_CMD_PWM_GET_SUPPORTED_GPIOS: IO_GPIO,
+        IS_IO_CMD_PWM_GET_MODE: UINT,
+        IS_IO_CMD_PWM_SET_MODE: UINT,
+        IS_IO_CMD_FLASH_GET_GPIO_PARAMS_MIN: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_SET_GPIO_PARAMS: IO_FLASH_PARAMS,
+        IS_IO_CMD_FLASH_GET_AUTO_FREERUN_DEFAULT: UINT,
+        IS_IO_CMD_FLASH_GET_AUTO_FREERUN: UINT,
+        IS_IO_CMD_FLASH_SET_AUTO_FREERUN: UINT,
+    }
+
+    /// Commands of [`is_Focus`].
+    mod focus = is_Focus(FOCUS_CMD) {
+        FOC_CMD_GET_CAPABILITIES: FOCUS_CAPABILITY_FLAGS,
+        FOC_CMD_SET_DISABLE_AUTOFOCUS: (),
+        FOC_CMD_SET_ENABLE_AUTOFOCUS: (),
+        FOC_CMD_GET_AUTOFOCUS_ENABLE: BOOL,
+        FOC_CMD_GET_DISTANCE: UINT,
+        FOC_CMD_SET_MANUAL_FOCUS: UINT,
+        FOC_CMD_GET_MANUAL_FOCUS: UINT,
+        FOC_CMD_GET_MANUAL_FOCUS_MIN: UINT,
+        FOC_CMD_GET_MANUAL_FOCUS_MAX: UINT,
+        FOC_CMD_GET_MANUAL_FOCUS_INC: UINT,
+        FOC_CMD_SET_ENABLE_AUTOFOCUS_ONCE: (),
+        FOC_CMD_GET_AUTOFOCUS_STATUS: FOCUS_STATUS,
+        FOC_CMD_SET_AUTOFOCUS_CALLBACK: AUTOFOCUS_CALLBACK,
+    }
+}
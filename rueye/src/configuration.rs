@@ -0,0 +1,164 @@
+//! System-wide configuration of the driver.
+//!
+//! These settings apply to every camera on the PC rather than to a single one. [`ConfigCapabilities`]
+//! tells which of them the installed driver supports; the functions here fail with
+//! [`Error::NotSupported`] for the others.
+//!
+//! With OpenMP enabled, the driver spreads the color conversion of each image across several
+//! threads, which speeds it up at the cost of CPU load. On Windows, the driver also runs an IPO
+//! thread that finds GigE cameras with an invalid IP configuration.
+//!
+//! The [initial parameter set](set_initial_parameter_set) is the
+//! [parameter set](crate::parameter_set) in the user memory of each camera, which the driver
+//! applies whenever the camera is opened.
+//!
+//! # Documentation
+//! * [is_Configuration](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configuration.html)
+//! * [Activating OpenMP](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configurationopenmp.html)
+//! * [Allowing IPO thread](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configurationipothread.html)
+//! * [Loading camera parameters during initializing](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configurationcameraparameter.html)
+
+use std::mem::size_of;
+
+#[cfg(target_os = "windows")]
+use ueye_sys::configuration::CONFIGURATION_SEL_IPO;
+use ueye_sys::{
+    configuration::{
+        is_Configuration, CONFIGURATION_CAPS, CONFIGURATION_CMD,
+        CONFIGURATION_SEL_INITIAL_PARAMETERSET, CONFIGURATION_SEL_OPEN_MP,
+    },
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    error::{check, Error, Result},
+    observer::call,
+};
+
+use CONFIGURATION_SEL_INITIAL_PARAMETERSET::{
+    IS_CONFIG_INITIAL_PARAMETERSET_1, IS_CONFIG_INITIAL_PARAMETERSET_NONE,
+};
+
+pub(crate) fn configuration<T>(cmd: CONFIGURATION_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        call!(is_Configuration(cmd, param as *mut T as *mut void, size_of::<T>() as UINT))
+    })
+}
+
+/// Query a switch of the configuration, read as a [`UINT`] to accept any value of the driver.
+pub(crate) fn get_switch(cmd: CONFIGURATION_CMD) -> Result<bool> {
+    let mut value: UINT = 0;
+    configuration(cmd, &mut value)?;
+    Ok(value != 0)
+}
+
+/// System-wide settings supported by the driver.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigCapabilities {
+    /// Whether processor operating states can be set (Windows only).
+    pub cpu_idle_states: bool,
+
+    /// Whether OpenMP can be enabled.
+    pub openmp: bool,
+
+    /// Whether a parameter set can be loaded when a camera is opened.
+    pub initial_parameter_set: bool,
+
+    /// Whether the IPO thread can be allowed (Windows only).
+    pub ipo: bool,
+
+    /// Whether trusted pairing of GigE cameras can be set.
+    pub trusted_pairing: bool,
+}
+
+impl ConfigCapabilities {
+    /// Query the system-wide settings supported by the driver.
+    pub fn query() -> Result<Self> {
+        let mut flags: UINT = 0;
+        configuration(CONFIGURATION_CMD::IS_CONFIG_CMD_GET_CAPABILITIES, &mut flags)?;
+
+        let has = |cap: CONFIGURATION_CAPS| flags & cap as UINT != 0;
+        Ok(Self {
+            cpu_idle_states: has(CONFIGURATION_CAPS::IS_CONFIG_CPU_IDLE_STATES_CAP_SUPPORTED),
+            openmp: has(CONFIGURATION_CAPS::IS_CONFIG_OPEN_MP_CAP_SUPPORTED),
+            initial_parameter_set: has(
+                CONFIGURATION_CAPS::IS_CONFIG_INITIAL_PARAMETERSET_CAP_SUPPORTED,
+            ),
+            ipo: has(CONFIGURATION_CAPS::IS_CONFIG_IPO_CAP_SUPPORTED),
+            trusted_pairing: has(CONFIGURATION_CAPS::IS_CONFIG_TRUSTED_PAIRING_CAP_SUPPORTED),
+        })
+    }
+}
+
+/// Fail with [`Error::NotSupported`] unless `supported` holds for the capabilities of the driver.
+pub(crate) fn require(supported: impl FnOnce(&ConfigCapabilities) -> bool) -> Result<()> {
+    match supported(&ConfigCapabilities::query()?) {
+        true => Ok(()),
+        false => Err(Error::NotSupported),
+    }
+}
+
+/// Query whether the driver converts images with OpenMP.
+pub fn openmp_enabled() -> Result<bool> {
+    require(|caps| caps.openmp)?;
+    get_switch(CONFIGURATION_CMD::IS_CONFIG_OPEN_MP_CMD_GET_ENABLE)
+}
+
+/// Query whether OpenMP is enabled by default.
+pub fn default_openmp_enabled() -> Result<bool> {
+    require(|caps| caps.openmp)?;
+    get_switch(CONFIGURATION_CMD::IS_CONFIG_OPEN_MP_CMD_GET_ENABLE_DEFAULT)
+}
+
+/// Enable or disable the conversion of images with OpenMP.
+pub fn set_openmp_enabled(enable: bool) -> Result<()> {
+    require(|caps| caps.openmp)?;
+    let mut value = CONFIGURATION_SEL_OPEN_MP::from(enable);
+    configuration(CONFIGURATION_CMD::IS_CONFIG_OPEN_MP_CMD_SET_ENABLE, &mut value)
+}
+
+/// Query whether the IPO thread is allowed to run, i.e. the `NoIpo` registry value is not set.
+#[cfg(target_os = "windows")]
+pub fn ipo_allowed() -> Result<bool> {
+    require(|caps| caps.ipo)?;
+    get_switch(CONFIGURATION_CMD::IS_CONFIG_IPO_CMD_GET_ALLOWED)
+}
+
+/// Allow or prevent the IPO thread, by deleting or setting the `NoIpo` registry value.
+///
+/// Writing the registry requires administrator rights.
+#[cfg(target_os = "windows")]
+pub fn set_ipo_allowed(allow: bool) -> Result<()> {
+    require(|caps| caps.ipo)?;
+    let mut value = CONFIGURATION_SEL_IPO::from(allow);
+    configuration(CONFIGURATION_CMD::IS_CONFIG_IPO_CMD_SET_ALLOWED, &mut value)
+}
+
+/// Query whether the parameter set in the user memory of a camera is applied when it is opened.
+///
+/// Fails with [`Error::UnexpectedValue`] if the driver is set to another set than the single
+/// one of the cameras.
+pub fn initial_parameter_set() -> Result<bool> {
+    require(|caps| caps.initial_parameter_set)?;
+    let mut value: UINT = 0;
+    configuration(CONFIGURATION_CMD::IS_CONFIG_INITIAL_PARAMETERSET_CMD_GET, &mut value)?;
+
+    match value {
+        value if value == IS_CONFIG_INITIAL_PARAMETERSET_NONE as UINT => Ok(false),
+        value if value == IS_CONFIG_INITIAL_PARAMETERSET_1 as UINT => Ok(true),
+        value => Err(Error::UnexpectedValue(value as INT)),
+    }
+}
+
+/// Apply the [parameter set](crate::parameter_set) in the user memory of each camera whenever it
+/// is opened, or keep the defaults of the cameras.
+pub fn set_initial_parameter_set(enable: bool) -> Result<()> {
+    require(|caps| caps.initial_parameter_set)?;
+    let mut value = match enable {
+        true => IS_CONFIG_INITIAL_PARAMETERSET_1,
+        false => IS_CONFIG_INITIAL_PARAMETERSET_NONE,
+    };
+
+    configuration(CONFIGURATION_CMD::IS_CONFIG_INITIAL_PARAMETERSET_CMD_SET, &mut value)
+}
@@ -0,0 +1,264 @@
+//! Conversion of raw Bayer frames by the driver.
+//!
+//! [`convert_frame`] converts a raw frame, e.g. one captured in [`ColorMode::SensorRaw8`] and
+//! stored for later, into any other color mode with the same algorithms the driver applies while
+//! capturing. The [`ConvertOptions`] take the place of the camera settings that would otherwise
+//! apply, so a frame can be converted repeatedly with different settings.
+//!
+//! The driver interprets the frame with the [Bayer pattern](crate::bayer) of the top left pixel
+//! of the sensor. Frames starting at an odd column or row of the sensor are padded by a column or
+//! row in front, so the pattern of the padded frame matches that of the sensor, and the padding
+//! is cropped from the converted frame.
+//!
+//! # Documentation
+//! [is_Convert](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_convert.html)
+
+use std::mem::{size_of, MaybeUninit};
+
+use ueye_sys::{
+    color::IS_CCOR_DISABLE,
+    convert::{
+        is_Convert, BUFFER_CONVERSION_PARAMS, CONVERT_CMD, IS_CONV_MODE_SOFTWARE_2X2,
+        IS_CONV_MODE_SOFTWARE_3X3, IS_CONV_MODE_SOFTWARE_5X5,
+    },
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    aoi::{self, Aoi},
+    camera::Camera,
+    color::ColorCorrectionMode,
+    color_mode::{ColorMode, PixelKind},
+    error::{check, Error, Result},
+    frame::{Frame, FrameBuf},
+    image_mem::ImageMemory,
+    observer::command,
+};
+
+/// Filter mask interpolating the missing colors of each pixel.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Converter {
+    /// 2×2 mask, fastest at the lowest quality.
+    Software2x2,
+
+    /// 3×3 mask.
+    #[default]
+    Software3x3,
+
+    /// 5×5 mask, slowest at the highest quality.
+    Software5x5,
+}
+
+impl Converter {
+    /// Raw value of the converter.
+    pub const fn bits(self) -> INT {
+        match self {
+            Converter::Software2x2 => IS_CONV_MODE_SOFTWARE_2X2,
+            Converter::Software3x3 => IS_CONV_MODE_SOFTWARE_3X3,
+            Converter::Software5x5 => IS_CONV_MODE_SOFTWARE_5X5,
+        }
+    }
+}
+
+/// Settings applied by a conversion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConvertOptions {
+    /// Filter mask of the conversion.
+    pub converter: Converter,
+
+    /// Gamma correction, multiplied by 100 (`100` leaving the image unchanged).
+    pub gamma: INT,
+
+    /// Edge enhancement level, `0` disabling it.
+    pub edge_enhancement: INT,
+
+    /// Color correction, or `None` to disable it.
+    pub color_correction: Option<ColorCorrectionMode>,
+
+    /// Saturation of the U channel, multiplied by 100 (`100` leaving the image unchanged).
+    pub saturation_u: INT,
+
+    /// Saturation of the V channel, multiplied by 100 (`100` leaving the image unchanged).
+    pub saturation_v: INT,
+
+    /// Position of the frame on the sensor, or `None` for the position of the current AOI.
+    ///
+    /// Only the parity of the position matters, as it determines the phase of the Bayer pattern.
+    pub sensor_offset: Option<(i32, i32)>,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            converter: Converter::default(),
+            gamma: 100,
+            edge_enhancement: 0,
+            color_correction: None,
+            saturation_u: 100,
+            saturation_v: 100,
+            sensor_offset: None,
+        }
+    }
+}
+
+/// Color mode of a raw frame, by its bit depth.
+fn raw_mode(frame: &Frame<'_>) -> Result<ColorMode> {
+    match frame.bits_per_pixel() {
+        8 => Ok(ColorMode::SensorRaw8),
+        16 => Ok(ColorMode::SensorRaw16),
+        _ => Err(Error::InvalidParameter),
+    }
+}
+
+/// Columns and rows of padding aligning a frame at `offset` with the Bayer pattern of the sensor.
+fn phase(camera: &Camera, src: &Frame<'_>, offset: Option<(i32, i32)>) -> Result<(usize, usize)> {
+    if src.width() == 0 || src.height() == 0 {
+        return Ok((0, 0));
+    }
+
+    let (x, y) = match offset {
+        Some(offset) => offset,
+        None => {
+            let aoi = aoi::current(camera)?;
+            (aoi.s32X, aoi.s32Y)
+        },
+    };
+
+    Ok((x.rem_euclid(2) as usize, y.rem_euclid(2) as usize))
+}
+
+/// Copy `src` into a new frame, preceded by `dx` columns and `dy` rows of padding.
+///
+/// The padding repeats the second column and row of `src`, which have the colors of the padding
+/// in the Bayer pattern.
+fn pad(src: &Frame<'_>, dx: usize, dy: usize) -> Result<FrameBuf> {
+    let size = src.bytes_per_pixel();
+    let mirror_x = 1.min(src.width() - 1) * size;
+    let mirror_y = 1.min(src.height() - 1);
+
+    let (width, height) = (src.width() + dx, src.height() + dy);
+    let mut buf =
+        FrameBuf::from_vec(vec![0; width * height * size], width, height, src.bits_per_pixel())
+            .ok_or(Error::InvalidImageSize)?;
+    let mut dst = buf.as_frame_mut();
+
+    for y in 0..dst.height() {
+        let row = src.row(y.checked_sub(dy).unwrap_or(mirror_y));
+        let line = dst.row_mut(y);
+        line[..dx * size].copy_from_slice(&row[mirror_x..mirror_x + dx * size]);
+        line[dx * size..].copy_from_slice(row);
+    }
+
+    Ok(buf)
+}
+
+/// Convert the raw Bayer frame `src` into `dst_format` with the default [`ConvertOptions`].
+///
+/// See [`convert_frame_with`].
+#[inline]
+pub fn convert_frame(camera: &Camera, src: &Frame<'_>, dst_format: ColorMode) -> Result<FrameBuf> {
+    convert_frame_with(camera, src, dst_format, &ConvertOptions::default())
+}
+
+/// Convert the raw Bayer frame `src` into `dst_format`, applying `options`.
+///
+/// The Bayer pattern is that of the sensor of `camera`, shifted by the
+/// [position](ConvertOptions::sensor_offset) of the frame. The frame is converted through two
+/// image memories allocated for the purpose, the last of which remains the active image memory of
+/// the camera until they are freed.
+///
+/// Fails with [`Error::InvalidParameter`] if `src` is neither 8 nor 16 bits per pixel, or if
+/// `dst_format` is a raw, planar or compressed color mode.
+pub fn convert_frame_with(
+    camera: &Camera,
+    src: &Frame<'_>,
+    dst_format: ColorMode,
+    options: &ConvertOptions,
+) -> Result<FrameBuf> {
+    if matches!(dst_format.kind(), PixelKind::Bayer | PixelKind::Planar | PixelKind::Compressed) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mode = raw_mode(src)?;
+    let (dx, dy) = phase(camera, src, options.sensor_offset)?;
+    let width = INT::try_from(src.width() + dx).map_err(|_| Error::InvalidParameter)?;
+    let height = INT::try_from(src.height() + dy).map_err(|_| Error::InvalidParameter)?;
+
+    let mut source = ImageMemory::alloc(camera, width, height, mode)?;
+    source.write_frame(&pad(src, dx, dy)?.as_frame())?;
+
+    let mut dest = ImageMemory::alloc(camera, width, height, dst_format)?;
+
+    let mut params = unsafe { MaybeUninit::<BUFFER_CONVERSION_PARAMS>::zeroed().assume_init() };
+    params.pSourceBuffer = source.as_mut_ptr();
+    params.pDestBuffer = dest.as_mut_ptr();
+    params.nDestPixelFormat = dst_format.bits();
+    params.nDestPixelConverter = options.converter.bits();
+    params.nDestGamma = options.gamma;
+    params.nDestEdgeEnhancement = options.edge_enhancement;
+    params.nDestColorCorrectionMode =
+        options.color_correction.map_or(IS_CCOR_DISABLE, ColorCorrectionMode::bits);
+    params.nDestSaturationU = options.saturation_u;
+    params.nDestSaturationV = options.saturation_v;
+
+    check(unsafe {
+        command!(is_Convert(
+            camera.handle(),
+            CONVERT_CMD::IS_CONVERT_CMD_APPLY_PARAMS_AND_CONVERT_BUFFER,
+            &mut params as *mut BUFFER_CONVERSION_PARAMS as *mut void,
+            size_of::<BUFFER_CONVERSION_PARAMS>() as UINT,
+        ))
+    })?;
+
+    let area =
+        Aoi { x: dx as INT, y: dy as INT, width: width - dx as INT, height: height - dy as INT };
+    let frame = dest.to_frame_buf()?;
+    Ok(FrameBuf::copy_from(&frame.as_frame().crop(area).ok_or(Error::InvalidImageSize)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3×2 frame of 8 bits per pixel with distinct values.
+    fn source() -> FrameBuf {
+        FrameBuf::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2, 8).unwrap()
+    }
+
+    fn padded(src: &FrameBuf, dx: usize, dy: usize) -> Vec<u8> {
+        pad(&src.as_frame(), dx, dy).unwrap().into_data()
+    }
+
+    #[test]
+    fn pad_even_offset_copies() {
+        assert_eq!(padded(&source(), 0, 0), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn pad_odd_column_repeats_second_column() {
+        assert_eq!(padded(&source(), 1, 0), [2, 1, 2, 3, 5, 4, 5, 6]);
+    }
+
+    #[test]
+    fn pad_odd_row_repeats_second_row() {
+        assert_eq!(padded(&source(), 0, 1), [4, 5, 6, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn pad_odd_column_and_row() {
+        assert_eq!(padded(&source(), 1, 1), [5, 4, 5, 6, 2, 1, 2, 3, 5, 4, 5, 6]);
+    }
+
+    #[test]
+    fn pad_keeps_pixels_of_16_bits() {
+        let src = FrameBuf::from_vec(vec![1, 2, 3, 4], 2, 1, 16).unwrap();
+        assert_eq!(padded(&src, 1, 1), [3, 4, 1, 2, 3, 4, 3, 4, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn pad_single_pixel_repeats_it() {
+        let src = FrameBuf::from_vec(vec![7], 1, 1, 8).unwrap();
+        assert_eq!(padded(&src, 1, 1), [7, 7, 7, 7]);
+    }
+}
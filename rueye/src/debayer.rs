@@ -0,0 +1,177 @@
+//! Debayering of raw frames on the CPU, without the driver.
+//!
+//! [`convert_frame`](crate::convert::convert_frame) has the driver convert raw frames, which needs
+//! an open camera and image memories. [`debayer`] instead interpolates the missing colors of each
+//! pixel bilinearly, from the neighbors of that color, so raw frames can be converted anywhere,
+//! e.g. with the mock backend or after loading them from disk.
+//!
+//! 8-bit frames are converted to the layout of [`ColorMode::Rgb8Packed`]; 16-bit frames, such as
+//! [`ColorMode::SensorRaw12`], to three 16-bit little-endian channels in red, green and blue order.
+
+use crate::{
+    bayer::{self, BayerColor, BayerPattern},
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{Error, Result},
+    frame::{Frame, FrameBuf},
+};
+
+/// Index of `color` in the red, green and blue order of the output.
+const fn channel(color: BayerColor) -> usize {
+    match color {
+        BayerColor::Red => 0,
+        BayerColor::Green => 1,
+        BayerColor::Blue => 2,
+    }
+}
+
+/// Samples of all pixels of `frame`, line by line.
+fn samples(frame: &Frame<'_>) -> Result<Vec<u16>> {
+    match frame.bits_per_pixel() {
+        8 => Ok(frame.rows().flat_map(|row| row.iter().map(|&sample| sample as u16)).collect()),
+        16 => Ok(frame
+            .rows()
+            .flat_map(|row| row.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])))
+            .collect()),
+        _ => Err(Error::InvalidParameter),
+    }
+}
+
+/// Debayer the raw `frame`, whose pixels are arranged in `pattern`.
+///
+/// Fails with [`Error::InvalidParameter`] if `frame` is neither 8 nor 16 bits per pixel.
+pub fn debayer(frame: &Frame<'_>, pattern: BayerPattern) -> Result<FrameBuf> {
+    let (width, height) = (frame.width(), frame.height());
+    let samples = samples(frame)?;
+    let wide = frame.bits_per_pixel() == 16;
+
+    let mut data = Vec::with_capacity(width * height * if wide { 6 } else { 3 });
+    for y in 0..height {
+        for x in 0..width {
+            let own = pattern.color_at(x as u32, y as u32);
+
+            // each 3×3 neighborhood holds the nearest pixels of every color
+            let mut sums = [0u32; 3];
+            let mut counts = [0u32; 3];
+            for ny in y.saturating_sub(1)..(y + 2).min(height) {
+                for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                    let color = channel(pattern.color_at(nx as u32, ny as u32));
+                    sums[color] += samples[ny * width + nx] as u32;
+                    counts[color] += 1;
+                }
+            }
+
+            let rgb: [u16; 3] = std::array::from_fn(|color| {
+                if color == channel(own) {
+                    samples[y * width + x]
+                } else {
+                    (sums[color] + counts[color] / 2).checked_div(counts[color]).unwrap_or(0) as u16
+                }
+            });
+
+            for value in rgb {
+                if wide {
+                    data.extend_from_slice(&value.to_le_bytes());
+                } else {
+                    data.push(value as u8);
+                }
+            }
+        }
+    }
+
+    let bits_per_pixel = if wide { 48 } else { ColorMode::Rgb8Packed.bits_per_pixel() };
+
+    Ok(FrameBuf::from_vec(data, width, height, bits_per_pixel)
+        .expect("buffer matches the geometry of the frame"))
+}
+
+/// Debayer the raw `frame`, captured from the current AOI of `camera`.
+///
+/// Fails with [`Error::NotSupported`] if the sensor has no Bayer filter, or if its pattern starts
+/// with green; see [`BayerPattern::of_sensor`].
+pub fn debayer_camera(camera: &Camera, frame: &Frame<'_>) -> Result<FrameBuf> {
+    let pattern = bayer::pattern(camera)?.ok_or(Error::NotSupported)?;
+    debayer(frame, pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Debayer an 8-bit mosaic of `width` pixels per line.
+    fn debayer8(mosaic: &[u8], width: usize, pattern: BayerPattern) -> Vec<u8> {
+        let frame = FrameBuf::from_vec(mosaic.to_vec(), width, mosaic.len() / width, 8).unwrap();
+        let rgb = debayer(&frame.as_frame(), pattern).unwrap();
+        assert_eq!(rgb.as_frame().bits_per_pixel(), 24);
+        rgb.into_data()
+    }
+
+    #[test]
+    fn rggb_edges_and_corners() {
+        #[rustfmt::skip]
+        let mosaic = [
+            10, 20, 30, 40,
+            50, 60, 70, 80,
+        ];
+
+        #[rustfmt::skip]
+        assert_eq!(debayer8(&mosaic, 4, BayerPattern::Rggb), [
+            10, 35, 60,   20, 20, 60,   30, 43, 70,   30, 40, 80,
+            10, 50, 60,   20, 47, 60,   30, 70, 70,   30, 55, 80,
+        ]);
+    }
+
+    #[test]
+    fn rggb_interior_averages_all_neighbors() {
+        #[rustfmt::skip]
+        let mosaic = [
+            10, 0, 30,
+            0,  5, 0,
+            50, 0, 70,
+        ];
+
+        let rgb = debayer8(&mosaic, 3, BayerPattern::Rggb);
+        assert_eq!(rgb[12..15], [40, 0, 5]);
+    }
+
+    #[test]
+    fn bggr_swaps_red_and_blue() {
+        #[rustfmt::skip]
+        let mosaic = [
+            1, 2,
+            3, 4,
+        ];
+
+        #[rustfmt::skip]
+        assert_eq!(debayer8(&mosaic, 2, BayerPattern::Bggr), [
+            4, 3, 1,   4, 2, 1,
+            4, 3, 1,   4, 3, 1,
+        ]);
+    }
+
+    #[test]
+    fn single_column_lacks_blue() {
+        assert_eq!(
+            debayer8(&[10, 20, 30], 1, BayerPattern::Rggb),
+            [10, 20, 0, 20, 20, 0, 30, 20, 0]
+        );
+    }
+
+    #[test]
+    fn single_line_of_16_bits() {
+        let mosaic = [1000u16, 2000, 3000].iter().flat_map(|value| value.to_le_bytes()).collect();
+        let frame = FrameBuf::from_vec(mosaic, 3, 1, 16).unwrap();
+        let rgb = debayer(&frame.as_frame(), BayerPattern::Rggb).unwrap();
+        assert_eq!(rgb.as_frame().bits_per_pixel(), 48);
+
+        let values: Vec<u16> =
+            rgb.data().chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect();
+        assert_eq!(values, [1000, 2000, 0, 2000, 2000, 0, 3000, 2000, 0]);
+    }
+
+    #[test]
+    fn rejects_other_bit_depths() {
+        let frame = FrameBuf::from_vec(vec![0; 8], 2, 1, 32).unwrap();
+        assert_eq!(debayer(&frame.as_frame(), BayerPattern::Rggb), Err(Error::InvalidParameter));
+    }
+}
@@ -0,0 +1,611 @@
+//! Special camera functions provided by specific models.
+//!
+//! [`is_DeviceFeature`](ueye_sys::device_feature::is_DeviceFeature) exchanges every parameter
+//! through an untyped pointer and size. The accessors of [`DeviceFeatures`] run the
+//! [typed commands](crate::command::device_feature), so that a mismatched value or size cannot
+//! be passed.
+//!
+//! # Documentation
+//! [is_DeviceFeature](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature.html)
+
+use std::fmt::{Display, Formatter};
+
+use ueye_sys::{
+    device_feature::{
+        DEVICE_FEATURE_MODE_CAPS, IMAGE_EFFECT_MODE, IS_TEMPERATURE_CONTROL_STATUS, LOG_MODES,
+        NOISE_REDUCTION_MODES, SENSOR_BIT_DEPTH,
+    },
+    types::{INT, IS_RANGE_S32, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    command::{device_command, device_feature::*, CommandParam},
+    error::{Error, Result},
+    range::ParamRange,
+};
+
+/// Every capability flag, with a description and the API depending on it, if any.
+const CAPABILITIES: &[(DEVICE_FEATURE_MODE_CAPS, &str, Option<&str>)] = &[
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING,
+        "Rolling shutter",
+        Some("DeviceFeatures::set_shutter_mode"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL,
+        "Global shutter",
+        Some("DeviceFeatures::set_shutter_mode"),
+    ),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_LINESCAN_MODE_FAST, "Fast line scan", None),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_LINESCAN_NUMBER, "Line scan number", None),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_PREFER_XS_HS_MODE, "Prefer XS HS mode", None),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_LOG_MODE,
+        "Log mode",
+        Some("DeviceFeatures::set_log_mode"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING_GLOBAL_START,
+        "Rolling shutter, global start",
+        Some("DeviceFeatures::set_shutter_mode"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL_ALTERNATIVE_TIMING,
+        "Global shutter, alternative timing",
+        Some("DeviceFeatures::set_shutter_mode"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_VERTICAL_AOI_MERGE,
+        "Vertical AOI merge",
+        Some("DeviceFeatures::set_vertical_aoi_merge_position"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_FPN_CORRECTION,
+        "Fixed pattern noise correction",
+        None,
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SENSOR_SOURCE_GAIN,
+        "Sensor source gain",
+        Some("DeviceFeatures::sensor_source_gain_range"),
+    ),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_BLACK_REFERENCE, "Black reference", None),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SENSOR_BIT_DEPTH,
+        "Sensor bit depth",
+        Some("DeviceFeatures::set_sensor_bit_depth"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_TEMPERATURE,
+        "Temperature",
+        Some("DeviceFeatures::sensor_temperature_value"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_JPEG_COMPRESSION,
+        "JPEG compression",
+        Some("DeviceFeatures::set_jpeg_compression"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_NOISE_REDUCTION,
+        "Noise reduction",
+        Some("DeviceFeatures::set_noise_reduction"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_TIMESTAMP_CONFIGURATION,
+        "Timestamp configuration",
+        Some("clock_sync::TimestampReset"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_IMAGE_EFFECT,
+        "Image effects",
+        Some("DeviceFeatures::set_image_effect"),
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_EXTENDED_PIXELCLOCK_RANGE,
+        "Extended pixel clock range",
+        Some("DeviceFeatures::set_extended_pixel_clock_range"),
+    ),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_MULTI_INTEGRATION, "Multi integration", None),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_WIDE_DYNAMIC_RANGE,
+        "Wide dynamic range",
+        None,
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_LEVEL_CONTROLLED_TRIGGER,
+        "Level controlled trigger",
+        None,
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_REPEATED_START_CONDITION_I2C,
+        "I2C repeated start condition",
+        None,
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_TEMPERATURE_STATUS,
+        "Temperature status",
+        Some("DeviceFeatures::temperature_status"),
+    ),
+    (DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_MEMORY_MODE, "Internal image memory", None),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SEND_EXTERNAL_INTERFACE_DATA,
+        "External interface data",
+        None,
+    ),
+    (
+        DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_END_OF_EXPOSURE,
+        "End of exposure event",
+        Some("event::EventKind::EndOfExposure"),
+    ),
+];
+
+/// Device features of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceFeatures<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the device features of the camera.
+    #[inline]
+    pub fn device_features(&self) -> DeviceFeatures<'_> {
+        DeviceFeatures { camera: self }
+    }
+}
+
+/// Value exchanged with the driver as a 32-bit integer parameter.
+trait FeatureValue: Sized {
+    fn into_raw(self) -> UINT;
+
+    fn from_raw(raw: UINT) -> Result<Self>;
+}
+
+impl FeatureValue for UINT {
+    #[inline]
+    fn into_raw(self) -> UINT {
+        self
+    }
+
+    #[inline]
+    fn from_raw(raw: UINT) -> Result<Self> {
+        Ok(raw)
+    }
+}
+
+impl FeatureValue for INT {
+    #[inline]
+    fn into_raw(self) -> UINT {
+        self as UINT
+    }
+
+    #[inline]
+    fn from_raw(raw: UINT) -> Result<Self> {
+        Ok(raw as INT)
+    }
+}
+
+impl FeatureValue for bool {
+    #[inline]
+    fn into_raw(self) -> UINT {
+        self as UINT
+    }
+
+    #[inline]
+    fn from_raw(raw: UINT) -> Result<Self> {
+        Ok(raw != 0)
+    }
+}
+
+/// Implement [`FeatureValue`] for enumerations of the driver, rejecting undocumented values.
+macro_rules! feature_values {
+    ($($type:ident { $($variant:ident),* $(,)? })*) => {
+        $(
+            impl FeatureValue for $type {
+                #[inline]
+                fn into_raw(self) -> UINT {
+                    self as UINT
+                }
+
+                fn from_raw(raw: UINT) -> Result<Self> {
+                    $(if raw == $type::$variant as UINT {
+                        return Ok($type::$variant);
+                    })*
+                    Err(Error::UnexpectedValue(raw as INT))
+                }
+            }
+        )*
+    };
+}
+
+feature_values! {
+    IMAGE_EFFECT_MODE {
+        IS_IMAGE_EFFECT_DISABLE,
+        IS_IMAGE_EFFECT_SEPIA,
+        IS_IMAGE_EFFECT_MONOCHROME,
+        IS_IMAGE_EFFECT_NEGATIVE,
+        IS_IMAGE_EFFECT_CROSSHAIRS,
+    }
+    IS_TEMPERATURE_CONTROL_STATUS {
+        TEMPERATURE_CONTROL_STATUS_NORMAL,
+        TEMPERATURE_CONTROL_STATUS_WARNING,
+        TEMPERATURE_CONTROL_STATUS_CRITICAL,
+    }
+    LOG_MODES { IS_LOG_MODE_FACTORY_DEFAULT, IS_LOG_MODE_OFF, IS_LOG_MODE_MANUAL, IS_LOG_MODE_AUTO }
+    NOISE_REDUCTION_MODES { IS_NOISE_REDUCTION_OFF, IS_NOISE_REDUCTION_ADAPTIVE }
+    SENSOR_BIT_DEPTH {
+        IS_SENSOR_BIT_DEPTH_AUTO,
+        IS_SENSOR_BIT_DEPTH_8_BIT,
+        IS_SENSOR_BIT_DEPTH_10_BIT,
+        IS_SENSOR_BIT_DEPTH_12_BIT,
+    }
+}
+
+/// Sensor shutter modes.
+///
+/// # Documentation
+/// [Switching the shutter mode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeatureshuttermode.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ShutterMode {
+    /// Rolling shutter.
+    Rolling,
+
+    /// Rolling shutter with global start.
+    RollingGlobalStart,
+
+    /// Global shutter.
+    Global,
+
+    /// Global shutter with alternative timing.
+    GlobalAlternativeTiming,
+}
+
+impl ShutterMode {
+    /// Capability flag of this shutter mode, which is also its parameter value.
+    #[inline]
+    pub const fn capability(self) -> DEVICE_FEATURE_MODE_CAPS {
+        match self {
+            ShutterMode::Rolling => DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING,
+            ShutterMode::RollingGlobalStart => {
+                DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING_GLOBAL_START
+            },
+            ShutterMode::Global => DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL,
+            ShutterMode::GlobalAlternativeTiming => {
+                DEVICE_FEATURE_MODE_CAPS::IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL_ALTERNATIVE_TIMING
+            },
+        }
+    }
+}
+
+impl FeatureValue for ShutterMode {
+    #[inline]
+    fn into_raw(self) -> UINT {
+        self.capability().bits()
+    }
+
+    fn from_raw(raw: UINT) -> Result<Self> {
+        [
+            ShutterMode::Rolling,
+            ShutterMode::RollingGlobalStart,
+            ShutterMode::Global,
+            ShutterMode::GlobalAlternativeTiming,
+        ]
+        .into_iter()
+        .find(|mode| mode.into_raw() == raw)
+        .ok_or(Error::UnexpectedValue(raw as INT))
+    }
+}
+
+impl DeviceFeatures<'_> {
+    fn get<C, T>(&self, _command: C) -> Result<T>
+    where
+        C: CommandParam,
+        C::Param: FeatureValue + Default,
+        T: FeatureValue,
+    {
+        let mut raw = C::Param::default();
+        device_command::<C>(self.camera, &mut raw)?;
+        T::from_raw(raw.into_raw())
+    }
+
+    fn set<C, T>(&self, _command: C, value: T) -> Result<()>
+    where
+        C: CommandParam,
+        C::Param: FeatureValue,
+        T: FeatureValue,
+    {
+        device_command::<C>(self.camera, &mut C::Param::from_raw(value.into_raw())?)
+    }
+
+    /// Query the range reported by `command`, along with the default reported by `default`.
+    fn range<C, D>(&self, _command: C, default: D) -> Result<ParamRange<i32>>
+    where
+        C: CommandParam<Param = IS_RANGE_S32>,
+        D: CommandParam,
+        D::Param: FeatureValue + Default,
+    {
+        let mut range = IS_RANGE_S32 { s32Min: 0, s32Max: 0, s32Inc: 0 };
+        device_command::<C>(self.camera, &mut range)?;
+        Ok(ParamRange::from(range).with_default(self.get(default)?))
+    }
+
+    /// Query the device features supported by the camera.
+    pub fn supported_features(&self) -> Result<DEVICE_FEATURE_MODE_CAPS> {
+        let mut features = DEVICE_FEATURE_MODE_CAPS::empty();
+        device_command::<IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES>(self.camera, &mut features)?;
+        Ok(features)
+    }
+
+    /// Query the device features supported by the camera, as a printable report.
+    #[inline]
+    pub fn capabilities(&self) -> Result<Capabilities> {
+        self.supported_features().map(|features| Capabilities { features })
+    }
+
+    /// Query the shutter mode.
+    #[inline]
+    pub fn shutter_mode(&self) -> Result<ShutterMode> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_SHUTTER_MODE)
+    }
+
+    /// Set the shutter mode.
+    #[inline]
+    pub fn set_shutter_mode(&self, mode: ShutterMode) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_SHUTTER_MODE, mode)
+    }
+
+    /// Query the sensor bit depths supported by the camera.
+    pub fn supported_sensor_bit_depths(&self) -> Result<Vec<SENSOR_BIT_DEPTH>> {
+        let bits: UINT = self.get(IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_SENSOR_BIT_DEPTHS)?;
+        Ok([
+            SENSOR_BIT_DEPTH::IS_SENSOR_BIT_DEPTH_8_BIT,
+            SENSOR_BIT_DEPTH::IS_SENSOR_BIT_DEPTH_10_BIT,
+            SENSOR_BIT_DEPTH::IS_SENSOR_BIT_DEPTH_12_BIT,
+        ]
+        .into_iter()
+        .filter(|&depth| bits & depth as UINT != 0)
+        .collect())
+    }
+
+    /// Query the sensor bit depth.
+    #[inline]
+    pub fn sensor_bit_depth(&self) -> Result<SENSOR_BIT_DEPTH> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_SENSOR_BIT_DEPTH)
+    }
+
+    /// Query the default sensor bit depth.
+    #[inline]
+    pub fn default_sensor_bit_depth(&self) -> Result<SENSOR_BIT_DEPTH> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_SENSOR_BIT_DEPTH_DEFAULT)
+    }
+
+    /// Set the sensor bit depth.
+    #[inline]
+    pub fn set_sensor_bit_depth(&self, depth: SENSOR_BIT_DEPTH) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_SENSOR_BIT_DEPTH, depth)
+    }
+
+    /// Query the last signaled temperature state.
+    #[inline]
+    pub fn temperature_status(&self) -> Result<IS_TEMPERATURE_CONTROL_STATUS> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_TEMPERATURE_STATUS)
+    }
+
+    /// Query the numerical value of the sensor temperature, which is not an absolute
+    /// temperature.
+    #[inline]
+    pub fn sensor_temperature_value(&self) -> Result<UINT> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_SENSOR_TEMPERATURE_NUMERICAL_VALUE)
+    }
+
+    /// Query the Log mode.
+    #[inline]
+    pub fn log_mode(&self) -> Result<LOG_MODES> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_LOG_MODE)
+    }
+
+    /// Set the Log mode.
+    #[inline]
+    pub fn set_log_mode(&self, mode: LOG_MODES) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_LOG_MODE, mode)
+    }
+
+    /// Query the manual value of the Log mode.
+    #[inline]
+    pub fn log_mode_manual_value(&self) -> Result<INT> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE)
+    }
+
+    /// Set the manual value of the Log mode.
+    #[inline]
+    pub fn set_log_mode_manual_value(&self, value: INT) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_LOG_MODE_MANUAL_VALUE, value)
+    }
+
+    /// Query the range of the manual value of the Log mode.
+    #[inline]
+    pub fn log_mode_manual_value_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_VALUE_DEFAULT,
+        )
+    }
+
+    /// Query the manual gain of the Log mode.
+    #[inline]
+    pub fn log_mode_manual_gain(&self) -> Result<INT> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN)
+    }
+
+    /// Set the manual gain of the Log mode.
+    #[inline]
+    pub fn set_log_mode_manual_gain(&self, gain: INT) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_LOG_MODE_MANUAL_GAIN, gain)
+    }
+
+    /// Query the range of the manual gain of the Log mode.
+    #[inline]
+    pub fn log_mode_manual_gain_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_LOG_MODE_MANUAL_GAIN_DEFAULT,
+        )
+    }
+
+    /// Query the position of the line used for the vertical AOI merge mode.
+    #[inline]
+    pub fn vertical_aoi_merge_position(&self) -> Result<INT> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION)
+    }
+
+    /// Set the position of the line used for the vertical AOI merge mode.
+    #[inline]
+    pub fn set_vertical_aoi_merge_position(&self, position: INT) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_VERTICAL_AOI_MERGE_POSITION, position)
+    }
+
+    /// Query the range of the line position of the vertical AOI merge mode.
+    #[inline]
+    pub fn vertical_aoi_merge_position_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_POSITION_DEFAULT,
+        )
+    }
+
+    /// Query the range of the additional line position of the vertical AOI merge mode.
+    #[inline]
+    pub fn vertical_aoi_merge_additional_position_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_VERTICAL_AOI_MERGE_ADDITIONAL_POSITION_DEFAULT,
+        )
+    }
+
+    /// Query the range of the analog source gain of the sensor.
+    #[inline]
+    pub fn sensor_source_gain_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_SENSOR_SOURCE_GAIN_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_SENSOR_SOURCE_GAIN_DEFAULT,
+        )
+    }
+
+    /// Query the JPEG compression (`1`…`9`).
+    #[inline]
+    pub fn jpeg_compression(&self) -> Result<INT> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION)
+    }
+
+    /// Set the JPEG compression (`1`…`9`); higher values compress more.
+    #[inline]
+    pub fn set_jpeg_compression(&self, compression: INT) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_JPEG_COMPRESSION, compression)
+    }
+
+    /// Query the range of the JPEG compression.
+    #[inline]
+    pub fn jpeg_compression_range(&self) -> Result<ParamRange<i32>> {
+        self.range(
+            IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION_RANGE,
+            IS_DEVICE_FEATURE_CMD_GET_JPEG_COMPRESSION_DEFAULT,
+        )
+    }
+
+    /// Query the noise reduction mode.
+    #[inline]
+    pub fn noise_reduction(&self) -> Result<NOISE_REDUCTION_MODES> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_NOISE_REDUCTION_MODE)
+    }
+
+    /// Set the noise reduction mode.
+    #[inline]
+    pub fn set_noise_reduction(&self, mode: NOISE_REDUCTION_MODES) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_NOISE_REDUCTION_MODE, mode)
+    }
+
+    /// Query the image effect.
+    #[inline]
+    pub fn image_effect(&self) -> Result<IMAGE_EFFECT_MODE> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_IMAGE_EFFECT)
+    }
+
+    /// Set the image effect.
+    #[inline]
+    pub fn set_image_effect(&self, effect: IMAGE_EFFECT_MODE) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_IMAGE_EFFECT, effect)
+    }
+
+    /// Query whether the extended pixel clock range is enabled.
+    #[inline]
+    pub fn extended_pixel_clock_range(&self) -> Result<bool> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_EXTENDED_PIXELCLOCK_RANGE_ENABLE)
+    }
+
+    /// Enable or disable the extended pixel clock range.
+    #[inline]
+    pub fn set_extended_pixel_clock_range(&self, enable: bool) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_EXTENDED_PIXELCLOCK_RANGE_ENABLE, enable)
+    }
+
+    /// Query whether the camera LUT is also applied to RAW formats.
+    #[inline]
+    pub fn raw_with_lut(&self) -> Result<bool> {
+        self.get(IS_DEVICE_FEATURE_CMD_GET_ALLOW_RAW_WITH_LUT)
+    }
+
+    /// Allow or disallow applying the camera LUT to RAW formats.
+    #[inline]
+    pub fn set_raw_with_lut(&self, allow: bool) -> Result<()> {
+        self.set(IS_DEVICE_FEATURE_CMD_SET_ALLOW_RAW_WITH_LUT, allow)
+    }
+}
+
+/// Set of supported device features.
+///
+/// The [`Display`] implementation renders a report of every known feature, suitable for support
+/// logs.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    features: DEVICE_FEATURE_MODE_CAPS,
+}
+
+impl Capabilities {
+    /// Raw capability mask, as returned by the driver.
+    #[inline]
+    pub const fn bits(&self) -> UINT {
+        self.features.bits()
+    }
+
+    /// Supported features as capability flags.
+    #[inline]
+    pub const fn features(&self) -> DEVICE_FEATURE_MODE_CAPS {
+        self.features
+    }
+
+    /// Whether `capability` is supported.
+    #[inline]
+    pub const fn contains(&self, capability: DEVICE_FEATURE_MODE_CAPS) -> bool {
+        self.features.contains(capability)
+    }
+
+    /// Supported capabilities.
+    pub fn iter(&self) -> impl Iterator<Item = DEVICE_FEATURE_MODE_CAPS> + '_ {
+        CAPABILITIES.iter().map(|&(capability, ..)| capability).filter(|&c| self.contains(c))
+    }
+}
+
+impl Display for Capabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Device features (0x{:08x}):", self.bits())?;
+        for &(capability, description, api) in CAPABILITIES {
+            let mark = if self.contains(capability) { 'x' } else { ' ' };
+            match api {
+                Some(api) => writeln!(f, "  [{mark}] {description} ({api})")?,
+                None => writeln!(f, "  [{mark}] {description}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+//! Device information.
+//!
+//! # Documentation
+//! * [is_DeviceInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_deviceinfo.html)
+//! * [is_GetCameraInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcamerainfo.html)
+//! * [is_GetSensorInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getsensorinfo.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    mem::{size_of, MaybeUninit},
+};
+
+use ueye_sys::{
+    camera_info::{
+        is_GetCameraInfo, is_GetSensorInfo, CAMINFO, IS_COLORMODE_BAYER, IS_COLORMODE_CBYCRY,
+        IS_COLORMODE_JPEG, IS_COLORMODE_MONOCHROME, SENSORINFO,
+    },
+    device_info::{is_DeviceInfo, IS_DEVICE_INFO, IS_DEVICE_INFO_CMD},
+    types::{char, void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    metadata::Version,
+    observer::{call, command},
+};
+
+/// Query the device information structure.
+pub fn query(camera: &Camera) -> Result<IS_DEVICE_INFO> {
+    // SAFETY: `IS_DEVICE_INFO` is plain data, for which all-zero is a valid value.
+    let mut info = unsafe { MaybeUninit::<IS_DEVICE_INFO>::zeroed().assume_init() };
+    check(unsafe {
+        command!(is_DeviceInfo(
+            camera.handle(),
+            IS_DEVICE_INFO_CMD::IS_DEVICE_INFO_CMD_GET_DEVICE_INFO,
+            &mut info as *mut IS_DEVICE_INFO as *mut void,
+            size_of::<IS_DEVICE_INFO>() as UINT,
+        ))
+    })?;
+
+    Ok(info)
+}
+
+/// Query the camera temperature in °Celsius, or `None` if the camera does not have a
+/// temperature sensor.
+#[inline]
+pub fn temperature(camera: &Camera) -> Result<Option<f32>> {
+    query(camera).map(|info| info.infoDevHeartbeat.temperature_celsius())
+}
+
+/// Query the camera information stored in the camera EEPROM.
+pub fn camera_info(camera: &Camera) -> Result<CAMINFO> {
+    let mut info = CAMINFO::default();
+    check(unsafe { call!(is_GetCameraInfo(camera.handle(), &mut info)) })?;
+    Ok(info)
+}
+
+/// Query the sensor information.
+pub fn sensor_info(camera: &Camera) -> Result<SENSORINFO> {
+    let mut info = SENSORINFO::default();
+    check(unsafe { call!(is_GetSensorInfo(camera.handle(), &mut info)) })?;
+    Ok(info)
+}
+
+/// Color filter of a sensor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorColor {
+    /// No color filter.
+    Monochrome,
+
+    /// Bayer color filter.
+    Bayer,
+
+    /// Color, delivered as CbYCrY.
+    CbYCrY,
+
+    /// Color, delivered as JPEG.
+    Jpeg,
+}
+
+impl SensorColor {
+    /// Whether the sensor delivers color images.
+    #[inline]
+    pub const fn is_color(self) -> bool {
+        !matches!(self, SensorColor::Monochrome)
+    }
+
+    /// Name of the color filter, e.g. `"Bayer"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            SensorColor::Monochrome => "monochrome",
+            SensorColor::Bayer => "Bayer",
+            SensorColor::CbYCrY => "CbYCrY",
+            SensorColor::Jpeg => "JPEG",
+        }
+    }
+}
+
+impl TryFrom<char> for SensorColor {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            IS_COLORMODE_MONOCHROME => Ok(SensorColor::Monochrome),
+            IS_COLORMODE_BAYER => Ok(SensorColor::Bayer),
+            IS_COLORMODE_CBYCRY => Ok(SensorColor::CbYCrY),
+            IS_COLORMODE_JPEG => Ok(SensorColor::Jpeg),
+            value => Err(Error::UnexpectedValue(value as INT)),
+        }
+    }
+}
+
+impl Display for SensorColor {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Shutter of a sensor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shutter {
+    /// All lines are exposed at the same time.
+    Global,
+
+    /// Lines are exposed one after another.
+    Rolling,
+}
+
+impl Display for Shutter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Shutter::Global => "global shutter",
+            Shutter::Rolling => "rolling shutter",
+        })
+    }
+}
+
+/// Summary of a camera and its sensor, e.g. for logs and camera listings.
+///
+/// Displays as a single line, e.g.
+/// `UI-3240CP-C-HQ #4102812345: 1280 × 1024, Bayer, global shutter, 5.30 µm pixels`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraDescriptor {
+    /// Camera model.
+    pub model: String,
+
+    /// Serial number of the camera.
+    pub serial_number: String,
+
+    /// Manufacturer of the camera.
+    pub manufacturer: String,
+
+    /// Sensor type.
+    pub sensor_id: u16,
+
+    /// Maximum image width, in pixels.
+    pub max_width: u32,
+
+    /// Maximum image height, in lines.
+    pub max_height: u32,
+
+    /// Color filter of the sensor.
+    pub color: SensorColor,
+
+    /// Shutter of the sensor.
+    pub shutter: Shutter,
+
+    /// Pixel size, in µm.
+    pub pixel_size: f32,
+}
+
+impl CameraDescriptor {
+    /// Combine the camera information `camera` and sensor information `sensor`.
+    pub fn new(camera: &CAMINFO, sensor: &SENSORINFO) -> Result<Self> {
+        Ok(Self {
+            model: c_string(&sensor.strSensorName),
+            serial_number: c_string(&camera.SerNo),
+            manufacturer: c_string(&camera.ID),
+            sensor_id: sensor.SensorID,
+            max_width: sensor.nMaxWidth,
+            max_height: sensor.nMaxHeight,
+            color: SensorColor::try_from(sensor.nColorMode)?,
+            shutter: match sensor.bGlobShutter {
+                0 => Shutter::Rolling,
+                _ => Shutter::Global,
+            },
+            pixel_size: f32::from(sensor.wPixelSize) / 100.0,
+        })
+    }
+}
+
+impl Display for CameraDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} #{}: {} × {}, {}, {}, {:.2} µm pixels",
+            self.model,
+            self.serial_number,
+            self.max_width,
+            self.max_height,
+            self.color,
+            self.shutter,
+            self.pixel_size,
+        )
+    }
+}
+
+/// Query the summary of the camera and its sensor.
+pub fn descriptor(camera: &Camera) -> Result<CameraDescriptor> {
+    CameraDescriptor::new(&camera_info(camera)?, &sensor_info(camera)?)
+}
+
+/// Version of the uEye API library, as `major.minor.build`.
+pub fn api_version() -> String {
+    Version::api().to_string()
+}
+
+/// Convert a fixed-size, null-padded string field of a driver structure.
+pub(crate) fn c_string(chars: &[char]) -> String {
+    let bytes: Vec<u8> = chars.iter().map(|&c| c as u8).take_while(|&byte| byte != 0).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
@@ -0,0 +1,332 @@
+//! Direct3D and OpenGL image display.
+//!
+//! A [`Renderer`] switches the camera to a Direct3D or OpenGL [`DisplayMode`] and controls the
+//! display and its overlay, which is drawn on top of the live image without flicker. Direct3D is
+//! only available on Windows, where the overlay is drawn through a GDI device context; in OpenGL
+//! mode, the overlay is a 32-bit buffer [accessed](Renderer::overlay) as a [`FrameMut`].
+//!
+//! The displayed images can be copied (_stolen_) into an image memory of the application, in a
+//! color mode of their own, while the display continues.
+//!
+//! # Documentation
+//! [is_DirectRenderer](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_directrenderer.html)
+
+use std::{
+    mem::size_of,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+use raw_window_handle::HasWindowHandle;
+#[cfg(not(target_os = "windows"))]
+use ueye_sys::direct_renderer::OPENGL_DISPLAY;
+#[cfg(target_os = "windows")]
+use ueye_sys::types::HDC;
+use ueye_sys::{
+    direct_renderer::{is_DirectRenderer, DR_CMD},
+    types::{void, INT, UINT},
+    video::{IS_DONT_WAIT, IS_WAIT},
+};
+
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+use crate::display::hwnd;
+use crate::{
+    camera::Camera,
+    color_mode::ColorMode,
+    display::DisplayMode,
+    error::{check, Error, Result},
+    frame::FrameMut,
+    image_mem::ImageMemory,
+    observer::command,
+};
+
+/// Bit depth of the OpenGL overlay buffer.
+const OVERLAY_BITS_PER_PIXEL: usize = 32;
+
+fn direct_renderer<T>(camera: &Camera, cmd: DR_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_DirectRenderer(
+            camera.handle(),
+            cmd,
+            param as *mut T as *mut void,
+            size_of::<T>() as UINT,
+        ))
+    })
+}
+
+fn direct_renderer_null(camera: &Camera, cmd: DR_CMD) -> Result<()> {
+    check(unsafe { command!(is_DirectRenderer(camera.handle(), cmd, ptr::null_mut(), 0)) })
+}
+
+/// Set `window` as the target of the Direct3D or OpenGL image display.
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+pub fn set_window(camera: &Camera, window: &impl HasWindowHandle) -> Result<()> {
+    let mut hwnd = hwnd(window)?;
+    direct_renderer(camera, DR_CMD::DR_SET_HWND, &mut hwnd)
+}
+
+/// Direct3D or OpenGL image display of a camera.
+#[derive(Debug)]
+pub struct Renderer<'c> {
+    camera: &'c Camera,
+    mode: DisplayMode,
+}
+
+impl<'c> Renderer<'c> {
+    /// Switch `camera` to the Direct3D or OpenGL display `mode`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for [`DisplayMode::Dib`].
+    pub fn new(camera: &'c Camera, mode: DisplayMode) -> Result<Self> {
+        if mode == DisplayMode::Dib {
+            return Err(Error::InvalidParameter);
+        }
+
+        mode.apply(camera)?;
+        Ok(Self { camera, mode })
+    }
+
+    /// Display mode of the renderer.
+    #[inline]
+    pub const fn mode(&self) -> DisplayMode {
+        self.mode
+    }
+
+    /// _Direct3D only:_ whether the graphics card fully supports the Direct3D functions.
+    pub fn is_compatible(&self) -> Result<bool> {
+        match direct_renderer_null(self.camera, DR_CMD::DR_CHECK_COMPATIBILITY) {
+            Ok(()) => Ok(true),
+            Err(Error::DrDeviceCapsInsufficient) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Set `window` as the target of the display.
+    #[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+    #[inline]
+    pub fn set_window(&self, window: &impl HasWindowHandle) -> Result<()> {
+        set_window(self.camera, window)
+    }
+
+    /// _OpenGL only:_ set the X11 window `window` on `display` as the target of the display.
+    ///
+    /// # Safety
+    /// `display` must be an open X11 `Display` holding `window`, for as long as the renderer
+    /// displays into it.
+    #[cfg(not(target_os = "windows"))]
+    pub unsafe fn set_x11_window(&self, window: INT, display: *mut void) -> Result<()> {
+        let mut target = OPENGL_DISPLAY { nWindowID: window, pDisplay: display };
+        direct_renderer(self.camera, DR_CMD::DR_SET_HWND, &mut target)
+    }
+
+    /// Scale the image to the size of the window; with `overlay`, the overlay is scaled along.
+    pub fn enable_scaling(&self, overlay: bool) -> Result<()> {
+        direct_renderer_null(
+            self.camera,
+            match overlay {
+                true => DR_CMD::DR_ENABLE_SCALING,
+                false => DR_CMD::DR_ENABLE_IMAGE_SCALING,
+            },
+        )
+    }
+
+    /// Display the image at its original size.
+    #[inline]
+    pub fn disable_scaling(&self) -> Result<()> {
+        direct_renderer_null(self.camera, DR_CMD::DR_DISABLE_SCALING)
+    }
+
+    /// Synchronize the display with the vertical sync of the monitor, or display images
+    /// immediately.
+    pub fn set_vsync(&self, enabled: bool) -> Result<()> {
+        direct_renderer_null(
+            self.camera,
+            match enabled {
+                true => DR_CMD::DR_SET_VSYNC_AUTO,
+                false => DR_CMD::DR_SET_VSYNC_OFF,
+            },
+        )
+    }
+
+    /// Show the overlay on top of the image.
+    #[inline]
+    pub fn show_overlay(&self) -> Result<()> {
+        direct_renderer_null(self.camera, DR_CMD::DR_SHOW_OVERLAY)
+    }
+
+    /// Hide the overlay.
+    #[inline]
+    pub fn hide_overlay(&self) -> Result<()> {
+        direct_renderer_null(self.camera, DR_CMD::DR_HIDE_OVERLAY)
+    }
+
+    /// Fill the overlay with black, i.e. clear it.
+    #[inline]
+    pub fn clear_overlay(&self) -> Result<()> {
+        direct_renderer_null(self.camera, DR_CMD::DR_CLEAR_OVERLAY)
+    }
+
+    /// Add the overlay to the image instead of replacing the pixels not in the key color.
+    pub fn set_semi_transparent(&self, enabled: bool) -> Result<()> {
+        direct_renderer_null(
+            self.camera,
+            match enabled {
+                true => DR_CMD::DR_ENABLE_SEMI_TRANSPARENT_OVERLAY,
+                false => DR_CMD::DR_DISABLE_SEMI_TRANSPARENT_OVERLAY,
+            },
+        )
+    }
+
+    /// Query the size of the overlay, as `(width, height)`.
+    pub fn overlay_size(&self) -> Result<(u32, u32)> {
+        let mut size: [UINT; 2] = [0; 2];
+        direct_renderer(self.camera, DR_CMD::DR_GET_OVERLAY_SIZE, &mut size)?;
+        Ok((size[0], size[1]))
+    }
+
+    /// Query the largest overlay size supported by the graphics card, as `(width, height)`.
+    pub fn max_overlay_size(&self) -> Result<(u32, u32)> {
+        let mut size: [UINT; 2] = [0; 2];
+        direct_renderer(self.camera, DR_CMD::DR_GET_MAX_OVERLAY_SIZE, &mut size)?;
+        Ok((size[0], size[1]))
+    }
+
+    /// Set the size of the overlay.
+    pub fn set_overlay_size(&self, width: u32, height: u32) -> Result<()> {
+        direct_renderer(self.camera, DR_CMD::DR_SET_OVERLAY_SIZE, &mut [width, height])
+    }
+
+    /// Set the position of the overlay in the image.
+    pub fn set_overlay_position(&self, x: u32, y: u32) -> Result<()> {
+        direct_renderer(self.camera, DR_CMD::DR_SET_OVERLAY_POSITION, &mut [x, y])
+    }
+
+    /// Query the key color of the overlay, as `[red, green, blue]`.
+    pub fn key_color(&self) -> Result<[u8; 3]> {
+        let mut color: [UINT; 3] = [0; 3];
+        direct_renderer(self.camera, DR_CMD::DR_GET_OVERLAY_KEY_COLOR, &mut color)?;
+        Ok(color.map(|channel| channel.min(u8::MAX as UINT) as u8))
+    }
+
+    /// Set the key color of the overlay, as `[red, green, blue]`; overlay pixels of the key color
+    /// are transparent.
+    pub fn set_key_color(&self, color: [u8; 3]) -> Result<()> {
+        let mut color = color.map(UINT::from);
+        direct_renderer(self.camera, DR_CMD::DR_SET_OVERLAY_KEY_COLOR, &mut color)
+    }
+
+    /// _OpenGL only:_ access the overlay buffer, 32 bits per pixel, for drawing.
+    ///
+    /// The display is updated with the drawing once the returned guard is dropped.
+    pub fn overlay(&mut self) -> Result<OverlayBuffer<'_>> {
+        let (width, height) = self.overlay_size()?;
+        let mut data: *mut void = ptr::null_mut();
+        direct_renderer(self.camera, DR_CMD::DR_GET_OVERLAY_DATA, &mut data)?;
+        if data.is_null() {
+            return Err(Error::InvalidMemoryPointer);
+        }
+
+        let (width, height) = (width as usize, height as usize);
+        let pitch = width * OVERLAY_BITS_PER_PIXEL / 8;
+
+        // SAFETY: the driver keeps the overlay buffer of `pitch * height` bytes allocated while
+        // the display mode is active, which `&mut self` keeps from changing.
+        let data = unsafe { std::slice::from_raw_parts_mut(data as *mut u8, pitch * height) };
+        let frame = FrameMut::new(data, width, height, pitch, OVERLAY_BITS_PER_PIXEL)
+            .ok_or(Error::InvalidMemoryPointer)?;
+
+        Ok(OverlayBuffer { camera: self.camera, frame })
+    }
+
+    /// _Direct3D only:_ get a GDI device context of the overlay for drawing.
+    ///
+    /// The overlay is updated with the drawing once the returned guard is dropped.
+    #[cfg(target_os = "windows")]
+    pub fn overlay_dc(&mut self) -> Result<OverlayDc<'_>> {
+        let mut dc: HDC = ptr::null_mut();
+        direct_renderer(self.camera, DR_CMD::DR_GET_OVERLAY_DC, &mut dc)?;
+        Ok(OverlayDc { camera: self.camera, dc })
+    }
+
+    /// Query the color mode images are stolen in.
+    pub fn steal_format(&self) -> Result<ColorMode> {
+        let mut mode: INT = 0;
+        direct_renderer(self.camera, DR_CMD::DR_GET_STEAL_FORMAT, &mut mode)?;
+        ColorMode::try_from(mode)
+    }
+
+    /// Set the color mode images are stolen in (_default: [`ColorMode::Bgra8Packed`]_).
+    pub fn set_steal_format(&self, mode: ColorMode) -> Result<()> {
+        direct_renderer(self.camera, DR_CMD::DR_SET_STEAL_FORMAT, &mut mode.bits())
+    }
+
+    /// Copy the next displayed image into `memory`, which must be allocated in the
+    /// [steal format](Renderer::steal_format), and wait until it is copied.
+    ///
+    /// `memory` becomes the active image memory.
+    pub fn steal_next_frame(&self, memory: &mut ImageMemory<'_>) -> Result<()> {
+        memory.set_active()?;
+        let mut wait: UINT = IS_WAIT;
+        direct_renderer(self.camera, DR_CMD::DR_STEAL_NEXT_FRAME, &mut wait)
+    }
+
+    /// Request a copy of the next displayed image into the active image memory, without
+    /// waiting for it.
+    pub fn request_steal(&self) -> Result<()> {
+        let mut wait: UINT = IS_DONT_WAIT;
+        direct_renderer(self.camera, DR_CMD::DR_STEAL_NEXT_FRAME, &mut wait)
+    }
+}
+
+/// _OpenGL only:_ overlay buffer of a [`Renderer`], updating the display when dropped.
+#[derive(Debug)]
+pub struct OverlayBuffer<'r> {
+    camera: &'r Camera,
+    frame: FrameMut<'r>,
+}
+
+impl<'r> Deref for OverlayBuffer<'r> {
+    type Target = FrameMut<'r>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.frame
+    }
+}
+
+impl DerefMut for OverlayBuffer<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.frame
+    }
+}
+
+impl Drop for OverlayBuffer<'_> {
+    fn drop(&mut self) {
+        let _ = direct_renderer_null(self.camera, DR_CMD::DR_UPDATE_OVERLAY_DATA);
+    }
+}
+
+/// _Direct3D only:_ GDI device context of the overlay of a [`Renderer`], released when dropped.
+#[cfg(target_os = "windows")]
+#[derive(Debug)]
+pub struct OverlayDc<'r> {
+    camera: &'r Camera,
+    dc: HDC,
+}
+
+#[cfg(target_os = "windows")]
+impl OverlayDc<'_> {
+    /// Handle of the device context, valid until the guard is dropped.
+    #[inline]
+    pub const fn handle(&self) -> HDC {
+        self.dc
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for OverlayDc<'_> {
+    fn drop(&mut self) {
+        let _ = direct_renderer_null(self.camera, DR_CMD::DR_RELEASE_OVERLAY_DC);
+    }
+}
@@ -0,0 +1,233 @@
+//! Display mode selection.
+//!
+//! [`is_SetDisplayMode`] accepts a bitmask of [`IS_SET_DM`] flags, but only a handful of
+//! combinations are documented as valid. [`DisplayMode`] encodes exactly those combinations.
+//!
+//! On Windows, with the `raw-window-handle` feature, images can be rendered into any window
+//! implementing `raw_window_handle::HasWindowHandle`, such as those of `winit` or `egui`.
+//!
+//! # Documentation
+//! * [is_SetDisplayMode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setdisplaymode.html)
+//! * [is_RenderBitmap](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_renderbitmap.html)
+//! * [is_SetHwnd](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethwnd.html)
+
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use ueye_sys::display::{is_SetDisplayMode, IS_SET_DM};
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+use ueye_sys::{
+    display::{is_RenderBitmap, is_SetHwnd, IS_RENDER_MODE},
+    types::{HWND, INT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Pixel format used by the Direct3D and OpenGL display modes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DisplayFormat {
+    /// Color image display.
+    #[default]
+    Color,
+
+    /// Monochrome image display.
+    Mono,
+
+    /// Raw Bayer format image display.
+    Bayer,
+}
+
+impl DisplayFormat {
+    #[inline]
+    const fn flags(self) -> IS_SET_DM {
+        match self {
+            DisplayFormat::Color => IS_SET_DM::empty(),
+            DisplayFormat::Mono => IS_SET_DM::IS_SET_DM_MONO,
+            DisplayFormat::Bayer => IS_SET_DM::IS_SET_DM_BAYER,
+        }
+    }
+}
+
+/// Valid display modes for [`is_SetDisplayMode`].
+///
+/// The obsolete DirectDraw modes are intentionally not represented.
+///
+/// # Documentation
+/// [is_SetDisplayMode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setdisplaymode.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DisplayMode {
+    /// Captures an image in system memory (RAM) (_default_).
+    ///
+    /// Using [`is_RenderBitmap`][ueye_sys::display::is_RenderBitmap], you can define the image
+    /// display.
+    #[default]
+    Dib,
+
+    /// Image display in Direct3D mode.
+    ///
+    /// The Direct3D display mode is not available on Linux operating systems.
+    Direct3D(DisplayFormat),
+
+    /// Image display in OpenGL mode.
+    OpenGl(DisplayFormat),
+}
+
+impl DisplayMode {
+    /// Flags passed to [`is_SetDisplayMode`] for this mode.
+    #[inline]
+    pub const fn flags(self) -> IS_SET_DM {
+        match self {
+            DisplayMode::Dib => IS_SET_DM::IS_SET_DM_DIB,
+            DisplayMode::Direct3D(format) => IS_SET_DM::IS_SET_DM_DIRECT3D.union(format.flags()),
+            DisplayMode::OpenGl(format) => IS_SET_DM::IS_SET_DM_OPENGL.union(format.flags()),
+        }
+    }
+
+    /// Query the display mode currently set on the camera.
+    ///
+    /// # Errors
+    /// * The error of the driver, if it returns an error code.
+    /// * [`Error::UnexpectedValue`] if the camera reports a combination not represented by
+    ///   [`DisplayMode`], such as one of the obsolete DirectDraw modes.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        match unsafe { call!(is_SetDisplayMode(camera.handle(), IS_SET_DM::IS_GET_DISPLAY_MODE)) } {
+            code if code < 0 => Err(Error::from_code(code)),
+            flags => Self::try_from(IS_SET_DM::from_bits_retain(flags)),
+        }
+    }
+
+    /// Apply this display mode to the camera.
+    pub fn apply(self, camera: &Camera) -> Result<()> {
+        check(unsafe { call!(is_SetDisplayMode(camera.handle(), self.flags())) })
+    }
+}
+
+impl From<DisplayMode> for IS_SET_DM {
+    #[inline]
+    fn from(value: DisplayMode) -> Self {
+        value.flags()
+    }
+}
+
+impl TryFrom<IS_SET_DM> for DisplayMode {
+    type Error = Error;
+
+    fn try_from(value: IS_SET_DM) -> Result<Self> {
+        let format = match value & (IS_SET_DM::IS_SET_DM_MONO | IS_SET_DM::IS_SET_DM_BAYER) {
+            IS_SET_DM::IS_SET_DM_MONO => DisplayFormat::Mono,
+            IS_SET_DM::IS_SET_DM_BAYER => DisplayFormat::Bayer,
+            flags if flags.is_empty() => DisplayFormat::Color,
+            _ => return Err(Error::UnexpectedValue(value.bits())),
+        };
+
+        let base = value
+            & (IS_SET_DM::IS_SET_DM_DIB
+                | IS_SET_DM::IS_SET_DM_DIRECTDRAW
+                | IS_SET_DM::IS_SET_DM_DIRECT3D
+                | IS_SET_DM::IS_SET_DM_OPENGL);
+
+        match (base, format) {
+            (IS_SET_DM::IS_SET_DM_DIB, DisplayFormat::Color) => Ok(DisplayMode::Dib),
+            (IS_SET_DM::IS_SET_DM_DIRECT3D, format) => Ok(DisplayMode::Direct3D(format)),
+            (IS_SET_DM::IS_SET_DM_OPENGL, format) => Ok(DisplayMode::OpenGl(format)),
+            _ => Err(Error::UnexpectedValue(value.bits())),
+        }
+    }
+}
+
+/// Win32 window handle of `window`.
+///
+/// # Errors
+/// [`Error::InvalidWindowHandle`] if the handle is unavailable or not a Win32 handle.
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+pub(crate) fn hwnd(window: &impl HasWindowHandle) -> Result<HWND> {
+    match window.window_handle().map_err(|_| Error::InvalidWindowHandle)?.as_raw() {
+        RawWindowHandle::Win32(handle) => Ok(handle.hwnd.get() as HWND),
+        _ => Err(Error::InvalidWindowHandle),
+    }
+}
+
+/// Render the image memory `mem_id` into `window`.
+///
+/// Only applies to the [`DisplayMode::Dib`] display mode.
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+pub fn render_bitmap(
+    camera: &Camera,
+    mem_id: INT,
+    window: &impl HasWindowHandle,
+    mode: IS_RENDER_MODE,
+) -> Result<()> {
+    let hwnd = hwnd(window)?;
+    check(unsafe { call!(is_RenderBitmap(camera.handle(), mem_id, hwnd, mode.bits())) })
+}
+
+/// Set `window` as the target of the event messages and the image display.
+#[cfg(all(target_os = "windows", feature = "raw-window-handle"))]
+pub fn set_window(camera: &Camera, window: &impl HasWindowHandle) -> Result<()> {
+    let hwnd = hwnd(window)?;
+    check(unsafe { call!(is_SetHwnd(camera.handle(), hwnd)) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [DisplayMode; 7] = [
+        DisplayMode::Dib,
+        DisplayMode::Direct3D(DisplayFormat::Color),
+        DisplayMode::Direct3D(DisplayFormat::Mono),
+        DisplayMode::Direct3D(DisplayFormat::Bayer),
+        DisplayMode::OpenGl(DisplayFormat::Color),
+        DisplayMode::OpenGl(DisplayFormat::Mono),
+        DisplayMode::OpenGl(DisplayFormat::Bayer),
+    ];
+
+    #[test]
+    fn modes_round_trip() {
+        for mode in MODES {
+            assert_eq!(DisplayMode::try_from(IS_SET_DM::from(mode)), Ok(mode));
+        }
+    }
+
+    #[test]
+    fn flags_of_modes() {
+        assert_eq!(DisplayMode::Dib.flags(), IS_SET_DM::IS_SET_DM_DIB);
+        assert_eq!(
+            DisplayMode::OpenGl(DisplayFormat::Mono).flags(),
+            IS_SET_DM::IS_SET_DM_OPENGL | IS_SET_DM::IS_SET_DM_MONO
+        );
+    }
+
+    #[test]
+    fn rejects_directdraw() {
+        let flags = IS_SET_DM::IS_SET_DM_DIRECTDRAW;
+        assert_eq!(DisplayMode::try_from(flags), Err(Error::UnexpectedValue(flags.bits())));
+    }
+
+    #[test]
+    fn rejects_dib_with_format() {
+        let flags = IS_SET_DM::IS_SET_DM_DIB | IS_SET_DM::IS_SET_DM_BAYER;
+        assert_eq!(DisplayMode::try_from(flags), Err(Error::UnexpectedValue(flags.bits())));
+    }
+
+    #[test]
+    fn rejects_mono_and_bayer() {
+        let flags =
+            IS_SET_DM::IS_SET_DM_OPENGL | IS_SET_DM::IS_SET_DM_MONO | IS_SET_DM::IS_SET_DM_BAYER;
+        assert_eq!(DisplayMode::try_from(flags), Err(Error::UnexpectedValue(flags.bits())));
+    }
+
+    #[test]
+    fn rejects_missing_and_combined_modes() {
+        for flags in [
+            IS_SET_DM::empty(),
+            IS_SET_DM::IS_SET_DM_MONO,
+            IS_SET_DM::IS_SET_DM_DIRECT3D | IS_SET_DM::IS_SET_DM_OPENGL,
+        ] {
+            assert_eq!(DisplayMode::try_from(flags), Err(Error::UnexpectedValue(flags.bits())));
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Software edge enhancement.
+//!
+//! The color conversion of Bayer images blurs edges; the edge filter of the driver sharpens them
+//! again, at the cost of CPU load. The valid levels depend on the camera, see [`range`]; level `0`
+//! disables the filter. The filter does not apply to raw Bayer formats.
+//!
+//! # Documentation
+//! [is_EdgeEnhancement](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_edgeenhancement.html)
+
+use crate::{
+    camera::Camera,
+    error::{Error, Result},
+    parameter::{EdgeEnhancement, Parameter},
+    range::ParamRange,
+};
+
+/// Query the current edge enhancement level.
+#[inline]
+pub fn current(camera: &Camera) -> Result<u32> {
+    EdgeEnhancement::get(camera)
+}
+
+/// Query the default edge enhancement level.
+#[inline]
+pub fn default(camera: &Camera) -> Result<u32> {
+    EdgeEnhancement::default(camera)
+}
+
+/// Query the range of edge enhancement levels.
+#[inline]
+pub fn range(camera: &Camera) -> Result<ParamRange<u32>> {
+    EdgeEnhancement::range(camera)
+}
+
+/// Set the edge enhancement level, `0` disabling it.
+///
+/// Fails with [`Error::InvalidParameter`] if `level` is not [within](ParamRange::contains) the
+/// [`range`] of the camera.
+pub fn set(camera: &Camera, level: u32) -> Result<()> {
+    if level != 0 && !range(camera)?.contains(level) {
+        return Err(Error::InvalidParameter);
+    }
+
+    EdgeEnhancement::set_unchecked(camera, level)
+}
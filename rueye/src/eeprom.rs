@@ -0,0 +1,94 @@
+//! User area of the camera EEPROM.
+//!
+//! The EEPROM holds 64 bytes of user data, e.g. a per-camera calibration. Accesses are
+//! bounds-checked against the user area and fail with [`Error::OutOfRange`] instead of reaching
+//! the driver. With the `eeprom` feature, `store` and `load` keep a small serializable value in
+//! the user area, encoded as JSON and padded with NUL bytes.
+//!
+//! The EEPROM functions are obsolete; newer cameras also expose the same area, alongside larger
+//! ones, through the [persistent memory](crate::persistent_memory).
+//!
+//! <div class="warning">
+//! The EEPROM can only be accessed while the live image acquisition is stopped.
+//! </div>
+
+#[allow(deprecated)]
+use ueye_sys::eeprom::{is_ReadEEPROM, is_WriteEEPROM};
+use ueye_sys::types::{char, INT};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Size of the user area (in bytes).
+pub const USER_AREA_SIZE: usize = 64;
+
+/// Fail with [`Error::OutOfRange`] unless `len` bytes at `offset` lie within the user area.
+fn check_range(offset: usize, len: usize) -> Result<()> {
+    match offset.checked_add(len) {
+        Some(end) if end <= USER_AREA_SIZE => Ok(()),
+        _ => Err(Error::OutOfRange { offset, len, size: USER_AREA_SIZE }),
+    }
+}
+
+/// Read `buffer.len()` bytes at `offset` of the user area into `buffer`.
+#[allow(deprecated)]
+pub fn read(camera: &Camera, offset: usize, buffer: &mut [u8]) -> Result<()> {
+    check_range(offset, buffer.len())?;
+    check(unsafe {
+        call!(is_ReadEEPROM(
+            camera.handle(),
+            offset as INT,
+            buffer.as_mut_ptr() as *mut char,
+            buffer.len() as INT,
+        ))
+    })
+}
+
+/// Write `data` at `offset` of the user area.
+#[allow(deprecated)]
+pub fn write(camera: &Camera, offset: usize, data: &[u8]) -> Result<()> {
+    check_range(offset, data.len())?;
+    check(unsafe {
+        call!(is_WriteEEPROM(
+            camera.handle(),
+            offset as INT,
+            data.as_ptr() as *const char,
+            data.len() as INT,
+        ))
+    })
+}
+
+/// Read the whole user area.
+pub fn read_all(camera: &Camera) -> Result<[u8; USER_AREA_SIZE]> {
+    let mut buffer = [0; USER_AREA_SIZE];
+    read(camera, 0, &mut buffer)?;
+
+    Ok(buffer)
+}
+
+/// Store `value` in the user area, replacing its content.
+///
+/// Fails with [`Error::OutOfRange`] if the encoded value exceeds the user area.
+#[cfg(feature = "eeprom")]
+pub fn store<T: serde::Serialize>(camera: &Camera, value: &T) -> Result<()> {
+    let mut buffer = serde_json::to_vec(value)?;
+    check_range(0, buffer.len())?;
+
+    buffer.resize(USER_AREA_SIZE, 0);
+    write(camera, 0, &buffer)
+}
+
+/// Load a value stored with [`store`] from the user area.
+///
+/// Fails with [`Error::Io`] ([`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)) if the
+/// user area does not hold a value of type `T`.
+#[cfg(feature = "eeprom")]
+pub fn load<T: serde::de::DeserializeOwned>(camera: &Camera) -> Result<T> {
+    let buffer = read_all(camera)?;
+    let len = buffer.iter().position(|&byte| byte == 0).unwrap_or(buffer.len());
+
+    Ok(serde_json::from_slice(&buffer[..len])?)
+}
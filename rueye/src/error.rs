@@ -0,0 +1,398 @@
+//! Errors reported by the high-level API.
+//!
+//! Every documented return code of the driver other than [`IS_SUCCESS`] maps to its own
+//! [`Error`] variant, so callers can match on failures instead of comparing raw integers.
+
+use std::{
+    ffi::CStr,
+    fmt::{Display, Formatter},
+    io::ErrorKind,
+    ptr,
+};
+
+use ueye_sys::{
+    constants::return_values::*,
+    error::is_GetError,
+    types::{char, INT},
+};
+
+use crate::{
+    camera::{Availability, Camera},
+    observer::call,
+};
+
+macro_rules! driver_errors {
+    ($($variant:ident = $code:ident: $message:literal,)*) => {
+        /// Error returned by the high-level API.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum Error {
+            $(
+                #[doc = concat!("[`", stringify!($code), "`]: ", $message, ".")]
+                $variant,
+            )*
+
+            /// The driver returned an undocumented error code.
+            Unknown(INT),
+
+            /// The driver returned a value that has no typed representation.
+            UnexpectedValue(INT),
+
+            /// An I/O operation outside the driver failed.
+            Io(ErrorKind),
+
+            /// No connected camera matches the requested one.
+            CameraNotFound,
+
+            /// The requested camera is in use.
+            CameraBusy(Availability),
+
+            /// An access of `len` bytes at `offset` exceeds a memory of `size` bytes.
+            OutOfRange { offset: usize, len: usize, size: usize },
+        }
+
+        impl Error {
+            /// Driver return code of the error, if it originates from the driver.
+            #[allow(deprecated)]
+            pub const fn code(&self) -> Option<INT> {
+                match *self {
+                    $(Error::$variant => Some($code),)*
+                    Error::Unknown(code) => Some(code),
+                    Error::UnexpectedValue(_)
+                    | Error::Io(_)
+                    | Error::CameraNotFound
+                    | Error::CameraBusy(_)
+                    | Error::OutOfRange { .. } => None,
+                }
+            }
+
+            /// Name of the driver return code constant, for documented driver errors.
+            pub const fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Error::$variant => Some(stringify!($code)),)*
+                    _ => None,
+                }
+            }
+
+            /// Documented meaning of the driver return code, for documented driver errors.
+            pub const fn message(&self) -> Option<&'static str> {
+                match self {
+                    $(Error::$variant => Some($message),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl TryFrom<INT> for Error {
+            type Error = INT;
+
+            /// Map a documented driver error code to its variant.
+            ///
+            /// # Errors
+            /// The code itself, if it is [`IS_SUCCESS`] or undocumented.
+            #[allow(deprecated)]
+            fn try_from(code: INT) -> std::result::Result<Self, INT> {
+                match code {
+                    $($code => Ok(Error::$variant),)*
+                    code => Err(code),
+                }
+            }
+        }
+    };
+}
+
+driver_errors! {
+    NoSuccess = IS_NO_SUCCESS: "general error",
+    InvalidCameraHandle = IS_INVALID_CAMERA_HANDLE: "invalid camera handle",
+    IoRequestFailed = IS_IO_REQUEST_FAILED: "an IO request from the uEye driver failed",
+    CantOpenDevice = IS_CANT_OPEN_DEVICE: "an attempt to initialize or select the camera failed (no camera connected or initialization error)",
+    CantCloseDevice = IS_CANT_CLOSE_DEVICE: "the device could not be closed",
+    CantSetupMemory = IS_CANT_SETUP_MEMORY: "the memory could not be set up",
+    NoHwndForErrorReport = IS_NO_HWND_FOR_ERROR_REPORT: "no window handle for the error report",
+    ErrorMessageNotCreated = IS_ERROR_MESSAGE_NOT_CREATED: "the error message could not be created",
+    ErrorStringNotFound = IS_ERROR_STRING_NOT_FOUND: "the error string was not found",
+    HookNotCreated = IS_HOOK_NOT_CREATED: "the hook could not be created",
+    TimerNotCreated = IS_TIMER_NOT_CREATED: "the timer could not be created",
+    CantOpenRegistry = IS_CANT_OPEN_REGISTRY: "error opening a Windows registry key",
+    CantReadRegistry = IS_CANT_READ_REGISTRY: "error reading settings from the Windows registry",
+    CantValidateBoard = IS_CANT_VALIDATE_BOARD: "the board could not be validated",
+    CantGiveBoardAccess = IS_CANT_GIVE_BOARD_ACCESS: "access to the board could not be granted",
+    NoImageMemAllocated = IS_NO_IMAGE_MEM_ALLOCATED: "the driver could not allocate memory",
+    CantCleanupMemory = IS_CANT_CLEANUP_MEMORY: "the driver could not release the allocated memory",
+    CantCommunicateWithDriver = IS_CANT_COMMUNICATE_WITH_DRIVER: "communication with the driver failed because no driver has been loaded",
+    FunctionNotSupportedYet = IS_FUNCTION_NOT_SUPPORTED_YET: "the function is not supported yet",
+    OperatingSystemNotSupported = IS_OPERATING_SYSTEM_NOT_SUPPORTED: "operating system not supported",
+    InvalidVideoIn = IS_INVALID_VIDEO_IN: "invalid video input",
+    InvalidImgSize = IS_INVALID_IMG_SIZE: "invalid image size",
+    InvalidAddress = IS_INVALID_ADDRESS: "invalid address",
+    InvalidVideoMode = IS_INVALID_VIDEO_MODE: "invalid video mode",
+    InvalidAgcMode = IS_INVALID_AGC_MODE: "invalid AGC mode",
+    InvalidGammaMode = IS_INVALID_GAMMA_MODE: "invalid gamma mode",
+    InvalidSyncLevel = IS_INVALID_SYNC_LEVEL: "invalid sync level",
+    InvalidCbarsMode = IS_INVALID_CBARS_MODE: "invalid color bars mode",
+    InvalidColorMode = IS_INVALID_COLOR_MODE: "invalid color mode",
+    InvalidScaleFactor = IS_INVALID_SCALE_FACTOR: "invalid scale factor",
+    InvalidImageSize = IS_INVALID_IMAGE_SIZE: "invalid image size",
+    InvalidImagePos = IS_INVALID_IMAGE_POS: "invalid image position",
+    InvalidCaptureMode = IS_INVALID_CAPTURE_MODE: "the function can not be executed in the current camera operating mode (free run, trigger or standby)",
+    InvalidRiscProgram = IS_INVALID_RISC_PROGRAM: "invalid RISC program",
+    InvalidBrightness = IS_INVALID_BRIGHTNESS: "invalid brightness",
+    InvalidContrast = IS_INVALID_CONTRAST: "invalid contrast",
+    InvalidSaturationU = IS_INVALID_SATURATION_U: "invalid U saturation",
+    InvalidSaturationV = IS_INVALID_SATURATION_V: "invalid V saturation",
+    InvalidHue = IS_INVALID_HUE: "invalid hue",
+    InvalidHorFilterStep = IS_INVALID_HOR_FILTER_STEP: "invalid horizontal filter step",
+    InvalidVertFilterStep = IS_INVALID_VERT_FILTER_STEP: "invalid vertical filter step",
+    InvalidEepromReadAddress = IS_INVALID_EEPROM_READ_ADDRESS: "invalid EEPROM read address",
+    InvalidEepromWriteAddress = IS_INVALID_EEPROM_WRITE_ADDRESS: "invalid EEPROM write address",
+    InvalidEepromReadLength = IS_INVALID_EEPROM_READ_LENGTH: "invalid EEPROM read length",
+    InvalidEepromWriteLength = IS_INVALID_EEPROM_WRITE_LENGTH: "invalid EEPROM write length",
+    InvalidBoardInfoPointer = IS_INVALID_BOARD_INFO_POINTER: "invalid board information pointer",
+    InvalidDisplayMode = IS_INVALID_DISPLAY_MODE: "invalid display mode",
+    InvalidErrRepMode = IS_INVALID_ERR_REP_MODE: "invalid error report mode",
+    InvalidBitsPixel = IS_INVALID_BITS_PIXEL: "invalid bits per pixel",
+    InvalidMemoryPointer = IS_INVALID_MEMORY_POINTER: "invalid pointer or invalid memory ID",
+    FileWriteOpenError = IS_FILE_WRITE_OPEN_ERROR: "file cannot be opened for writing or reading",
+    FileReadOpenError = IS_FILE_READ_OPEN_ERROR: "the file cannot be opened",
+    FileReadInvalidBmpId = IS_FILE_READ_INVALID_BMP_ID: "the specified file is not a valid bitmap file",
+    FileReadInvalidBmpSize = IS_FILE_READ_INVALID_BMP_SIZE: "the bitmap size is not correct (bitmap too large)",
+    FileReadInvalidBitCount = IS_FILE_READ_INVALID_BIT_COUNT: "the bitmap has an invalid bit count",
+    WrongKernelVersion = IS_WRONG_KERNEL_VERSION: "wrong kernel driver version",
+    RiscInvalidXlength = IS_RISC_INVALID_XLENGTH: "invalid RISC X length",
+    RiscInvalidYlength = IS_RISC_INVALID_YLENGTH: "invalid RISC Y length",
+    RiscExceedImgSize = IS_RISC_EXCEED_IMG_SIZE: "the RISC program exceeds the image size",
+    DdMainFailed = IS_DD_MAIN_FAILED: "DirectDraw could not be initialized",
+    DdPrimsurfaceFailed = IS_DD_PRIMSURFACE_FAILED: "the DirectDraw primary surface could not be created",
+    DdScrnSizeNotSupported = IS_DD_SCRN_SIZE_NOT_SUPPORTED: "the screen size is not supported by DirectDraw",
+    DdClipperFailed = IS_DD_CLIPPER_FAILED: "the DirectDraw clipper could not be created",
+    DdClipperHwndFailed = IS_DD_CLIPPER_HWND_FAILED: "the DirectDraw clipper window could not be set",
+    DdClipperConnectFailed = IS_DD_CLIPPER_CONNECT_FAILED: "the DirectDraw clipper could not be connected",
+    DdBacksurfaceFailed = IS_DD_BACKSURFACE_FAILED: "the DirectDraw back surface could not be created",
+    DdBacksurfaceInSysmem = IS_DD_BACKSURFACE_IN_SYSMEM: "the DirectDraw back surface is in system memory",
+    DdMdlMallocErr = IS_DD_MDL_MALLOC_ERR: "the DirectDraw MDL could not be allocated",
+    DdMdlSizeErr = IS_DD_MDL_SIZE_ERR: "invalid DirectDraw MDL size",
+    DdClipNoChange = IS_DD_CLIP_NO_CHANGE: "the DirectDraw clip list did not change",
+    DdPrimmemNull = IS_DD_PRIMMEM_NULL: "the DirectDraw primary memory is null",
+    DdBackmemNull = IS_DD_BACKMEM_NULL: "the DirectDraw back buffer memory is null",
+    DdBackovlmemNull = IS_DD_BACKOVLMEM_NULL: "the DirectDraw back overlay memory is null",
+    DdOverlaysurfaceFailed = IS_DD_OVERLAYSURFACE_FAILED: "the DirectDraw overlay surface could not be created",
+    DdOverlaysurfaceInSysmem = IS_DD_OVERLAYSURFACE_IN_SYSMEM: "the DirectDraw overlay surface is in system memory",
+    DdOverlayNotAllowed = IS_DD_OVERLAY_NOT_ALLOWED: "the DirectDraw overlay is not allowed",
+    DdOverlayColkeyErr = IS_DD_OVERLAY_COLKEY_ERR: "invalid DirectDraw overlay color key",
+    DdOverlayNotEnabled = IS_DD_OVERLAY_NOT_ENABLED: "the DirectDraw overlay is not enabled",
+    DdGetDcError = IS_DD_GET_DC_ERROR: "the DirectDraw device context could not be obtained",
+    DdDdrawDllNotLoaded = IS_DD_DDRAW_DLL_NOT_LOADED: "the DirectDraw library is not loaded",
+    DdThreadNotCreated = IS_DD_THREAD_NOT_CREATED: "the DirectDraw thread could not be created",
+    DdCantGetCaps = IS_DD_CANT_GET_CAPS: "the DirectDraw capabilities could not be queried",
+    DdNoOverlaysurface = IS_DD_NO_OVERLAYSURFACE: "no DirectDraw overlay surface",
+    DdNoOverlaystretch = IS_DD_NO_OVERLAYSTRETCH: "DirectDraw overlay stretching is not supported",
+    DdCantCreateOverlaysurface = IS_DD_CANT_CREATE_OVERLAYSURFACE: "the DirectDraw overlay surface could not be created",
+    DdCantUpdateOverlaysurface = IS_DD_CANT_UPDATE_OVERLAYSURFACE: "the DirectDraw overlay surface could not be updated",
+    DdInvalidStretch = IS_DD_INVALID_STRETCH: "invalid DirectDraw stretch factor",
+    EvInvalidEventNumber = IS_EV_INVALID_EVENT_NUMBER: "invalid event number",
+    InvalidMode = IS_INVALID_MODE: "invalid mode",
+    CantFindFalchook = IS_CANT_FIND_FALCHOOK: "the hook could not be found",
+    CantGetHookProcAddr = IS_CANT_GET_HOOK_PROC_ADDR: "the hook procedure address could not be obtained",
+    CantChainHookProc = IS_CANT_CHAIN_HOOK_PROC: "the hook procedure could not be chained",
+    CantSetupWndProc = IS_CANT_SETUP_WND_PROC: "the window procedure could not be set up",
+    HwndNull = IS_HWND_NULL: "the window handle is null",
+    InvalidUpdateMode = IS_INVALID_UPDATE_MODE: "invalid update mode",
+    NoActiveImgMem = IS_NO_ACTIVE_IMG_MEM: "no active image memory available",
+    CantInitEvent = IS_CANT_INIT_EVENT: "the event could not be initialized",
+    FuncNotAvailInOs = IS_FUNC_NOT_AVAIL_IN_OS: "the function is not available in this operating system",
+    CameraNotConnected = IS_CAMERA_NOT_CONNECTED: "the camera is not connected",
+    SequenceListEmpty = IS_SEQUENCE_LIST_EMPTY: "the sequence list is empty and cannot be deleted",
+    CantAddToSequence = IS_CANT_ADD_TO_SEQUENCE: "the image memory is already included in the sequence and cannot be added again",
+    LowOfSequenceRiscMem = IS_LOW_OF_SEQUENCE_RISC_MEM: "not enough memory for the sequence",
+    Imgmem2freeUsedInSeq = IS_IMGMEM2FREE_USED_IN_SEQ: "the image memory to be freed is used in a sequence",
+    ImgmemNotInSequenceList = IS_IMGMEM_NOT_IN_SEQUENCE_LIST: "the image memory is not in the sequence list",
+    SequenceBufAlreadyLocked = IS_SEQUENCE_BUF_ALREADY_LOCKED: "the memory could not be locked",
+    InvalidDeviceId = IS_INVALID_DEVICE_ID: "the device ID is invalid",
+    InvalidBoardId = IS_INVALID_BOARD_ID: "the board ID is invalid",
+    AllDevicesBusy = IS_ALL_DEVICES_BUSY: "all cameras are in use",
+    HookBusy = IS_HOOK_BUSY: "the hook is busy",
+    TimedOut = IS_TIMED_OUT: "a timeout occurred",
+    NullPointer = IS_NULL_POINTER: "invalid array",
+    WrongHookVersion = IS_WRONG_HOOK_VERSION: "wrong hook version",
+    InvalidParameter = IS_INVALID_PARAMETER: "one of the submitted parameters is outside the valid range or is not supported for this sensor or is not available in this mode",
+    NotAllowed = IS_NOT_ALLOWED: "the operation is not allowed",
+    OutOfMemory = IS_OUT_OF_MEMORY: "no memory could be allocated",
+    InvalidWhileLive = IS_INVALID_WHILE_LIVE: "the operation is not possible while live video is running",
+    AccessViolation = IS_ACCESS_VIOLATION: "an access violation has occurred",
+    UnknownRopEffect = IS_UNKNOWN_ROP_EFFECT: "unknown raster operation effect",
+    InvalidRenderMode = IS_INVALID_RENDER_MODE: "invalid render mode",
+    InvalidThreadContext = IS_INVALID_THREAD_CONTEXT: "invalid thread context",
+    NoHardwareInstalled = IS_NO_HARDWARE_INSTALLED: "no hardware installed",
+    InvalidWatchdogTime = IS_INVALID_WATCHDOG_TIME: "invalid watchdog time",
+    InvalidWatchdogMode = IS_INVALID_WATCHDOG_MODE: "invalid watchdog mode",
+    InvalidPassthroughIn = IS_INVALID_PASSTHROUGH_IN: "invalid passthrough input",
+    ErrorSettingPassthroughIn = IS_ERROR_SETTING_PASSTHROUGH_IN: "the passthrough input could not be set",
+    FailureOnSettingWatchdog = IS_FAILURE_ON_SETTING_WATCHDOG: "the watchdog could not be set",
+    NoUsb20 = IS_NO_USB20: "the camera is connected to a port which does not support the USB 2.0 high-speed standard",
+    CaptureRunning = IS_CAPTURE_RUNNING: "a capturing operation is in progress and must be terminated first",
+    MemoryBoardActivated = IS_MEMORY_BOARD_ACTIVATED: "the operation could not execute while the memory board is enabled",
+    MemoryBoardDeactivated = IS_MEMORY_BOARD_DEACTIVATED: "the operation could not execute while the memory board is disabled",
+    NoMemoryBoardConnected = IS_NO_MEMORY_BOARD_CONNECTED: "no memory board connected",
+    TooLessMemory = IS_TOO_LESS_MEMORY: "image size is above memory capacity",
+    ImageNotPresent = IS_IMAGE_NOT_PRESENT: "the requested image is not available in the camera memory or is no longer valid",
+    MemoryModeRunning = IS_MEMORY_MODE_RUNNING: "the memory mode is running",
+    MemoryboardDisabled = IS_MEMORYBOARD_DISABLED: "the memory board is disabled",
+    TriggerActivated = IS_TRIGGER_ACTIVATED: "the function cannot be used because the camera is waiting for a trigger signal",
+    WrongKey = IS_WRONG_KEY: "wrong key",
+    CrcError = IS_CRC_ERROR: "a CRC error-correction problem occurred while reading the settings",
+    NotYetReleased = IS_NOT_YET_RELEASED: "this function has not been enabled yet in this version",
+    NotCalibrated = IS_NOT_CALIBRATED: "the camera does not contain any calibration data",
+    WaitingForKernel = IS_WAITING_FOR_KERNEL: "the system is waiting for the kernel driver to respond",
+    NotSupported = IS_NOT_SUPPORTED: "the camera model used here does not support this function or setting",
+    TriggerNotActivated = IS_TRIGGER_NOT_ACTIVATED: "the function is not possible as trigger is disabled",
+    OperationAborted = IS_OPERATION_ABORTED: "the operation was cancelled",
+    BadStructureSize = IS_BAD_STRUCTURE_SIZE: "an internal structure has an incorrect size",
+    InvalidBufferSize = IS_INVALID_BUFFER_SIZE: "the image memory has an inappropriate size to store the image in the desired format",
+    InvalidPixelClock = IS_INVALID_PIXEL_CLOCK: "this setting is not available for the currently set pixel clock frequency",
+    InvalidExposureTime = IS_INVALID_EXPOSURE_TIME: "this setting is not available for the currently set exposure time",
+    AutoExposureRunning = IS_AUTO_EXPOSURE_RUNNING: "this setting cannot be changed while automatic exposure time control is enabled",
+    CannotCreateBbSurf = IS_CANNOT_CREATE_BB_SURF: "the BackBuffer surface cannot be created",
+    CannotCreateBbMix = IS_CANNOT_CREATE_BB_MIX: "the BackBuffer mix surface cannot be created",
+    BbOvlmemNull = IS_BB_OVLMEM_NULL: "the BackBuffer overlay memory cannot be locked",
+    CannotCreateBbOvl = IS_CANNOT_CREATE_BB_OVL: "the BackBuffer overlay memory cannot be created",
+    NotSuppInOvlSurfMode = IS_NOT_SUPP_IN_OVL_SURF_MODE: "not supported in BackBuffer Overlay mode",
+    InvalidSurface = IS_INVALID_SURFACE: "back buffer surface invalid",
+    SurfaceLost = IS_SURFACE_LOST: "back buffer surface not found",
+    ReleaseBbOvlDc = IS_RELEASE_BB_OVL_DC: "error releasing the overlay device context",
+    BbTimerNotCreated = IS_BB_TIMER_NOT_CREATED: "the back buffer timer could not be created",
+    BbOvlNotEn = IS_BB_OVL_NOT_EN: "the back buffer overlay was not enabled",
+    OnlyInBbMode = IS_ONLY_IN_BB_MODE: "only possible in BackBuffer mode",
+    InvalidColorFormat = IS_INVALID_COLOR_FORMAT: "invalid color format",
+    InvalidWbBinningMode = IS_INVALID_WB_BINNING_MODE: "mono binning/mono sub-sampling do not support automatic white balance",
+    InvalidI2cDeviceAddress = IS_INVALID_I2C_DEVICE_ADDRESS: "invalid I2C device address",
+    CouldNotConvert = IS_COULD_NOT_CONVERT: "the current image could not be processed",
+    TransferError = IS_TRANSFER_ERROR: "transfer error",
+    ParameterSetNotPresent = IS_PARAMETER_SET_NOT_PRESENT: "parameter set is not present",
+    InvalidCameraType = IS_INVALID_CAMERA_TYPE: "the camera type defined in the .ini file does not match the current camera model",
+    InvalidHostIpHibyte = IS_INVALID_HOST_IP_HIBYTE: "invalid HIBYTE of host address",
+    CmNotSuppInCurrDisplaymode = IS_CM_NOT_SUPP_IN_CURR_DISPLAYMODE: "the color mode is not supported in the current display mode",
+    NoIrFilter = IS_NO_IR_FILTER: "no IR filter available",
+    StarterFwUploadNeeded = IS_STARTER_FW_UPLOAD_NEEDED: "the camera's starter firmware is not compatible with the driver and needs to be updated",
+    DrLibraryNotFound = IS_DR_LIBRARY_NOT_FOUND: "the DirectRenderer library could not be found",
+    DrDeviceOutOfMemory = IS_DR_DEVICE_OUT_OF_MEMORY: "not enough graphics memory available",
+    DrCannotCreateSurface = IS_DR_CANNOT_CREATE_SURFACE: "the image surface or overlay surface could not be created",
+    DrCannotCreateVertexBuffer = IS_DR_CANNOT_CREATE_VERTEX_BUFFER: "the vertex buffer could not be created",
+    DrCannotCreateTexture = IS_DR_CANNOT_CREATE_TEXTURE: "the texture could not be created",
+    DrCannotLockOverlaySurface = IS_DR_CANNOT_LOCK_OVERLAY_SURFACE: "the overlay surface could not be locked",
+    DrCannotUnlockOverlaySurface = IS_DR_CANNOT_UNLOCK_OVERLAY_SURFACE: "the overlay surface could not be unlocked",
+    DrCannotGetOverlayDc = IS_DR_CANNOT_GET_OVERLAY_DC: "could not get the device context handle for the overlay",
+    DrCannotReleaseOverlayDc = IS_DR_CANNOT_RELEASE_OVERLAY_DC: "could not release the device context handle for the overlay",
+    DrDeviceCapsInsufficient = IS_DR_DEVICE_CAPS_INSUFFICIENT: "function is not supported by the graphics hardware",
+    IncompatibleSetting = IS_INCOMPATIBLE_SETTING: "because of other incompatible settings the function is not possible",
+    DrNotAllowedWhileDcIsActive = IS_DR_NOT_ALLOWED_WHILE_DC_IS_ACTIVE: "a device context handle is still open in the application",
+    DeviceAlreadyPaired = IS_DEVICE_ALREADY_PAIRED: "the device is already in use by the system or is being used by another system",
+    SubnetmaskMismatch = IS_SUBNETMASK_MISMATCH: "the subnet mask of the camera and PC network card are different",
+    SubnetMismatch = IS_SUBNET_MISMATCH: "the subnet of the camera and PC network card are different",
+    InvalidIpConfiguration = IS_INVALID_IP_CONFIGURATION: "the configuration of the IP address is invalid",
+    DeviceNotCompatible = IS_DEVICE_NOT_COMPATIBLE: "the device is not compatible to the drivers",
+    NetworkFrameSizeIncompatible = IS_NETWORK_FRAME_SIZE_INCOMPATIBLE: "the settings for the image size of the camera are not compatible to the PC network card",
+    NetworkConfigurationInvalid = IS_NETWORK_CONFIGURATION_INVALID: "the configuration of the network card is invalid",
+    ErrorCpuIdleStatesConfiguration = IS_ERROR_CPU_IDLE_STATES_CONFIGURATION: "the configuration of the CPU idle has failed",
+    DeviceBusy = IS_DEVICE_BUSY: "the camera is busy and cannot transfer the requested image",
+    SensorInitializationFailed = IS_SENSOR_INITIALIZATION_FAILED: "the initialization of the sensor failed",
+    ImageBufferNotDwordAligned = IS_IMAGE_BUFFER_NOT_DWORD_ALIGNED: "the image buffer is not DWORD aligned",
+    SeqBufferIsLocked = IS_SEQ_BUFFER_IS_LOCKED: "the image memory is locked",
+    FilePathDoesNotExist = IS_FILE_PATH_DOES_NOT_EXIST: "the file path does not exist",
+    InvalidWindowHandle = IS_INVALID_WINDOW_HANDLE: "invalid Window handle",
+    InvalidImageParameter = IS_INVALID_IMAGE_PARAMETER: "invalid image parameter (position or size)",
+    NoSuchDevice = IS_NO_SUCH_DEVICE: "no such device",
+    DeviceInUse = IS_DEVICE_IN_USE: "the device is in use",
+}
+
+impl Error {
+    /// Error for a driver return code other than [`IS_SUCCESS`].
+    ///
+    /// Undocumented codes map to [`Error::Unknown`].
+    #[inline]
+    pub fn from_code(code: INT) -> Self {
+        Self::try_from(code).unwrap_or(Error::Unknown(code))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unknown(code) => write!(f, "uEye driver returned unknown error code {code}"),
+            Error::UnexpectedValue(value) => {
+                write!(f, "uEye driver returned unexpected value {value}")
+            },
+            Error::Io(kind) => write!(f, "I/O error: {kind}"),
+            Error::CameraNotFound => write!(f, "no connected camera matches"),
+            Error::CameraBusy(availability) => write!(f, "camera is {availability}"),
+            Error::OutOfRange { offset, len, size } => {
+                write!(f, "access of {len} bytes at offset {offset} exceeds memory of {size} bytes")
+            },
+            error => write!(
+                f,
+                "{} ({})",
+                error.message().unwrap_or_default(),
+                error.name().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value.kind())
+    }
+}
+
+#[cfg(any(feature = "eeprom", feature = "sidecar"))]
+impl From<serde_json::Error> for Error {
+    #[inline]
+    fn from(value: serde_json::Error) -> Self {
+        Error::Io(value.io_error_kind().unwrap_or(ErrorKind::InvalidData))
+    }
+}
+
+/// Result type of the high-level API.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Convert a return value from the driver into a [`Result`].
+#[inline]
+pub fn check(ret: INT) -> Result<()> {
+    match ret {
+        IS_SUCCESS => Ok(()),
+        code => Err(Error::from_code(code)),
+    }
+}
+
+/// Report a function the camera does not support as [`None`].
+pub(crate) fn optional<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotSupported) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Query the last error reported by the driver for `camera`, along with the driver's message.
+///
+/// Returns [`None`] if no error occurred. The driver only keeps the most recent error, which is
+/// overwritten as soon as another one occurs.
+pub fn last_error(camera: &Camera) -> Result<Option<(Error, String)>> {
+    let mut code: INT = IS_SUCCESS;
+    let mut message: *const char = ptr::null();
+    check(unsafe { call!(is_GetError(camera.handle(), &mut code, &mut message)) })?;
+
+    if code == IS_SUCCESS {
+        return Ok(None);
+    }
+
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        // SAFETY: the driver returns a null-terminated string, valid until the next error occurs.
+        unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned()
+    };
+
+    Ok(Some((Error::from_code(code), message)))
+}
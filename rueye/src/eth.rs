@@ -0,0 +1,129 @@
+//! GigE network addresses.
+//!
+//! IPv4 addresses of the driver ([`UEYE_ETH_ADDR_IPV4`]) convert to and from
+//! [`std::net::Ipv4Addr`] directly; MAC addresses ([`UEYE_ETH_ADDR_MAC`]) convert to and from
+//! [`MacAddr`].
+//!
+//! [`UEYE_ETH_ADDR_IPV4`]: ueye_sys::eth::UEYE_ETH_ADDR_IPV4
+
+use std::{
+    fmt,
+    mem::{size_of, MaybeUninit},
+    str::FromStr,
+};
+
+use ueye_sys::{
+    eth::{is_GetEthDeviceInfo, UEYE_ETH_ADDR_MAC, UEYE_ETH_DEVICE_INFO},
+    types::{HIDS, UINT},
+};
+
+use crate::{
+    error::{check, Error},
+    observer::call,
+};
+
+/// Query the Ethernet device information of the camera `hcam`.
+///
+/// The information is left uninitialized where the driver does not fill it, and its enumerations
+/// may hold values they lack; read the fields one by one through raw pointers, as raw values for
+/// the enumerations.
+///
+/// Fails with [`Error::NotSupported`] for cameras other than GigE cameras, and with the error of
+/// the driver otherwise.
+pub(crate) fn device_info(hcam: HIDS) -> Result<MaybeUninit<UEYE_ETH_DEVICE_INFO>, Error> {
+    let mut info = MaybeUninit::<UEYE_ETH_DEVICE_INFO>::zeroed();
+    let ret = unsafe {
+        call!(is_GetEthDeviceInfo(
+            hcam,
+            info.as_mut_ptr(),
+            size_of::<UEYE_ETH_DEVICE_INFO>() as UINT,
+        ))
+    };
+
+    // USB cameras have no Ethernet device information; the driver rejects them as either
+    // unsupported or as an invalid camera handle for the function
+    match check(ret) {
+        Ok(()) => Ok(info),
+        Err(Error::NotSupported | Error::InvalidParameter) => Err(Error::NotSupported),
+        Err(err) => Err(err),
+    }
+}
+
+/// MAC address, displayed as `aa:bb:cc:dd:ee:ff`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// Octets of the address, most significant first.
+    #[inline]
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    #[inline]
+    fn from(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    #[inline]
+    fn from(addr: MacAddr) -> Self {
+        addr.0
+    }
+}
+
+impl From<UEYE_ETH_ADDR_MAC> for MacAddr {
+    #[inline]
+    fn from(addr: UEYE_ETH_ADDR_MAC) -> Self {
+        Self(addr.abyOctet)
+    }
+}
+
+impl From<MacAddr> for UEYE_ETH_ADDR_MAC {
+    #[inline]
+    fn from(addr: MacAddr) -> Self {
+        Self { abyOctet: addr.0 }
+    }
+}
+
+impl TryFrom<&[u8]> for MacAddr {
+    type Error = Error;
+
+    /// Fails with [`Error::InvalidParameter`] unless `octets` has exactly six bytes.
+    #[inline]
+    fn try_from(octets: &[u8]) -> Result<Self, Self::Error> {
+        octets.try_into().map(Self).map_err(|_| Error::InvalidParameter)
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = Error;
+
+    /// Parse `aa:bb:cc:dd:ee:ff`, also accepting `-` as separator.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut octets = [0u8; 6];
+        let mut parts = s.split([':', '-']);
+
+        for octet in &mut octets {
+            let part =
+                parts.next().filter(|part| part.len() == 2).ok_or(Error::InvalidParameter)?;
+            *octet = u8::from_str_radix(part, 16).map_err(|_| Error::InvalidParameter)?;
+        }
+
+        match parts.next() {
+            Some(_) => Err(Error::InvalidParameter),
+            None => Ok(Self(octets)),
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! Waiting for driver events.
+//!
+//! An [`EventWaiter`] registers a set of [`EventKind`]s on a camera, or on no camera for the
+//! device-independent ones, and blocks until one of them is signaled. The driver's `is_Event`
+//! interface works the same on Linux and Windows, so there is no platform-specific path: the
+//! former Windows-only `is_InitEvent` with an event `HANDLE` and the Linux-only `is_WaitEvent` are
+//! both covered by it.
+//!
+//! Events of the same kind are not queued: if an event is signaled several times before it is
+//! waited for, the wait returns once.
+//!
+//! # Documentation
+//! * [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+//! * [Event/message handling](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_funktionsbloecke_event_handling.html)
+
+use std::{marker::PhantomData, mem::size_of, time::Duration};
+
+use ueye_sys::{
+    constants::event::*,
+    event::{is_Event, IS_EVENT_CMD, IS_INIT_EVENT, IS_WAIT_EVENT, IS_WAIT_EVENTS},
+    types::{void, FALSE, HIDS, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Kind of driver event.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventKind {
+    /// A new image is available.
+    Frame,
+
+    /// An image captured on a trigger was transferred completely.
+    ExternalTrigger,
+
+    /// The image memory sequence is completed.
+    Sequence,
+
+    /// An image extracted from the overlay is available.
+    Steal,
+
+    /// Capture status information, e.g. a buffer overflow or a failed transfer, is available.
+    CaptureStatus,
+
+    /// The camera was reconnected after it had been disconnected.
+    DeviceReconnected,
+
+    /// An image arrived before the driver post-processed it.
+    FrameReceived,
+
+    /// The automatic white balance is completed.
+    WhiteBalanceFinished,
+
+    /// The automatic brightness control in run-once mode is completed.
+    AutoBrightnessFinished,
+
+    /// The overlay parameters are invalid and the overlay must be drawn again.
+    OverlayDataLost,
+
+    /// An image acquisition iteration of the camera memory mode is finished.
+    CameraMemory,
+
+    /// The connection speed of a USB 3 camera changed.
+    ConnectionSpeedChanged,
+
+    /// The automatic focus control is finished.
+    AutofocusFinished,
+
+    /// The first data packet of an image was transferred.
+    FirstPacketReceived,
+
+    /// _Multicast mode:_ the master changed the image format.
+    ImageParamsChanged,
+
+    /// A paired GigE camera was reconnected to the network.
+    DevicePluggedIn,
+
+    /// A paired GigE camera was disconnected from the network.
+    DeviceUnplugged,
+
+    /// The temperature state of the camera changed.
+    TemperatureStatus,
+
+    /// The exposure is finished.
+    EndOfExposure,
+
+    /// An image was discarded.
+    FrameSkipped,
+
+    /// The camera was removed.
+    DeviceRemoved,
+
+    /// _Device-independent:_ any camera was removed.
+    Removal,
+
+    /// _Device-independent:_ any camera was connected.
+    NewDevice,
+
+    /// _Device-independent, Linux only:_ the availability of any camera changed.
+    #[cfg(target_os = "linux")]
+    StatusChanged,
+}
+
+impl EventKind {
+    /// Event ID of the driver.
+    pub const fn bits(self) -> UINT {
+        match self {
+            EventKind::Frame => IS_SET_EVENT_FRAME,
+            EventKind::ExternalTrigger => IS_SET_EVENT_EXTTRIG,
+            EventKind::Sequence => IS_SET_EVENT_SEQ,
+            EventKind::Steal => IS_SET_EVENT_STEAL,
+            EventKind::CaptureStatus => IS_SET_EVENT_CAPTURE_STATUS,
+            EventKind::DeviceReconnected => IS_SET_EVENT_DEVICE_RECONNECTED,
+            EventKind::FrameReceived => IS_SET_EVENT_FRAME_RECEIVED,
+            EventKind::WhiteBalanceFinished => IS_SET_EVENT_WB_FINISHED,
+            EventKind::AutoBrightnessFinished => IS_SET_EVENT_AUTOBRIGHTNESS_FINISHED,
+            EventKind::OverlayDataLost => IS_SET_EVENT_OVERLAY_DATA_LOST,
+            EventKind::CameraMemory => IS_SET_EVENT_CAMERA_MEMORY,
+            EventKind::ConnectionSpeedChanged => IS_SET_EVENT_CONNECTIONSPEED_CHANGED,
+            EventKind::AutofocusFinished => IS_SET_EVENT_AUTOFOCUS_FINISHED,
+            EventKind::FirstPacketReceived => IS_SET_EVENT_FIRST_PACKET_RECEIVED,
+            EventKind::ImageParamsChanged => IS_SET_EVENT_PMC_IMAGE_PARAMS_CHANGED,
+            EventKind::DevicePluggedIn => IS_SET_EVENT_DEVICE_PLUGGED_IN,
+            EventKind::DeviceUnplugged => IS_SET_EVENT_DEVICE_UNPLUGGED,
+            EventKind::TemperatureStatus => IS_SET_EVENT_TEMPERATURE_STATUS,
+            EventKind::EndOfExposure => IS_SET_EVENT_END_OF_EXPOSURE,
+            EventKind::FrameSkipped => IS_SET_EVENT_FRAME_SKIPPED,
+            EventKind::DeviceRemoved => IS_SET_EVENT_REMOVE,
+            EventKind::Removal => IS_SET_EVENT_REMOVAL,
+            EventKind::NewDevice => IS_SET_EVENT_NEW_DEVICE,
+            #[cfg(target_os = "linux")]
+            EventKind::StatusChanged => IS_SET_EVENT_STATUS_CHANGED,
+        }
+    }
+
+    /// Whether the event is independent of a camera, i.e. registered without a camera handle.
+    pub const fn is_device_independent(self) -> bool {
+        match self {
+            EventKind::Removal | EventKind::NewDevice => true,
+            #[cfg(target_os = "linux")]
+            EventKind::StatusChanged => true,
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<UINT> for EventKind {
+    type Error = Error;
+
+    fn try_from(value: UINT) -> Result<Self> {
+        match value {
+            IS_SET_EVENT_FRAME => Ok(EventKind::Frame),
+            IS_SET_EVENT_EXTTRIG => Ok(EventKind::ExternalTrigger),
+            IS_SET_EVENT_SEQ => Ok(EventKind::Sequence),
+            IS_SET_EVENT_STEAL => Ok(EventKind::Steal),
+            IS_SET_EVENT_CAPTURE_STATUS => Ok(EventKind::CaptureStatus),
+            IS_SET_EVENT_DEVICE_RECONNECTED => Ok(EventKind::DeviceReconnected),
+            IS_SET_EVENT_FRAME_RECEIVED => Ok(EventKind::FrameReceived),
+            IS_SET_EVENT_WB_FINISHED => Ok(EventKind::WhiteBalanceFinished),
+            IS_SET_EVENT_AUTOBRIGHTNESS_FINISHED => Ok(EventKind::AutoBrightnessFinished),
+            IS_SET_EVENT_OVERLAY_DATA_LOST => Ok(EventKind::OverlayDataLost),
+            IS_SET_EVENT_CAMERA_MEMORY => Ok(EventKind::CameraMemory),
+            IS_SET_EVENT_CONNECTIONSPEED_CHANGED => Ok(EventKind::ConnectionSpeedChanged),
+            IS_SET_EVENT_AUTOFOCUS_FINISHED => Ok(EventKind::AutofocusFinished),
+            IS_SET_EVENT_FIRST_PACKET_RECEIVED => Ok(EventKind::FirstPacketReceived),
+            IS_SET_EVENT_PMC_IMAGE_PARAMS_CHANGED => Ok(EventKind::ImageParamsChanged),
+            IS_SET_EVENT_DEVICE_PLUGGED_IN => Ok(EventKind::DevicePluggedIn),
+            IS_SET_EVENT_DEVICE_UNPLUGGED => Ok(EventKind::DeviceUnplugged),
+            IS_SET_EVENT_TEMPERATURE_STATUS => Ok(EventKind::TemperatureStatus),
+            IS_SET_EVENT_END_OF_EXPOSURE => Ok(EventKind::EndOfExposure),
+            IS_SET_EVENT_FRAME_SKIPPED => Ok(EventKind::FrameSkipped),
+            IS_SET_EVENT_REMOVE => Ok(EventKind::DeviceRemoved),
+            IS_SET_EVENT_REMOVAL => Ok(EventKind::Removal),
+            IS_SET_EVENT_NEW_DEVICE => Ok(EventKind::NewDevice),
+            #[cfg(target_os = "linux")]
+            IS_SET_EVENT_STATUS_CHANGED => Ok(EventKind::StatusChanged),
+            value => Err(Error::UnexpectedValue(value as INT)),
+        }
+    }
+}
+
+/// Registered events of a camera, or of no camera, released on drop.
+#[derive(Debug)]
+pub struct EventWaiter<'c> {
+    hcam: HIDS,
+    events: Vec<UINT>,
+    camera: PhantomData<&'c Camera>,
+}
+
+impl<'c> EventWaiter<'c> {
+    /// Register and enable `kinds` on `camera`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for
+    /// [device-independent](EventKind::is_device_independent) events, which are registered with
+    /// [`EventWaiter::devices`] instead.
+    pub fn new(camera: &'c Camera, kinds: &[EventKind]) -> Result<Self> {
+        if kinds.iter().any(|kind| kind.is_device_independent()) {
+            return Err(Error::InvalidParameter);
+        }
+
+        Self::register(camera.handle(), kinds)
+    }
+
+    fn register(hcam: HIDS, kinds: &[EventKind]) -> Result<Self> {
+        let mut events: Vec<UINT> = kinds.iter().map(|kind| kind.bits()).collect();
+        events.sort_unstable();
+        events.dedup();
+        init_events(hcam, &events)?;
+
+        Ok(Self { hcam, events, camera: PhantomData })
+    }
+
+    /// Wait for `kind` until it is signaled or `timeout` elapses.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `kind` is not registered, and with
+    /// [`Error::TimedOut`] if `timeout` elapses.
+    pub fn wait(&self, kind: EventKind, timeout: Duration) -> Result<()> {
+        if !self.events.contains(&kind.bits()) {
+            return Err(Error::InvalidParameter);
+        }
+
+        wait_one(self.hcam, kind.bits(), timeout)
+    }
+
+    /// Wait for any of the registered events until one is signaled or `timeout` elapses,
+    /// returning which one.
+    pub fn wait_any(&self, timeout: Duration) -> Result<EventKind> {
+        wait_any(self.hcam, &self.events, timeout).and_then(EventKind::try_from)
+    }
+
+    /// Signal `kind` manually, e.g. to wake up a thread waiting for it.
+    pub fn signal(&self, kind: EventKind) -> Result<()> {
+        event_command(self.hcam, IS_EVENT_CMD::IS_EVENT_CMD_SET, &[kind.bits()])
+    }
+}
+
+impl EventWaiter<'static> {
+    /// Register and enable device-independent `kinds`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for events that belong to a camera.
+    pub fn devices(kinds: &[EventKind]) -> Result<Self> {
+        if !kinds.iter().all(|kind| kind.is_device_independent()) {
+            return Err(Error::InvalidParameter);
+        }
+
+        Self::register(0, kinds)
+    }
+}
+
+impl Drop for EventWaiter<'_> {
+    fn drop(&mut self) {
+        exit_events(self.hcam, &self.events);
+    }
+}
+
+/// Register and enable auto-reset `events` on `hcam`.
+pub(crate) fn init_events(hcam: HIDS, events: &[UINT]) -> Result<()> {
+    for &event in events {
+        let mut init = IS_INIT_EVENT { nEvent: event, bManualReset: FALSE, bInitialState: FALSE };
+        check(unsafe {
+            command!(is_Event(
+                hcam,
+                IS_EVENT_CMD::IS_EVENT_CMD_INIT,
+                &mut init as *mut IS_INIT_EVENT as *mut void,
+                size_of::<IS_INIT_EVENT>() as UINT,
+            ))
+        })?;
+    }
+
+    event_command(hcam, IS_EVENT_CMD::IS_EVENT_CMD_ENABLE, events)
+}
+
+/// Disable and release `events` on `hcam`, undoing [`init_events`].
+pub(crate) fn exit_events(hcam: HIDS, events: &[UINT]) {
+    let _ = event_command(hcam, IS_EVENT_CMD::IS_EVENT_CMD_DISABLE, events);
+    let _ = event_command(hcam, IS_EVENT_CMD::IS_EVENT_CMD_EXIT, events);
+}
+
+fn event_command(hcam: HIDS, command: IS_EVENT_CMD, events: &[UINT]) -> Result<()> {
+    let mut events = events.to_vec();
+    check(unsafe {
+        command!(is_Event(
+            hcam,
+            command,
+            events.as_mut_ptr() as *mut void,
+            (events.len() * size_of::<UINT>()) as UINT,
+        ))
+    })
+}
+
+/// Block until `event` on `hcam` is signaled or `timeout` elapses.
+pub(crate) fn wait_one(hcam: HIDS, event: UINT, timeout: Duration) -> Result<()> {
+    let mut wait = IS_WAIT_EVENT {
+        nEvent: event,
+        nTimeoutMilliseconds: timeout.as_millis().try_into().unwrap_or(UINT::MAX),
+        nSignaled: 0,
+        nSetCount: 0,
+    };
+    check(unsafe {
+        command!(is_Event(
+            hcam,
+            IS_EVENT_CMD::IS_EVENT_CMD_WAIT,
+            &mut wait as *mut IS_WAIT_EVENT as *mut void,
+            size_of::<IS_WAIT_EVENT>() as UINT,
+        ))
+    })
+}
+
+/// Block until any of `events` on `hcam` is signaled or `timeout` elapses, returning the ID of the
+/// signaled event.
+pub(crate) fn wait_any(hcam: HIDS, events: &[UINT], timeout: Duration) -> Result<UINT> {
+    let mut events = events.to_vec();
+    let mut wait = IS_WAIT_EVENTS {
+        pEvents: events.as_mut_ptr(),
+        nCount: events.len() as UINT,
+        bWaitAll: FALSE,
+        nTimeoutMilliseconds: timeout.as_millis().try_into().unwrap_or(UINT::MAX),
+        nSignaled: 0,
+        nSetCount: 0,
+    };
+
+    check(unsafe {
+        command!(is_Event(
+            hcam,
+            IS_EVENT_CMD::IS_EVENT_CMD_WAIT,
+            &mut wait as *mut IS_WAIT_EVENTS as *mut void,
+            size_of::<IS_WAIT_EVENTS>() as UINT,
+        ))
+    })?;
+
+    Ok(wait.nSignaled)
+}
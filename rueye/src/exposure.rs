@@ -0,0 +1,226 @@
+//! Exposure time.
+//!
+//! The free functions take and return exposure times in milliseconds, as used by the driver. The
+//! [`Camera`] methods take and return [`Duration`]s instead, which leave no doubt about the unit.
+//!
+//! # Documentation
+//! [is_Exposure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_exposure.html)
+
+use std::{mem::size_of, time::Duration};
+
+use ueye_sys::{
+    exposure::{is_Exposure, EXPOSURE_CAPS, EXPOSURE_CMD},
+    types::{double, void, IS_RANGE_F64, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    observer::command,
+    range::ParamRange,
+};
+
+/// Query the currently set exposure time (in ms).
+pub fn current(camera: &Camera) -> Result<f64> {
+    let mut value: double = 0.0;
+    check(unsafe {
+        command!(is_Exposure(
+            camera.handle(),
+            EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_EXPOSURE,
+            &mut value as *mut double as *mut void,
+            size_of::<double>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
+
+/// Set the exposure time (in ms).
+///
+/// # Return values
+/// The exposure time actually set, which may vary slightly from `ms` depending on the sensor.
+pub fn set(camera: &Camera, ms: f64) -> Result<f64> {
+    let mut value: double = ms;
+    check(unsafe {
+        command!(is_Exposure(
+            camera.handle(),
+            EXPOSURE_CMD::IS_EXPOSURE_CMD_SET_EXPOSURE,
+            &mut value as *mut double as *mut void,
+            size_of::<double>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
+
+/// Query the exposure time range (in ms).
+#[inline]
+pub fn range(camera: &Camera) -> Result<ParamRange<f64>> {
+    query_range(camera, EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_EXPOSURE_RANGE)
+}
+
+/// Query the exposure time range with fine increments (in ms).
+#[inline]
+pub fn fine_increment_range(camera: &Camera) -> Result<ParamRange<f64>> {
+    query_range(camera, EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_FINE_INCREMENT_RANGE)
+}
+
+/// Query the exposure time range with long exposure enabled (in ms).
+#[inline]
+pub fn long_exposure_range(camera: &Camera) -> Result<ParamRange<f64>> {
+    query_range(camera, EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_LONG_EXPOSURE_RANGE)
+}
+
+fn query_range(camera: &Camera, cmd: EXPOSURE_CMD) -> Result<ParamRange<f64>> {
+    let mut range = IS_RANGE_F64 { f64Min: 0.0, f64Max: 0.0, f64Inc: 0.0 };
+    check(unsafe {
+        command!(is_Exposure(
+            camera.handle(),
+            cmd,
+            &mut range as *mut IS_RANGE_F64 as *mut void,
+            size_of::<IS_RANGE_F64>() as UINT,
+        ))
+    })?;
+
+    Ok(range.into())
+}
+
+fn query_uint(camera: &Camera, cmd: EXPOSURE_CMD) -> Result<UINT> {
+    let mut value: UINT = 0;
+    check(unsafe {
+        command!(is_Exposure(
+            camera.handle(),
+            cmd,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
+
+/// Exposure features supported by a camera.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExposureCaps {
+    /// The exposure time can be set.
+    pub exposure: bool,
+
+    /// The exposure time can be set in fine increments.
+    pub fine_increment: bool,
+
+    /// Long exposure can be enabled.
+    pub long_exposure: bool,
+
+    /// Odd and even lines can be exposed with different exposure times.
+    pub dual_exposure: bool,
+}
+
+impl From<UINT> for ExposureCaps {
+    fn from(caps: UINT) -> Self {
+        let has = |cap: EXPOSURE_CAPS| caps & cap as UINT != 0;
+        Self {
+            exposure: has(EXPOSURE_CAPS::IS_EXPOSURE_CAP_EXPOSURE),
+            fine_increment: has(EXPOSURE_CAPS::IS_EXPOSURE_CAP_FINE_INCREMENT),
+            long_exposure: has(EXPOSURE_CAPS::IS_EXPOSURE_CAP_LONG_EXPOSURE),
+            dual_exposure: has(EXPOSURE_CAPS::IS_EXPOSURE_CAP_DUAL_EXPOSURE),
+        }
+    }
+}
+
+/// Query the exposure features supported by the camera.
+#[inline]
+pub fn caps(camera: &Camera) -> Result<ExposureCaps> {
+    query_uint(camera, EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_CAPS).map(ExposureCaps::from)
+}
+
+/// Query whether long exposure is enabled.
+#[inline]
+pub fn long_exposure_enabled(camera: &Camera) -> Result<bool> {
+    query_uint(camera, EXPOSURE_CMD::IS_EXPOSURE_CMD_GET_LONG_EXPOSURE_ENABLE).map(|on| on != 0)
+}
+
+/// Enable or disable long exposure, extending the exposure time range to
+/// [`long_exposure_range`].
+pub fn set_long_exposure(camera: &Camera, enable: bool) -> Result<()> {
+    let mut value = UINT::from(enable);
+    check(unsafe {
+        command!(is_Exposure(
+            camera.handle(),
+            EXPOSURE_CMD::IS_EXPOSURE_CMD_SET_LONG_EXPOSURE_ENABLE,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })
+}
+
+/// Convert a time in ms as used by the driver.
+#[inline]
+fn from_ms(ms: f64) -> Duration {
+    Duration::try_from_secs_f64(ms / 1000.0).unwrap_or(Duration::ZERO)
+}
+
+/// Exposure time range.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ExposureRange {
+    /// Shortest exposure time.
+    pub min: Duration,
+
+    /// Longest exposure time.
+    pub max: Duration,
+
+    /// Step between two exposure times.
+    pub increment: Duration,
+}
+
+impl ExposureRange {
+    /// Clamp `exposure` into the range.
+    #[inline]
+    pub fn clamp(&self, exposure: Duration) -> Duration {
+        exposure.clamp(self.min, self.max)
+    }
+
+    /// Whether `exposure` lies within the range.
+    #[inline]
+    pub fn contains(&self, exposure: Duration) -> bool {
+        (self.min..=self.max).contains(&exposure)
+    }
+}
+
+impl From<ParamRange<f64>> for ExposureRange {
+    fn from(range: ParamRange<f64>) -> Self {
+        Self { min: from_ms(range.min), max: from_ms(range.max), increment: from_ms(range.inc) }
+    }
+}
+
+impl Camera {
+    /// Query the currently set exposure time.
+    #[inline]
+    pub fn exposure(&self) -> Result<Duration> {
+        current(self).map(from_ms)
+    }
+
+    /// Set the exposure time, returning the exposure time actually set.
+    #[inline]
+    pub fn set_exposure(&self, exposure: Duration) -> Result<Duration> {
+        set(self, exposure.as_secs_f64() * 1000.0).map(from_ms)
+    }
+
+    /// Query the exposure time range.
+    #[inline]
+    pub fn exposure_range(&self) -> Result<ExposureRange> {
+        range(self).map(ExposureRange::from)
+    }
+
+    /// Query the exposure time range with fine increments.
+    #[inline]
+    pub fn fine_exposure_range(&self) -> Result<ExposureRange> {
+        fine_increment_range(self).map(ExposureRange::from)
+    }
+
+    /// Query the exposure time range with long exposure enabled.
+    #[inline]
+    pub fn long_exposure_range(&self) -> Result<ExposureRange> {
+        long_exposure_range(self).map(ExposureRange::from)
+    }
+}
@@ -0,0 +1,251 @@
+//! Flash strobe output.
+//!
+//! # Documentation
+//! [Using flash](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_ioflash.html)
+
+use ueye_sys::{
+    io::{
+        IO_FLASH_GPIO_PORT_MASK, IO_FLASH_MODE, IO_FLASH_PARAMS, IO_FLASH_PORT, IO_GPIO,
+        IS_FLASH_MODE_PWM,
+    },
+    types::{INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    command::{device_command, io::*, CommandParam},
+    error::{Error, Result},
+    range::ParamRange,
+};
+
+/// Flash delay and duration.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlashParams {
+    /// Delay after the start of the exposure (in µs).
+    pub delay_us: i32,
+
+    /// Duration (in µs), or `0` to keep the output active until the end of the exposure.
+    pub duration_us: u32,
+}
+
+impl From<IO_FLASH_PARAMS> for FlashParams {
+    #[inline]
+    fn from(params: IO_FLASH_PARAMS) -> Self {
+        Self { delay_us: params.s32Delay, duration_us: params.u32Duration }
+    }
+}
+
+impl From<FlashParams> for IO_FLASH_PARAMS {
+    #[inline]
+    fn from(params: FlashParams) -> Self {
+        Self { s32Delay: params.delay_us, u32Duration: params.duration_us }
+    }
+}
+
+/// Behavior of the flash output.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlashOutput {
+    /// Output disabled.
+    #[default]
+    Off,
+
+    /// Low for the flash duration, in trigger mode.
+    TriggerLowActive,
+
+    /// High for the flash duration, in trigger mode.
+    TriggerHighActive,
+
+    /// Constantly high.
+    ConstantHigh,
+
+    /// Constantly low.
+    ConstantLow,
+
+    /// Low for the flash duration, in freerun mode.
+    FreerunLowActive,
+
+    /// High for the flash duration, in freerun mode.
+    FreerunHighActive,
+}
+
+impl FlashOutput {
+    /// Raw value of the output behavior.
+    pub const fn bits(self) -> UINT {
+        let mode = match self {
+            FlashOutput::Off => IO_FLASH_MODE::IO_FLASH_MODE_OFF,
+            FlashOutput::TriggerLowActive => IO_FLASH_MODE::IO_FLASH_MODE_TRIGGER_LO_ACTIVE,
+            FlashOutput::TriggerHighActive => IO_FLASH_MODE::IO_FLASH_MODE_TRIGGER_HI_ACTIVE,
+            FlashOutput::ConstantHigh => IO_FLASH_MODE::IO_FLASH_MODE_CONSTANT_HIGH,
+            FlashOutput::ConstantLow => IO_FLASH_MODE::IO_FLASH_MODE_CONSTANT_LOW,
+            FlashOutput::FreerunLowActive => IO_FLASH_MODE::IO_FLASH_MODE_FREERUN_LO_ACTIVE,
+            FlashOutput::FreerunHighActive => IO_FLASH_MODE::IO_FLASH_MODE_FREERUN_HI_ACTIVE,
+        };
+        mode as UINT
+    }
+}
+
+impl TryFrom<UINT> for FlashOutput {
+    type Error = Error;
+
+    fn try_from(value: UINT) -> Result<Self> {
+        [
+            FlashOutput::Off,
+            FlashOutput::TriggerLowActive,
+            FlashOutput::TriggerHighActive,
+            FlashOutput::ConstantHigh,
+            FlashOutput::ConstantLow,
+            FlashOutput::FreerunLowActive,
+            FlashOutput::FreerunHighActive,
+        ]
+        .into_iter()
+        .find(|output| output.bits() == value)
+        .ok_or(Error::UnexpectedValue(value as INT))
+    }
+}
+
+/// Flash mode: the output behavior, the GPIOs also used as flash output and their PWM.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FlashMode {
+    /// Behavior of the flash output.
+    pub output: FlashOutput,
+
+    /// GPIOs used as flash output in addition to the flash output itself.
+    pub gpios: IO_GPIO,
+
+    /// Whether the flash GPIOs output pulse-width modulation (PWM).
+    pub pwm: bool,
+}
+
+impl Default for FlashMode {
+    #[inline]
+    fn default() -> Self {
+        Self { output: FlashOutput::Off, gpios: IO_GPIO::empty(), pwm: false }
+    }
+}
+
+/// Shift of the GPIO bits in the raw flash mode.
+const PORT_SHIFT: u32 = IO_FLASH_PORT::IO_FLASH_MODE_GPIO_1.bits().trailing_zeros();
+
+impl FlashMode {
+    /// Raw value of the flash mode.
+    pub const fn bits(self) -> UINT {
+        let pwm = if self.pwm { IS_FLASH_MODE_PWM } else { 0 };
+        self.output.bits() | (self.gpios.bits() << PORT_SHIFT) & IO_FLASH_GPIO_PORT_MASK | pwm
+    }
+}
+
+impl TryFrom<UINT> for FlashMode {
+    type Error = Error;
+
+    fn try_from(value: UINT) -> Result<Self> {
+        Ok(Self {
+            output: FlashOutput::try_from(value & !(IO_FLASH_GPIO_PORT_MASK | IS_FLASH_MODE_PWM))?,
+            gpios: IO_GPIO::from_bits_truncate((value & IO_FLASH_GPIO_PORT_MASK) >> PORT_SHIFT),
+            pwm: value & IS_FLASH_MODE_PWM != 0,
+        })
+    }
+}
+
+/// Query the flash mode.
+pub fn mode(camera: &Camera) -> Result<FlashMode> {
+    let mut mode: UINT = 0;
+    device_command::<IS_IO_CMD_FLASH_GET_MODE>(camera, &mut mode)?;
+    FlashMode::try_from(mode)
+}
+
+/// Set the flash mode.
+pub fn set_mode(camera: &Camera, mode: FlashMode) -> Result<()> {
+    device_command::<IS_IO_CMD_FLASH_SET_MODE>(camera, &mut mode.bits())
+}
+
+/// Ranges of the flash delay and duration (in µs).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlashRange {
+    /// Range of the delay.
+    pub delay_us: ParamRange<i32>,
+
+    /// Range of the duration.
+    pub duration_us: ParamRange<u32>,
+}
+
+impl FlashRange {
+    /// Whether both values of `params` lie within their ranges.
+    #[inline]
+    pub fn contains(&self, params: FlashParams) -> bool {
+        self.delay_us.contains(params.delay_us) && self.duration_us.contains(params.duration_us)
+    }
+
+    /// Clamp both values of `params` into their ranges and round them to their increments.
+    #[inline]
+    pub fn snap_to_increment(&self, params: FlashParams) -> FlashParams {
+        FlashParams {
+            delay_us: self.delay_us.snap_to_increment(params.delay_us),
+            duration_us: self.duration_us.snap_to_increment(params.duration_us),
+        }
+    }
+}
+
+fn get_params<C>(camera: &Camera, _command: C) -> Result<FlashParams>
+where
+    C: CommandParam<Param = IO_FLASH_PARAMS>,
+{
+    let mut params = IO_FLASH_PARAMS::default();
+    device_command::<C>(camera, &mut params)?;
+    Ok(params.into())
+}
+
+/// Query the flash delay and duration.
+#[inline]
+pub fn params(camera: &Camera) -> Result<FlashParams> {
+    get_params(camera, IS_IO_CMD_FLASH_GET_PARAMS)
+}
+
+/// Query the ranges of the flash delay and duration.
+pub fn params_range(camera: &Camera) -> Result<FlashRange> {
+    let min = get_params(camera, IS_IO_CMD_FLASH_GET_PARAMS_MIN)?;
+    let max = get_params(camera, IS_IO_CMD_FLASH_GET_PARAMS_MAX)?;
+    let inc = get_params(camera, IS_IO_CMD_FLASH_GET_PARAMS_INC)?;
+
+    Ok(FlashRange {
+        delay_us: ParamRange::new(min.delay_us, max.delay_us, inc.delay_us),
+        duration_us: ParamRange::new(min.duration_us, max.duration_us, inc.duration_us),
+    })
+}
+
+/// Set the flash delay and duration.
+pub fn set_params(camera: &Camera, params: FlashParams) -> Result<()> {
+    device_command::<IS_IO_CMD_FLASH_SET_PARAMS>(camera, &mut IO_FLASH_PARAMS::from(params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flash_mode_round_trips() {
+        let mode = FlashMode {
+            output: FlashOutput::FreerunHighActive,
+            gpios: IO_GPIO::IO_GPIO_1 | IO_GPIO::IO_GPIO_3,
+            pwm: true,
+        };
+        assert_eq!(mode.bits(), 6 | 0x0010 | 0x0040 | 0x8000);
+        assert_eq!(FlashMode::try_from(mode.bits()), Ok(mode));
+    }
+
+    #[test]
+    fn flash_mode_of_output_only() {
+        assert_eq!(
+            FlashMode::try_from(2),
+            Ok(FlashMode { output: FlashOutput::TriggerHighActive, ..FlashMode::default() })
+        );
+        assert_eq!(FlashMode::default().bits(), 0);
+    }
+
+    #[test]
+    fn flash_mode_rejects_unknown_output() {
+        assert_eq!(FlashMode::try_from(7 | 0x0010), Err(Error::UnexpectedValue(7)));
+    }
+}
@@ -0,0 +1,191 @@
+//! Focus of cameras with a focus lens.
+//!
+//! [`Focus`] controls the manual focus and the autofocus. In triggered autofocus, the camera
+//! focuses once per request and signals [`EventKind::AutofocusFinished`] when done;
+//! [`Focus::autofocus_once`] coordinates the request, the event and the status, and returns once
+//! the lens settled. [`Focus::on_autofocus`] registers a closure which the driver calls with the
+//! focus position and sharpness at every autofocus step.
+//!
+//! # Documentation
+//! * [is_Focus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus.html)
+//! * [Focus settings for uEye LE USB 3.1 Gen 1 AF](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus_le_af.html)
+
+use std::{ptr, time::Duration};
+
+use ueye_sys::{
+    focus::{AUTOFOCUS_CALLBACK, FOCUS_CAPABILITY_FLAGS, FOCUS_STATUS},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    callback::Context,
+    camera::Camera,
+    command::{device_command, focus::*, CommandParam},
+    error::{Error, Result},
+    event::{EventKind, EventWaiter},
+};
+
+/// Outcome of a triggered autofocus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AutofocusOutcome {
+    /// Final status of the autofocus.
+    pub status: FOCUS_STATUS,
+
+    /// Focus position the lens settled at.
+    pub position: UINT,
+}
+
+impl AutofocusOutcome {
+    /// Whether the lens is in focus.
+    #[inline]
+    pub const fn is_focused(&self) -> bool {
+        self.status.contains(FOCUS_STATUS::FOC_STATUS_FOCUSED)
+    }
+}
+
+/// Focus of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct Focus<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the focus of the camera.
+    #[inline]
+    pub fn focus(&self) -> Focus<'_> {
+        Focus { camera: self }
+    }
+}
+
+impl<'c> Focus<'c> {
+    fn get<C: CommandParam<Param = UINT>>(&self, _command: C) -> Result<UINT> {
+        let mut value: UINT = 0;
+        device_command::<C>(self.camera, &mut value)?;
+        Ok(value)
+    }
+
+    fn trigger<C: CommandParam<Param = ()>>(&self, _command: C) -> Result<()> {
+        device_command::<C>(self.camera, &mut ())
+    }
+
+    /// Query the focus functions supported by the camera.
+    #[inline]
+    pub fn capabilities(&self) -> Result<FOCUS_CAPABILITY_FLAGS> {
+        let mut caps = FOCUS_CAPABILITY_FLAGS::empty();
+        device_command::<FOC_CMD_GET_CAPABILITIES>(self.camera, &mut caps)?;
+        Ok(caps)
+    }
+
+    /// Query the status of the autofocus.
+    #[inline]
+    pub fn status(&self) -> Result<FOCUS_STATUS> {
+        let mut status = FOCUS_STATUS::empty();
+        device_command::<FOC_CMD_GET_AUTOFOCUS_STATUS>(self.camera, &mut status)?;
+        Ok(status)
+    }
+
+    /// Query whether the continuous autofocus is enabled.
+    #[inline]
+    pub fn autofocus_enabled(&self) -> Result<bool> {
+        Ok(self.get(FOC_CMD_GET_AUTOFOCUS_ENABLE)? != 0)
+    }
+
+    /// Enable or disable the continuous autofocus.
+    pub fn set_autofocus_enabled(&self, enable: bool) -> Result<()> {
+        match enable {
+            true => self.trigger(FOC_CMD_SET_ENABLE_AUTOFOCUS),
+            false => self.trigger(FOC_CMD_SET_DISABLE_AUTOFOCUS),
+        }
+    }
+
+    /// Query the focus position of the lens.
+    #[inline]
+    pub fn position(&self) -> Result<UINT> {
+        self.get(FOC_CMD_GET_MANUAL_FOCUS)
+    }
+
+    /// Move the lens to the focus position `position`, disabling the autofocus.
+    pub fn set_position(&self, position: UINT) -> Result<()> {
+        device_command::<FOC_CMD_SET_MANUAL_FOCUS>(self.camera, &mut { position })
+    }
+
+    /// Focus once and wait until the autofocus finished.
+    ///
+    /// The camera must be capturing for the autofocus to act. Fails with [`Error::TimedOut`] if
+    /// the autofocus is still focusing after `timeout`; otherwise the
+    /// [status](AutofocusOutcome::status) tells whether it found the focus.
+    pub fn autofocus_once(&self, timeout: Duration) -> Result<AutofocusOutcome> {
+        // Register before triggering, so that the event cannot be missed
+        let waiter = EventWaiter::new(self.camera, &[EventKind::AutofocusFinished])?;
+        self.trigger(FOC_CMD_SET_ENABLE_AUTOFOCUS_ONCE)?;
+
+        let status = match waiter.wait(EventKind::AutofocusFinished, timeout) {
+            Ok(()) => self.status()?,
+            // The autofocus may have finished without signaling the event
+            Err(Error::TimedOut) => match self.status()? {
+                status if is_finished(status) => status,
+                _ => return Err(Error::TimedOut),
+            },
+            Err(error) => return Err(error),
+        };
+
+        Ok(AutofocusOutcome { status, position: self.position()? })
+    }
+
+    /// Call `callback` with the focus position and sharpness at every step of the autofocus,
+    /// until the returned [`AutofocusCallback`] is dropped.
+    ///
+    /// `callback` is called from a driver thread. A panic of `callback` is caught, since it must
+    /// not unwind into the driver.
+    pub fn on_autofocus(
+        &self,
+        callback: impl FnMut(UINT, INT) + Send + 'static,
+    ) -> Result<AutofocusCallback<'c>> {
+        let context: Context<AutofocusFn> = Context::new(Box::new(callback));
+        set_callback(
+            self.camera,
+            AUTOFOCUS_CALLBACK { pfFunc: Some(trampoline), pContext: context.as_ptr() },
+        )?;
+
+        Ok(AutofocusCallback { camera: self.camera, _context: context })
+    }
+}
+
+type AutofocusFn = dyn FnMut(UINT, INT) + Send;
+
+/// Autofocus closure registered with the driver, unregistered on drop.
+#[derive(Debug)]
+pub struct AutofocusCallback<'c> {
+    camera: &'c Camera,
+
+    // Dropped after the callback was unregistered
+    _context: Context<AutofocusFn>,
+}
+
+impl Drop for AutofocusCallback<'_> {
+    fn drop(&mut self) {
+        let _ = set_callback(
+            self.camera,
+            AUTOFOCUS_CALLBACK { pfFunc: None, pContext: ptr::null_mut() },
+        );
+    }
+}
+
+unsafe extern "C" fn trampoline(focus: UINT, sharpness: INT, context: *mut void) {
+    // SAFETY: the context is registered by `Focus::on_autofocus` and outlives the registration
+    unsafe { Context::<AutofocusFn>::invoke(context, |callback| callback(focus, sharpness)) };
+}
+
+fn set_callback(camera: &Camera, callback: AUTOFOCUS_CALLBACK) -> Result<()> {
+    device_command::<FOC_CMD_SET_AUTOFOCUS_CALLBACK>(camera, &mut { callback })
+}
+
+/// Whether `status` is final, i.e. the autofocus is not focusing anymore.
+fn is_finished(status: FOCUS_STATUS) -> bool {
+    status.intersects(
+        FOCUS_STATUS::FOC_STATUS_ERROR
+            | FOCUS_STATUS::FOC_STATUS_FOCUSED
+            | FOCUS_STATUS::FOC_STATUS_TIMEOUT
+            | FOCUS_STATUS::FOC_STATUS_CANCEL,
+    )
+}
@@ -0,0 +1,489 @@
+//! Views over captured image data.
+//!
+//! Image memories allocated by the driver may pad each line, so the line increment (_pitch_) can
+//! be larger than `width * bytes_per_pixel`. The views in this module always address lines through
+//! the pitch and never expose the padding bytes.
+//!
+//! With the `ndarray` feature, a [`Frame`] can also be viewed as an `ArrayView` without copying,
+//! whose line stride is the pitch.
+
+use std::borrow::Cow;
+
+use ueye_sys::{
+    image_mem::is_InquireImageMem,
+    types::{char, HIDS, INT},
+};
+
+use crate::{
+    aoi::Aoi,
+    camera::Camera,
+    error::{check, Error, Result},
+    image_mem,
+    observer::call,
+};
+
+/// Number of bytes occupied by a line of `width` pixels at `bits_per_pixel`, saturating at
+/// [`usize::MAX`].
+#[inline]
+const fn line_bytes(width: usize, bits_per_pixel: usize) -> usize {
+    width.saturating_mul(bits_per_pixel.div_ceil(8))
+}
+
+/// Check that `len` bytes hold `height` lines of `line` bytes spaced `pitch` bytes apart.
+#[inline]
+const fn fits(len: usize, height: usize, pitch: usize, line: usize) -> bool {
+    if pitch < line {
+        return false;
+    }
+    if height == 0 {
+        return true;
+    }
+
+    match pitch.checked_mul(height - 1) {
+        Some(start) => match start.checked_add(line) {
+            Some(end) => len >= end,
+            None => false,
+        },
+        None => false,
+    }
+}
+
+/// Geometry of an image memory allocated by the driver.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct MemGeometry {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) bits_per_pixel: usize,
+    pub(crate) pitch: usize,
+}
+
+impl MemGeometry {
+    /// Query the geometry of the image memory `mem` with ID `id` with [`is_InquireImageMem`].
+    pub(crate) fn inquire(hcam: HIDS, mem: *const char, id: INT) -> Result<Self> {
+        let (mut width, mut height, mut bits, mut pitch): (INT, INT, INT, INT) = (0, 0, 0, 0);
+        check(unsafe {
+            call!(is_InquireImageMem(hcam, mem, id, &mut width, &mut height, &mut bits, &mut pitch))
+        })?;
+
+        let [width, height, bits_per_pixel, pitch] =
+            [width, height, bits, pitch].map(|value| usize::try_from(value).unwrap_or(0));
+        Ok(Self { width, height, bits_per_pixel, pitch })
+    }
+
+    /// Number of bytes spanned by the image memory.
+    ///
+    /// Fails with [`Error::InvalidImageSize`] if a line does not fit in the pitch, or the size
+    /// overflows [`usize`].
+    pub(crate) fn len(&self) -> Result<usize> {
+        if self.pitch < line_bytes(self.width, self.bits_per_pixel) {
+            return Err(Error::InvalidImageSize);
+        }
+
+        self.pitch.checked_mul(self.height).ok_or(Error::InvalidImageSize)
+    }
+}
+
+/// Read-only view of a frame.
+#[derive(Debug, Copy, Clone)]
+pub struct Frame<'a> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    pitch: usize,
+    bits_per_pixel: usize,
+}
+
+impl<'a> Frame<'a> {
+    /// Create a view over `data`.
+    ///
+    /// # Input parameters
+    /// * `data` - Image data, starting with the first line.
+    /// * `width` - Image width, in pixels.
+    /// * `height` - Image height, in lines.
+    /// * `pitch` - Line increment, in bytes.
+    /// * `bits_per_pixel` - Image bit depth (bits per pixel).
+    ///
+    /// # Return values
+    /// [`None`] if `data` is too small for the given geometry.
+    pub fn new(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+        bits_per_pixel: usize,
+    ) -> Option<Self> {
+        fits(data.len(), height, pitch, line_bytes(width, bits_per_pixel)).then_some(Self {
+            data,
+            width,
+            height,
+            pitch,
+            bits_per_pixel,
+        })
+    }
+
+    /// Create a view over an image memory allocated by the driver, querying its geometry with
+    /// [`is_InquireImageMem`].
+    ///
+    /// # Safety
+    /// `mem` and `id` must denote an image memory of `camera` which stays allocated, and which the
+    /// driver does not write to, for `'a`.
+    pub unsafe fn from_image_mem(camera: &Camera, mem: *mut char, id: INT) -> Result<Self> {
+        if mem.is_null() {
+            return Err(Error::InvalidMemoryPointer);
+        }
+
+        let geometry = MemGeometry::inquire(camera.handle(), mem, id)?;
+        let len = geometry.len()?;
+
+        // SAFETY: the driver guarantees the image memory spans `pitch * height` bytes, and the
+        // caller guarantees it stays allocated for `'a`.
+        let data = unsafe { std::slice::from_raw_parts(mem as *const u8, len) };
+        Self::new(data, geometry.width, geometry.height, geometry.pitch, geometry.bits_per_pixel)
+            .ok_or(Error::InvalidMemoryPointer)
+    }
+
+    /// Image width, in pixels.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Image height, in lines.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Line increment, in bytes.
+    #[inline]
+    pub const fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// Image bit depth (bits per pixel).
+    #[inline]
+    pub const fn bits_per_pixel(&self) -> usize {
+        self.bits_per_pixel
+    }
+
+    /// Number of bytes occupied by a single pixel.
+    #[inline]
+    pub const fn bytes_per_pixel(&self) -> usize {
+        self.bits_per_pixel.div_ceil(8)
+    }
+
+    /// Pixel data of line `y`, without the padding.
+    ///
+    /// # Panics
+    /// If `y` is not less than [`Frame::height`].
+    #[inline]
+    pub fn row(&self, y: usize) -> &'a [u8] {
+        assert!(y < self.height, "line {y} out of bounds for height {}", self.height);
+        let start = y * self.pitch;
+        &self.data[start..start + line_bytes(self.width, self.bits_per_pixel)]
+    }
+
+    /// Iterate over all lines, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Underlying image data, including the line padding.
+    #[inline]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Whether the lines follow each other without padding.
+    #[inline]
+    pub const fn is_contiguous(&self) -> bool {
+        self.pitch == line_bytes(self.width, self.bits_per_pixel)
+    }
+
+    /// Copy the pixel data of all lines, dropping the line padding.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut data =
+            Vec::with_capacity(line_bytes(self.width, self.bits_per_pixel) * self.height);
+        self.rows().for_each(|row| data.extend_from_slice(row));
+        data
+    }
+
+    /// Pixel data of all lines without padding, borrowed if the frame is
+    /// [contiguous](Frame::is_contiguous) and copied otherwise.
+    pub fn to_contiguous(&self) -> Cow<'a, [u8]> {
+        if self.is_contiguous() {
+            let len = line_bytes(self.width, self.bits_per_pixel) * self.height;
+            Cow::Borrowed(&self.data[..len])
+        } else {
+            Cow::Owned(self.to_vec())
+        }
+    }
+
+    /// View of the area `rect` of the frame, sharing the pitch of the frame.
+    ///
+    /// # Return values
+    /// [`None`] if `rect` does not lie within the frame.
+    pub fn crop(&self, rect: Aoi) -> Option<Frame<'a>> {
+        let [x, y, width, height] =
+            [rect.x, rect.y, rect.width, rect.height].map(|value| usize::try_from(value).ok());
+        let (x, y, width, height) = (x?, y?, width?, height?);
+        if x.checked_add(width)? > self.width || y.checked_add(height)? > self.height {
+            return None;
+        }
+
+        let start = if height == 0 { 0 } else { y * self.pitch + x * self.bytes_per_pixel() };
+        Frame::new(&self.data[start..], width, height, self.pitch, self.bits_per_pixel)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a> Frame<'a> {
+    /// View a frame of one byte per pixel as an array indexed by line and column.
+    ///
+    /// # Return values
+    /// [`None`] if a pixel occupies more than one byte.
+    pub fn as_array2(&self) -> Option<ndarray::ArrayView2<'a, u8>> {
+        use ndarray::ShapeBuilder;
+
+        if self.bytes_per_pixel() != 1 {
+            return None;
+        }
+
+        let shape = (self.height, self.width).strides((self.pitch, 1));
+        ndarray::ArrayView2::from_shape(shape, self.data).ok()
+    }
+
+    /// View the frame as an array indexed by line, column and byte of the pixel.
+    ///
+    /// # Return values
+    /// [`None`] if the geometry of the frame cannot be expressed as array strides.
+    pub fn as_array3(&self) -> Option<ndarray::ArrayView3<'a, u8>> {
+        use ndarray::ShapeBuilder;
+
+        let bytes = self.bytes_per_pixel();
+        let shape = (self.height, self.width, bytes).strides((self.pitch, bytes, 1));
+        ndarray::ArrayView3::from_shape(shape, self.data).ok()
+    }
+}
+
+/// Mutable view of a frame.
+#[derive(Debug)]
+pub struct FrameMut<'a> {
+    data: &'a mut [u8],
+    width: usize,
+    height: usize,
+    pitch: usize,
+    bits_per_pixel: usize,
+}
+
+impl<'a> FrameMut<'a> {
+    /// Create a mutable view over `data`.
+    ///
+    /// See [`Frame::new`] for a description of the parameters.
+    pub fn new(
+        data: &'a mut [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+        bits_per_pixel: usize,
+    ) -> Option<Self> {
+        fits(data.len(), height, pitch, line_bytes(width, bits_per_pixel)).then_some(Self {
+            data,
+            width,
+            height,
+            pitch,
+            bits_per_pixel,
+        })
+    }
+
+    /// Reborrow as a read-only [`Frame`].
+    #[inline]
+    pub fn as_frame(&self) -> Frame<'_> {
+        Frame {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            pitch: self.pitch,
+            bits_per_pixel: self.bits_per_pixel,
+        }
+    }
+
+    /// Image width, in pixels.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Image height, in lines.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Line increment, in bytes.
+    #[inline]
+    pub const fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// Image bit depth (bits per pixel).
+    #[inline]
+    pub const fn bits_per_pixel(&self) -> usize {
+        self.bits_per_pixel
+    }
+
+    /// Number of bytes occupied by a single pixel.
+    #[inline]
+    pub const fn bytes_per_pixel(&self) -> usize {
+        self.bits_per_pixel.div_ceil(8)
+    }
+
+    /// Mutable pixel data of line `y`, without the padding.
+    ///
+    /// # Panics
+    /// If `y` is not less than [`FrameMut::height`].
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> &mut [u8] {
+        assert!(y < self.height, "line {y} out of bounds for height {}", self.height);
+        let start = y * self.pitch;
+        &mut self.data[start..start + line_bytes(self.width, self.bits_per_pixel)]
+    }
+
+    /// Mutable bytes of the pixel at (`x`, `y`), or [`None`] if it lies outside the frame.
+    #[inline]
+    pub fn pixel_mut(&mut self, x: usize, y: usize) -> Option<&mut [u8]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let size = self.bytes_per_pixel();
+        Some(&mut self.row_mut(y)[x * size..(x + 1) * size])
+    }
+}
+
+/// Owned frame, holding a copy of the image data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameBuf {
+    data: Vec<u8>,
+    width: usize,
+    height: usize,
+    pitch: usize,
+    bits_per_pixel: usize,
+}
+
+impl FrameBuf {
+    /// Take ownership of image data without line padding.
+    ///
+    /// # Return values
+    /// [`None`] if `data` is too small for the given geometry.
+    pub fn from_vec(
+        data: Vec<u8>,
+        width: usize,
+        height: usize,
+        bits_per_pixel: usize,
+    ) -> Option<Self> {
+        let pitch = line_bytes(width, bits_per_pixel);
+        fits(data.len(), height, pitch, pitch).then_some(Self {
+            data,
+            width,
+            height,
+            pitch,
+            bits_per_pixel,
+        })
+    }
+
+    /// Copy the contents of `frame`, dropping the line padding.
+    pub fn copy_from(frame: &Frame<'_>) -> Self {
+        Self {
+            data: frame.to_vec(),
+            width: frame.width,
+            height: frame.height,
+            pitch: line_bytes(frame.width, frame.bits_per_pixel),
+            bits_per_pixel: frame.bits_per_pixel,
+        }
+    }
+
+    /// Copy the image last captured into the active image memory of the camera.
+    pub fn copy_active(camera: &Camera) -> Result<Self> {
+        let (mem, id) = image_mem::active(camera)?;
+
+        // SAFETY: the active image memory remains allocated for the duration of this copy.
+        let frame = unsafe { Frame::from_image_mem(camera, mem, id)? };
+        Ok(Self::copy_from(&frame))
+    }
+
+    /// Borrow as a read-only [`Frame`].
+    #[inline]
+    pub fn as_frame(&self) -> Frame<'_> {
+        Frame {
+            data: &self.data,
+            width: self.width,
+            height: self.height,
+            pitch: self.pitch,
+            bits_per_pixel: self.bits_per_pixel,
+        }
+    }
+
+    /// Borrow as a mutable [`FrameMut`].
+    #[inline]
+    pub fn as_frame_mut(&mut self) -> FrameMut<'_> {
+        FrameMut {
+            data: &mut self.data,
+            width: self.width,
+            height: self.height,
+            pitch: self.pitch,
+            bits_per_pixel: self.bits_per_pixel,
+        }
+    }
+
+    /// Image data, without line padding.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consume the frame, returning the image data without line padding.
+    #[inline]
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_geometry() {
+        assert!(fits(10, 2, 6, 4));
+        assert!(!fits(9, 2, 6, 4));
+        assert!(!fits(100, 2, 3, 4));
+        assert!(fits(0, 0, 6, 4));
+    }
+
+    #[test]
+    fn fits_rejects_overflow() {
+        assert!(!fits(usize::MAX, 3, usize::MAX / 2 + 1, 1));
+        assert!(!fits(usize::MAX, 2, usize::MAX, usize::MAX));
+    }
+
+    #[test]
+    fn new_rejects_overflowing_geometry() {
+        let data = [0u8; 16];
+        assert!(Frame::new(&data, 4, 4, 4, 8).is_some());
+        assert!(Frame::new(&data, usize::MAX, 1, usize::MAX, 16).is_none());
+        assert!(Frame::new(&data, 1, usize::MAX, usize::MAX / 2, 8).is_none());
+        assert!(FrameBuf::from_vec(vec![0; 16], usize::MAX / 2, 2, 32).is_none());
+    }
+
+    #[test]
+    fn mem_geometry_len() {
+        let geometry = MemGeometry { width: 3, height: 2, bits_per_pixel: 24, pitch: 12 };
+        assert_eq!(geometry.len(), Ok(24));
+        assert_eq!(MemGeometry { pitch: 8, ..geometry }.len(), Err(Error::InvalidImageSize));
+        assert_eq!(
+            MemGeometry { height: usize::MAX, ..geometry }.len(),
+            Err(Error::InvalidImageSize)
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Detection of lost frames.
+//!
+//! Frames get lost in two places: before the driver hands them to the application, where the
+//! driver counts them by cause in its [`CaptureStats`], and in the application itself, e.g. when
+//! a queue drops frames the consumer did not keep up with. A [`DropDetector`] combines both: it
+//! follows the frame numbers of the received frames, whose gaps count every lost frame, and the
+//! capture error counters accumulated since it was created, which tell the causes.
+//!
+//! With the `tracing` feature, each gap and each increase of a counter is logged as a warning.
+//!
+//! # Documentation
+//! * [is_CaptureStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturestatus.html)
+//! * [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+use crate::{camera::Camera, capture_status::CaptureStats, error::Result, image_info::FrameInfo};
+
+/// Gap in the frame numbers of the received frames.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gap {
+    /// Frame number of the last frame received before the gap.
+    pub after: u64,
+
+    /// Number of frames lost in the gap.
+    pub missing: u64,
+}
+
+/// Frames received and lost since a [`DropDetector`] was created.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropReport {
+    /// Number of received frames.
+    pub received: u64,
+
+    /// Number of frames lost in gaps of the frame numbers.
+    pub missing: u64,
+
+    /// Number of gaps.
+    pub gaps: u64,
+
+    /// Capture errors counted by the driver, by cause, as of the last
+    /// [update](DropDetector::update).
+    pub causes: CaptureStats,
+}
+
+impl DropReport {
+    /// Whether any frame was lost.
+    #[inline]
+    pub const fn has_drops(&self) -> bool {
+        self.missing != 0 || self.causes.has_errors()
+    }
+
+    /// Share of the frames lost in gaps, out of all frames captured (`0.0`…`1.0`).
+    pub fn drop_rate(&self) -> f64 {
+        match self.received + self.missing {
+            0 => 0.0,
+            captured => self.missing as f64 / captured as f64,
+        }
+    }
+}
+
+/// Largest step across the wrap of the frame number that is taken as a gap rather than a
+/// restart of the capture.
+pub const WRAP_WINDOW: u64 = 1 << 32;
+
+/// Detector of lost frames during a capture.
+///
+/// Record every received frame in capture order with [`DropDetector::record`], and
+/// [update](DropDetector::update) the capture error counters now and then, e.g. once per second
+/// or on [`EventKind::CaptureStatus`](crate::event::EventKind::CaptureStatus).
+///
+/// The driver resets the frame numbers when the capture is started; a frame number lower than
+/// the previous one is therefore taken as a restart rather than a gap, unless it follows the
+/// previous one across the wrap of the counter by less than [`WRAP_WINDOW`] frames.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DropDetector {
+    last: Option<u64>,
+    report: DropReport,
+    baseline: CaptureStats,
+}
+
+impl DropDetector {
+    /// Create a detector for `camera`, counting capture errors from now on.
+    pub fn new(camera: &Camera) -> Result<Self> {
+        Ok(Self { last: None, report: DropReport::default(), baseline: camera.capture_stats()? })
+    }
+
+    /// Record a frame with frame number `frame_number`, returning the gap before it, if any.
+    pub fn record(&mut self, frame_number: u64) -> Option<Gap> {
+        self.report.received += 1;
+        let last = self.last.replace(frame_number)?;
+
+        let step = frame_number.wrapping_sub(last);
+        if frame_number < last && step > WRAP_WINDOW {
+            return None;
+        }
+
+        let missing = step.checked_sub(1).filter(|&missing| missing != 0)?;
+        self.report.missing += missing;
+        self.report.gaps += 1;
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(after = last, missing, "frames lost");
+
+        Some(Gap { after: last, missing })
+    }
+
+    /// Record the frame described by `info`, returning the gap before it, if any.
+    #[inline]
+    pub fn record_info(&mut self, info: &FrameInfo) -> Option<Gap> {
+        self.record(info.frame_number)
+    }
+
+    /// Query the capture error counters of `camera`, returning those accumulated since the last
+    /// update.
+    pub fn update(&mut self, camera: &Camera) -> Result<CaptureStats> {
+        let causes = camera.capture_stats()?.since(&self.baseline);
+        let increase = causes.since(&self.report.causes);
+        self.report.causes = causes;
+
+        #[cfg(feature = "tracing")]
+        for (cause, count) in increase.errors() {
+            tracing::warn!(?cause, count, "capture errors");
+        }
+
+        Ok(increase)
+    }
+
+    /// Frames received and lost so far.
+    #[inline]
+    pub const fn report(&self) -> DropReport {
+        self.report
+    }
+
+    /// Restart counting from now on, e.g. after the capture was restarted.
+    pub fn reset(&mut self, camera: &Camera) -> Result<()> {
+        *self = Self::new(camera)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector() -> DropDetector {
+        DropDetector {
+            last: None,
+            report: DropReport::default(),
+            baseline: CaptureStats::default(),
+        }
+    }
+
+    #[test]
+    fn consecutive_frames_have_no_gap() {
+        let mut detector = detector();
+        assert!((1..=5).all(|number| detector.record(number).is_none()));
+
+        let report = detector.report();
+        assert_eq!((report.received, report.missing, report.gaps), (5, 0, 0));
+        assert!(!report.has_drops());
+    }
+
+    #[test]
+    fn gaps_count_missing_frames() {
+        let mut detector = detector();
+        assert_eq!(detector.record(1), None);
+        assert_eq!(detector.record(4), Some(Gap { after: 1, missing: 2 }));
+        assert_eq!(detector.record(5), None);
+        assert_eq!(detector.record(7), Some(Gap { after: 5, missing: 1 }));
+
+        let report = detector.report();
+        assert_eq!((report.received, report.missing, report.gaps), (4, 3, 2));
+        assert!(report.has_drops());
+        assert_eq!(report.drop_rate(), 3.0 / 7.0);
+    }
+
+    #[test]
+    fn repeated_frame_number_is_no_gap() {
+        let mut detector = detector();
+        detector.record(3);
+        assert_eq!(detector.record(3), None);
+        assert_eq!(detector.report().missing, 0);
+    }
+
+    #[test]
+    fn lower_frame_number_restarts() {
+        let mut detector = detector();
+        detector.record(1_000);
+        assert_eq!(detector.record(0), None);
+        assert_eq!(detector.record(2), Some(Gap { after: 0, missing: 1 }));
+        assert_eq!(detector.report().gaps, 1);
+    }
+
+    #[test]
+    fn counter_wrap_continues() {
+        let mut detector = detector();
+        detector.record(u64::MAX - 1);
+        assert_eq!(detector.record(u64::MAX), None);
+        assert_eq!(detector.record(0), None);
+
+        detector.record(u64::MAX - 1);
+        assert_eq!(detector.record(1), Some(Gap { after: u64::MAX - 1, missing: 2 }));
+    }
+
+    #[test]
+    fn counter_wrap_beyond_window_restarts() {
+        let mut detector = detector();
+        detector.record(u64::MAX - WRAP_WINDOW);
+        assert_eq!(detector.record(0), None);
+        assert_eq!(detector.report().missing, 0);
+    }
+
+    #[test]
+    fn drop_rate_without_frames() {
+        assert_eq!(DropReport::default().drop_rate(), 0.0);
+    }
+}
@@ -0,0 +1,126 @@
+//! Frame rate.
+//!
+//! The frame rate range depends on the [pixel clock](crate::pixel_clock) and the image size, and
+//! in turn limits the [exposure time](crate::exposure) range. After changing the pixel clock, set
+//! the frame rate again and then the exposure time; [`set`] returns the frame rate actually
+//! applied, and [`measured`] the frame rate actually achieved while capturing.
+//!
+//! # Documentation
+//! * [is_SetFrameRate](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setframerate.html)
+//! * [is_GetFramesPerSecond](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframespersecond.html)
+//! * [is_GetFrameTimeRange](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframetimerange.html)
+
+use std::time::Duration;
+
+use ueye_sys::{
+    frame_rate::{
+        is_GetFrameTimeRange, is_GetFramesPerSecond, is_SetFrameRate, IS_GET_DEFAULT_FRAMERATE,
+        IS_GET_FRAMERATE,
+    },
+    types::double,
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    observer::call,
+};
+
+fn set_frame_rate(camera: &Camera, fps: double) -> Result<f64> {
+    let mut new_fps: double = 0.0;
+    check(unsafe { call!(is_SetFrameRate(camera.handle(), fps, &mut new_fps)) })?;
+
+    Ok(new_fps)
+}
+
+/// Query the currently set frame rate (in frames per second).
+#[inline]
+pub fn current(camera: &Camera) -> Result<f64> {
+    set_frame_rate(camera, IS_GET_FRAMERATE)
+}
+
+/// Query the default frame rate (in frames per second).
+#[inline]
+pub fn default(camera: &Camera) -> Result<f64> {
+    set_frame_rate(camera, IS_GET_DEFAULT_FRAMERATE)
+}
+
+/// Set the frame rate (in frames per second) for freerun mode.
+///
+/// # Return values
+/// The frame rate actually set, limited to the [range] and rounded to its increment.
+#[inline]
+pub fn set(camera: &Camera, fps: f64) -> Result<f64> {
+    set_frame_rate(camera, fps)
+}
+
+/// Query the frame rate achieved while capturing (in frames per second).
+pub fn measured(camera: &Camera) -> Result<f64> {
+    let mut fps: double = 0.0;
+    check(unsafe { call!(is_GetFramesPerSecond(camera.handle(), &mut fps)) })?;
+
+    Ok(fps)
+}
+
+/// Frame rate range, given by the range of the frame time.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FrameRateRange {
+    /// Shortest frame time, i.e. at the highest frame rate.
+    pub min_frame_time: Duration,
+
+    /// Longest frame time, i.e. at the lowest frame rate.
+    pub max_frame_time: Duration,
+
+    /// Step between two frame times.
+    pub increment: Duration,
+}
+
+impl FrameRateRange {
+    /// Lowest frame rate (in frames per second).
+    #[inline]
+    pub fn min_fps(&self) -> f64 {
+        fps(self.max_frame_time)
+    }
+
+    /// Highest frame rate (in frames per second).
+    #[inline]
+    pub fn max_fps(&self) -> f64 {
+        fps(self.min_frame_time)
+    }
+
+    /// Clamp `fps` into the range.
+    #[inline]
+    pub fn clamp(&self, fps: f64) -> f64 {
+        fps.clamp(self.min_fps(), self.max_fps())
+    }
+
+    /// Whether `fps` lies within the range.
+    #[inline]
+    pub fn contains(&self, fps: f64) -> bool {
+        (self.min_fps()..=self.max_fps()).contains(&fps)
+    }
+}
+
+/// Frame rate of `frame_time`, or `0.0` for a zero frame time.
+#[inline]
+fn fps(frame_time: Duration) -> f64 {
+    match frame_time.as_secs_f64() {
+        0.0 => 0.0,
+        secs => secs.recip(),
+    }
+}
+
+/// Query the frame rate range for the current pixel clock and image size.
+pub fn range(camera: &Camera) -> Result<FrameRateRange> {
+    let (mut min, mut max, mut increment): (double, double, double) = (0.0, 0.0, 0.0);
+    check(unsafe {
+        call!(is_GetFrameTimeRange(camera.handle(), &mut min, &mut max, &mut increment))
+    })?;
+
+    let secs = |secs: double| Duration::try_from_secs_f64(secs).unwrap_or(Duration::ZERO);
+    Ok(FrameRateRange {
+        min_frame_time: secs(min),
+        max_frame_time: secs(max),
+        increment: secs(increment),
+    })
+}
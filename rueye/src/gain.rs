@@ -0,0 +1,81 @@
+//! Hardware gain.
+//!
+//! Gain factors are given in percent of the maximum gain (`0`…`100`), or as multiplication
+//! factors where `100` corresponds to `1.00`.
+//!
+//! # Documentation
+//! * [is_SetHardwareGain](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethardwaregain.html)
+//! * [is_SetHWGainFactor](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethwgainfactor.html)
+
+use ueye_sys::{
+    gain::{
+        is_SetHWGainFactor, is_SetHardwareGain, IS_GET_MASTER_GAIN, IS_GET_MASTER_GAIN_FACTOR,
+        IS_IGNORE_PARAMETER, IS_INQUIRE_MASTER_GAIN_FACTOR, IS_MAX_GAIN, IS_SET_MASTER_GAIN_FACTOR,
+    },
+    types::INT,
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Query the current master gain factor (`0`…`100`).
+pub fn current(camera: &Camera) -> Result<INT> {
+    match unsafe {
+        call!(is_SetHardwareGain(
+            camera.handle(),
+            IS_GET_MASTER_GAIN,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+        ))
+    } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
+
+/// Set the master gain factor (`0`…`100`), leaving the color channels unchanged.
+pub fn set(camera: &Camera, master: INT) -> Result<()> {
+    check(unsafe {
+        call!(is_SetHardwareGain(
+            camera.handle(),
+            master,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+            IS_IGNORE_PARAMETER,
+        ))
+    })
+}
+
+/// Query the current master gain as a multiplication factor (`100` = `1.00`).
+pub fn factor(camera: &Camera) -> Result<INT> {
+    match unsafe { call!(is_SetHWGainFactor(camera.handle(), IS_GET_MASTER_GAIN_FACTOR, 0)) } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
+
+/// Set the master gain as a multiplication factor (`100` = `1.00`).
+///
+/// # Return values
+/// The factor actually set, rounded to the gain steps of the sensor.
+pub fn set_factor(camera: &Camera, factor: INT) -> Result<INT> {
+    match unsafe { call!(is_SetHWGainFactor(camera.handle(), IS_SET_MASTER_GAIN_FACTOR, factor)) } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
+
+/// Query the largest master gain multiplication factor supported by the sensor
+/// (`100` = `1.00`).
+pub fn max_factor(camera: &Camera) -> Result<INT> {
+    match unsafe {
+        call!(is_SetHWGainFactor(camera.handle(), IS_INQUIRE_MASTER_GAIN_FACTOR, IS_MAX_GAIN))
+    } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
@@ -0,0 +1,126 @@
+//! General purpose inputs/outputs (GPIOs).
+//!
+//! GPIOs are addressed as [`IO_GPIO`] bitmasks, so several of them can be read or changed at once.
+//!
+//! # Documentation
+//! [Using GPIO](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_iogpio.html)
+
+use ueye_sys::io::IO_GPIO;
+
+use crate::{
+    camera::Camera,
+    command::{device_command, io::*, CommandParam},
+    error::{Error, Result},
+};
+
+/// Numbers of the GPIOs in `gpios`, starting at `1` for [`IO_GPIO::IO_GPIO_1`].
+pub fn numbers(gpios: IO_GPIO) -> Vec<u8> {
+    (0..u32::BITS as u8).filter(|bit| gpios.bits() & 1 << bit != 0).map(|bit| bit + 1).collect()
+}
+
+/// GPIOs with the given numbers, starting at `1` for [`IO_GPIO::IO_GPIO_1`].
+///
+/// Fails with [`Error::InvalidParameter`] for numbers of GPIOs the driver does not know.
+pub fn from_numbers(numbers: &[u8]) -> Result<IO_GPIO> {
+    numbers.iter().try_fold(IO_GPIO::empty(), |gpios, &number| {
+        let bit = number.checked_sub(1).and_then(|bit| 1u32.checked_shl(bit.into()));
+        match bit.and_then(IO_GPIO::from_bits) {
+            Some(gpio) => Ok(gpios | gpio),
+            None => Err(Error::InvalidParameter),
+        }
+    })
+}
+
+fn query<C: CommandParam<Param = IO_GPIO>>(camera: &Camera, _command: C) -> Result<IO_GPIO> {
+    let mut mask = IO_GPIO::empty();
+    device_command::<C>(camera, &mut mask)?;
+    Ok(IO_GPIO::from_bits_truncate(mask.bits()))
+}
+
+fn apply<C: CommandParam<Param = IO_GPIO>>(
+    camera: &Camera,
+    _command: C,
+    gpios: IO_GPIO,
+) -> Result<()> {
+    device_command::<C>(camera, &mut { gpios })
+}
+
+/// Query the GPIOs of the camera.
+#[inline]
+pub fn supported(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_GPIOS_GET_SUPPORTED)
+}
+
+/// Query the GPIOs that can be used as output.
+#[inline]
+pub fn supported_outputs(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_GPIOS_GET_SUPPORTED_OUTPUTS)
+}
+
+/// Query the GPIOs that can be used as flash output.
+#[inline]
+pub fn supported_flash(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_FLASH_GET_SUPPORTED_GPIOS)
+}
+
+/// Query the GPIOs that can be used as pulse-width modulation (PWM) output.
+#[inline]
+pub fn supported_pwm(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_PWM_GET_SUPPORTED_GPIOS)
+}
+
+/// Query the GPIOs configured as output; all others are inputs.
+#[inline]
+pub fn outputs(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_GPIOS_GET_DIRECTION)
+}
+
+/// Configure `gpios` as output and all others as input.
+#[inline]
+pub fn set_outputs(camera: &Camera, gpios: IO_GPIO) -> Result<()> {
+    apply(camera, IS_IO_CMD_GPIOS_SET_DIRECTION, gpios)
+}
+
+/// Query the GPIOs at high level.
+#[inline]
+pub fn high(camera: &Camera) -> Result<IO_GPIO> {
+    query(camera, IS_IO_CMD_GPIOS_GET_STATE)
+}
+
+/// Drive the outputs of `gpios` high and all other outputs low.
+#[inline]
+pub fn set_high(camera: &Camera, gpios: IO_GPIO) -> Result<()> {
+    apply(camera, IS_IO_CMD_GPIOS_SET_STATE, gpios)
+}
+
+/// Configure `gpio` as output and drive it to the given level, leaving the other GPIOs unchanged.
+pub fn set_output(camera: &Camera, gpio: IO_GPIO, level: bool) -> Result<()> {
+    let outputs = outputs(camera)?;
+    if !outputs.contains(gpio) {
+        set_outputs(camera, outputs | gpio)?;
+    }
+
+    let mut high = high(camera)?;
+    high.set(gpio, level);
+    set_high(camera, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_round_trip() {
+        let gpios = IO_GPIO::IO_GPIO_1 | IO_GPIO::IO_GPIO_4;
+        assert_eq!(numbers(gpios), [1, 4]);
+        assert_eq!(from_numbers(&[1, 4]), Ok(gpios));
+        assert!(numbers(IO_GPIO::empty()).is_empty());
+    }
+
+    #[test]
+    fn from_numbers_rejects_unknown_gpios() {
+        assert_eq!(from_numbers(&[0]), Err(Error::InvalidParameter));
+        assert_eq!(from_numbers(&[200]), Err(Error::InvalidParameter));
+        assert_eq!(from_numbers(&[u8::MAX]), Err(Error::InvalidParameter));
+    }
+}
@@ -0,0 +1,353 @@
+//! Hot pixel lists.
+//!
+//! Lists are exchanged with the driver as [`WORD`] arrays holding the number of hot pixels,
+//! followed by the X and Y coordinates of each of them.
+//!
+//! The camera applies the merged list of its factory-set and user-defined lists, so user-defined
+//! pixels which are already part of the factory list only take up space in the limited
+//! non-volatile camera memory. [`trim_camera_user_list`] removes them.
+//!
+//! [`HotPixelList`] holds a list without duplicates and provides the set operations to combine
+//! lists, e.g. to add newly detected pixels to a user-defined list. List files are read and
+//! written by the driver, through [`load_software_user_list`] and [`save_software_user_list`].
+//!
+//! # Documentation
+//! [is_HotPixel](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_hotpixel.html)
+
+use std::{collections::BTreeSet, mem::size_of, ops::Deref, path::Path, ptr};
+
+use ueye_sys::{
+    hot_pixel::{is_HotPixel, IS_HOTPIXEL_CMD},
+    types::{void, wchar_t, INT, UINT, WORD},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    image_file::wide_path,
+    observer::command,
+};
+
+use IS_HOTPIXEL_CMD::*;
+
+/// Hot pixel coordinates, as `(x, y)`.
+pub type HotPixel = (WORD, WORD);
+
+/// List of hot pixels without duplicates, in insertion order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HotPixelList {
+    pixels: Vec<HotPixel>,
+}
+
+impl HotPixelList {
+    /// Empty list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { pixels: Vec::new() }
+    }
+
+    /// Pixels of the list.
+    #[inline]
+    pub fn as_slice(&self) -> &[HotPixel] {
+        &self.pixels
+    }
+
+    /// Consume the list, returning its pixels.
+    #[inline]
+    pub fn into_vec(self) -> Vec<HotPixel> {
+        self.pixels
+    }
+
+    /// Add `pixel`, returning whether it was not yet part of the list.
+    pub fn insert(&mut self, pixel: HotPixel) -> bool {
+        if self.pixels.contains(&pixel) {
+            return false;
+        }
+
+        self.pixels.push(pixel);
+        true
+    }
+
+    /// Pixels of either list: the pixels of `self`, followed by those only in `other`.
+    pub fn union(&self, other: &[HotPixel]) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    /// Pixels of `self` which are not in `other`.
+    pub fn difference(&self, other: &[HotPixel]) -> Self {
+        let other: BTreeSet<&HotPixel> = other.iter().collect();
+        self.pixels.iter().copied().filter(|pixel| !other.contains(pixel)).collect()
+    }
+
+    /// Pixels of `self` which are also in `other`.
+    pub fn intersection(&self, other: &[HotPixel]) -> Self {
+        let other: BTreeSet<&HotPixel> = other.iter().collect();
+        self.pixels.iter().copied().filter(|pixel| other.contains(pixel)).collect()
+    }
+
+    /// Fail with [`Error::InvalidParameter`] if the list holds more than `max` pixels, e.g. the
+    /// [capacity](camera_user_list_max) of the camera.
+    pub fn check_capacity(&self, max: usize) -> Result<()> {
+        match self.pixels.len() <= max {
+            true => Ok(()),
+            false => Err(Error::InvalidParameter),
+        }
+    }
+}
+
+impl Deref for HotPixelList {
+    type Target = [HotPixel];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.pixels
+    }
+}
+
+impl Extend<HotPixel> for HotPixelList {
+    fn extend<I: IntoIterator<Item = HotPixel>>(&mut self, pixels: I) {
+        let mut known: BTreeSet<HotPixel> = self.pixels.iter().copied().collect();
+        self.pixels.extend(pixels.into_iter().filter(|&pixel| known.insert(pixel)));
+    }
+}
+
+impl FromIterator<HotPixel> for HotPixelList {
+    fn from_iter<I: IntoIterator<Item = HotPixel>>(pixels: I) -> Self {
+        let mut list = Self::new();
+        list.extend(pixels);
+        list
+    }
+}
+
+impl From<Vec<HotPixel>> for HotPixelList {
+    #[inline]
+    fn from(pixels: Vec<HotPixel>) -> Self {
+        pixels.into_iter().collect()
+    }
+}
+
+impl From<HotPixelList> for Vec<HotPixel> {
+    #[inline]
+    fn from(list: HotPixelList) -> Self {
+        list.pixels
+    }
+}
+
+impl IntoIterator for HotPixelList {
+    type Item = HotPixel;
+    type IntoIter = std::vec::IntoIter<HotPixel>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.pixels.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a HotPixelList {
+    type Item = &'a HotPixel;
+    type IntoIter = std::slice::Iter<'a, HotPixel>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.pixels.iter()
+    }
+}
+
+/// Query the number of hot pixels in a list.
+fn number(camera: &Camera, command: IS_HOTPIXEL_CMD) -> Result<usize> {
+    let mut number: INT = 0;
+    check(unsafe {
+        command!(is_HotPixel(
+            camera.handle(),
+            command,
+            &mut number as *mut INT as *mut void,
+            size_of::<INT>() as UINT,
+        ))
+    })?;
+
+    Ok(number.max(0) as usize)
+}
+
+/// Query a list, sized by the number returned for `number_command`.
+fn get(
+    camera: &Camera,
+    number_command: IS_HOTPIXEL_CMD,
+    list_command: IS_HOTPIXEL_CMD,
+) -> Result<HotPixelList> {
+    let number = number(camera, number_command)?;
+    if number == 0 {
+        return Ok(HotPixelList::new());
+    }
+
+    let mut words: Vec<WORD> = vec![0; 1 + 2 * number];
+    check(unsafe {
+        command!(is_HotPixel(
+            camera.handle(),
+            list_command,
+            words.as_mut_ptr() as *mut void,
+            (words.len() * size_of::<WORD>()) as UINT,
+        ))
+    })?;
+
+    let listed = (words[0] as usize).min(number);
+    Ok(words[1..].chunks_exact(2).take(listed).map(|pixel| (pixel[0], pixel[1])).collect())
+}
+
+/// Set a list to `pixels`.
+///
+/// Fails with [`Error::InvalidParameter`] if the list holds more pixels than a [`WORD`] counts.
+fn set(camera: &Camera, command: IS_HOTPIXEL_CMD, pixels: &[HotPixel]) -> Result<()> {
+    let count = WORD::try_from(pixels.len()).map_err(|_| Error::InvalidParameter)?;
+
+    let mut words: Vec<WORD> = Vec::with_capacity(1 + 2 * pixels.len());
+    words.push(count);
+    words.extend(pixels.iter().flat_map(|&(x, y)| [x, y]));
+
+    check(unsafe {
+        command!(is_HotPixel(
+            camera.handle(),
+            command,
+            words.as_mut_ptr() as *mut void,
+            (words.len() * size_of::<WORD>()) as UINT,
+        ))
+    })
+}
+
+/// Query the factory-set hot pixel list of the non-volatile camera memory.
+#[inline]
+pub fn camera_factory_list(camera: &Camera) -> Result<HotPixelList> {
+    get(camera, IS_HOTPIXEL_GET_CAMERA_FACTORY_LIST_NUMBER, IS_HOTPIXEL_GET_CAMERA_FACTORY_LIST)
+}
+
+/// Query the user-defined hot pixel list of the non-volatile camera memory.
+#[inline]
+pub fn camera_user_list(camera: &Camera) -> Result<HotPixelList> {
+    get(camera, IS_HOTPIXEL_GET_CAMERA_USER_LIST_NUMBER, IS_HOTPIXEL_GET_CAMERA_USER_LIST)
+}
+
+/// Query the largest number of pixels of the user-defined list in the non-volatile camera memory.
+#[inline]
+pub fn camera_user_list_max(camera: &Camera) -> Result<usize> {
+    number(camera, IS_HOTPIXEL_GET_CAMERA_USER_LIST_MAX_NUMBER)
+}
+
+/// Query the merged factory-set and user-defined hot pixel list applied by the camera.
+#[inline]
+pub fn merged_camera_list(camera: &Camera) -> Result<HotPixelList> {
+    get(camera, IS_HOTPIXEL_GET_MERGED_CAMERA_LIST_NUMBER, IS_HOTPIXEL_GET_MERGED_CAMERA_LIST)
+}
+
+/// Query the software hot pixel list of the driver.
+#[inline]
+pub fn software_user_list(camera: &Camera) -> Result<HotPixelList> {
+    get(camera, IS_HOTPIXEL_GET_SOFTWARE_USER_LIST_NUMBER, IS_HOTPIXEL_GET_SOFTWARE_USER_LIST)
+}
+
+/// Set the software hot pixel list of the driver, corrected with
+/// `IS_HOTPIXEL_ENABLE_SOFTWARE_USER_CORRECTION`.
+#[inline]
+pub fn set_software_user_list(camera: &Camera, pixels: &[HotPixel]) -> Result<()> {
+    set(camera, IS_HOTPIXEL_SET_SOFTWARE_USER_LIST, pixels)
+}
+
+/// Run a file command of the software hot pixel list on the file at `path`.
+fn software_user_list_file(camera: &Camera, command: IS_HOTPIXEL_CMD, path: &Path) -> Result<()> {
+    let mut file_name = wide_path(path)?;
+    check(unsafe {
+        command!(is_HotPixel(
+            camera.handle(),
+            command,
+            file_name.as_mut_ptr() as *mut void,
+            (file_name.len() * size_of::<wchar_t>()) as UINT,
+        ))
+    })
+}
+
+/// Save the software hot pixel list of the driver to the file at `path`, in the binary format of
+/// the driver which the _uEye Hotpixel Editor_ reads.
+#[inline]
+pub fn save_software_user_list(camera: &Camera, path: impl AsRef<Path>) -> Result<()> {
+    software_user_list_file(camera, IS_HOTPIXEL_SAVE_SOFTWARE_USER_LIST_UNICODE, path.as_ref())
+}
+
+/// Load the software hot pixel list of the driver from the file at `path`, saved by
+/// [`save_software_user_list`] or the _uEye Hotpixel Editor_.
+#[inline]
+pub fn load_software_user_list(camera: &Camera, path: impl AsRef<Path>) -> Result<()> {
+    software_user_list_file(camera, IS_HOTPIXEL_LOAD_SOFTWARE_USER_LIST_UNICODE, path.as_ref())
+}
+
+/// Write the user-defined hot pixel list to the non-volatile camera memory.
+///
+/// An empty list deletes the user-defined list. Fails with [`Error::InvalidParameter`] if the
+/// list exceeds the [capacity](camera_user_list_max) of the camera.
+pub fn set_camera_user_list(camera: &Camera, pixels: &[HotPixel]) -> Result<()> {
+    if pixels.is_empty() {
+        return check(unsafe {
+            command!(is_HotPixel(
+                camera.handle(),
+                IS_HOTPIXEL_DELETE_CAMERA_USER_LIST,
+                ptr::null_mut(),
+                0
+            ))
+        });
+    }
+
+    if pixels.len() > camera_user_list_max(camera)? {
+        return Err(Error::InvalidParameter);
+    }
+
+    set(camera, IS_HOTPIXEL_SET_CAMERA_USER_LIST, pixels)
+}
+
+/// Pixels of `user` which are already part of `factory`, in the order of `user`.
+pub fn redundant(factory: &[HotPixel], user: &[HotPixel]) -> Vec<HotPixel> {
+    let factory: BTreeSet<HotPixel> = factory.iter().copied().collect();
+    user.iter().copied().filter(|pixel| factory.contains(pixel)).collect()
+}
+
+/// Remove the pixels of the user-defined list which are already in the factory-set list, writing
+/// the trimmed list back to the non-volatile camera memory.
+///
+/// The camera memory is only written if any pixel was removed. Returns the removed pixels.
+pub fn trim_camera_user_list(camera: &Camera) -> Result<HotPixelList> {
+    let user = camera_user_list(camera)?;
+    if user.is_empty() {
+        return Ok(HotPixelList::new());
+    }
+
+    let factory = camera_factory_list(camera)?;
+    let removed = user.intersection(&factory);
+    if !removed.is_empty() {
+        set_camera_user_list(camera, &user.difference(&factory))?;
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_skips_duplicates() {
+        let mut list: HotPixelList = vec![(1, 2), (3, 4), (1, 2)].into();
+        assert_eq!(list.as_slice(), [(1, 2), (3, 4)]);
+        assert!(!list.insert((3, 4)));
+        assert!(list.insert((5, 6)));
+        assert_eq!(list.as_slice(), [(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let list: HotPixelList = vec![(1, 1), (2, 2), (3, 3)].into();
+        let other = [(3, 3), (4, 4), (1, 1)];
+
+        assert_eq!(list.union(&other).as_slice(), [(1, 1), (2, 2), (3, 3), (4, 4)]);
+        assert_eq!(list.difference(&other).as_slice(), [(2, 2)]);
+        assert_eq!(list.intersection(&other).as_slice(), [(1, 1), (3, 3)]);
+        assert_eq!(redundant(&other, &list), [(1, 1), (3, 3)]);
+    }
+}
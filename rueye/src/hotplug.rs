@@ -0,0 +1,208 @@
+//! Device arrival and removal notifications.
+//!
+//! A [`DeviceWatcher`] waits for the driver's device arrival and removal events on a background
+//! thread. These events do not tell which camera changed, so the watcher compares the camera list
+//! before and after each of them and reports every camera that appeared or disappeared. Opened
+//! cameras can additionally be [watched][DeviceWatcher::watch] for reconnection.
+//!
+//! # Documentation
+//! * [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+//! * [is_GetCameraList](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+        Arc, Mutex, PoisonError,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use ueye_sys::{
+    constants::event::{
+        IS_SET_EVENT_DEVICE_RECONNECTED, IS_SET_EVENT_NEW_DEVICE, IS_SET_EVENT_REMOVAL,
+    },
+    types::{HIDS, UINT},
+};
+
+use crate::{
+    camera::{list_cameras, Camera, CameraInfo},
+    error::Result,
+    event::{exit_events, init_events, wait_any},
+};
+
+/// Device-independent events, registered on the null camera handle.
+const DEVICE_EVENTS: [UINT; 2] = [IS_SET_EVENT_NEW_DEVICE, IS_SET_EVENT_REMOVAL];
+
+/// Longest time the watcher thread waits before checking whether it should stop.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Change of the cameras connected to the system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceEvent {
+    /// A camera was connected.
+    Arrived(CameraInfo),
+
+    /// A camera was disconnected.
+    Removed(CameraInfo),
+
+    /// A [watched][DeviceWatcher::watch] camera, given by its handle, was reconnected.
+    Reconnected(HIDS),
+}
+
+/// Background watcher of device arrival and removal, stopped on drop.
+///
+/// The watcher borrows the cameras it [watches][DeviceWatcher::watch] for reconnection, so that
+/// it is stopped before they are closed.
+#[derive(Debug)]
+pub struct DeviceWatcher<'c> {
+    stop: Arc<AtomicBool>,
+    watched: Arc<Mutex<Vec<HIDS>>>,
+    thread: Option<JoinHandle<()>>,
+    cameras: PhantomData<&'c Camera>,
+}
+
+impl<'c> DeviceWatcher<'c> {
+    /// Start watching, calling `callback` on the watcher thread for every event.
+    pub fn spawn(mut callback: impl FnMut(DeviceEvent) + Send + 'static) -> Result<Self> {
+        init_events(0, &DEVICE_EVENTS)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let watched = Arc::new(Mutex::new(Vec::new()));
+        let mut cameras = list_cameras().unwrap_or_default();
+
+        let thread = thread::spawn({
+            let stop = Arc::clone(&stop);
+            let watched = Arc::clone(&watched);
+            move || {
+                while !stop.load(Ordering::Acquire) {
+                    if wait_any(0, &DEVICE_EVENTS, POLL_INTERVAL).is_ok() {
+                        // the list is left unchanged while it cannot be read
+                        if let Ok(current) = list_cameras() {
+                            diff(&cameras, &current, &mut callback);
+                            cameras = current;
+                        }
+                    }
+
+                    let handles = watched.lock().unwrap_or_else(PoisonError::into_inner).clone();
+                    for hcam in handles {
+                        let events = [IS_SET_EVENT_DEVICE_RECONNECTED];
+                        if wait_any(hcam, &events, Duration::ZERO).is_ok() {
+                            callback(DeviceEvent::Reconnected(hcam));
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self { stop, watched, thread: Some(thread), cameras: PhantomData })
+    }
+
+    /// Start watching, sending every event to the returned receiver.
+    pub fn channel() -> Result<(Self, Receiver<DeviceEvent>)> {
+        let (sender, receiver) = mpsc::channel();
+        let watcher = Self::spawn(move |event| {
+            // events are dropped once the receiver is gone
+            let _ = sender.send(event);
+        })?;
+
+        Ok((watcher, receiver))
+    }
+
+    /// Additionally report when `camera` is reconnected after being disconnected.
+    pub fn watch(&self, camera: &'c Camera) -> Result<()> {
+        init_events(camera.handle(), &[IS_SET_EVENT_DEVICE_RECONNECTED])?;
+        self.watched.lock().unwrap_or_else(PoisonError::into_inner).push(camera.handle());
+        Ok(())
+    }
+}
+
+impl Drop for DeviceWatcher<'_> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+
+        exit_events(0, &DEVICE_EVENTS);
+        for &hcam in self.watched.lock().unwrap_or_else(PoisonError::into_inner).iter() {
+            exit_events(hcam, &[IS_SET_EVENT_DEVICE_RECONNECTED]);
+        }
+    }
+}
+
+/// Report the cameras of `current` missing from `previous` as arrived, and vice versa.
+fn diff(previous: &[CameraInfo], current: &[CameraInfo], callback: &mut impl FnMut(DeviceEvent)) {
+    let same = |a: &CameraInfo, b: &CameraInfo| {
+        a.device_id == b.device_id && a.serial_number == b.serial_number
+    };
+
+    for info in previous.iter().filter(|info| !current.iter().any(|other| same(info, other))) {
+        callback(DeviceEvent::Removed(info.clone()));
+    }
+
+    for info in current.iter().filter(|info| !previous.iter().any(|other| same(info, other))) {
+        callback(DeviceEvent::Arrived(info.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(device_id: u32, serial_number: &str) -> CameraInfo {
+        CameraInfo {
+            camera_id: 1,
+            device_id,
+            sensor_id: 0,
+            serial_number: serial_number.to_owned(),
+            model: "UI-3240CP-M-GL".to_owned(),
+            full_model_name: "UI-3240CP-M-GL Rev.2".to_owned(),
+            in_use: false,
+        }
+    }
+
+    fn events(previous: &[CameraInfo], current: &[CameraInfo]) -> Vec<DeviceEvent> {
+        let mut events = Vec::new();
+        diff(previous, current, &mut |event| events.push(event));
+        events
+    }
+
+    #[test]
+    fn diff_reports_arrival() {
+        let previous = [info(1, "4102000001")];
+        let current = [info(1, "4102000001"), info(2, "4102000002")];
+        assert_eq!(events(&previous, &current), [DeviceEvent::Arrived(current[1].clone())]);
+    }
+
+    #[test]
+    fn diff_reports_removal() {
+        let previous = [info(1, "4102000001"), info(2, "4102000002")];
+        let current = [info(2, "4102000002")];
+        assert_eq!(events(&previous, &current), [DeviceEvent::Removed(previous[0].clone())]);
+    }
+
+    #[test]
+    fn diff_ignores_unchanged() {
+        let previous = [info(1, "4102000001"), info(2, "4102000002")];
+        let current = [info(2, "4102000002"), info(1, "4102000001")];
+        assert_eq!(events(&previous, &current), []);
+
+        // other fields, e.g. whether the camera is in use, may change between lists
+        let current = [info(1, "4102000001"), CameraInfo { in_use: true, ..info(2, "4102000002") }];
+        assert_eq!(events(&previous, &current), []);
+    }
+
+    #[test]
+    fn diff_reports_replaced_camera() {
+        // another camera at the same device ID
+        let previous = [info(1, "4102000001")];
+        let current = [info(1, "4102000002")];
+        assert_eq!(
+            events(&previous, &current),
+            [DeviceEvent::Removed(previous[0].clone()), DeviceEvent::Arrived(current[0].clone())]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Conversion of frames into [`image`] buffers.
+//!
+//! A [`Frame`] does not know the color mode it was captured in, so the conversions take the
+//! [`ColorLayout`] of its pixels. The line padding is removed and the channels of BGR frames are
+//! reordered to the RGB order of the [`image`] crate.
+
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, RgbImage, RgbaImage};
+
+use crate::{
+    error::{Error, Result},
+    frame::Frame,
+};
+
+/// Single-channel image with 16-bit samples.
+pub type Gray16Image = ImageBuffer<Luma<u16>, Vec<u16>>;
+
+/// Layout of the pixels of a frame, matching the color mode set on the camera.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorLayout {
+    /// 8-bit grayscale (`IS_CM_MONO8`).
+    Mono8,
+
+    /// 16-bit little-endian grayscale (`IS_CM_MONO10`, `IS_CM_MONO12` and `IS_CM_MONO16`).
+    Mono16,
+
+    /// 8-bit blue, green and red channels (`IS_CM_BGR8_PACKED`).
+    Bgr8,
+
+    /// 8-bit blue, green, red and alpha channels (`IS_CM_BGRA8_PACKED`).
+    Bgra8,
+
+    /// 8-bit red, green and blue channels (`IS_CM_RGB8_PACKED`).
+    Rgb8,
+}
+
+impl ColorLayout {
+    /// Image bit depth (bits per pixel) of the layout.
+    pub const fn bits_per_pixel(self) -> usize {
+        match self {
+            Self::Mono8 => 8,
+            Self::Mono16 => 16,
+            Self::Bgr8 | Self::Rgb8 => 24,
+            Self::Bgra8 => 32,
+        }
+    }
+}
+
+/// Check that `frame` is laid out as `layout`.
+fn expect_layout(frame: &Frame<'_>, layout: ColorLayout) -> Result<()> {
+    match frame.bits_per_pixel() == layout.bits_per_pixel() {
+        true => Ok(()),
+        false => Err(Error::InvalidColorFormat),
+    }
+}
+
+/// Build a buffer of `frame` from its padding-free samples.
+fn buffer<P: image::Pixel>(
+    frame: &Frame<'_>,
+    samples: Vec<P::Subpixel>,
+) -> Result<ImageBuffer<P, Vec<P::Subpixel>>> {
+    let width = u32::try_from(frame.width()).map_err(|_| Error::InvalidParameter)?;
+    let height = u32::try_from(frame.height()).map_err(|_| Error::InvalidParameter)?;
+    ImageBuffer::from_raw(width, height, samples).ok_or(Error::InvalidParameter)
+}
+
+/// Copy the samples of `frame`, reversing the order of the first three channels of each pixel of
+/// `channels` samples.
+fn swap_bgr(frame: &Frame<'_>, channels: usize) -> Vec<u8> {
+    let mut samples = frame.to_vec();
+    samples.chunks_exact_mut(channels).for_each(|pixel| pixel.swap(0, 2));
+    samples
+}
+
+impl Frame<'_> {
+    /// Copy a [`ColorLayout::Mono8`] frame into a grayscale image.
+    pub fn to_luma8(&self) -> Result<GrayImage> {
+        expect_layout(self, ColorLayout::Mono8)?;
+        buffer(self, self.to_vec())
+    }
+
+    /// Copy a [`ColorLayout::Mono16`] frame into a 16-bit grayscale image.
+    pub fn to_luma16(&self) -> Result<Gray16Image> {
+        expect_layout(self, ColorLayout::Mono16)?;
+
+        let mut samples = Vec::with_capacity(self.width() * self.height());
+        for row in self.rows() {
+            samples.extend(
+                row.chunks_exact(2).map(|sample| u16::from_le_bytes([sample[0], sample[1]])),
+            );
+        }
+
+        buffer(self, samples)
+    }
+
+    /// Copy a [`ColorLayout::Rgb8`] or [`ColorLayout::Bgr8`] frame into an RGB image.
+    pub fn to_rgb8(&self, layout: ColorLayout) -> Result<RgbImage> {
+        expect_layout(self, layout)?;
+        match layout {
+            ColorLayout::Rgb8 => buffer(self, self.to_vec()),
+            ColorLayout::Bgr8 => buffer(self, swap_bgr(self, 3)),
+            _ => Err(Error::InvalidColorFormat),
+        }
+    }
+
+    /// Copy a [`ColorLayout::Bgra8`] frame into an RGBA image.
+    pub fn to_rgba8(&self) -> Result<RgbaImage> {
+        expect_layout(self, ColorLayout::Bgra8)?;
+        buffer(self, swap_bgr(self, 4))
+    }
+
+    /// Copy a frame of any [`ColorLayout`] into the matching dynamic image.
+    pub fn to_image(&self, layout: ColorLayout) -> Result<DynamicImage> {
+        Ok(match layout {
+            ColorLayout::Mono8 => DynamicImage::ImageLuma8(self.to_luma8()?),
+            ColorLayout::Mono16 => DynamicImage::ImageLuma16(self.to_luma16()?),
+            ColorLayout::Bgr8 | ColorLayout::Rgb8 => DynamicImage::ImageRgb8(self.to_rgb8(layout)?),
+            ColorLayout::Bgra8 => DynamicImage::ImageRgba8(self.to_rgba8()?),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+//! Saving images to files and loading them back.
+//!
+//! The driver takes file names as wide strings: UTF-16 on Windows, where any path converts
+//! losslessly, and UTF-32 elsewhere, where paths must be valid UTF-8. Failures to open or parse a
+//! file are reported as [`Error::Io`] rather than the file error codes of the driver.
+//!
+//! Saving JPEG and PNG files on Linux requires additional libraries of the driver.
+//!
+//! # Documentation
+//! [is_ImageFile](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagefile.html)
+
+use std::{io::ErrorKind, mem::size_of, path::Path, ptr};
+
+use ueye_sys::{
+    constants::image::IMG,
+    image_file::{is_ImageFile, IMAGE_FILE_CMD, IMAGE_FILE_PARAMS},
+    image_mem::is_FreeImageMem,
+    types::{char, void, wchar_t, INT, UINT},
+};
+
+#[cfg(feature = "sidecar")]
+use crate::sidecar::FrameMetadata;
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    frame::{Frame, FrameBuf},
+    observer::{call, command},
+};
+
+/// File format of an image file.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageFormat {
+    /// Windows bitmap.
+    Bmp,
+
+    /// JPEG, always saved at 8 or 24 bits per pixel.
+    Jpeg,
+
+    /// PNG, saved at 16 bits per channel for image bit depths above 8.
+    #[default]
+    Png,
+}
+
+impl ImageFormat {
+    /// File type of the format for [`is_ImageFile`].
+    pub const fn file_type(self) -> IMG {
+        match self {
+            ImageFormat::Bmp => IMG::IS_IMG_BMP,
+            ImageFormat::Jpeg => IMG::IS_IMG_JPG,
+            ImageFormat::Png => IMG::IS_IMG_PNG,
+        }
+    }
+
+    /// Format of a file, by its extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "bmp" => Some(ImageFormat::Bmp),
+            "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+/// Options for [`save`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SaveOptions {
+    /// File type to be saved.
+    pub file_type: IMG,
+
+    /// Image quality for PNG and JPEG (`1`…`100`), or `0` for the driver default of `75`.
+    /// Ignored for BMP.
+    pub quality: UINT,
+}
+
+impl Default for SaveOptions {
+    #[inline]
+    fn default() -> Self {
+        Self { file_type: IMG::IS_IMG_PNG, quality: 0 }
+    }
+}
+
+/// File name of `path` as a NUL-terminated wide string.
+#[cfg(target_os = "windows")]
+pub(crate) fn wide_path(path: &Path) -> Result<Vec<wchar_t>> {
+    use std::os::windows::ffi::OsStrExt;
+
+    Ok(path.as_os_str().encode_wide().chain(std::iter::once(0)).collect())
+}
+
+/// File name of `path` as a NUL-terminated wide string.
+///
+/// Fails with [`Error::Io`] if `path` is not valid UTF-8.
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn wide_path(path: &Path) -> Result<Vec<wchar_t>> {
+    let path = path.to_str().ok_or(Error::Io(ErrorKind::InvalidInput))?;
+    Ok(path.chars().map(|c| c as wchar_t).chain(std::iter::once(0)).collect())
+}
+
+/// Translate the file error codes of the driver into I/O errors.
+fn file_error(error: Error) -> Error {
+    match error {
+        Error::FilePathDoesNotExist => Error::Io(ErrorKind::NotFound),
+        Error::FileWriteOpenError | Error::FileReadOpenError => {
+            Error::Io(ErrorKind::PermissionDenied)
+        },
+        Error::FileReadInvalidBmpId
+        | Error::FileReadInvalidBmpSize
+        | Error::FileReadInvalidBitCount => Error::Io(ErrorKind::InvalidData),
+        error => error,
+    }
+}
+
+fn image_file(camera: &Camera, cmd: IMAGE_FILE_CMD, params: &mut IMAGE_FILE_PARAMS) -> Result<()> {
+    check(unsafe {
+        command!(is_ImageFile(
+            camera.handle(),
+            cmd,
+            params as *mut IMAGE_FILE_PARAMS as *mut void,
+            size_of::<IMAGE_FILE_PARAMS>() as UINT,
+        ))
+    })
+    .map_err(file_error)
+}
+
+/// Save the active image memory to `path`.
+pub fn save(camera: &Camera, path: impl AsRef<Path>, options: &SaveOptions) -> Result<()> {
+    let mut file_name = wide_path(path.as_ref())?;
+    let mut params =
+        IMAGE_FILE_PARAMS::new(file_name.as_mut_ptr(), options.file_type, options.quality);
+
+    image_file(camera, IMAGE_FILE_CMD::IS_IMAGE_FILE_CMD_SAVE, &mut params)
+}
+
+/// Save the active image memory to `path` in `format`, at `quality` (`1`…`100`, or `0` for the
+/// driver default) for JPEG and PNG.
+#[inline]
+pub fn save_frame(
+    camera: &Camera,
+    path: impl AsRef<Path>,
+    format: ImageFormat,
+    quality: u32,
+) -> Result<()> {
+    save(camera, path, &SaveOptions { file_type: format.file_type(), quality })
+}
+
+/// Load the BMP, JPEG or PNG file at `path`.
+///
+/// The driver loads the image into an image memory allocated for the purpose, which is copied and
+/// freed again; the active image memory is left untouched.
+pub fn load_image(camera: &Camera, path: impl AsRef<Path>) -> Result<FrameBuf> {
+    let path = path.as_ref();
+    let format = ImageFormat::from_path(path).unwrap_or_default();
+
+    let mut file_name = wide_path(path)?;
+    let mut mem: *mut char = ptr::null_mut();
+    let mut id: UINT = 0;
+    let mut params = IMAGE_FILE_PARAMS::new(file_name.as_mut_ptr(), format.file_type(), 0);
+    params.ppcImageMem = &mut mem;
+    params.pnImageID = &mut id;
+
+    image_file(camera, IMAGE_FILE_CMD::IS_IMAGE_FILE_CMD_LOAD, &mut params)?;
+
+    // SAFETY: the driver allocated the image memory, which stays allocated until freed below.
+    let frame = unsafe { Frame::from_image_mem(camera, mem, id as INT) }
+        .map(|frame| FrameBuf::copy_from(&frame));
+    check(unsafe { call!(is_FreeImageMem(camera.handle(), mem, id as INT)) })?;
+
+    frame
+}
+
+/// Save the active image memory to `path`, followed by a JSON sidecar holding `metadata`.
+///
+/// See [`FrameMetadata::sidecar_path`] for the location of the sidecar.
+#[cfg(feature = "sidecar")]
+pub fn save_with_sidecar(
+    camera: &Camera,
+    path: impl AsRef<Path>,
+    options: &SaveOptions,
+    metadata: &FrameMetadata,
+) -> Result<()> {
+    save(camera, path.as_ref(), options)?;
+    metadata.write_sidecar(path)
+}
@@ -0,0 +1,95 @@
+//! Additional image information.
+//!
+//! The driver records additional information along with each image, such as the device timestamp,
+//! the frame number and the state of the digital inputs. [`query`] reads all of it; a
+//! [`FrameInfo`] keeps the part needed to correlate frames, along with the host time at which the
+//! frame was received, and is attached to the frames of a
+//! [`FrameIterator`](crate::capture::FrameIterator) and a [`Queue`](crate::image_queue::Queue).
+//!
+//! # Documentation
+//! [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+use std::{mem::size_of, time::SystemTime};
+
+use ueye_sys::{
+    image_info::{is_GetImageInfo, UEYEIMAGEINFO},
+    types::{HIDS, INT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Result},
+    observer::call,
+};
+
+/// Query the additional information of the image in the image memory `mem_id`.
+#[inline]
+pub fn query(camera: &Camera, mem_id: INT) -> Result<UEYEIMAGEINFO> {
+    query_handle(camera.handle(), mem_id)
+}
+
+/// Query the additional information of the image in the image memory `mem_id` of `hcam`.
+pub(crate) fn query_handle(hcam: HIDS, mem_id: INT) -> Result<UEYEIMAGEINFO> {
+    let mut info = UEYEIMAGEINFO::default();
+    check(unsafe {
+        call!(is_GetImageInfo(hcam, mem_id, &mut info, size_of::<UEYEIMAGEINFO>() as INT))
+    })?;
+
+    Ok(info)
+}
+
+/// Query the device timestamp of the image in the image memory `mem_id` (in 0.1 µs steps).
+#[inline]
+pub fn device_timestamp(camera: &Camera, mem_id: INT) -> Result<u64> {
+    query(camera, mem_id).map(|info| info.u64TimestampDevice)
+}
+
+/// Information of a captured frame, for detecting lost frames and correlating the digital inputs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameInfo {
+    /// ID of the image memory holding the frame.
+    pub mem_id: INT,
+
+    /// Internal image counter, reset when the camera is opened and when the capture is started.
+    ///
+    /// Gaps between consecutive frames denote lost frames.
+    pub frame_number: u64,
+
+    /// Device timestamp, set when the sensor begins to read out the image (in 0.1 µs steps).
+    pub device_timestamp: u64,
+
+    /// Host time at which the frame was received.
+    pub host_timestamp: SystemTime,
+
+    /// State of the digital inputs at the time of capture, bit `n` for input `n`.
+    pub io_status: u32,
+}
+
+impl FrameInfo {
+    /// Query the information of the frame in the image memory `mem_id`, received at host time
+    /// `received`.
+    #[inline]
+    pub fn query(camera: &Camera, mem_id: INT, received: SystemTime) -> Result<Self> {
+        Self::query_handle(camera.handle(), mem_id, received)
+    }
+
+    /// Query the information of the frame in the image memory `mem_id` of `hcam`, received at
+    /// host time `received`.
+    pub(crate) fn query_handle(hcam: HIDS, mem_id: INT, received: SystemTime) -> Result<Self> {
+        let info = query_handle(hcam, mem_id)?;
+        Ok(Self {
+            mem_id,
+            frame_number: info.u64FrameNumber,
+            device_timestamp: info.u64TimestampDevice,
+            host_timestamp: received,
+            io_status: info.dwIoStatus,
+        })
+    }
+
+    /// Whether digital input `input` was high at the time of capture.
+    #[inline]
+    pub const fn input(&self, input: u32) -> bool {
+        input < u32::BITS && self.io_status & (1 << input) != 0
+    }
+}
@@ -0,0 +1,322 @@
+//! Image memories allocated by the driver or by the application.
+//!
+//! The driver writes captured images into an image memory behind the back of Rust, so an
+//! [`ImageMemory`] copies image data in and out, and only borrows it through `unsafe` accessors
+//! whose callers guarantee the camera is not capturing into it at the same time.
+//!
+//! An image memory in a buffer of the application is [owned](ImageMemory::with_buffer) by the
+//! [`ImageMemory`] while the driver knows it, and only handed back once the driver
+//! [released](ImageMemory::into_buffer) it, so the driver never writes to freed memory.
+//!
+//! # Documentation
+//! * [is_AllocImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_allocimagemem.html)
+//! * [is_SetAllocatedImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setallocatedimagemem.html)
+//! * [is_CopyImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_copyimagemem.html)
+
+use std::{mem, ptr};
+
+use ueye_sys::{
+    image_mem::{
+        is_AllocImageMem, is_CopyImageMem, is_FreeImageMem, is_GetActiveImageMem,
+        is_GetImageMemPitch, is_SetAllocatedImageMem, is_SetImageMem,
+    },
+    types::{char, INT},
+};
+
+use crate::{
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{check, Error, Result},
+    frame::{Frame, FrameBuf, FrameMut, MemGeometry},
+    observer::call,
+};
+
+/// Image memory allocated by the driver, or in a buffer of the application, released when
+/// dropped.
+#[derive(Debug)]
+pub struct ImageMemory<'c> {
+    camera: &'c Camera,
+    mem: *mut char,
+    id: INT,
+    width: usize,
+    height: usize,
+    pitch: usize,
+    bits_per_pixel: usize,
+    len: usize,
+    buffer: Option<Box<[u8]>>,
+}
+
+impl<'c> ImageMemory<'c> {
+    /// Allocate an image memory of `width` × `height` pixels in color mode `mode`.
+    ///
+    /// Fails with [`Error::NotSupported`] for [planar](ColorMode::is_planar) modes, whose images
+    /// [frames](Frame) cannot describe.
+    pub fn alloc(camera: &'c Camera, width: INT, height: INT, mode: ColorMode) -> Result<Self> {
+        if mode.is_planar() {
+            return Err(Error::NotSupported);
+        }
+
+        let mut mem: *mut char = ptr::null_mut();
+        let mut id: INT = 0;
+        check(unsafe {
+            call!(is_AllocImageMem(
+                camera.handle(),
+                width,
+                height,
+                mode.bits_per_pixel() as INT,
+                &mut mem,
+                &mut id
+            ))
+        })?;
+
+        // from here on, `Drop` frees the memory
+        Self::register(camera, mem, id, width, height, mode, None)
+    }
+
+    /// Hand `buffer` to the driver as an image memory of `width` × `height` pixels in color mode
+    /// `mode`.
+    ///
+    /// The buffer stays owned by the image memory until it is released with
+    /// [`ImageMemory::into_buffer`] or dropped. Fails with [`Error::InvalidBufferSize`] if the
+    /// buffer is smaller than the image memory, and with [`Error::NotSupported`] for
+    /// [planar](ColorMode::is_planar) modes.
+    pub fn with_buffer(
+        camera: &'c Camera,
+        buffer: impl Into<Box<[u8]>>,
+        width: INT,
+        height: INT,
+        mode: ColorMode,
+    ) -> Result<Self> {
+        if mode.is_planar() {
+            return Err(Error::NotSupported);
+        }
+
+        let mut buffer = buffer.into();
+        let line = mode.line_bytes(usize::try_from(width).unwrap_or(0));
+        if buffer.len() < line * usize::try_from(height).unwrap_or(0) {
+            return Err(Error::InvalidBufferSize);
+        }
+
+        let mem = buffer.as_mut_ptr() as *mut char;
+        let mut id: INT = 0;
+        check(unsafe {
+            call!(is_SetAllocatedImageMem(
+                camera.handle(),
+                width,
+                height,
+                mode.bits_per_pixel() as INT,
+                mem,
+                &mut id
+            ))
+        })?;
+
+        // from here on, `Drop` releases the memory from the driver before the buffer
+        let memory = Self::register(camera, mem, id, width, height, mode, Some(buffer))?;
+        if memory.buffer.as_ref().is_some_and(|buffer| buffer.len() < memory.len()) {
+            return Err(Error::InvalidBufferSize);
+        }
+
+        Ok(memory)
+    }
+
+    /// Complete an image memory known to the driver with its pitch.
+    ///
+    /// Fails with [`Error::InvalidImageSize`] if a line does not fit in the pitch, or the size of
+    /// the memory overflows [`usize`].
+    fn register(
+        camera: &'c Camera,
+        mem: *mut char,
+        id: INT,
+        width: INT,
+        height: INT,
+        mode: ColorMode,
+        buffer: Option<Box<[u8]>>,
+    ) -> Result<Self> {
+        let mut memory = Self {
+            camera,
+            mem,
+            id,
+            width: usize::try_from(width).unwrap_or(0),
+            height: usize::try_from(height).unwrap_or(0),
+            pitch: 0,
+            bits_per_pixel: mode.bits_per_pixel(),
+            len: 0,
+            buffer,
+        };
+
+        // the pitch is only reported for the active image memory
+        memory.set_active()?;
+        let mut pitch: INT = 0;
+        check(unsafe { call!(is_GetImageMemPitch(camera.handle(), &mut pitch)) })?;
+        memory.pitch = usize::try_from(pitch).unwrap_or(0);
+        memory.len = MemGeometry {
+            width: memory.width,
+            height: memory.height,
+            bits_per_pixel: memory.bits_per_pixel,
+            pitch: memory.pitch,
+        }
+        .len()?;
+
+        Ok(memory)
+    }
+
+    /// Release the image memory from the driver and hand back the buffer of the application, or
+    /// `None` if the driver allocated the memory.
+    pub fn into_buffer(mut self) -> Result<Option<Box<[u8]>>> {
+        let result =
+            check(unsafe { call!(is_FreeImageMem(self.camera.handle(), self.mem, self.id)) });
+        let buffer = self.buffer.take();
+        mem::forget(self);
+
+        match result {
+            Ok(()) => Ok(buffer),
+            Err(error) => {
+                // the driver may still write to the buffer, so it is leaked instead
+                mem::forget(buffer);
+                Err(error)
+            },
+        }
+    }
+
+    /// ID of the image memory.
+    #[inline]
+    pub const fn id(&self) -> INT {
+        self.id
+    }
+
+    /// Starting address of the image memory.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const char {
+        self.mem
+    }
+
+    /// Mutable starting address of the image memory.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut char {
+        self.mem
+    }
+
+    /// Size of the image memory, in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the image memory is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Make this the active image memory, receiving the next captured image.
+    pub fn set_active(&mut self) -> Result<()> {
+        check(unsafe { call!(is_SetImageMem(self.camera.handle(), self.mem, self.id)) })
+    }
+
+    /// Borrow the image data.
+    ///
+    /// # Safety
+    /// The camera must not capture into the image memory while the frame is borrowed, e.g. because
+    /// it was [made active](Self::set_active) or added to a sequence while live video is running.
+    /// [`ImageMemory::to_frame_buf`] copies the image data safely instead.
+    pub unsafe fn as_frame(&self) -> Result<Frame<'_>> {
+        // SAFETY: the image memory spans `len` bytes while it is allocated, and the caller
+        // guarantees the driver does not write to it for the lifetime of the frame.
+        let data = unsafe { std::slice::from_raw_parts(self.mem as *const u8, self.len()) };
+        Frame::new(data, self.width, self.height, self.pitch, self.bits_per_pixel)
+            .ok_or(Error::InvalidImageSize)
+    }
+
+    /// Mutably borrow the image data.
+    ///
+    /// # Safety
+    /// As for [`ImageMemory::as_frame`]. [`ImageMemory::write_frame`] writes image data safely
+    /// instead.
+    pub unsafe fn as_frame_mut(&mut self) -> Result<FrameMut<'_>> {
+        // SAFETY: as for `as_frame`, and `&mut self` guarantees exclusive access on our side.
+        let data = unsafe { std::slice::from_raw_parts_mut(self.mem as *mut u8, self.len()) };
+        FrameMut::new(data, self.width, self.height, self.pitch, self.bits_per_pixel)
+            .ok_or(Error::InvalidImageSize)
+    }
+
+    /// Copy the image data into a new [`FrameBuf`] using the driver.
+    pub fn to_frame_buf(&self) -> Result<FrameBuf> {
+        let mut data = vec![0; self.len()];
+        self.copy_to(&mut data)?;
+
+        let frame = Frame::new(&data, self.width, self.height, self.pitch, self.bits_per_pixel)
+            .ok_or(Error::InvalidImageSize)?;
+        Ok(FrameBuf::copy_from(&frame))
+    }
+
+    /// Write `frame` into the image memory, line by line.
+    ///
+    /// Fails with [`Error::InvalidImageSize`] if `frame` differs from the image memory in size or
+    /// bits per pixel.
+    pub fn write_frame(&mut self, frame: &Frame<'_>) -> Result<()> {
+        if (frame.width(), frame.height(), frame.bits_per_pixel())
+            != (self.width, self.height, self.bits_per_pixel)
+        {
+            return Err(Error::InvalidImageSize);
+        }
+
+        for (y, line) in frame.rows().enumerate() {
+            // SAFETY: a line fits in the pitch of the image memory, which spans `pitch * height`
+            // bytes; the bytes are copied through the pointer without borrowing the memory.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    line.as_ptr(),
+                    (self.mem as *mut u8).add(y * self.pitch),
+                    line.len(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy the image memory into `destination` using the driver.
+    ///
+    /// # Return values
+    /// [`Error::InvalidBufferSize`] if `destination` is smaller than the image memory.
+    pub fn copy_to(&self, destination: &mut [u8]) -> Result<()> {
+        if destination.len() < self.len() {
+            return Err(Error::InvalidBufferSize);
+        }
+
+        check(unsafe {
+            call!(is_CopyImageMem(
+                self.camera.handle(),
+                self.mem,
+                self.id,
+                destination.as_mut_ptr() as *mut char,
+            ))
+        })
+    }
+}
+
+impl Drop for ImageMemory<'_> {
+    fn drop(&mut self) {
+        let result =
+            check(unsafe { call!(is_FreeImageMem(self.camera.handle(), self.mem, self.id)) });
+
+        if result.is_err() {
+            // the driver may still write to the buffer, so it is leaked instead
+            mem::forget(self.buffer.take());
+        }
+    }
+}
+
+/// Query the address and ID of the active image memory of `camera`.
+///
+/// Fails with [`Error::NoActiveImgMem`] if the camera has no active image memory.
+pub(crate) fn active(camera: &Camera) -> Result<(*mut char, INT)> {
+    let mut mem: *mut char = ptr::null_mut();
+    let mut id: INT = 0;
+    check(unsafe { call!(is_GetActiveImageMem(camera.handle(), &mut mem, &mut id)) })?;
+
+    match mem.is_null() {
+        true => Err(Error::NoActiveImgMem),
+        false => Ok((mem, id)),
+    }
+}
@@ -0,0 +1,280 @@
+//! Image quality controls: saturation, sharpness and color temperature.
+//!
+//! Each control has its own driver function, but all of them are an integer with a range,
+//! increment and default. [`ImageProcessing`] addresses them uniformly by [`ProcessingControl`],
+//! so that e.g. a GUI can list the [supported](ImageProcessing::supported) controls and build a
+//! slider from each [`ParamRange`].
+//!
+//! # Documentation
+//! * [is_Saturation](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_saturation.html)
+//! * [is_Sharpness](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sharpness.html)
+//! * [is_ColorTemperature](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_colortemperature.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    color_temperature::{is_ColorTemperature, COLOR_TEMPERATURE_CMD, RGB_COLOR_MODELS},
+    saturation::{is_Saturation, SATURATION_CAPABILITY_FLAGS, SATURATION_CMD},
+    sharpness::{is_Sharpness, SHARPNESS_CAPABILITY_FLAGS, SHARPNESS_CMD},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+    parameter::{ColorTemperature, Parameter, Saturation, Sharpness},
+    range::ParamRange,
+};
+
+/// Image quality control.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessingControl {
+    /// Color saturation.
+    Saturation,
+
+    /// Sharpness.
+    Sharpness,
+
+    /// Color temperature (in Kelvin) of the color conversion.
+    ColorTemperature,
+}
+
+impl ProcessingControl {
+    /// All image quality controls.
+    pub const ALL: [ProcessingControl; 3] = [
+        ProcessingControl::Saturation,
+        ProcessingControl::Sharpness,
+        ProcessingControl::ColorTemperature,
+    ];
+
+    /// Display name of the control.
+    pub const fn name(self) -> &'static str {
+        match self {
+            ProcessingControl::Saturation => "Saturation",
+            ProcessingControl::Sharpness => "Sharpness",
+            ProcessingControl::ColorTemperature => "Color temperature",
+        }
+    }
+
+    /// Unit of the control values, if any.
+    pub const fn unit(self) -> Option<&'static str> {
+        match self {
+            ProcessingControl::ColorTemperature => Some("K"),
+            _ => None,
+        }
+    }
+}
+
+/// Image quality controls of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct ImageProcessing<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the image quality controls of the camera.
+    #[inline]
+    pub fn image_processing(&self) -> ImageProcessing<'_> {
+        ImageProcessing { camera: self }
+    }
+}
+
+impl ImageProcessing<'_> {
+    fn saturation_cmd(&self, cmd: SATURATION_CMD, value: &mut INT) -> Result<()> {
+        check(unsafe {
+            command!(is_Saturation(
+                self.camera.handle(),
+                cmd,
+                value as *mut INT as *mut void,
+                size_of::<INT>() as UINT,
+            ))
+        })
+    }
+
+    fn sharpness_cmd(&self, cmd: SHARPNESS_CMD, value: &mut INT) -> Result<()> {
+        check(unsafe {
+            command!(is_Sharpness(
+                self.camera.handle(),
+                cmd,
+                value as *mut INT as *mut void,
+                size_of::<INT>() as UINT,
+            ))
+        })
+    }
+
+    fn color_temperature_cmd(&self, cmd: COLOR_TEMPERATURE_CMD, value: &mut UINT) -> Result<()> {
+        check(unsafe {
+            command!(is_ColorTemperature(
+                self.camera.handle(),
+                cmd,
+                value as *mut UINT as *mut void,
+                size_of::<UINT>() as UINT,
+            ))
+        })
+    }
+
+    /// Query whether the camera supports `control`.
+    pub fn is_supported(&self, control: ProcessingControl) -> Result<bool> {
+        let result = match control {
+            ProcessingControl::Saturation => {
+                let mut caps: INT = 0;
+                self.saturation_cmd(SATURATION_CMD::SATURATION_CMD_GET_CAPABILITIES, &mut caps).map(
+                    |()| {
+                        SATURATION_CAPABILITY_FLAGS::from_bits_retain(caps as UINT).contains(
+                            SATURATION_CAPABILITY_FLAGS::SATURATION_CAP_SATURATION_SUPPORTED,
+                        )
+                    },
+                )
+            },
+            ProcessingControl::Sharpness => {
+                let mut caps: INT = 0;
+                self.sharpness_cmd(SHARPNESS_CMD::SHARPNESS_CMD_GET_CAPABILITIES, &mut caps).map(
+                    |()| {
+                        SHARPNESS_CAPABILITY_FLAGS::from_bits_retain(caps as UINT)
+                            .contains(SHARPNESS_CAPABILITY_FLAGS::SHARPNESS_CAP_SHARPNESS_SUPPORTED)
+                    },
+                )
+            },
+            ProcessingControl::ColorTemperature => {
+                self.supported_rgb_color_models().map(|models| !models.is_empty())
+            },
+        };
+
+        match result {
+            Err(Error::NotSupported) => Ok(false),
+            result => result,
+        }
+    }
+
+    /// Query the controls supported by the camera.
+    pub fn supported(&self) -> Result<Vec<ProcessingControl>> {
+        let mut controls = Vec::new();
+        for control in ProcessingControl::ALL {
+            if self.is_supported(control)? {
+                controls.push(control);
+            }
+        }
+
+        Ok(controls)
+    }
+
+    /// Query the range of `control`.
+    pub fn range(&self, control: ProcessingControl) -> Result<ParamRange<i32>> {
+        match control {
+            ProcessingControl::Saturation => Saturation::range(self.camera),
+            ProcessingControl::Sharpness => Sharpness::range(self.camera),
+            ProcessingControl::ColorTemperature => {
+                let range = ColorTemperature::range(self.camera)?;
+                Ok(ParamRange {
+                    min: range.min as i32,
+                    max: range.max as i32,
+                    inc: range.inc as i32,
+                    default: range.default.map(|default| default as i32),
+                })
+            },
+        }
+    }
+
+    /// Query the current value of `control`.
+    pub fn get(&self, control: ProcessingControl) -> Result<i32> {
+        match control {
+            ProcessingControl::Saturation => Saturation::get(self.camera),
+            ProcessingControl::Sharpness => Sharpness::get(self.camera),
+            ProcessingControl::ColorTemperature => {
+                ColorTemperature::get(self.camera).map(|kelvin| kelvin as i32)
+            },
+        }
+    }
+
+    /// Set `control` to `value`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `value` is not [within](ParamRange::contains)
+    /// the range of the control.
+    pub fn set(&self, control: ProcessingControl, value: i32) -> Result<()> {
+        match control {
+            ProcessingControl::Saturation => Saturation::set(self.camera, value),
+            ProcessingControl::Sharpness => Sharpness::set(self.camera, value),
+            ProcessingControl::ColorTemperature => {
+                let kelvin = UINT::try_from(value).map_err(|_| Error::InvalidParameter)?;
+                ColorTemperature::set(self.camera, kelvin)
+            },
+        }
+    }
+
+    /// Reset `control` to its default value.
+    pub fn reset(&self, control: ProcessingControl) -> Result<()> {
+        match control {
+            ProcessingControl::Saturation => Saturation::reset(self.camera),
+            ProcessingControl::Sharpness => Sharpness::reset(self.camera),
+            ProcessingControl::ColorTemperature => ColorTemperature::reset(self.camera),
+        }
+    }
+
+    /// Query the color saturation.
+    #[inline]
+    pub fn saturation(&self) -> Result<i32> {
+        self.get(ProcessingControl::Saturation)
+    }
+
+    /// Set the color saturation.
+    #[inline]
+    pub fn set_saturation(&self, value: i32) -> Result<()> {
+        self.set(ProcessingControl::Saturation, value)
+    }
+
+    /// Query the sharpness.
+    #[inline]
+    pub fn sharpness(&self) -> Result<i32> {
+        self.get(ProcessingControl::Sharpness)
+    }
+
+    /// Set the sharpness.
+    #[inline]
+    pub fn set_sharpness(&self, value: i32) -> Result<()> {
+        self.set(ProcessingControl::Sharpness, value)
+    }
+
+    /// Query the color temperature (in Kelvin).
+    #[inline]
+    pub fn color_temperature(&self) -> Result<i32> {
+        self.get(ProcessingControl::ColorTemperature)
+    }
+
+    /// Set the color temperature (in Kelvin).
+    #[inline]
+    pub fn set_color_temperature(&self, kelvin: i32) -> Result<()> {
+        self.set(ProcessingControl::ColorTemperature, kelvin)
+    }
+
+    /// Query the RGB color models supported for the color temperature.
+    pub fn supported_rgb_color_models(&self) -> Result<RGB_COLOR_MODELS> {
+        let mut models: UINT = 0;
+        self.color_temperature_cmd(
+            COLOR_TEMPERATURE_CMD::COLOR_TEMPERATURE_CMD_GET_SUPPORTED_RGB_COLOR_MODELS,
+            &mut models,
+        )?;
+
+        Ok(RGB_COLOR_MODELS::from_bits_retain(models))
+    }
+
+    /// Query the RGB color model of the color temperature.
+    pub fn rgb_color_model(&self) -> Result<RGB_COLOR_MODELS> {
+        let mut model: UINT = 0;
+        self.color_temperature_cmd(
+            COLOR_TEMPERATURE_CMD::COLOR_TEMPERATURE_CMD_GET_RGB_COLOR_MODEL,
+            &mut model,
+        )?;
+
+        Ok(RGB_COLOR_MODELS::from_bits_retain(model))
+    }
+
+    /// Set the RGB color model of the color temperature.
+    pub fn set_rgb_color_model(&self, model: RGB_COLOR_MODELS) -> Result<()> {
+        self.color_temperature_cmd(
+            COLOR_TEMPERATURE_CMD::COLOR_TEMPERATURE_CMD_SET_RGB_COLOR_MODEL,
+            &mut model.bits(),
+        )
+    }
+}
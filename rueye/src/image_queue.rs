@@ -0,0 +1,270 @@
+//! Queue mode for the image memories of a ring buffer.
+//!
+//! In queue mode, the driver queues every image memory of the sequence once it has been filled,
+//! so frames are taken in the order they were captured instead of only the last one. The queue
+//! holds at most as many frames as there are image memories, [`QueueOptions::buffers`]; once
+//! every memory is queued or locked, [`QueueOptions::overflow`] decides which frames are kept.
+//!
+//! A [`Queue`] enables queue mode on a [`RingBuffer`] and starts live capture. Each frame taken
+//! from it is held in a [`QueuedFrame`], whose image memory stays locked until it is dropped, along
+//! with its [`FrameInfo`].
+//!
+//! The free functions of this module require queue mode to be enabled.
+//!
+//! # Documentation
+//! [is_ImageQueue](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagequeue.html)
+
+use std::{
+    mem::size_of,
+    ptr,
+    time::{Duration, SystemTime},
+};
+
+use ueye_sys::{
+    image_queue::{is_ImageQueue, IMAGEQUEUE_WAIT_BUFFER, IMAGE_QUEUE_CMD},
+    types::{char, void, INT, UINT},
+    video::{is_CaptureVideo, is_StopLiveVideo, IS_DONT_WAIT, IS_FORCE_VIDEO_STOP},
+};
+
+use crate::{
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{check, Result},
+    frame::Frame,
+    image_info::FrameInfo,
+    metadata::ImageMetadata,
+    observer::{call, command},
+    ring_buffer::RingBuffer,
+};
+
+/// Frames kept once the queue is full.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Keep the queued frames; new frames are lost until one is taken (_driver behaviour_).
+    #[default]
+    KeepOldest,
+
+    /// Discard all but the newest queued frame whenever one is taken (_lowest latency_).
+    KeepNewest,
+}
+
+impl Overflow {
+    /// Apply this overflow behaviour to the queue before taking a frame.
+    pub fn apply(self, camera: &Camera) -> Result<()> {
+        match self {
+            Self::KeepOldest => Ok(()),
+            Self::KeepNewest => match pending(camera)? {
+                0 | 1 => Ok(()),
+                pending => discard(camera, pending - 1),
+            },
+        }
+    }
+}
+
+/// Configuration of the image queue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct QueueOptions {
+    /// Number of image memories in the sequence, and thus the most frames queued at once (at
+    /// least one).
+    pub buffers: usize,
+
+    /// Color mode of the image memories, matching the color mode set on the camera.
+    pub color_mode: ColorMode,
+
+    /// Frames kept once the queue is full.
+    pub overflow: Overflow,
+
+    /// Longest time to wait for a frame.
+    pub timeout: Duration,
+}
+
+impl Default for QueueOptions {
+    fn default() -> Self {
+        Self {
+            buffers: 4,
+            color_mode: ColorMode::Mono8,
+            overflow: Overflow::default(),
+            timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Query the number of frames in the queue.
+pub fn pending(camera: &Camera) -> Result<usize> {
+    let mut pending: UINT = 0;
+    check(unsafe {
+        command!(is_ImageQueue(
+            camera.handle(),
+            IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_GET_PENDING,
+            &mut pending as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(pending as usize)
+}
+
+/// Discard the `count` oldest frames in the queue.
+pub fn discard(camera: &Camera, count: usize) -> Result<()> {
+    let mut count = UINT::try_from(count).unwrap_or(UINT::MAX);
+    check(unsafe {
+        command!(is_ImageQueue(
+            camera.handle(),
+            IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_DISCARD_N_ITEMS,
+            &mut count as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })
+}
+
+/// Discard all frames in the queue.
+pub fn flush(camera: &Camera) -> Result<()> {
+    check(unsafe {
+        command!(is_ImageQueue(
+            camera.handle(),
+            IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_FLUSH,
+            ptr::null_mut(),
+            0
+        ))
+    })
+}
+
+/// Live capture in queue mode, stopped when dropped.
+#[derive(Debug)]
+pub struct Queue<'c> {
+    camera: &'c Camera,
+    ring: RingBuffer<'c>,
+    options: QueueOptions,
+}
+
+impl<'c> Queue<'c> {
+    /// Allocate the ring buffer for the current AOI, enable queue mode and start live capture.
+    pub fn new(camera: &'c Camera, options: &QueueOptions) -> Result<Self> {
+        let ring = RingBuffer::alloc(camera, options.buffers, options.color_mode)?;
+
+        let mut mode: UINT = 0;
+        check(unsafe {
+            command!(is_ImageQueue(
+                camera.handle(),
+                IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_INIT,
+                &mut mode as *mut UINT as *mut void,
+                size_of::<UINT>() as UINT,
+            ))
+        })?;
+
+        // from here on, `Drop` undoes the queue mode and the live capture
+        let queue = Self { camera, ring, options: *options };
+        check(unsafe { call!(is_CaptureVideo(camera.handle(), IS_DONT_WAIT as INT)) })?;
+
+        Ok(queue)
+    }
+
+    /// Wait up to `timeout` for the next frame in the queue, taking it out of the queue.
+    ///
+    /// The [`QueueOptions::overflow`] behaviour is applied before waiting.
+    pub fn next(&self, timeout: Duration) -> Result<QueuedFrame<'_>> {
+        self.options.overflow.apply(self.camera)?;
+
+        let mut mem: *mut char = ptr::null_mut();
+        let mut id: INT = 0;
+        let mut wait = IMAGEQUEUE_WAIT_BUFFER {
+            timeout: timeout.as_millis().try_into().unwrap_or(UINT::MAX),
+            ppcMem: &mut mem,
+            pnMemId: &mut id,
+        };
+        check(unsafe {
+            command!(is_ImageQueue(
+                self.camera.handle(),
+                IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_WAIT,
+                &mut wait as *mut IMAGEQUEUE_WAIT_BUFFER as *mut void,
+                size_of::<IMAGEQUEUE_WAIT_BUFFER>() as UINT,
+            ))
+        })?;
+
+        // the driver locks the image memory it hands out
+        let received = SystemTime::now();
+        let position = self.ring.adopt_lock(mem)?;
+        let info = self.ring.frame_info(position, received).inspect_err(|_| {
+            let _ = self.ring.unlock(position);
+        })?;
+
+        Ok(QueuedFrame { ring: &self.ring, position, info })
+    }
+
+    /// Wait for the next frame in the queue, up to the [`QueueOptions::timeout`].
+    #[inline]
+    pub fn next_frame(&self) -> Result<QueuedFrame<'_>> {
+        self.next(self.options.timeout)
+    }
+
+    /// Query the number of frames in the queue.
+    #[inline]
+    pub fn pending(&self) -> Result<usize> {
+        pending(self.camera)
+    }
+
+    /// Discard all frames in the queue.
+    #[inline]
+    pub fn flush(&self) -> Result<()> {
+        flush(self.camera)
+    }
+}
+
+impl Drop for Queue<'_> {
+    fn drop(&mut self) {
+        unsafe { call!(is_StopLiveVideo(self.camera.handle(), IS_FORCE_VIDEO_STOP as INT)) };
+        let _ = check(unsafe {
+            command!(is_ImageQueue(
+                self.camera.handle(),
+                IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_EXIT,
+                ptr::null_mut(),
+                0
+            ))
+        });
+    }
+}
+
+/// Frame taken from a [`Queue`], held in a locked image memory.
+///
+/// The image memory is unlocked when the frame is dropped.
+#[derive(Debug)]
+pub struct QueuedFrame<'q> {
+    ring: &'q RingBuffer<'q>,
+    position: usize,
+    info: FrameInfo,
+}
+
+impl QueuedFrame<'_> {
+    /// ID of the image memory holding the frame.
+    #[inline]
+    pub fn mem_id(&self) -> INT {
+        self.ring.id(self.position).expect("queued positions are in range")
+    }
+
+    /// Information of the frame, recorded when it was taken out of the queue.
+    #[inline]
+    pub fn info(&self) -> &FrameInfo {
+        &self.info
+    }
+
+    /// Query the metadata of the frame.
+    #[inline]
+    pub fn metadata(&self) -> Result<ImageMetadata> {
+        self.ring.metadata(self.position)
+    }
+
+    /// Borrow the image data.
+    ///
+    /// The image memory is locked against writes by the driver until this frame is dropped.
+    #[inline]
+    pub fn as_frame(&self) -> Result<Frame<'_>> {
+        // SAFETY: the memory is only unlocked when this frame is dropped, after the borrow ends.
+        unsafe { self.ring.frame(self.position) }
+    }
+}
+
+impl Drop for QueuedFrame<'_> {
+    fn drop(&mut self) {
+        let _ = self.ring.unlock(self.position);
+    }
+}
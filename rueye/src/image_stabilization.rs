@@ -0,0 +1,94 @@
+//! Electronic image stabilization of the sensor.
+//!
+//! With image stabilization enabled, the sensor steadies the image against slight camera shake,
+//! e.g. of handheld or vehicle-mounted cameras. Only some sensors offer it, so
+//! [`ImageStabilization::set_enabled`] probes [`ImageStabilization::supported`] first and fails with
+//! [`Error::NotSupported`] on the others.
+//!
+//! # Documentation
+//! [is_ImageStabilization](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagestabilization.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    image_stabilization::{is_ImageStabilization, IMGSTAB_CAPABILITY_FLAGS, IS_IMGSTAB_CMD},
+    types::{void, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Image stabilization of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct ImageStabilization<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the image stabilization of the camera.
+    #[inline]
+    pub fn image_stabilization(&self) -> ImageStabilization<'_> {
+        ImageStabilization { camera: self }
+    }
+}
+
+impl ImageStabilization<'_> {
+    fn command(&self, cmd: IS_IMGSTAB_CMD, value: &mut UINT) -> Result<()> {
+        check(unsafe {
+            command!(is_ImageStabilization(
+                self.camera.handle(),
+                cmd,
+                value as *mut UINT as *mut void,
+                size_of::<UINT>() as UINT,
+            ))
+        })
+    }
+
+    /// Query whether the sensor supports image stabilization.
+    pub fn supported(&self) -> Result<bool> {
+        let mut caps: UINT = 0;
+        match self.command(IS_IMGSTAB_CMD::IMGSTAB_CMD_GET_CAPABILITIES, &mut caps) {
+            Ok(()) => Ok(IMGSTAB_CAPABILITY_FLAGS::from_bits_retain(caps)
+                .contains(IMGSTAB_CAPABILITY_FLAGS::IMGSTAB_CAP_IMAGE_STABILIZATION_SUPPORTED)),
+            Err(Error::NotSupported) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Query whether image stabilization is enabled.
+    pub fn is_enabled(&self) -> Result<bool> {
+        let mut enabled: UINT = 0;
+        self.command(IS_IMGSTAB_CMD::IMGSTAB_CMD_GET_ENABLE, &mut enabled)?;
+        Ok(enabled != 0)
+    }
+
+    /// Enable or disable image stabilization.
+    ///
+    /// Fails with [`Error::NotSupported`] if the sensor does not support it.
+    pub fn set_enabled(&self, enable: bool) -> Result<()> {
+        if !self.supported()? {
+            return Err(Error::NotSupported);
+        }
+
+        let cmd = match enable {
+            true => IS_IMGSTAB_CMD::IMGSTAB_CMD_SET_ENABLE,
+            false => IS_IMGSTAB_CMD::IMGSTAB_CMD_SET_DISABLE,
+        };
+        self.command(cmd, &mut 0)
+    }
+
+    /// Enable image stabilization, see [`ImageStabilization::set_enabled`].
+    #[inline]
+    pub fn enable(&self) -> Result<()> {
+        self.set_enabled(true)
+    }
+
+    /// Disable image stabilization, see [`ImageStabilization::set_enabled`].
+    #[inline]
+    pub fn disable(&self) -> Result<()> {
+        self.set_enabled(false)
+    }
+}
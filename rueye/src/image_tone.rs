@@ -0,0 +1,304 @@
+//! Gamma correction and lookup tables (LUTs).
+//!
+//! Both map the brightness of each pixel through a curve: gamma through a power function, a LUT
+//! through 64 knots (_0.0…1.0_) with linear interpolation in between. [`ImageTone`] combines
+//! both functions, and [`Lut`] builds the knots of a user LUT from any transfer function.
+//!
+//! Neither gamma nor the LUT apply to RAW formats, unless the camera is
+//! [allowed](ImageTone::set_raw_with_lut) to apply its LUT to them.
+//!
+//! # Documentation
+//! * [is_Gamma](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_gamma.html)
+//! * [is_LUT](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_lut.html)
+//! * [Using camera LUT with RAW formats](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeaturelut.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    mem::{size_of, MaybeUninit},
+};
+
+use ueye_sys::{
+    gamma::{is_Gamma, GAMMA_CMD, IS_GAMMA_VALUE_MAX, IS_GAMMA_VALUE_MIN},
+    lut::{
+        is_LUT, IS_LUT_64, IS_LUT_CONFIGURATION_64, IS_LUT_SUPPORT_INFO, LUT_CMD, LUT_MODE,
+        LUT_STATE_ID,
+    },
+    types::{void, BOOL, FALSE, INT, TRUE, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Where the camera applies its LUT and gamma.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LutMode {
+    /// In hardware if supported, otherwise in software (_default_).
+    #[default]
+    Default,
+
+    /// In hardware only.
+    ForceHardware,
+
+    /// In software only.
+    ForceSoftware,
+}
+
+impl LutMode {
+    /// Every LUT mode.
+    pub const ALL: [LutMode; 3] =
+        [LutMode::Default, LutMode::ForceHardware, LutMode::ForceSoftware];
+
+    /// Value of the mode for [`is_LUT`].
+    pub const fn bits(self) -> LUT_MODE {
+        match self {
+            LutMode::Default => LUT_MODE::IS_LUT_MODE_ID_DEFAULT,
+            LutMode::ForceHardware => LUT_MODE::IS_LUT_MODE_ID_FORCE_HARDWARE,
+            LutMode::ForceSoftware => LUT_MODE::IS_LUT_MODE_ID_FORCE_SOFTWARE,
+        }
+    }
+}
+
+impl TryFrom<UINT> for LutMode {
+    type Error = Error;
+
+    fn try_from(value: UINT) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.bits() as UINT == value)
+            .ok_or(Error::UnexpectedValue(value as INT))
+    }
+}
+
+impl Display for LutMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LutMode::Default => "default",
+            LutMode::ForceHardware => "hardware",
+            LutMode::ForceSoftware => "software",
+        })
+    }
+}
+
+/// State of the LUT.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LutState {
+    /// Whether the LUT is enabled.
+    pub enabled: bool,
+
+    /// Where the LUT and gamma are applied, if at all.
+    pub state: LUT_STATE_ID,
+
+    /// Requested LUT mode.
+    pub mode: LutMode,
+
+    /// Bits of the LUT in use.
+    pub bits: i32,
+}
+
+/// Layout of `IS_LUT_STATE`, with the enumerations as their raw values, since the driver may
+/// report values which they lack.
+#[repr(C)]
+struct RawLutState {
+    enabled: BOOL,
+    state: UINT,
+    mode: UINT,
+    bits: INT,
+}
+
+/// User LUT of 64 knots, each with a value for the red, green and blue channel (`0.0`…`1.0`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Lut {
+    /// Output values at the knots, which are spaced evenly over the input range.
+    pub knots: [[f64; 3]; IS_LUT_64],
+}
+
+impl Default for Lut {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Lut {
+    /// Linear LUT, leaving the image unchanged.
+    pub fn identity() -> Self {
+        Self::from_fn(|x| x)
+    }
+
+    /// LUT of the transfer function `f`, applied to all channels.
+    ///
+    /// `f` is sampled at the knots (`0.0`…`1.0`), and its results clamped to `0.0`…`1.0`.
+    pub fn from_fn(f: impl Fn(f64) -> f64) -> Self {
+        Self::from_channels(|x| [f(x); 3])
+    }
+
+    /// LUT of the transfer function `f`, returning a value per channel (red, green, blue).
+    ///
+    /// `f` is sampled at the knots (`0.0`…`1.0`), and its results clamped to `0.0`…`1.0`.
+    pub fn from_channels(f: impl Fn(f64) -> [f64; 3]) -> Self {
+        let last = (IS_LUT_64 - 1) as f64;
+        Self {
+            knots: std::array::from_fn(|knot| {
+                f(knot as f64 / last).map(|value| value.clamp(0.0, 1.0))
+            }),
+        }
+    }
+
+    /// Whether all channels share the same curve.
+    pub fn is_uniform(&self) -> bool {
+        self.knots.iter().all(|[red, green, blue]| red == green && green == blue)
+    }
+}
+
+impl From<Lut> for IS_LUT_CONFIGURATION_64 {
+    fn from(lut: Lut) -> Self {
+        Self {
+            dblValues: lut.knots,
+            bAllChannelsAreEqual: if lut.is_uniform() { TRUE } else { FALSE },
+        }
+    }
+}
+
+impl From<IS_LUT_CONFIGURATION_64> for Lut {
+    #[inline]
+    fn from(config: IS_LUT_CONFIGURATION_64) -> Self {
+        Self { knots: config.dblValues }
+    }
+}
+
+/// Gamma correction and LUT of a camera.
+#[derive(Debug, Copy, Clone)]
+pub struct ImageTone<'c> {
+    camera: &'c Camera,
+}
+
+impl Camera {
+    /// Access the gamma correction and LUT of the camera.
+    #[inline]
+    pub fn image_tone(&self) -> ImageTone<'_> {
+        ImageTone { camera: self }
+    }
+}
+
+impl ImageTone<'_> {
+    fn gamma_command(&self, cmd: GAMMA_CMD, value: &mut INT) -> Result<()> {
+        check(unsafe {
+            command!(is_Gamma(
+                self.camera.handle(),
+                cmd,
+                value as *mut INT as *mut void,
+                size_of::<INT>() as UINT,
+            ))
+        })
+    }
+
+    fn lut_command<T>(&self, cmd: LUT_CMD, param: &mut T) -> Result<()> {
+        check(unsafe {
+            command!(is_LUT(
+                self.camera.handle(),
+                cmd,
+                param as *mut T as *mut void,
+                size_of::<T>() as UINT,
+            ))
+        })
+    }
+
+    fn get_lut(&self, cmd: LUT_CMD) -> Result<Lut> {
+        let mut config =
+            IS_LUT_CONFIGURATION_64 { dblValues: [[0.0; 3]; IS_LUT_64], bAllChannelsAreEqual: 0 };
+        self.lut_command(cmd, &mut config)?;
+        Ok(config.into())
+    }
+
+    /// Query the gamma value (`1.0` = linear).
+    pub fn gamma(&self) -> Result<f32> {
+        let mut value: INT = 0;
+        self.gamma_command(GAMMA_CMD::IS_GAMMA_CMD_GET, &mut value)?;
+        Ok(value as f32 / 100.0)
+    }
+
+    /// Query the default gamma value.
+    pub fn default_gamma(&self) -> Result<f32> {
+        let mut value: INT = 0;
+        self.gamma_command(GAMMA_CMD::IS_GAMMA_CMD_GET_DEFAULT, &mut value)?;
+        Ok(value as f32 / 100.0)
+    }
+
+    /// Set the gamma value (`0.01`…`10.0`, `1.0` = linear), rounded to two decimals.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `gamma` is out of range.
+    pub fn set_gamma(&self, gamma: f32) -> Result<()> {
+        let mut value = (gamma * 100.0).round() as INT;
+        if !(IS_GAMMA_VALUE_MIN..=IS_GAMMA_VALUE_MAX).contains(&value) {
+            return Err(Error::InvalidParameter);
+        }
+
+        self.gamma_command(GAMMA_CMD::IS_GAMMA_CMD_SET, &mut value)
+    }
+
+    /// Query whether the camera supports a hardware or software LUT.
+    pub fn lut_support(&self) -> Result<IS_LUT_SUPPORT_INFO> {
+        // SAFETY: `IS_LUT_SUPPORT_INFO` is plain data, for which all-zero is a valid value.
+        let mut info = unsafe { MaybeUninit::<IS_LUT_SUPPORT_INFO>::zeroed().assume_init() };
+        self.lut_command(LUT_CMD::IS_LUT_CMD_GET_SUPPORT_INFO, &mut info)?;
+        Ok(info)
+    }
+
+    /// Query the state of the LUT.
+    ///
+    /// Fails with [`Error::UnexpectedValue`] if the driver reports an unknown LUT mode.
+    pub fn lut_state(&self) -> Result<LutState> {
+        let mut state = RawLutState { enabled: FALSE, state: 0, mode: 0, bits: 0 };
+        self.lut_command(LUT_CMD::IS_LUT_CMD_GET_STATE, &mut state)?;
+
+        Ok(LutState {
+            enabled: state.enabled != FALSE,
+            state: LUT_STATE_ID::from_bits_retain(state.state),
+            mode: state.mode.try_into()?,
+            bits: state.bits,
+        })
+    }
+
+    /// Enable or disable the LUT.
+    pub fn set_lut_enabled(&self, enable: bool) -> Result<()> {
+        self.lut_command(LUT_CMD::IS_LUT_CMD_SET_ENABLED, &mut UINT::from(enable))
+    }
+
+    /// Upload `lut` as user LUT.
+    pub fn set_lut(&self, lut: &Lut) -> Result<()> {
+        self.lut_command(LUT_CMD::IS_LUT_CMD_SET_USER_LUT, &mut IS_LUT_CONFIGURATION_64::from(*lut))
+    }
+
+    /// Upload the LUT of the transfer function `f` as user LUT, see [`Lut::from_fn`].
+    #[inline]
+    pub fn set_lut_fn(&self, f: impl Fn(f64) -> f64) -> Result<()> {
+        self.set_lut(&Lut::from_fn(f))
+    }
+
+    /// Query the user LUT.
+    #[inline]
+    pub fn user_lut(&self) -> Result<Lut> {
+        self.get_lut(LUT_CMD::IS_LUT_CMD_GET_USER_LUT)
+    }
+
+    /// Query the LUT currently applied by the camera, combining the user LUT and gamma.
+    #[inline]
+    pub fn active_lut(&self) -> Result<Lut> {
+        self.get_lut(LUT_CMD::IS_LUT_CMD_GET_COMPLETE_LUT)
+    }
+
+    /// Query whether the LUT is also applied to RAW formats.
+    #[inline]
+    pub fn raw_with_lut(&self) -> Result<bool> {
+        self.camera.device_features().raw_with_lut()
+    }
+
+    /// Allow or disallow applying the LUT to RAW formats.
+    #[inline]
+    pub fn set_raw_with_lut(&self, allow: bool) -> Result<()> {
+        self.camera.device_features().set_raw_with_lut(allow)
+    }
+}
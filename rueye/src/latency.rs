@@ -0,0 +1,253 @@
+//! Per-frame latency measurements.
+//!
+//! The latency of a frame is split into two stages:
+//! 1. _Exposure end → event signaled_: from the end of the exposure until the application is
+//!    notified of the frame, covering readout, transfer and driver processing.
+//! 2. _Event signaled → callback_: from the notification until the application starts processing
+//!    the frame, covering scheduling and queueing in the application.
+//!
+//! The end of the exposure is taken from the device timestamp, which marks the start of the sensor
+//! readout, converted to host time through a [`DeviceClock`]. [`LatencyStats`] estimates the clock
+//! with an [`OffsetEstimator`] from the frames it records; as the estimate assumes the fastest
+//! frame seen so far arrived without delay, the first stage then measures the latency on top of
+//! that of the fastest frame.
+//!
+//! # Documentation
+//! [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ueye_sys::{image_info::UEYEIMAGEINFO, types::INT};
+
+use crate::{
+    camera::Camera,
+    error::Result,
+    image_info,
+    timestamp::{DeviceClock, OffsetEstimator},
+};
+
+/// Latency of a single frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameLatency {
+    /// Frame number reported by the driver.
+    pub frame_number: u64,
+
+    /// Time from the end of the exposure until the event was signaled.
+    pub exposure_to_event: Duration,
+
+    /// Time from the event being signaled until the callback started.
+    pub event_to_callback: Duration,
+}
+
+impl FrameLatency {
+    /// Measure the latency of the frame described by `info`.
+    ///
+    /// # Input parameters
+    /// * `info` - Image information of the frame.
+    /// * `clock` - Device clock of the camera.
+    /// * `signaled` - Host time at which the event for the frame was signaled.
+    /// * `callback` - Host time at which the callback processing the frame started.
+    pub fn measure(
+        info: &UEYEIMAGEINFO,
+        clock: &DeviceClock,
+        signaled: SystemTime,
+        callback: SystemTime,
+    ) -> Self {
+        let exposure_end = clock.system_time(info.u64TimestampDevice);
+        Self {
+            frame_number: info.u64FrameNumber,
+            exposure_to_event: signaled.duration_since(exposure_end).unwrap_or_default(),
+            event_to_callback: callback.duration_since(signaled).unwrap_or_default(),
+        }
+    }
+
+    /// Time from the end of the exposure until the callback started.
+    #[inline]
+    pub fn total(&self) -> Duration {
+        self.exposure_to_event + self.event_to_callback
+    }
+}
+
+/// Summary of the latencies of a stage over all recorded frames.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatencySummary {
+    /// Number of recorded frames.
+    pub count: u64,
+
+    /// Shortest latency.
+    pub min: Duration,
+
+    /// Longest latency.
+    pub max: Duration,
+
+    /// Mean latency.
+    pub mean: Duration,
+
+    /// Latency of the last recorded frame.
+    pub last: Duration,
+}
+
+/// Running summary of a stage.
+#[derive(Debug, Copy, Clone, Default)]
+struct Accumulator {
+    count: u64,
+    min: Duration,
+    max: Duration,
+    sum: Duration,
+    last: Duration,
+}
+
+impl Accumulator {
+    fn add(&mut self, latency: Duration) {
+        if self.count == 0 || latency < self.min {
+            self.min = latency;
+        }
+        self.max = self.max.max(latency);
+        self.sum = self.sum.saturating_add(latency);
+        self.last = latency;
+        self.count += 1;
+    }
+
+    fn summary(&self) -> Option<LatencySummary> {
+        (self.count > 0).then(|| LatencySummary {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.sum.div_f64(self.count as f64),
+            last: self.last,
+        })
+    }
+}
+
+/// Latency statistics over a sequence of frames.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LatencyStats {
+    clock: OffsetEstimator,
+    exposure_to_event: Accumulator,
+    event_to_callback: Accumulator,
+    total: Accumulator,
+}
+
+impl LatencyStats {
+    /// Create empty statistics.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the latency of a frame.
+    pub fn add(&mut self, latency: &FrameLatency) {
+        self.exposure_to_event.add(latency.exposure_to_event);
+        self.event_to_callback.add(latency.event_to_callback);
+        self.total.add(latency.total());
+    }
+
+    /// Measure and add the latency of the frame described by `info`, taking the current time as
+    /// the start of the callback.
+    ///
+    /// The frame also refines the estimated device clock.
+    pub fn record(&mut self, info: &UEYEIMAGEINFO, signaled: SystemTime) -> FrameLatency {
+        self.clock.record(info.u64TimestampDevice, signaled);
+
+        // without an estimate, the timestamp lies beyond the host time since the epoch
+        let clock = self.clock.clock().unwrap_or(DeviceClock::new(UNIX_EPOCH));
+        let latency = FrameLatency::measure(info, &clock, signaled, SystemTime::now());
+        self.add(&latency);
+        latency
+    }
+
+    /// Measure and add the latency of the frame in the image memory `mem_id`, taking the current
+    /// time as the start of the callback.
+    ///
+    /// `signaled` is the host time at which the wait for the frame returned.
+    pub fn record_frame(
+        &mut self,
+        camera: &Camera,
+        mem_id: INT,
+        signaled: SystemTime,
+    ) -> Result<FrameLatency> {
+        let info = image_info::query(camera, mem_id)?;
+        Ok(self.record(&info, signaled))
+    }
+
+    /// Summary of the exposure end → event signaled stage, or `None` before the first frame.
+    #[inline]
+    pub fn exposure_to_event(&self) -> Option<LatencySummary> {
+        self.exposure_to_event.summary()
+    }
+
+    /// Summary of the event signaled → callback stage, or `None` before the first frame.
+    #[inline]
+    pub fn event_to_callback(&self) -> Option<LatencySummary> {
+        self.event_to_callback.summary()
+    }
+
+    /// Summary of the end-to-end latency, or `None` before the first frame.
+    #[inline]
+    pub fn total(&self) -> Option<LatencySummary> {
+        self.total.summary()
+    }
+
+    /// Estimated device clock, if any frame was recorded.
+    #[inline]
+    pub fn clock(&self) -> Option<DeviceClock> {
+        self.clock.clock()
+    }
+
+    /// Discard all recorded frames and the estimated device clock, e.g. after the camera was
+    /// reset.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(frame_number: u64, seconds: u64) -> UEYEIMAGEINFO {
+        let mut info = UEYEIMAGEINFO::default();
+        info.u64FrameNumber = frame_number;
+        info.u64TimestampDevice = seconds * crate::timestamp::TICKS_PER_SECOND;
+        info
+    }
+
+    #[test]
+    fn measure_from_device_timestamp() {
+        let origin = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let clock = DeviceClock::new(origin);
+        let signaled = origin + Duration::from_millis(2_030);
+        let callback = signaled + Duration::from_millis(5);
+
+        let latency = FrameLatency::measure(&info(7, 2), &clock, signaled, callback);
+        assert_eq!(latency.frame_number, 7);
+        assert_eq!(latency.exposure_to_event, Duration::from_millis(30));
+        assert_eq!(latency.event_to_callback, Duration::from_millis(5));
+        assert_eq!(latency.total(), Duration::from_millis(35));
+    }
+
+    #[test]
+    fn record_relative_to_fastest_frame() {
+        let origin = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let mut stats = LatencyStats::new();
+
+        let first = stats.record(&info(0, 1), origin + Duration::from_millis(1_020));
+        assert_eq!(first.exposure_to_event, Duration::ZERO);
+
+        let second = stats.record(&info(1, 2), origin + Duration::from_millis(2_050));
+        assert_eq!(second.exposure_to_event, Duration::from_millis(30));
+
+        let third = stats.record(&info(2, 3), origin + Duration::from_millis(3_010));
+        assert_eq!(third.exposure_to_event, Duration::ZERO);
+        assert_eq!(stats.clock().unwrap().origin(), origin + Duration::from_millis(10));
+
+        let summary = stats.exposure_to_event().unwrap();
+        assert_eq!((summary.count, summary.max), (3, Duration::from_millis(30)));
+
+        stats.reset();
+        assert_eq!(stats.clock(), None);
+    }
+}
@@ -0,0 +1,101 @@
+//! Safe, high-level bindings for the uEye API built on top of [`ueye_sys`].
+
+pub mod adapter;
+pub mod aoi;
+pub mod auto_control;
+#[cfg(feature = "avi")]
+pub mod avi;
+pub mod bayer;
+pub mod boot_boost;
+pub mod bracketing;
+pub mod brightness_control;
+pub mod callback;
+pub mod camera;
+pub mod camera_memory;
+pub mod capabilities;
+pub mod capture;
+pub mod capture_state;
+pub mod capture_status;
+pub mod clock_sync;
+pub mod color;
+pub mod color_mode;
+pub mod command;
+pub mod configuration;
+pub mod convert;
+#[cfg(feature = "debayer")]
+pub mod debayer;
+pub mod device_feature;
+pub mod device_info;
+pub mod direct_renderer;
+pub mod display;
+pub mod edge_enhancement;
+pub mod eeprom;
+pub mod error;
+pub mod eth;
+pub mod event;
+pub mod exposure;
+pub mod flash;
+pub mod focus;
+pub mod frame;
+pub mod frame_drop;
+pub mod frame_rate;
+pub mod gain;
+pub mod gpio;
+pub mod hot_pixel;
+pub mod hotplug;
+#[cfg(feature = "image")]
+pub mod image_buffer;
+pub mod image_file;
+pub mod image_info;
+pub mod image_mem;
+pub mod image_processing;
+pub mod image_queue;
+pub mod image_stabilization;
+pub mod image_tone;
+pub mod latency;
+#[cfg(feature = "locale")]
+pub mod locale;
+pub mod measure;
+pub mod metadata;
+pub mod multicast;
+pub mod observer;
+pub mod operating_mode;
+pub mod optimal_timing;
+#[cfg(feature = "overlay")]
+pub mod overlay;
+pub mod parameter;
+pub mod parameter_set;
+pub mod persistent_memory;
+pub mod pixel_clock;
+pub mod power_delivery;
+#[cfg(feature = "profile")]
+pub mod profile;
+pub mod queue;
+pub mod range;
+#[cfg(feature = "profile")]
+pub mod reconnect;
+pub mod recorder;
+pub mod reset;
+pub mod ring_buffer;
+pub mod scene_preset;
+#[cfg(feature = "sidecar")]
+pub mod sidecar;
+pub mod statistics;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod support;
+pub mod sync_start;
+pub mod temperature;
+#[cfg(feature = "wgpu")]
+pub mod texture;
+pub mod timestamp;
+pub mod transaction;
+pub mod transfer;
+pub mod trigger;
+pub mod trigger_debounce;
+#[cfg(all(target_os = "linux", feature = "v4l2"))]
+pub mod v4l2;
+pub mod zoom;
+
+pub use camera::{list_cameras, Camera};
+pub use error::{check, Error, Result};
@@ -0,0 +1,340 @@
+//! Localized error messages.
+//!
+//! The messages of [`Error`] are available in every [`Language`] of the catalog, following the
+//! wording of the IDS documentation. The language used by [`Error::localized`] is selected at
+//! runtime with [`set_language`], e.g. from the settings of an operator-facing UI.
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{camera::Availability, error::Error};
+
+/// Language of error messages.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    /// English, the language of the driver.
+    #[default]
+    English,
+
+    /// German.
+    German,
+}
+
+impl Language {
+    /// Every language of the catalog.
+    pub const ALL: [Self; 2] = [Self::English, Self::German];
+
+    /// IETF language tag of the language, e.g. `"de"`.
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+        }
+    }
+
+    /// Language of an IETF language tag, matching its primary subtag, e.g. `"de-AT"`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?;
+        Self::ALL.into_iter().find(|language| language.tag().eq_ignore_ascii_case(primary))
+    }
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Select the language used by [`Error::localized`].
+#[inline]
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// Language used by [`Error::localized`].
+#[inline]
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::German,
+        _ => Language::English,
+    }
+}
+
+impl Error {
+    /// Documented meaning of the driver return code in `language`, for documented driver errors.
+    pub const fn message_in(&self, language: Language) -> Option<&'static str> {
+        match language {
+            Language::English => self.message(),
+            Language::German => german(self),
+        }
+    }
+
+    /// Display the error in the language selected with [`set_language`].
+    #[inline]
+    pub fn localized(&self) -> Localized {
+        self.localized_in(language())
+    }
+
+    /// Display the error in `language`.
+    #[inline]
+    pub const fn localized_in(&self, language: Language) -> Localized {
+        Localized { error: *self, language }
+    }
+}
+
+/// Error displayed in a [`Language`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Localized {
+    error: Error,
+    language: Language,
+}
+
+impl Display for Localized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.language == Language::English {
+            return self.error.fmt(f);
+        }
+
+        match self.error {
+            Error::Unknown(code) => write!(f, "uEye-Treiber meldet unbekannten Fehlercode {code}"),
+            Error::UnexpectedValue(value) => {
+                write!(f, "uEye-Treiber meldet unerwarteten Wert {value}")
+            },
+            Error::Io(kind) => write!(f, "E/A-Fehler: {kind}"),
+            Error::CameraNotFound => write!(f, "keine angeschlossene Kamera stimmt überein"),
+            Error::CameraBusy(availability) => write!(
+                f,
+                "Kamera ist {}",
+                match availability {
+                    Availability::Available => "verfügbar",
+                    Availability::InUse => "in Benutzung",
+                    Availability::Paired => "mit diesem PC gekoppelt",
+                    Availability::Opened => "auf diesem PC geöffnet",
+                }
+            ),
+            Error::OutOfRange { offset, len, size } => write!(
+                f,
+                "Zugriff auf {len} Bytes ab Offset {offset} überschreitet Speicher von {size} Bytes"
+            ),
+            error => write!(
+                f,
+                "{} ({})",
+                error.message_in(self.language).unwrap_or_default(),
+                error.name().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// German catalog.
+const fn german(error: &Error) -> Option<&'static str> {
+    use Error::*;
+
+    Some(match error {
+        NoSuccess => "allgemeiner Fehler",
+        InvalidCameraHandle => "ungültiges Kamera-Handle",
+        IoRequestFailed => "eine IO-Anforderung des uEye-Treibers ist fehlgeschlagen",
+        CantOpenDevice => "der Versuch, die Kamera zu initialisieren oder auszuwählen, ist fehlgeschlagen (keine Kamera angeschlossen oder Initialisierungsfehler)",
+        CantCloseDevice => "das Gerät konnte nicht geschlossen werden",
+        CantSetupMemory => "der Speicher konnte nicht eingerichtet werden",
+        NoHwndForErrorReport => "kein Fenster-Handle für den Fehlerbericht",
+        ErrorMessageNotCreated => "die Fehlermeldung konnte nicht erzeugt werden",
+        ErrorStringNotFound => "der Fehlertext wurde nicht gefunden",
+        HookNotCreated => "der Hook konnte nicht erzeugt werden",
+        TimerNotCreated => "der Timer konnte nicht erzeugt werden",
+        CantOpenRegistry => "Fehler beim Öffnen eines Windows-Registry-Schlüssels",
+        CantReadRegistry => "Fehler beim Lesen von Einstellungen aus der Windows-Registry",
+        CantValidateBoard => "die Karte konnte nicht validiert werden",
+        CantGiveBoardAccess => "der Zugriff auf die Karte konnte nicht gewährt werden",
+        NoImageMemAllocated => "der Treiber konnte keinen Speicher anlegen",
+        CantCleanupMemory => "der Treiber konnte den belegten Speicher nicht freigeben",
+        CantCommunicateWithDriver => "die Kommunikation mit dem Treiber ist fehlgeschlagen, da kein Treiber geladen ist",
+        FunctionNotSupportedYet => "die Funktion wird noch nicht unterstützt",
+        OperatingSystemNotSupported => "Betriebssystem wird nicht unterstützt",
+        InvalidVideoIn => "ungültiger Videoeingang",
+        InvalidImgSize => "ungültige Bildgröße",
+        InvalidAddress => "ungültige Adresse",
+        InvalidVideoMode => "ungültiger Videomodus",
+        InvalidAgcMode => "ungültiger AGC-Modus",
+        InvalidGammaMode => "ungültiger Gamma-Modus",
+        InvalidSyncLevel => "ungültiger Sync-Pegel",
+        InvalidCbarsMode => "ungültiger Farbbalken-Modus",
+        InvalidColorMode => "ungültiger Farbmodus",
+        InvalidScaleFactor => "ungültiger Skalierungsfaktor",
+        InvalidImageSize => "ungültige Bildgröße",
+        InvalidImagePos => "ungültige Bildposition",
+        InvalidCaptureMode => "die Funktion kann im aktuellen Betriebsmodus der Kamera (Freerun, Trigger oder Standby) nicht ausgeführt werden",
+        InvalidRiscProgram => "ungültiges RISC-Programm",
+        InvalidBrightness => "ungültige Helligkeit",
+        InvalidContrast => "ungültiger Kontrast",
+        InvalidSaturationU => "ungültige U-Sättigung",
+        InvalidSaturationV => "ungültige V-Sättigung",
+        InvalidHue => "ungültiger Farbton",
+        InvalidHorFilterStep => "ungültige horizontale Filterstufe",
+        InvalidVertFilterStep => "ungültige vertikale Filterstufe",
+        InvalidEepromReadAddress => "ungültige EEPROM-Leseadresse",
+        InvalidEepromWriteAddress => "ungültige EEPROM-Schreibadresse",
+        InvalidEepromReadLength => "ungültige EEPROM-Leselänge",
+        InvalidEepromWriteLength => "ungültige EEPROM-Schreiblänge",
+        InvalidBoardInfoPointer => "ungültiger Zeiger auf die Karteninformation",
+        InvalidDisplayMode => "ungültiger Anzeigemodus",
+        InvalidErrRepMode => "ungültiger Fehlerberichtsmodus",
+        InvalidBitsPixel => "ungültige Bits pro Pixel",
+        InvalidMemoryPointer => "ungültiger Zeiger oder ungültige Speicher-ID",
+        FileWriteOpenError => "die Datei kann nicht zum Schreiben oder Lesen geöffnet werden",
+        FileReadOpenError => "die Datei kann nicht geöffnet werden",
+        FileReadInvalidBmpId => "die angegebene Datei ist keine gültige Bitmap-Datei",
+        FileReadInvalidBmpSize => "die Bitmap-Größe ist nicht korrekt (Bitmap zu groß)",
+        FileReadInvalidBitCount => "die Bitmap hat eine ungültige Bittiefe",
+        WrongKernelVersion => "falsche Version des Kernel-Treibers",
+        RiscInvalidXlength => "ungültige RISC-X-Länge",
+        RiscInvalidYlength => "ungültige RISC-Y-Länge",
+        RiscExceedImgSize => "das RISC-Programm überschreitet die Bildgröße",
+        DdMainFailed => "DirectDraw konnte nicht initialisiert werden",
+        DdPrimsurfaceFailed => "die primäre DirectDraw-Oberfläche konnte nicht erzeugt werden",
+        DdScrnSizeNotSupported => "die Bildschirmgröße wird von DirectDraw nicht unterstützt",
+        DdClipperFailed => "der DirectDraw-Clipper konnte nicht erzeugt werden",
+        DdClipperHwndFailed => "das Fenster des DirectDraw-Clippers konnte nicht gesetzt werden",
+        DdClipperConnectFailed => "der DirectDraw-Clipper konnte nicht verbunden werden",
+        DdBacksurfaceFailed => "die DirectDraw-Back-Surface konnte nicht erzeugt werden",
+        DdBacksurfaceInSysmem => "die DirectDraw-Back-Surface liegt im Systemspeicher",
+        DdMdlMallocErr => "die DirectDraw-MDL konnte nicht angelegt werden",
+        DdMdlSizeErr => "ungültige Größe der DirectDraw-MDL",
+        DdClipNoChange => "die DirectDraw-Clip-Liste hat sich nicht geändert",
+        DdPrimmemNull => "der primäre DirectDraw-Speicher ist null",
+        DdBackmemNull => "der DirectDraw-Back-Buffer-Speicher ist null",
+        DdBackovlmemNull => "der DirectDraw-Back-Overlay-Speicher ist null",
+        DdOverlaysurfaceFailed => "die DirectDraw-Overlay-Oberfläche konnte nicht erzeugt werden",
+        DdOverlaysurfaceInSysmem => "die DirectDraw-Overlay-Oberfläche liegt im Systemspeicher",
+        DdOverlayNotAllowed => "das DirectDraw-Overlay ist nicht erlaubt",
+        DdOverlayColkeyErr => "ungültiger Farbschlüssel des DirectDraw-Overlays",
+        DdOverlayNotEnabled => "das DirectDraw-Overlay ist nicht aktiviert",
+        DdGetDcError => "der DirectDraw-Gerätekontext konnte nicht abgerufen werden",
+        DdDdrawDllNotLoaded => "die DirectDraw-Bibliothek ist nicht geladen",
+        DdThreadNotCreated => "der DirectDraw-Thread konnte nicht erzeugt werden",
+        DdCantGetCaps => "die DirectDraw-Fähigkeiten konnten nicht abgefragt werden",
+        DdNoOverlaysurface => "keine DirectDraw-Overlay-Oberfläche",
+        DdNoOverlaystretch => "das Strecken des DirectDraw-Overlays wird nicht unterstützt",
+        DdCantCreateOverlaysurface => "die DirectDraw-Overlay-Oberfläche konnte nicht erzeugt werden",
+        DdCantUpdateOverlaysurface => "die DirectDraw-Overlay-Oberfläche konnte nicht aktualisiert werden",
+        DdInvalidStretch => "ungültiger DirectDraw-Streckfaktor",
+        EvInvalidEventNumber => "ungültige Ereignisnummer",
+        InvalidMode => "ungültiger Modus",
+        CantFindFalchook => "der Hook konnte nicht gefunden werden",
+        CantGetHookProcAddr => "die Adresse der Hook-Prozedur konnte nicht ermittelt werden",
+        CantChainHookProc => "die Hook-Prozedur konnte nicht verkettet werden",
+        CantSetupWndProc => "die Fensterprozedur konnte nicht eingerichtet werden",
+        HwndNull => "das Fenster-Handle ist null",
+        InvalidUpdateMode => "ungültiger Aktualisierungsmodus",
+        NoActiveImgMem => "kein aktiver Bildspeicher vorhanden",
+        CantInitEvent => "das Ereignis konnte nicht initialisiert werden",
+        FuncNotAvailInOs => "die Funktion ist in diesem Betriebssystem nicht verfügbar",
+        CameraNotConnected => "die Kamera ist nicht angeschlossen",
+        SequenceListEmpty => "die Sequenzliste ist leer und kann nicht gelöscht werden",
+        CantAddToSequence => "der Bildspeicher ist bereits in der Sequenz enthalten und kann nicht erneut hinzugefügt werden",
+        LowOfSequenceRiscMem => "nicht genügend Speicher für die Sequenz",
+        Imgmem2freeUsedInSeq => "der freizugebende Bildspeicher wird in einer Sequenz verwendet",
+        ImgmemNotInSequenceList => "der Bildspeicher ist nicht in der Sequenzliste enthalten",
+        SequenceBufAlreadyLocked => "der Speicher konnte nicht gesperrt werden",
+        InvalidDeviceId => "die Geräte-ID ist ungültig",
+        InvalidBoardId => "die Karten-ID ist ungültig",
+        AllDevicesBusy => "alle Kameras sind in Benutzung",
+        HookBusy => "der Hook ist belegt",
+        TimedOut => "eine Zeitüberschreitung ist aufgetreten",
+        NullPointer => "ungültiges Array",
+        WrongHookVersion => "falsche Hook-Version",
+        InvalidParameter => "einer der übergebenen Parameter liegt außerhalb des gültigen Bereichs, wird für diesen Sensor nicht unterstützt oder ist in diesem Modus nicht verfügbar",
+        NotAllowed => "der Vorgang ist nicht erlaubt",
+        OutOfMemory => "es konnte kein Speicher angelegt werden",
+        InvalidWhileLive => "der Vorgang ist während des Live-Betriebs nicht möglich",
+        AccessViolation => "eine Zugriffsverletzung ist aufgetreten",
+        UnknownRopEffect => "unbekannter Rasteroperationseffekt",
+        InvalidRenderMode => "ungültiger Render-Modus",
+        InvalidThreadContext => "ungültiger Thread-Kontext",
+        NoHardwareInstalled => "keine Hardware installiert",
+        InvalidWatchdogTime => "ungültige Watchdog-Zeit",
+        InvalidWatchdogMode => "ungültiger Watchdog-Modus",
+        InvalidPassthroughIn => "ungültiger Durchleitungseingang",
+        ErrorSettingPassthroughIn => "der Durchleitungseingang konnte nicht gesetzt werden",
+        FailureOnSettingWatchdog => "der Watchdog konnte nicht gesetzt werden",
+        NoUsb20 => "die Kamera ist an einem Anschluss angeschlossen, der den USB-2.0-High-Speed-Standard nicht unterstützt",
+        CaptureRunning => "ein Aufnahmevorgang läuft und muss zuerst beendet werden",
+        MemoryBoardActivated => "der Vorgang konnte nicht ausgeführt werden, während das Speicherboard aktiviert ist",
+        MemoryBoardDeactivated => "der Vorgang konnte nicht ausgeführt werden, während das Speicherboard deaktiviert ist",
+        NoMemoryBoardConnected => "kein Speicherboard angeschlossen",
+        TooLessMemory => "die Bildgröße übersteigt die Speicherkapazität",
+        ImageNotPresent => "das angeforderte Bild ist nicht im Kameraspeicher vorhanden oder nicht mehr gültig",
+        MemoryModeRunning => "der Speichermodus läuft",
+        MemoryboardDisabled => "das Speicherboard ist deaktiviert",
+        TriggerActivated => "die Funktion kann nicht verwendet werden, da die Kamera auf ein Triggersignal wartet",
+        WrongKey => "falscher Schlüssel",
+        CrcError => "beim Lesen der Einstellungen ist ein CRC-Fehler aufgetreten",
+        NotYetReleased => "diese Funktion ist in dieser Version noch nicht freigeschaltet",
+        NotCalibrated => "die Kamera enthält keine Kalibrierdaten",
+        WaitingForKernel => "das System wartet auf eine Antwort des Kernel-Treibers",
+        NotSupported => "das verwendete Kameramodell unterstützt diese Funktion oder Einstellung nicht",
+        TriggerNotActivated => "die Funktion ist nicht möglich, da der Trigger deaktiviert ist",
+        OperationAborted => "der Vorgang wurde abgebrochen",
+        BadStructureSize => "eine interne Struktur hat eine falsche Größe",
+        InvalidBufferSize => "der Bildspeicher hat eine ungeeignete Größe, um das Bild im gewünschten Format zu speichern",
+        InvalidPixelClock => "diese Einstellung ist für die aktuell eingestellte Pixeltaktfrequenz nicht verfügbar",
+        InvalidExposureTime => "diese Einstellung ist für die aktuell eingestellte Belichtungszeit nicht verfügbar",
+        AutoExposureRunning => "diese Einstellung kann nicht geändert werden, solange die automatische Belichtungszeitregelung aktiviert ist",
+        CannotCreateBbSurf => "die BackBuffer-Oberfläche kann nicht erzeugt werden",
+        CannotCreateBbMix => "die BackBuffer-Mix-Oberfläche kann nicht erzeugt werden",
+        BbOvlmemNull => "der BackBuffer-Overlay-Speicher kann nicht gesperrt werden",
+        CannotCreateBbOvl => "der BackBuffer-Overlay-Speicher kann nicht erzeugt werden",
+        NotSuppInOvlSurfMode => "im BackBuffer-Overlay-Modus nicht unterstützt",
+        InvalidSurface => "ungültige Back-Buffer-Oberfläche",
+        SurfaceLost => "Back-Buffer-Oberfläche nicht gefunden",
+        ReleaseBbOvlDc => "Fehler beim Freigeben des Overlay-Gerätekontexts",
+        BbTimerNotCreated => "der Back-Buffer-Timer konnte nicht erzeugt werden",
+        BbOvlNotEn => "das Back-Buffer-Overlay wurde nicht aktiviert",
+        OnlyInBbMode => "nur im BackBuffer-Modus möglich",
+        InvalidColorFormat => "ungültiges Farbformat",
+        InvalidWbBinningMode => "Mono-Binning/Mono-Subsampling unterstützen keinen automatischen Weißabgleich",
+        InvalidI2cDeviceAddress => "ungültige I2C-Geräteadresse",
+        CouldNotConvert => "das aktuelle Bild konnte nicht verarbeitet werden",
+        TransferError => "Übertragungsfehler",
+        ParameterSetNotPresent => "der Parametersatz ist nicht vorhanden",
+        InvalidCameraType => "der in der .ini-Datei angegebene Kameratyp stimmt nicht mit dem aktuellen Kameramodell überein",
+        InvalidHostIpHibyte => "ungültiges HIBYTE der Host-Adresse",
+        CmNotSuppInCurrDisplaymode => "der Farbmodus wird im aktuellen Anzeigemodus nicht unterstützt",
+        NoIrFilter => "kein IR-Filter vorhanden",
+        StarterFwUploadNeeded => "die Starter-Firmware der Kamera ist nicht mit dem Treiber kompatibel und muss aktualisiert werden",
+        DrLibraryNotFound => "die DirectRenderer-Bibliothek wurde nicht gefunden",
+        DrDeviceOutOfMemory => "nicht genügend Grafikspeicher verfügbar",
+        DrCannotCreateSurface => "die Bild- oder Overlay-Oberfläche konnte nicht erzeugt werden",
+        DrCannotCreateVertexBuffer => "der Vertex-Buffer konnte nicht erzeugt werden",
+        DrCannotCreateTexture => "die Textur konnte nicht erzeugt werden",
+        DrCannotLockOverlaySurface => "die Overlay-Oberfläche konnte nicht gesperrt werden",
+        DrCannotUnlockOverlaySurface => "die Overlay-Oberfläche konnte nicht entsperrt werden",
+        DrCannotGetOverlayDc => "das Gerätekontext-Handle für das Overlay konnte nicht abgerufen werden",
+        DrCannotReleaseOverlayDc => "das Gerätekontext-Handle für das Overlay konnte nicht freigegeben werden",
+        DrDeviceCapsInsufficient => "die Funktion wird von der Grafikhardware nicht unterstützt",
+        IncompatibleSetting => "die Funktion ist aufgrund anderer inkompatibler Einstellungen nicht möglich",
+        DrNotAllowedWhileDcIsActive => "in der Anwendung ist noch ein Gerätekontext-Handle geöffnet",
+        DeviceAlreadyPaired => "das Gerät wird bereits vom System oder von einem anderen System verwendet",
+        SubnetmaskMismatch => "die Subnetzmasken von Kamera und PC-Netzwerkkarte unterscheiden sich",
+        SubnetMismatch => "die Subnetze von Kamera und PC-Netzwerkkarte unterscheiden sich",
+        InvalidIpConfiguration => "die Konfiguration der IP-Adresse ist ungültig",
+        DeviceNotCompatible => "das Gerät ist nicht mit den Treibern kompatibel",
+        NetworkFrameSizeIncompatible => "die Einstellungen für die Bildgröße der Kamera sind nicht mit der PC-Netzwerkkarte kompatibel",
+        NetworkConfigurationInvalid => "die Konfiguration der Netzwerkkarte ist ungültig",
+        ErrorCpuIdleStatesConfiguration => "die Konfiguration der CPU-Idle-Zustände ist fehlgeschlagen",
+        DeviceBusy => "die Kamera ist ausgelastet und kann das angeforderte Bild nicht übertragen",
+        SensorInitializationFailed => "die Initialisierung des Sensors ist fehlgeschlagen",
+        ImageBufferNotDwordAligned => "der Bildpuffer ist nicht an DWORD-Grenzen ausgerichtet",
+        SeqBufferIsLocked => "der Bildspeicher ist gesperrt",
+        FilePathDoesNotExist => "der Dateipfad existiert nicht",
+        InvalidWindowHandle => "ungültiges Fenster-Handle",
+        InvalidImageParameter => "ungültiger Bildparameter (Position oder Größe)",
+        NoSuchDevice => "kein solches Gerät",
+        DeviceInUse => "das Gerät ist in Benutzung",
+        Unknown(_)
+        | UnexpectedValue(_)
+        | Io(_)
+        | CameraNotFound
+        | CameraBusy(_)
+        | OutOfRange { .. } => return None,
+    })
+}
@@ -0,0 +1,92 @@
+//! Sharpness measurement in the camera image.
+//!
+//! The driver evaluates the edges within up to [`MAX_AOIS`] AOIs of the current image. The
+//! sharpness is a relative value: it only compares images of the same scene, e.g. while moving a
+//! lens, which is enough to build an autofocus on the host with [`measure_sharpness`].
+//!
+//! # Documentation
+//! [is_Measure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_measure.html)
+
+use std::{mem::size_of, ptr};
+
+use ueye_sys::{
+    measure::{
+        is_Measure, MEASURE_CMD, MEASURE_SHARPNESS_AOI_PRESETS,
+        MEASURE_SHARPNESS_CALCULATION_ALGORITHM, MEASURE_SHARPNESS_INFO,
+    },
+    types::{void, IS_RECT, UINT},
+};
+
+use crate::{
+    aoi::Aoi,
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Largest number of AOIs measured at once.
+pub const MAX_AOIS: u32 = 5;
+
+fn measure<T>(camera: &Camera, cmd: MEASURE_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_Measure(
+            camera.handle(),
+            cmd,
+            param as *mut T as *mut void,
+            size_of::<T>() as UINT,
+        ))
+    })
+}
+
+fn sharpness_info(number: u32, rect: IS_RECT) -> MEASURE_SHARPNESS_INFO {
+    MEASURE_SHARPNESS_INFO {
+        u32NumberAOI: number,
+        fSharpnessValue: 0.0,
+        rcAOI: rect,
+        pcImageMem: ptr::null_mut(),
+    }
+}
+
+/// Set the measurement AOI `number` (`0`…`4`) to `aoi`.
+///
+/// Fails with [`Error::InvalidParameter`] if `number` is out of range.
+pub fn set_aoi(camera: &Camera, number: u32, aoi: Aoi) -> Result<()> {
+    if number >= MAX_AOIS {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut info = sharpness_info(number, aoi.into());
+    measure(camera, MEASURE_CMD::IS_MEASURE_CMD_SHARPNESS_AOI_SET, &mut info)
+}
+
+/// Set the measurement AOIs to the four corners and the center, each a third of the image in
+/// width and height.
+pub fn set_aoi_preset(camera: &Camera) -> Result<()> {
+    let mut preset = MEASURE_SHARPNESS_AOI_PRESETS::IS_MEASURE_SHARPNESS_AOI_PRESET_1 as UINT;
+    measure(camera, MEASURE_CMD::IS_MEASURE_CMD_SHARPNESS_AOI_SET_PRESET, &mut preset)
+}
+
+/// Query the measurement AOI `number` along with its sharpness in the current image.
+pub fn sharpness(camera: &Camera, number: u32) -> Result<(Aoi, f64)> {
+    let mut info = sharpness_info(number, IS_RECT { s32X: 0, s32Y: 0, s32Width: 0, s32Height: 0 });
+    measure(camera, MEASURE_CMD::IS_MEASURE_CMD_SHARPNESS_AOI_INQUIRE, &mut info)?;
+
+    Ok((info.rcAOI.into(), info.fSharpnessValue as f64))
+}
+
+/// Measure the sharpness within `aoi` of the current image, using measurement AOI `0`.
+///
+/// The higher the value, the sharper the image.
+pub fn measure_sharpness(camera: &Camera, aoi: Aoi) -> Result<f64> {
+    set_aoi(camera, 0, aoi)?;
+    sharpness(camera, 0).map(|(_, sharpness)| sharpness)
+}
+
+/// _uEye LE USB 3.1 Gen 1 AF:_ set the algorithm of the sharpness calculation.
+pub fn set_algorithm(
+    camera: &Camera,
+    algorithm: MEASURE_SHARPNESS_CALCULATION_ALGORITHM,
+) -> Result<()> {
+    let mut algorithm = algorithm as UINT;
+    measure(camera, MEASURE_CMD::IS_MEASURE_CMD_SHARPNESS_CALCULATION_ALGORITHM_SET, &mut algorithm)
+}
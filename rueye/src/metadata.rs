@@ -0,0 +1,164 @@
+//! Metadata of the driver, the camera and captured images.
+//!
+//! [`Camera::metadata`] gathers the identity of the camera along with the version of the uEye API
+//! library, and [`Camera::image_metadata`] decodes the additional information the driver keeps for
+//! each image memory. Frames held in a locked image memory expose their metadata directly, e.g.
+//! [`LockedFrame::metadata`](crate::capture::LockedFrame::metadata).
+//!
+//! # Documentation
+//! * [is_GetDLLVersion](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getdllversion.html)
+//! * [is_GetCameraInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcamerainfo.html)
+//! * [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+use ueye_sys::{
+    image_info::UEYEIMAGEINFO,
+    meta::{is_GetDLLVersion, split_version},
+    types::INT,
+};
+
+use crate::{
+    camera::Camera,
+    device_info::{self, c_string},
+    error::Result,
+    image_info,
+    observer::call,
+};
+
+/// Version of the uEye API library.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub build: u16,
+}
+
+impl Version {
+    /// Version of the uEye API library in use.
+    #[inline]
+    pub fn api() -> Self {
+        Self::from(unsafe { call!(is_GetDLLVersion()) })
+    }
+}
+
+impl From<INT> for Version {
+    /// Decode a version number as returned by [`is_GetDLLVersion`].
+    #[inline]
+    fn from(value: INT) -> Self {
+        let (major, minor, build) = split_version(value);
+        Self { major: major as u8, minor: minor as u8, build: build as u16 }
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.build)
+    }
+}
+
+/// Identity of a camera and the library it is driven by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata {
+    /// Version of the uEye API library.
+    pub api_version: Version,
+
+    /// Serial number of the camera.
+    pub serial_number: String,
+
+    /// Manufacturer of the camera.
+    pub manufacturer: String,
+
+    /// Camera hardware version.
+    pub hardware_version: String,
+
+    /// Date of the final quality check.
+    pub date: String,
+
+    /// Camera model.
+    pub model: String,
+
+    /// Sensor type.
+    pub sensor_id: u16,
+}
+
+/// Additional information of a captured image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageMetadata {
+    /// Internal image counter, reset when the camera is opened and when the capture is started.
+    pub frame_number: u64,
+
+    /// Device timestamp, set when the sensor begins to read out the image (in 0.1 µs steps).
+    pub device_timestamp: u64,
+
+    /// Status of the digital inputs at the time of capture.
+    pub io_status: u32,
+
+    /// Index of the AOI in the AOI sequence.
+    pub aoi_index: u16,
+
+    /// Number of AOI sequence cycles.
+    pub aoi_cycle: u16,
+
+    /// Number of image memories in the sequence.
+    pub buffers: u32,
+
+    /// Number of image memories in use.
+    pub buffers_in_use: u32,
+
+    /// Image width, in pixels.
+    pub width: u32,
+
+    /// Image height, in lines.
+    pub height: u32,
+
+    /// Processing time of the image on the host.
+    pub host_process_time: Duration,
+}
+
+impl From<&UEYEIMAGEINFO> for ImageMetadata {
+    fn from(info: &UEYEIMAGEINFO) -> Self {
+        Self {
+            frame_number: info.u64FrameNumber,
+            device_timestamp: info.u64TimestampDevice,
+            io_status: info.dwIoStatus,
+            aoi_index: info.wAOIIndex,
+            aoi_cycle: info.wAOICycle,
+            buffers: info.dwImageBuffers,
+            buffers_in_use: info.dwImageBuffersInUse,
+            width: info.dwImageWidth,
+            height: info.dwImageHeight,
+            host_process_time: Duration::from_micros(u64::from(info.dwHostProcessTime) * 10),
+        }
+    }
+}
+
+impl Camera {
+    /// Query the identity of the camera and the version of the uEye API library.
+    pub fn metadata(&self) -> Result<Metadata> {
+        let camera = device_info::camera_info(self)?;
+        let sensor = device_info::sensor_info(self)?;
+
+        Ok(Metadata {
+            api_version: Version::api(),
+            serial_number: c_string(&camera.SerNo),
+            manufacturer: c_string(&camera.ID),
+            hardware_version: c_string(&camera.Version),
+            date: c_string(&camera.Date),
+            model: c_string(&sensor.strSensorName),
+            sensor_id: sensor.SensorID,
+        })
+    }
+
+    /// Query the additional information of the image in the image memory `mem_id`.
+    #[inline]
+    pub fn image_metadata(&self, mem_id: INT) -> Result<ImageMetadata> {
+        image_info::query(self, mem_id).map(|info| ImageMetadata::from(&info))
+    }
+}
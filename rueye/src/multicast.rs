@@ -0,0 +1,285 @@
+//! Multicast streaming of GigE cameras to several PCs.
+//!
+//! The master PC opens the camera as usual, after enabling its multicast mode and setting its
+//! multicast IP address; both are configured by device ID while the camera is not opened. Client
+//! PCs then receive the same image stream through a read-only _virtual_ camera, without
+//! controlling the device: a [`MulticastClient`] creates the virtual camera for a
+//! [`MulticastSource`], looks up the device ID the driver assigned to it, and opens it.
+//!
+//! Clients can only change camera-independent settings, such as the software gamma or LUT; the
+//! image format follows the master, signaled by [`EventKind::ImageParamsChanged`].
+//!
+//! # Documentation
+//! [is_Multicast](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_multicast.html)
+//!
+//! [`EventKind::ImageParamsChanged`]: crate::event::EventKind::ImageParamsChanged
+
+use std::{
+    mem::{size_of, ManuallyDrop},
+    net::Ipv4Addr,
+    ops::Deref,
+    ptr,
+    sync::Mutex,
+};
+
+use ueye_sys::{
+    camera::IS_USE_DEVICE_ID,
+    eth::UEYE_ETH_ADDR_IPV4,
+    multicast::{
+        is_Multicast, IS_AMC_SUPPORTED_FLAG, IS_PMC_ERRORHANDLING, IS_PMC_READONLYDEVICEDESCRIPTOR,
+        MULTICAST_CMD,
+    },
+    types::{void, BOOL, HIDS, UINT},
+};
+
+use crate::{
+    camera::{list_cameras, Camera, CameraInfo},
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Number of open clients, which keep the passive multicast mode initialized.
+static CLIENTS: Mutex<usize> = Mutex::new(0);
+
+fn multicast<T>(hcam: HIDS, cmd: MULTICAST_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_Multicast(hcam, cmd, param as *mut T as *mut void, size_of::<T>() as UINT))
+    })
+}
+
+fn multicast_null(cmd: MULTICAST_CMD) -> Result<()> {
+    check(unsafe { command!(is_Multicast(0, cmd, ptr::null_mut(), 0)) })
+}
+
+/// Handle of the camera listed as `info`, addressed by its device ID.
+#[inline]
+fn device_handle(info: &CameraInfo) -> HIDS {
+    info.device_id as HIDS | IS_USE_DEVICE_ID
+}
+
+/// Whether the camera listed as `info` and its firmware support the multicast mode.
+pub fn is_supported(info: &CameraInfo) -> Result<bool> {
+    let mut flags = IS_AMC_SUPPORTED_FLAG::empty();
+    multicast(device_handle(info), MULTICAST_CMD::IS_AMC_CMD_GET_MC_SUPPORTED, &mut flags)?;
+    Ok(flags.contains(IS_AMC_SUPPORTED_FLAG::all()))
+}
+
+/// Query whether the multicast mode of the camera listed as `info` is enabled.
+pub fn is_enabled(info: &CameraInfo) -> Result<bool> {
+    let mut enabled: BOOL = 0;
+    multicast(device_handle(info), MULTICAST_CMD::IS_AMC_CMD_GET_MC_ENABLED, &mut enabled)?;
+    Ok(enabled != 0)
+}
+
+/// Enable or disable the multicast mode of the camera listed as `info`, which must not be opened.
+pub fn set_enabled(info: &CameraInfo, enable: bool) -> Result<()> {
+    let mut enable = enable as BOOL;
+    multicast(device_handle(info), MULTICAST_CMD::IS_AMC_CMD_SET_MC_ENABLED, &mut enable)
+}
+
+/// Query the multicast IP address of the camera listed as `info`.
+pub fn address(info: &CameraInfo) -> Result<Ipv4Addr> {
+    let mut address = UEYE_ETH_ADDR_IPV4 { dwAddr: 0 };
+    multicast(device_handle(info), MULTICAST_CMD::IS_AMC_CMD_GET_MC_IP, &mut address)?;
+    Ok(address.into())
+}
+
+/// Set the multicast IP address of the camera listed as `info`, which must not be opened.
+///
+/// Fails with [`Error::InvalidParameter`] unless `address` is a multicast address outside of the
+/// range `224.0.0.0/24` reserved for routing protocols.
+pub fn set_address(info: &CameraInfo, address: Ipv4Addr) -> Result<()> {
+    if !is_valid_address(address) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut address = UEYE_ETH_ADDR_IPV4::from(address);
+    multicast(device_handle(info), MULTICAST_CMD::IS_AMC_CMD_SET_MC_IP, &mut address)
+}
+
+/// Whether `address` can be used as multicast IP address of a camera.
+#[inline]
+fn is_valid_address(address: Ipv4Addr) -> bool {
+    address.is_multicast() && address.octets()[..3] != [224, 0, 0]
+}
+
+/// Query whether the passive multicast mode is enabled on this PC.
+pub fn is_system_enabled() -> Result<bool> {
+    let mut enabled: BOOL = 0;
+    multicast(0, MULTICAST_CMD::IS_PMC_CMD_SYSTEM_GET_ENABLE, &mut enabled)?;
+    Ok(enabled != 0)
+}
+
+/// Enable or disable the passive multicast mode on this PC, which clients require.
+pub fn set_system_enabled(enable: bool) -> Result<()> {
+    let mut enable = enable as BOOL;
+    multicast(0, MULTICAST_CMD::IS_PMC_CMD_SYSTEM_SET_ENABLE, &mut enable)
+}
+
+/// Handling of images of which network packets were lost.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorHandling {
+    /// Discard incomplete images.
+    #[default]
+    RejectImages,
+
+    /// Deliver incomplete images, with the missing parts black.
+    IgnoreMissingParts,
+
+    /// Fill missing parts from the previous image, and deliver images only once complete again.
+    MergeOnComplete,
+
+    /// Fill missing parts from the previous image, and deliver every new image immediately.
+    MergeOnReceived,
+}
+
+impl From<ErrorHandling> for IS_PMC_ERRORHANDLING {
+    fn from(handling: ErrorHandling) -> Self {
+        match handling {
+            ErrorHandling::RejectImages => IS_PMC_ERRORHANDLING::IS_PMC_ERRORHANDLING_REJECT_IMAGES,
+            ErrorHandling::IgnoreMissingParts => {
+                IS_PMC_ERRORHANDLING::IS_PMC_ERRORHANDLING_IGNORE_MISSING_PARTS
+            },
+            ErrorHandling::MergeOnComplete => {
+                IS_PMC_ERRORHANDLING::IS_PMC_ERRORHANDLING_MERGE_IMAGES_RELEASE_ON_COMPLETE
+            },
+            ErrorHandling::MergeOnReceived => {
+                IS_PMC_ERRORHANDLING::IS_PMC_ERRORHANDLING_MERGE_IMAGES_RELEASE_ON_RECEIVED_IMGLEN
+            },
+        }
+    }
+}
+
+/// Multicast stream of a camera, as received by clients.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MulticastSource {
+    /// Persistent IP address of the camera.
+    pub camera_ip: Ipv4Addr,
+
+    /// Multicast IP address of the camera.
+    pub multicast_ip: Ipv4Addr,
+
+    /// Camera ID of the virtual camera, free on this PC.
+    pub camera_id: u32,
+
+    /// Handling of incomplete images.
+    pub error_handling: ErrorHandling,
+}
+
+impl MulticastSource {
+    /// Stream of the camera at `camera_ip`, sent to `multicast_ip`, received as camera
+    /// `camera_id`.
+    #[inline]
+    pub const fn new(camera_ip: Ipv4Addr, multicast_ip: Ipv4Addr, camera_id: u32) -> Self {
+        Self { camera_ip, multicast_ip, camera_id, error_handling: ErrorHandling::RejectImages }
+    }
+
+    fn descriptor(&self) -> IS_PMC_READONLYDEVICEDESCRIPTOR {
+        IS_PMC_READONLYDEVICEDESCRIPTOR {
+            ipCamera: self.camera_ip.into(),
+            ipMulticast: self.multicast_ip.into(),
+            u32CameraId: self.camera_id,
+            u32ErrorHandlingMode: self.error_handling.into(),
+        }
+    }
+}
+
+/// Keep the passive multicast mode initialized while a client is open.
+fn acquire_passive() -> Result<()> {
+    let mut clients = CLIENTS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if *clients == 0 {
+        multicast_null(MULTICAST_CMD::IS_PMC_CMD_INITIALIZE)?;
+    }
+
+    *clients += 1;
+    Ok(())
+}
+
+fn release_passive() {
+    let mut clients = CLIENTS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    *clients = clients.saturating_sub(1);
+    if *clients == 0 {
+        let _ = multicast_null(MULTICAST_CMD::IS_PMC_CMD_DEINITIALIZE);
+    }
+}
+
+/// Read-only virtual camera receiving the multicast stream of a camera opened on another PC.
+///
+/// The client dereferences to the [`Camera`] for capturing; the virtual camera is closed and
+/// removed again when the client is dropped.
+#[derive(Debug)]
+pub struct MulticastClient {
+    camera: ManuallyDrop<Camera>,
+    descriptor: IS_PMC_READONLYDEVICEDESCRIPTOR,
+}
+
+impl MulticastClient {
+    /// Create the virtual camera of `source` and open it.
+    ///
+    /// The passive multicast mode must be [enabled](set_system_enabled) on this PC. Fails with
+    /// [`Error::InvalidParameter`] if the multicast IP address is invalid, and with
+    /// [`Error::CantOpenDevice`] if the driver lists no virtual camera for `source`.
+    pub fn open(source: &MulticastSource) -> Result<Self> {
+        if !is_valid_address(source.multicast_ip) {
+            return Err(Error::InvalidParameter);
+        }
+
+        acquire_passive()?;
+        let mut descriptor = source.descriptor();
+        if let Err(error) = multicast(0, MULTICAST_CMD::IS_PMC_CMD_ADDMCDEVICE, &mut descriptor) {
+            release_passive();
+            return Err(error);
+        }
+
+        match open_virtual(source.camera_id) {
+            Ok(camera) => Ok(Self { camera: ManuallyDrop::new(camera), descriptor }),
+            Err(error) => {
+                remove(descriptor);
+                Err(error)
+            },
+        }
+    }
+
+    /// Camera ID of the virtual camera.
+    #[inline]
+    pub const fn camera_id(&self) -> u32 {
+        self.descriptor.u32CameraId
+    }
+}
+
+/// Open the virtual camera listed with `camera_id`.
+///
+/// The virtual camera gets a device ID of its own from the driver, different from that of the
+/// physical camera, which may be listed with the same camera ID when it is in the same subnet.
+fn open_virtual(camera_id: u32) -> Result<Camera> {
+    list_cameras()?
+        .iter()
+        .find(|info| info.camera_id == camera_id && !info.in_use)
+        .ok_or(Error::CantOpenDevice)?
+        .open()
+}
+
+/// Remove the virtual camera of `descriptor`, and release the passive multicast mode.
+fn remove(mut descriptor: IS_PMC_READONLYDEVICEDESCRIPTOR) {
+    let _ = multicast(0, MULTICAST_CMD::IS_PMC_CMD_REMOVEMCDEVICE, &mut descriptor);
+    release_passive();
+}
+
+impl Deref for MulticastClient {
+    type Target = Camera;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.camera
+    }
+}
+
+impl Drop for MulticastClient {
+    fn drop(&mut self) {
+        // SAFETY: the camera is not used after this point.
+        unsafe { ManuallyDrop::drop(&mut self.camera) };
+        remove(self.descriptor);
+    }
+}
@@ -0,0 +1,162 @@
+//! Observation of the raw API calls made by this crate.
+//!
+//! An observer set with [`set_call_observer`] receives a [`CallRecord`] for every uEye API call
+//! made through the safe layer, e.g. to persist command traffic for later analysis or to redact it
+//! before logging. Records are only built while an observer is set.
+//!
+//! With the `tracing` feature, every call is also recorded as a `ueye_call` span at the trace
+//! level, carrying the function and command, and closed by an event holding the return code and
+//! the duration of the call.
+
+use std::{
+    fmt::Debug,
+    slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+};
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+use ueye_sys::types::{void, INT, UINT};
+
+/// Raw API call, as reported to the call observer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CallRecord {
+    /// Name of the API function, e.g. `is_Exposure`.
+    pub function: &'static str,
+
+    /// Name of the command, for functions of the command interface, e.g.
+    /// `IS_EXPOSURE_CMD_GET_EXPOSURE`.
+    pub command: Option<String>,
+
+    /// Parameter bytes after the call, for functions of the command interface.
+    ///
+    /// These hold the values passed to the driver for commands setting a value, and the values
+    /// returned by it for commands querying one.
+    pub parameter: Vec<u8>,
+
+    /// Return code of the call.
+    pub result: INT,
+}
+
+type Observer = Arc<dyn Fn(CallRecord) + Send + Sync>;
+
+static OBSERVER: RwLock<Option<Observer>> = RwLock::new(None);
+
+/// Whether [`OBSERVER`] is set, checked before building any record.
+static OBSERVED: AtomicBool = AtomicBool::new(false);
+
+/// Report every subsequent API call to `observer`, replacing any previous observer.
+///
+/// The observer is called on the thread making the call, right after it returns.
+pub fn set_call_observer(observer: impl Fn(CallRecord) + Send + Sync + 'static) {
+    *OBSERVER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(observer));
+    OBSERVED.store(true, Ordering::Release);
+}
+
+/// Stop reporting API calls.
+pub fn clear_call_observer() {
+    OBSERVED.store(false, Ordering::Release);
+    *OBSERVER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Report a call to the observer, if any.
+pub(crate) fn observe(function: &'static str, result: INT) {
+    if OBSERVED.load(Ordering::Acquire) {
+        notify(CallRecord { function, command: None, parameter: Vec::new(), result });
+    }
+}
+
+/// Report a call of the command interface to the observer, if any.
+///
+/// # Safety
+/// Unless null, `param` must be valid for reads of `size` bytes, as required by the call itself.
+pub(crate) unsafe fn observe_command(
+    function: &'static str,
+    command: &impl Debug,
+    param: *const void,
+    size: UINT,
+    result: INT,
+) {
+    if OBSERVED.load(Ordering::Acquire) {
+        let parameter = if param.is_null() {
+            Vec::new()
+        } else {
+            unsafe { slice::from_raw_parts(param as *const u8, size as usize) }.to_vec()
+        };
+
+        notify(CallRecord { function, command: Some(format!("{command:?}")), parameter, result });
+    }
+}
+
+fn notify(record: CallRecord) {
+    // the lock is released before calling, so the observer may replace itself
+    let observer = OBSERVER.read().unwrap_or_else(PoisonError::into_inner).clone();
+    if let Some(observer) = observer {
+        observer(record);
+    }
+}
+
+/// Span of an API call being traced.
+#[cfg(feature = "tracing")]
+pub(crate) struct CallSpan {
+    span: tracing::span::EnteredSpan,
+    start: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl CallSpan {
+    /// Enter the span of a call of `function`, with `command` for functions of the command
+    /// interface.
+    pub(crate) fn enter(function: &'static str, command: Option<&dyn Debug>) -> Self {
+        let span = tracing::trace_span!(
+            "ueye_call",
+            function,
+            command = command.map(tracing::field::debug)
+        );
+        Self { span: span.entered(), start: Instant::now() }
+    }
+
+    /// Record the return code and duration of the call, and leave the span.
+    pub(crate) fn exit(self, result: INT) {
+        let duration = self.start.elapsed();
+        tracing::trace!(result, ?duration, "returned");
+        drop(self.span);
+    }
+}
+
+/// Call `function(hcam, command, param, size)` of the command interface, reporting it to the
+/// call observer. Must be used within `unsafe`, like the call itself.
+macro_rules! command {
+    ($function:ident($hcam:expr, $command:expr, $param:expr, $size:expr $(,)?)) => {{
+        let command = $command;
+        let param: *mut ::ueye_sys::types::void = $param;
+        let size: ::ueye_sys::types::UINT = $size;
+        #[cfg(feature = "tracing")]
+        let span = $crate::observer::CallSpan::enter(stringify!($function), Some(&command));
+        let result = $function($hcam, command, param, size);
+        #[cfg(feature = "tracing")]
+        span.exit(result);
+        $crate::observer::observe_command(stringify!($function), &command, param, size, result);
+        result
+    }};
+}
+
+/// Call an API function, reporting its name and return code to the call observer. Must be used
+/// within `unsafe`, like the call itself.
+macro_rules! call {
+    ($function:ident($($argument:expr),* $(,)?)) => {{
+        #[cfg(feature = "tracing")]
+        let span = $crate::observer::CallSpan::enter(stringify!($function), None);
+        let result = $function($($argument),*);
+        #[cfg(feature = "tracing")]
+        span.exit(result as ::ueye_sys::types::INT);
+        $crate::observer::observe(stringify!($function), result as ::ueye_sys::types::INT);
+        result
+    }};
+}
+
+pub(crate) use {call, command};
@@ -0,0 +1,156 @@
+//! Camera operating modes and standby.
+//!
+//! A camera runs either in freerun mode, in trigger mode or in standby. In standby, the sensor is
+//! powered down while the camera remains initialized, so power-sensitive deployments can park a
+//! camera without closing its handle, e.g. with [`Camera::standby`].
+//!
+//! # Documentation
+//! * [is_CameraStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_camerastatus.html)
+//! * [is_SetExternalTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setexternaltrigger.html)
+
+use ueye_sys::{
+    camera_status::{is_CameraStatus, IS_GET_STATUS, IS_STANDBY, IS_STANDBY_SUPPORTED},
+    constants::return_values::IS_NO_SUCCESS,
+    types::{FALSE, INT, TRUE, ULONG},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+    trigger::TriggerMode,
+};
+
+/// Operating mode of the camera.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum OperatingMode {
+    /// Images are captured continuously (_freerun_).
+    #[default]
+    Freerun,
+
+    /// Images are captured on the given trigger.
+    Trigger(TriggerMode),
+
+    /// The sensor is powered down.
+    Standby,
+}
+
+impl OperatingMode {
+    /// Query the operating mode of the camera.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        if is_standby(camera)? {
+            return Ok(OperatingMode::Standby);
+        }
+
+        Ok(match TriggerMode::current(camera)? {
+            TriggerMode::Off => OperatingMode::Freerun,
+            mode => OperatingMode::Trigger(mode),
+        })
+    }
+
+    /// Switch the camera to this operating mode, leaving standby if necessary.
+    pub fn apply(self, camera: &Camera) -> Result<()> {
+        match self {
+            OperatingMode::Standby => enter_standby(camera),
+            OperatingMode::Freerun => {
+                leave_standby(camera)?;
+                TriggerMode::Off.apply(camera)
+            },
+            OperatingMode::Trigger(mode) => {
+                leave_standby(camera)?;
+                mode.apply(camera)
+            },
+        }
+    }
+}
+
+#[inline]
+fn status(camera: &Camera, info: INT) -> Result<ULONG> {
+    match unsafe { call!(is_CameraStatus(camera.handle(), info, IS_GET_STATUS)) } {
+        value if value as INT == IS_NO_SUCCESS => Err(Error::NoSuccess),
+        value => Ok(value),
+    }
+}
+
+/// Power the sensor down or up again, keeping the camera initialized.
+///
+/// Entering standby fails with [`Error::NotSupported`] if the camera does not
+/// [support](standby_supported) it, while leaving it always succeeds for such cameras.
+pub fn set_standby(camera: &Camera, standby: bool) -> Result<()> {
+    if !standby_supported(camera)? {
+        return if standby { Err(Error::NotSupported) } else { Ok(()) };
+    }
+
+    let value = if standby { TRUE } else { FALSE };
+    check(unsafe { call!(is_CameraStatus(camera.handle(), IS_STANDBY, value as ULONG)) } as INT)
+}
+
+/// Whether the camera supports standby.
+pub fn standby_supported(camera: &Camera) -> Result<bool> {
+    status(camera, IS_STANDBY_SUPPORTED).map(|value| value == TRUE as ULONG)
+}
+
+/// Whether the camera is in standby.
+pub fn is_standby(camera: &Camera) -> Result<bool> {
+    status(camera, IS_STANDBY).map(|value| value == TRUE as ULONG)
+}
+
+/// Power down the sensor, keeping the camera initialized.
+#[inline]
+pub fn enter_standby(camera: &Camera) -> Result<()> {
+    set_standby(camera, true)
+}
+
+/// Power up the sensor after [`enter_standby`].
+#[inline]
+pub fn leave_standby(camera: &Camera) -> Result<()> {
+    set_standby(camera, false)
+}
+
+impl Camera {
+    /// Enter or leave standby, see [`set_standby`].
+    #[inline]
+    pub fn set_standby(&self, standby: bool) -> Result<()> {
+        set_standby(self, standby)
+    }
+
+    /// Whether the camera is in standby.
+    #[inline]
+    pub fn is_standby(&self) -> Result<bool> {
+        is_standby(self)
+    }
+
+    /// Whether the camera supports standby.
+    #[inline]
+    pub fn standby_supported(&self) -> Result<bool> {
+        standby_supported(self)
+    }
+
+    /// Enter standby until the returned guard is dropped, e.g. while the camera idles.
+    pub fn standby(&self) -> Result<Standby<'_>> {
+        enter_standby(self)?;
+        Ok(Standby { camera: self })
+    }
+}
+
+/// Standby of a camera, left when dropped.
+#[derive(Debug)]
+#[must_use = "the camera leaves standby when the guard is dropped"]
+pub struct Standby<'c> {
+    camera: &'c Camera,
+}
+
+impl Standby<'_> {
+    /// Leave standby, reporting a failure which dropping the guard ignores.
+    pub fn wake(self) -> Result<()> {
+        let camera = self.camera;
+        std::mem::forget(self);
+        leave_standby(camera)
+    }
+}
+
+impl Drop for Standby<'_> {
+    fn drop(&mut self) {
+        let _ = leave_standby(self.camera);
+    }
+}
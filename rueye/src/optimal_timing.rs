@@ -0,0 +1,130 @@
+//! Optimal camera timing of GigE and USB cameras.
+//!
+//! The driver determines the highest pixel clock at which the camera transfers images without
+//! errors, by raising it while capturing in freerun mode, and fine-tuning it for a period in which
+//! no transfer error may occur. [`measure`] runs this measurement and reports the result, leaving
+//! the pixel clock as it was; [`optimize`] applies it, along with the highest frame rate then
+//! possible.
+//!
+//! The measured pixel clock is at the limit of the connection, so both lower it by a tolerance,
+//! a fraction of the pixel clock kept as a safety margin.
+//!
+//! # Documentation
+//! [is_OptimalCameraTiming](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_optimalcameratiming.html)
+
+use std::{mem::size_of, ops::RangeInclusive, time::Duration};
+
+use ueye_sys::{
+    optimal_camera_timing::{
+        is_OptimalCameraTiming, IS_BEST_PCLK_RUN_ONCE, IS_OPTIMAL_CAMERA_TIMING,
+        IS_OPTIMAL_CAMERA_TIMING_CMD,
+    },
+    types::{double, void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    frame_rate,
+    observer::command,
+    pixel_clock,
+};
+
+/// Valid fine-tuning periods of the measurement.
+const FINE_TUNING: RangeInclusive<Duration> = Duration::from_secs(4)..=Duration::from_secs(20);
+
+/// Optimal timing of a camera.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimalTiming {
+    /// Pixel clock (in MHz).
+    pub pixel_clock: u32,
+
+    /// Highest frame rate at the pixel clock (in frames per second).
+    pub max_fps: f64,
+}
+
+/// Run the measurement of the driver, returning the pixel clock and frame rate it determined.
+fn run(camera: &Camera, fine_tuning: Duration) -> Result<(u32, f64)> {
+    if !FINE_TUNING.contains(&fine_tuning) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut pixel_clock: INT = 0;
+    let mut fps: double = 0.0;
+    let mut timing = IS_OPTIMAL_CAMERA_TIMING {
+        s32Mode: IS_BEST_PCLK_RUN_ONCE,
+        s32TimeoutFineTuning: fine_tuning.as_millis() as INT,
+        ps32PixelClock: &mut pixel_clock,
+        pdFramerate: &mut fps,
+    };
+
+    check(unsafe {
+        command!(is_OptimalCameraTiming(
+            camera.handle(),
+            IS_OPTIMAL_CAMERA_TIMING_CMD::IS_OPTIMAL_CAMERA_TIMING_CMD_GET_PIXELCLOCK,
+            &mut timing as *mut IS_OPTIMAL_CAMERA_TIMING as *mut void,
+            size_of::<IS_OPTIMAL_CAMERA_TIMING>() as UINT,
+        ))
+    })?;
+
+    Ok((pixel_clock.max(0) as u32, fps))
+}
+
+/// Highest supported pixel clock of `camera` at most `tolerance` below `measured`, or the lowest
+/// supported one.
+fn tolerated_clock(camera: &Camera, measured: u32, tolerance: f64) -> Result<u32> {
+    let limit = measured as f64 * (1.0 - tolerance);
+    let clocks = pixel_clock::supported_clocks(camera)?;
+
+    clocks
+        .iter()
+        .rev()
+        .find(|&&clock| clock as f64 <= limit)
+        .or(clocks.first())
+        .copied()
+        .ok_or(Error::NotSupported)
+}
+
+/// Measure the optimal timing of `camera`, lowered by `tolerance`, without applying it.
+///
+/// The camera must be capturing in freerun mode. The driver fine-tunes the pixel clock during
+/// `fine_tuning`, between 4 and 20 seconds; the longer the period, the more stable the result.
+/// The frame rate is scaled to the pixel clock after lowering it.
+///
+/// Fails with [`Error::InvalidParameter`] if `tolerance` is not in `[0, 1)` or `fine_tuning` is
+/// out of range.
+pub fn measure(camera: &Camera, tolerance: f64, fine_tuning: Duration) -> Result<OptimalTiming> {
+    if !(0.0..1.0).contains(&tolerance) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let previous = pixel_clock::current(camera)?;
+    let measured = run(camera, fine_tuning);
+
+    // the driver changes the pixel clock while measuring
+    if pixel_clock::current(camera)? != previous {
+        pixel_clock::set(camera, previous)?;
+    }
+
+    let (measured, fps) = measured?;
+    let clock = tolerated_clock(camera, measured, tolerance)?;
+    let max_fps = match measured {
+        0 => fps,
+        _ => fps * clock as f64 / measured as f64,
+    };
+
+    Ok(OptimalTiming { pixel_clock: clock, max_fps })
+}
+
+/// Measure the optimal timing of `camera`, lowered by `tolerance`, and apply it.
+///
+/// Sets the pixel clock and then the highest frame rate possible at it, which is returned as
+/// actually set. See [`measure`] for the parameters.
+pub fn optimize(camera: &Camera, tolerance: f64, fine_tuning: Duration) -> Result<OptimalTiming> {
+    let timing = measure(camera, tolerance, fine_tuning)?;
+    pixel_clock::set(camera, timing.pixel_clock)?;
+
+    let max_fps = frame_rate::set(camera, frame_rate::range(camera)?.max_fps())?;
+    Ok(OptimalTiming { max_fps, ..timing })
+}
@@ -0,0 +1,430 @@
+//! Software annotation of frames.
+//!
+//! Draws crosshairs, timestamps, frame numbers and AOI outlines directly into the image data on
+//! the CPU, before the frame is displayed or saved. An [`Overlay`] holds the configuration for a
+//! capture session and is applied to each frame with [`Overlay::apply`].
+//!
+//! Packed formats with 1 (mono), 2 (mono, 16-bit container), 3 (BGR), 4 (BGRA), 6 (BGR, 16-bit
+//! channels) and 8 (BGRA, 16-bit channels) bytes per pixel are supported; pixels of other sizes
+//! are left unchanged.
+
+use std::time::Duration;
+
+use crate::{aoi::Aoi, frame::FrameMut};
+
+/// Width of a glyph, in font pixels.
+const GLYPH_WIDTH: usize = 5;
+
+/// Height of a glyph, in font pixels.
+const GLYPH_HEIGHT: usize = 7;
+
+/// Horizontal spacing between glyphs, in font pixels.
+const GLYPH_SPACING: usize = 1;
+
+/// Margin between the frame border and the text, in frame pixels.
+const TEXT_MARGIN: usize = 4;
+
+/// Bitmap for `c`, one byte per row with the 5 least significant bits used.
+const fn glyph(c: char) -> [u8; GLYPH_HEIGHT] {
+    match c {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        _ => [0x00; GLYPH_HEIGHT],
+    }
+}
+
+/// Color of the annotations.
+///
+/// Mono frames receive the luma of the color.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// Red component.
+    pub r: u8,
+
+    /// Green component.
+    pub g: u8,
+
+    /// Blue component.
+    pub b: u8,
+}
+
+impl Color {
+    /// Black, for bright scenes.
+    pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+
+    /// White, for dark scenes and mono frames.
+    pub const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+
+    /// Pure red.
+    pub const RED: Color = Color { r: 255, g: 0, b: 0 };
+
+    /// Pure green.
+    pub const GREEN: Color = Color { r: 0, g: 255, b: 0 };
+
+    /// Luma of the color (ITU-R BT.601).
+    #[inline]
+    pub const fn luma(&self) -> u8 {
+        ((self.r as u32 * 299 + self.g as u32 * 587 + self.b as u32 * 114) / 1000) as u8
+    }
+
+    /// Write the color into the bytes of a single pixel, leaving the alpha channel unchanged.
+    fn write(&self, pixel: &mut [u8]) {
+        let wide = |value: u8| (value as u16 * 257).to_le_bytes();
+
+        match pixel.len() {
+            1 => pixel[0] = self.luma(),
+            2 => pixel.copy_from_slice(&wide(self.luma())),
+            3 | 4 => pixel[..3].copy_from_slice(&[self.b, self.g, self.r]),
+            6 | 8 => {
+                pixel[0..2].copy_from_slice(&wide(self.b));
+                pixel[2..4].copy_from_slice(&wide(self.g));
+                pixel[4..6].copy_from_slice(&wide(self.r));
+            },
+            _ => {},
+        }
+    }
+}
+
+impl Default for Color {
+    #[inline]
+    fn default() -> Self {
+        Color::GREEN
+    }
+}
+
+/// Crosshair configuration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Crosshair {
+    /// Center of the crosshair, or [`None`] for the center of the frame.
+    pub center: Option<(usize, usize)>,
+
+    /// Length of each arm, in pixels, or [`None`] to span the whole frame.
+    pub arm: Option<usize>,
+
+    /// Line thickness, in pixels.
+    pub thickness: usize,
+}
+
+impl Default for Crosshair {
+    #[inline]
+    fn default() -> Self {
+        Self { center: None, arm: None, thickness: 1 }
+    }
+}
+
+/// Per-frame values rendered by an [`Overlay`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Annotation {
+    /// Frame number.
+    pub frame_number: Option<u64>,
+
+    /// Timestamp, rendered as `HH:MM:SS.mmm`.
+    pub timestamp: Option<Duration>,
+}
+
+/// Overlay configuration for a capture session.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Overlay {
+    /// Color of all annotations.
+    pub color: Color,
+
+    /// Crosshair, if any.
+    pub crosshair: Option<Crosshair>,
+
+    /// Render the frame number in the top-left corner.
+    pub frame_number: bool,
+
+    /// Render the timestamp in the top-left corner, below the frame number.
+    pub timestamp: bool,
+
+    /// AOI outlines to draw.
+    pub aoi_outlines: Vec<Aoi>,
+
+    /// Thickness of the AOI outlines, in pixels.
+    pub outline_thickness: usize,
+
+    /// Integer scale factor of the text.
+    pub text_scale: usize,
+}
+
+impl Default for Overlay {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: Color::default(),
+            crosshair: None,
+            frame_number: false,
+            timestamp: false,
+            aoi_outlines: Vec::new(),
+            outline_thickness: 1,
+            text_scale: 2,
+        }
+    }
+}
+
+impl Overlay {
+    /// Draw all configured annotations onto `frame`.
+    ///
+    /// Annotations are clipped to the frame; values missing from `annotation` are skipped.
+    pub fn apply(&self, frame: &mut FrameMut<'_>, annotation: &Annotation) {
+        for rect in &self.aoi_outlines {
+            self.draw_rect(frame, rect);
+        }
+
+        if let Some(crosshair) = &self.crosshair {
+            self.draw_crosshair(frame, crosshair);
+        }
+
+        let scale = self.text_scale.max(1);
+        let line_height = (GLYPH_HEIGHT + 2).saturating_mul(scale);
+        let mut y = TEXT_MARGIN;
+
+        if let Some(number) = annotation.frame_number.filter(|_| self.frame_number) {
+            self.draw_text(frame, TEXT_MARGIN, y, &format!("#{number}"));
+            y = y.saturating_add(line_height);
+        }
+
+        if let Some(timestamp) = annotation.timestamp.filter(|_| self.timestamp) {
+            self.draw_text(frame, TEXT_MARGIN, y, &format_timestamp(timestamp));
+        }
+    }
+
+    fn fill(&self, frame: &mut FrameMut<'_>, x: usize, y: usize, width: usize, height: usize) {
+        let x_end = x.saturating_add(width).min(frame.width());
+        let y_end = y.saturating_add(height).min(frame.height());
+
+        for py in y..y_end {
+            for px in x..x_end {
+                if let Some(pixel) = frame.pixel_mut(px, py) {
+                    self.color.write(pixel);
+                }
+            }
+        }
+    }
+
+    fn draw_rect(&self, frame: &mut FrameMut<'_>, rect: &Aoi) {
+        let (Ok(x), Ok(y), Ok(width), Ok(height)) = (
+            usize::try_from(rect.x),
+            usize::try_from(rect.y),
+            usize::try_from(rect.width),
+            usize::try_from(rect.height),
+        ) else {
+            return;
+        };
+
+        let t = self.outline_thickness.max(1);
+        self.fill(frame, x, y, width, t);
+        self.fill(frame, x, y.saturating_add(height).saturating_sub(t), width, t);
+        self.fill(frame, x, y, t, height);
+        self.fill(frame, x.saturating_add(width).saturating_sub(t), y, t, height);
+    }
+
+    fn draw_crosshair(&self, frame: &mut FrameMut<'_>, crosshair: &Crosshair) {
+        let (cx, cy) = crosshair.center.unwrap_or((frame.width() / 2, frame.height() / 2));
+        let t = crosshair.thickness.max(1);
+        let half = t / 2;
+
+        let (x0, x1, y0, y1) = match crosshair.arm {
+            Some(arm) => (
+                cx.saturating_sub(arm),
+                cx.saturating_add(arm).saturating_add(1).min(frame.width()),
+                cy.saturating_sub(arm),
+                cy.saturating_add(arm).saturating_add(1).min(frame.height()),
+            ),
+            None => (0, frame.width(), 0, frame.height()),
+        };
+
+        self.fill(frame, x0, cy.saturating_sub(half), x1.saturating_sub(x0), t);
+        self.fill(frame, cx.saturating_sub(half), y0, t, y1.saturating_sub(y0));
+    }
+
+    fn draw_text(&self, frame: &mut FrameMut<'_>, x: usize, y: usize, text: &str) {
+        let scale = self.text_scale.max(1);
+        let advance = (GLYPH_WIDTH + GLYPH_SPACING).saturating_mul(scale);
+
+        for (i, c) in text.chars().enumerate() {
+            let gx = x.saturating_add(i.saturating_mul(advance));
+            if gx >= frame.width() || y >= frame.height() {
+                break;
+            }
+
+            for (row, bits) in glyph(c).iter().enumerate() {
+                let py = y.saturating_add(row.saturating_mul(scale));
+                for col in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                        let px = gx.saturating_add(col.saturating_mul(scale));
+                        self.fill(frame, px, py, scale, scale);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Format `timestamp` as `HH:MM:SS.mmm`.
+fn format_timestamp(timestamp: Duration) -> String {
+    let secs = timestamp.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    format!("{hours:02}:{minutes:02}:{seconds:02}.{:03}", timestamp.subsec_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::FrameBuf;
+
+    /// Blank frame of `width` × `height` pixels of 8 bits.
+    fn blank(width: usize, height: usize) -> FrameBuf {
+        FrameBuf::from_vec(vec![0; width * height], width, height, 8).unwrap()
+    }
+
+    /// Rows of the frame, `#` for drawn and `.` for blank pixels.
+    fn render(frame: &FrameBuf) -> Vec<String> {
+        let frame = frame.as_frame();
+        frame
+            .rows()
+            .map(|row| row.iter().map(|&p| if p == 0 { '.' } else { '#' }).collect())
+            .collect()
+    }
+
+    fn overlay() -> Overlay {
+        Overlay { color: Color::WHITE, text_scale: 1, ..Overlay::default() }
+    }
+
+    #[test]
+    fn write_handles_every_pixel_size() {
+        let color = Color { r: 0x10, g: 0x20, b: 0x30 };
+
+        let mut pixel = [0u8; 1];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [color.luma()]);
+
+        let mut pixel = [0u8; 2];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [color.luma(), color.luma()]);
+
+        let mut pixel = [0u8; 3];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [0x30, 0x20, 0x10]);
+
+        let mut pixel = [0xFFu8; 4];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [0x30, 0x20, 0x10, 0xFF]);
+
+        let mut pixel = [0u8; 6];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [0x30, 0x30, 0x20, 0x20, 0x10, 0x10]);
+
+        let mut pixel = [0xFFu8; 8];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [0x30, 0x30, 0x20, 0x20, 0x10, 0x10, 0xFF, 0xFF]);
+
+        let mut pixel = [0xAAu8; 5];
+        color.write(&mut pixel);
+        assert_eq!(pixel, [0xAA; 5]);
+    }
+
+    #[test]
+    fn apply_draws_on_wide_pixels() {
+        let mut frame = FrameBuf::from_vec(vec![0; 2 * 2 * 6], 2, 2, 48).unwrap();
+        let overlay =
+            Overlay { aoi_outlines: vec![Aoi { x: 0, y: 0, width: 1, height: 1 }], ..overlay() };
+        overlay.apply(&mut frame.as_frame_mut(), &Annotation::default());
+
+        assert_eq!(&frame.data()[..6], [0xFF; 6]);
+        assert_eq!(&frame.data()[6..], [0; 18]);
+    }
+
+    #[test]
+    fn aoi_outline_is_clipped() {
+        let mut frame = blank(6, 5);
+        let overlay =
+            Overlay { aoi_outlines: vec![Aoi { x: 1, y: 1, width: 4, height: 3 }], ..overlay() };
+        overlay.apply(&mut frame.as_frame_mut(), &Annotation::default());
+
+        assert_eq!(render(&frame), ["......", ".####.", ".#..#.", ".####.", "......"]);
+    }
+
+    #[test]
+    fn crosshair_arms() {
+        let mut frame = blank(7, 5);
+        let crosshair = Crosshair { center: Some((3, 2)), arm: Some(1), thickness: 1 };
+        let overlay = Overlay { crosshair: Some(crosshair), ..overlay() };
+        overlay.apply(&mut frame.as_frame_mut(), &Annotation::default());
+
+        assert_eq!(render(&frame), [".......", "...#...", "..###..", "...#...", "......."]);
+    }
+
+    #[test]
+    fn crosshair_spans_frame_by_default() {
+        let mut frame = blank(5, 3);
+        let overlay = Overlay { crosshair: Some(Crosshair::default()), ..overlay() };
+        overlay.apply(&mut frame.as_frame_mut(), &Annotation::default());
+
+        assert_eq!(render(&frame), ["..#..", "#####", "..#.."]);
+    }
+
+    #[test]
+    fn frame_number_glyphs() {
+        let mut frame = blank(TEXT_MARGIN + 12, TEXT_MARGIN + GLYPH_HEIGHT);
+        let overlay = Overlay { frame_number: true, ..overlay() };
+        overlay.apply(
+            &mut frame.as_frame_mut(),
+            &Annotation { frame_number: Some(1), ..Default::default() },
+        );
+
+        let rows = render(&frame);
+        let text: Vec<&str> = rows[TEXT_MARGIN..].iter().map(|row| &row[TEXT_MARGIN..]).collect();
+        assert_eq!(
+            text,
+            [
+                ".#.#....#...",
+                ".#.#...##...",
+                "#####...#...",
+                ".#.#....#...",
+                "#####...#...",
+                ".#.#....#...",
+                ".#.#...###..",
+            ]
+        );
+        assert!(rows[..TEXT_MARGIN].iter().all(|row| !row.contains('#')));
+    }
+
+    #[test]
+    fn huge_scale_and_offsets_do_not_overflow() {
+        let mut frame = blank(8, 8);
+        let overlay = Overlay {
+            text_scale: usize::MAX,
+            frame_number: true,
+            timestamp: true,
+            crosshair: Some(Crosshair {
+                center: Some((usize::MAX, usize::MAX)),
+                arm: Some(usize::MAX),
+                thickness: usize::MAX,
+            }),
+            aoi_outlines: vec![Aoi { x: i32::MAX, y: i32::MAX, width: i32::MAX, height: i32::MAX }],
+            outline_thickness: usize::MAX,
+            ..overlay()
+        };
+        let annotation =
+            Annotation { frame_number: Some(u64::MAX), timestamp: Some(Duration::MAX) };
+        overlay.apply(&mut frame.as_frame_mut(), &annotation);
+    }
+
+    #[test]
+    fn format_timestamp_pads_fields() {
+        assert_eq!(format_timestamp(Duration::from_millis(3_723_004)), "01:02:03.004");
+    }
+}
@@ -0,0 +1,292 @@
+//! Numeric camera parameters with a range and a default.
+//!
+//! Many driver functions follow the same pattern of commands: query the value, set it, and query
+//! its range and default. Each [`Parameter`] stands for one such value, so that all of them are
+//! read, validated and written the same way, e.g. through [`Camera::parameter`] and
+//! [`Camera::set_parameter`].
+//!
+//! # Documentation
+//! * [is_Blacklevel](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_blacklevel.html)
+//! * [is_ColorTemperature](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_colortemperature.html)
+//! * [is_EdgeEnhancement](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_edgeenhancement.html)
+//! * [is_Saturation](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_saturation.html)
+//! * [is_SetTriggerDelay](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_settriggerdelay.html)
+//! * [is_Sharpness](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sharpness.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    black_level::{is_Blacklevel, BLACKLEVEL_CMD},
+    color_temperature::{is_ColorTemperature, COLOR_TEMPERATURE_CMD},
+    edge_enhancement::{is_EdgeEnhancement, IS_EDGE_ENHANCEMENT_CMD},
+    external_trigger::{
+        is_SetTriggerDelay, IS_GET_MAX_TRIGGER_DELAY, IS_GET_MIN_TRIGGER_DELAY,
+        IS_GET_TRIGGER_DELAY, IS_GET_TRIGGER_DELAY_GRANULARITY,
+    },
+    saturation::{is_Saturation, SATURATION_CMD},
+    sharpness::{is_Sharpness, SHARPNESS_CMD},
+    types::{void, INT, IS_RANGE_S32, IS_RANGE_U32, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::{call, command},
+    range::{ParamRange, RangeValue},
+};
+
+/// Numeric parameter of a camera.
+pub trait Parameter {
+    /// Type of the value.
+    type Value: RangeValue;
+
+    /// Name of the parameter, e.g. for labels.
+    const NAME: &'static str;
+
+    /// Query the current value.
+    fn get(camera: &Camera) -> Result<Self::Value>;
+
+    /// Set `value` without checking it against the range.
+    fn set_unchecked(camera: &Camera, value: Self::Value) -> Result<()>;
+
+    /// Query the range of the value.
+    fn range(camera: &Camera) -> Result<ParamRange<Self::Value>>;
+
+    /// Query the default value.
+    ///
+    /// Fails with [`Error::NotSupported`] if the driver reports no default.
+    fn default(camera: &Camera) -> Result<Self::Value> {
+        Self::range(camera)?.default.ok_or(Error::NotSupported)
+    }
+
+    /// Set `value`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `value` is not [within](ParamRange::contains)
+    /// the range of the parameter.
+    fn set(camera: &Camera, value: Self::Value) -> Result<()> {
+        if !Self::range(camera)?.contains(value) {
+            return Err(Error::InvalidParameter);
+        }
+
+        Self::set_unchecked(camera, value)
+    }
+
+    /// Reset the parameter to its default value.
+    fn reset(camera: &Camera) -> Result<()> {
+        Self::set_unchecked(camera, Self::default(camera)?)
+    }
+}
+
+/// Exchange `value` of type `$type` with `$function` through command `$cmd`.
+macro_rules! exchange {
+    ($camera:expr, $function:ident, $cmd:expr, $type:ty, $value:expr) => {
+        check(unsafe {
+            command!($function(
+                $camera.handle(),
+                $cmd,
+                $value as *mut $type as *mut void,
+                size_of::<$type>() as UINT,
+            ))
+        })
+    };
+}
+
+/// Implement [`Parameter`] for a command family of `$function`, whose range is queried either as
+/// a range structure or as separate minimum, maximum and increment.
+macro_rules! parameter {
+    (
+        $(#[$attr:meta])*
+        $name:ident: $type:ty, $label:literal, $function:ident($cmd:ident) {
+            get: $get:ident,
+            set: $set:ident,
+            default: $default:ident,
+            range: $range:ident as $range_type:ty $(,)?
+        }
+    ) => {
+        parameter!(@impl $(#[$attr])* $name: $type, $label, $function($cmd) {
+            get: $get,
+            set: $set,
+            range: |camera| {
+                // SAFETY: the range structures are plain data, for which all-zero is valid.
+                let mut range: $range_type = unsafe { std::mem::zeroed() };
+                exchange!(camera, $function, $cmd::$range, $range_type, &mut range)?;
+
+                let mut default: $type = Default::default();
+                exchange!(camera, $function, $cmd::$default, $type, &mut default)?;
+
+                Ok(ParamRange::from(range).with_default(default))
+            },
+        });
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident: $type:ty, $label:literal, $function:ident($cmd:ident) {
+            get: $get:ident,
+            set: $set:ident,
+            default: $default:ident,
+            min: $min:ident,
+            max: $max:ident,
+            inc: $inc:ident $(,)?
+        }
+    ) => {
+        parameter!(@impl $(#[$attr])* $name: $type, $label, $function($cmd) {
+            get: $get,
+            set: $set,
+            range: |camera| {
+                let [mut min, mut max, mut inc, mut default]: [$type; 4] = Default::default();
+                exchange!(camera, $function, $cmd::$min, $type, &mut min)?;
+                exchange!(camera, $function, $cmd::$max, $type, &mut max)?;
+                exchange!(camera, $function, $cmd::$inc, $type, &mut inc)?;
+                exchange!(camera, $function, $cmd::$default, $type, &mut default)?;
+
+                Ok(ParamRange::new(min, max, inc).with_default(default))
+            },
+        });
+    };
+    (
+        @impl $(#[$attr:meta])* $name:ident: $type:ty, $label:literal, $function:ident($cmd:ident) {
+            get: $get:ident,
+            set: $set:ident,
+            range: $range:expr,
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl Parameter for $name {
+            type Value = $type;
+
+            const NAME: &'static str = $label;
+
+            fn get(camera: &Camera) -> Result<$type> {
+                let mut value: $type = Default::default();
+                exchange!(camera, $function, $cmd::$get, $type, &mut value)?;
+                Ok(value)
+            }
+
+            fn set_unchecked(camera: &Camera, value: $type) -> Result<()> {
+                exchange!(camera, $function, $cmd::$set, $type, &mut { value })
+            }
+
+            fn range(camera: &Camera) -> Result<ParamRange<$type>> {
+                let range: fn(&Camera) -> Result<ParamRange<$type>> = $range;
+                range(camera)
+            }
+        }
+    };
+}
+
+parameter! {
+    /// Offset of the black level.
+    BlackLevelOffset: INT, "black level offset", is_Blacklevel(BLACKLEVEL_CMD) {
+        get: IS_BLACKLEVEL_CMD_GET_OFFSET,
+        set: IS_BLACKLEVEL_CMD_SET_OFFSET,
+        default: IS_BLACKLEVEL_CMD_GET_OFFSET_DEFAULT,
+        range: IS_BLACKLEVEL_CMD_GET_OFFSET_RANGE as IS_RANGE_S32,
+    }
+}
+
+parameter! {
+    /// Level of the software edge enhancement, `0` disabling it.
+    EdgeEnhancement: UINT, "edge enhancement", is_EdgeEnhancement(IS_EDGE_ENHANCEMENT_CMD) {
+        get: IS_EDGE_ENHANCEMENT_CMD_GET,
+        set: IS_EDGE_ENHANCEMENT_CMD_SET,
+        default: IS_EDGE_ENHANCEMENT_CMD_GET_DEFAULT,
+        range: IS_EDGE_ENHANCEMENT_CMD_GET_RANGE as IS_RANGE_U32,
+    }
+}
+
+parameter! {
+    /// Color saturation.
+    Saturation: INT, "saturation", is_Saturation(SATURATION_CMD) {
+        get: SATURATION_CMD_GET_VALUE,
+        set: SATURATION_CMD_SET_VALUE,
+        default: SATURATION_CMD_GET_DEFAULT_VALUE,
+        min: SATURATION_CMD_GET_MIN_VALUE,
+        max: SATURATION_CMD_GET_MAX_VALUE,
+        inc: SATURATION_CMD_GET_INCREMENT,
+    }
+}
+
+parameter! {
+    /// Sharpness.
+    Sharpness: INT, "sharpness", is_Sharpness(SHARPNESS_CMD) {
+        get: SHARPNESS_CMD_GET_VALUE,
+        set: SHARPNESS_CMD_SET_VALUE,
+        default: SHARPNESS_CMD_GET_DEFAULT_VALUE,
+        min: SHARPNESS_CMD_GET_MIN_VALUE,
+        max: SHARPNESS_CMD_GET_MAX_VALUE,
+        inc: SHARPNESS_CMD_GET_INCREMENT,
+    }
+}
+
+parameter! {
+    /// Color temperature of the color conversion (in Kelvin).
+    ColorTemperature: UINT, "color temperature", is_ColorTemperature(COLOR_TEMPERATURE_CMD) {
+        get: COLOR_TEMPERATURE_CMD_GET_TEMPERATURE,
+        set: COLOR_TEMPERATURE_CMD_SET_TEMPERATURE,
+        default: COLOR_TEMPERATURE_CMD_GET_TEMPERATURE_DEFAULT,
+        min: COLOR_TEMPERATURE_CMD_GET_TEMPERATURE_MIN,
+        max: COLOR_TEMPERATURE_CMD_GET_TEMPERATURE_MAX,
+        inc: COLOR_TEMPERATURE_CMD_GET_TEMPERATURE_INC,
+    }
+}
+
+/// Delay of the trigger (in µs), after the trigger signal and before the exposure; `0` by default.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TriggerDelay;
+
+impl Parameter for TriggerDelay {
+    type Value = INT;
+
+    const NAME: &'static str = "trigger delay";
+
+    #[inline]
+    fn get(camera: &Camera) -> Result<INT> {
+        query_trigger_delay(camera, IS_GET_TRIGGER_DELAY)
+    }
+
+    #[inline]
+    fn set_unchecked(camera: &Camera, value: INT) -> Result<()> {
+        check(unsafe { call!(is_SetTriggerDelay(camera.handle(), value)) })
+    }
+
+    fn range(camera: &Camera) -> Result<ParamRange<INT>> {
+        Ok(ParamRange::new(
+            query_trigger_delay(camera, IS_GET_MIN_TRIGGER_DELAY)?,
+            query_trigger_delay(camera, IS_GET_MAX_TRIGGER_DELAY)?,
+            query_trigger_delay(camera, IS_GET_TRIGGER_DELAY_GRANULARITY)?,
+        )
+        .with_default(0))
+    }
+}
+
+/// Query a value of the trigger delay; negative return values are error codes.
+#[inline]
+fn query_trigger_delay(camera: &Camera, which: INT) -> Result<INT> {
+    match unsafe { call!(is_SetTriggerDelay(camera.handle(), which)) } {
+        value if value < 0 => Err(Error::from_code(value)),
+        value => Ok(value),
+    }
+}
+
+impl Camera {
+    /// Query the current value of parameter `P`.
+    #[inline]
+    pub fn parameter<P: Parameter>(&self) -> Result<P::Value> {
+        P::get(self)
+    }
+
+    /// Set parameter `P` to `value`, checked against its range.
+    #[inline]
+    pub fn set_parameter<P: Parameter>(&self, value: P::Value) -> Result<()> {
+        P::set(self, value)
+    }
+
+    /// Query the range of parameter `P`.
+    #[inline]
+    pub fn parameter_range<P: Parameter>(&self) -> Result<ParamRange<P::Value>> {
+        P::range(self)
+    }
+}
@@ -0,0 +1,81 @@
+//! Parameter set in the user memory of the camera.
+//!
+//! The camera keeps a single parameter set in its non-volatile user memory, so that it keeps its
+//! settings across power cycles. The driver addresses it without a slot number. With
+//! [`set_initial_parameter_set`], the driver applies it whenever a camera is opened, e.g. for
+//! headless devices that boot into a known configuration.
+//!
+//! Long exposure and color mode settings are not stored in the user memory.
+//!
+//! # Documentation
+//! * [is_ParameterSet](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_parameterset.html)
+//! * [Loading camera parameters during initializing](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configurationcameraparameter.html)
+//!
+//! [`set_initial_parameter_set`]: crate::configuration::set_initial_parameter_set
+
+use std::{mem::size_of, ptr};
+
+use ueye_sys::{
+    parameter_set::{is_ParameterSet, PARAMETERSET_CMD},
+    types::{void, BOOL, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+fn parameter_set<T>(camera: &Camera, cmd: PARAMETERSET_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_ParameterSet(
+            camera.handle(),
+            cmd,
+            param as *mut T as *mut void,
+            size_of::<T>() as UINT,
+        ))
+    })
+}
+
+/// Run a command without parameter on the parameter set in the user memory.
+///
+/// Fails with [`Error::NotSupported`] if the camera has no user memory for a parameter set.
+fn user_memory_command(camera: &Camera, cmd: PARAMETERSET_CMD) -> Result<()> {
+    if !is_available(camera)? {
+        return Err(Error::NotSupported);
+    }
+
+    check(unsafe { command!(is_ParameterSet(camera.handle(), cmd, ptr::null_mut(), 0)) })
+}
+
+/// Query whether the camera can keep a parameter set in its user memory.
+pub fn is_available(camera: &Camera) -> Result<bool> {
+    let mut available: BOOL = 0;
+    parameter_set(
+        camera,
+        PARAMETERSET_CMD::IS_PARAMETERSET_CMD_GET_HW_PARAMETERSET_AVAILABLE,
+        &mut available,
+    )?;
+    Ok(available != 0)
+}
+
+/// Save the current settings of the camera to its user memory.
+#[inline]
+pub fn save_to_camera(camera: &Camera) -> Result<()> {
+    user_memory_command(camera, PARAMETERSET_CMD::IS_PARAMETERSET_CMD_SAVE_EEPROM)
+}
+
+/// Apply the parameter set in the user memory to the camera.
+///
+/// Fails with [`Error::ParameterSetNotPresent`] if none was saved. Capture should be stopped
+/// beforehand, since the image format may change; image memories must then be allocated again.
+#[inline]
+pub fn load_from_camera(camera: &Camera) -> Result<()> {
+    user_memory_command(camera, PARAMETERSET_CMD::IS_PARAMETERSET_CMD_LOAD_EEPROM)
+}
+
+/// Erase the parameter set in the user memory.
+#[inline]
+pub fn erase(camera: &Camera) -> Result<()> {
+    user_memory_command(camera, PARAMETERSET_CMD::IS_PARAMETERSET_CMD_ERASE_HW_PARAMETERSET)
+}
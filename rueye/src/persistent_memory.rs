@@ -0,0 +1,173 @@
+//! Non-volatile user memory of the camera.
+//!
+//! Every camera has a 64-byte user memory; some models additionally provide a 64 kB extended and
+//! a protected user memory. Accesses are bounds-checked against the size reported by the camera,
+//! and fail with [`Error::OutOfRange`] instead of reaching the driver.
+//!
+//! <div class="warning">
+//! Images may be lost when writing to the user memory during image acquisition.
+//! </div>
+//!
+//! # Documentation
+//! [is_PersistentMemory](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_persistentmemory.html)
+
+use std::{io::ErrorKind, mem::size_of};
+
+use ueye_sys::{
+    persistent_memory::{is_PersistentMemory, IS_PERSISTENT_MEMORY, PERSISTENT_MEMORY_CMD},
+    types::{char, void, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Area of the non-volatile user memory.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserMemory {
+    /// 64-byte user memory, available on all cameras.
+    #[default]
+    User,
+
+    /// Extended user memory (64 kB).
+    Extended,
+
+    /// Protected user memory.
+    Protected,
+}
+
+impl UserMemory {
+    const fn read_command(self) -> PERSISTENT_MEMORY_CMD {
+        match self {
+            UserMemory::User => PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_READ_USER,
+            UserMemory::Extended => PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_READ_USER_EXTENDED,
+            UserMemory::Protected => {
+                PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_READ_USER_PROTECTED
+            },
+        }
+    }
+
+    const fn write_command(self) -> PERSISTENT_MEMORY_CMD {
+        match self {
+            UserMemory::User => PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_WRITE_USER,
+            UserMemory::Extended => PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_WRITE_USER_EXTENDED,
+            UserMemory::Protected => {
+                PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_WRITE_USER_PROTECTED
+            },
+        }
+    }
+
+    const fn size_command(self) -> PERSISTENT_MEMORY_CMD {
+        match self {
+            UserMemory::User => PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_GET_SIZE_USER,
+            UserMemory::Extended => {
+                PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_GET_SIZE_USER_EXTENDED
+            },
+            UserMemory::Protected => {
+                PERSISTENT_MEMORY_CMD::IS_PERSISTENT_MEMORY_GET_SIZE_USER_PROTECTED
+            },
+        }
+    }
+}
+
+/// Query the size of `memory` (in bytes).
+pub fn size(camera: &Camera, memory: UserMemory) -> Result<usize> {
+    let mut size: UINT = 0;
+    check(unsafe {
+        command!(is_PersistentMemory(
+            camera.handle(),
+            memory.size_command(),
+            &mut size as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(size as usize)
+}
+
+/// Fail with [`Error::OutOfRange`] unless `len` bytes at `offset` lie within `memory`.
+fn check_range(camera: &Camera, memory: UserMemory, offset: usize, len: usize) -> Result<()> {
+    let size = size(camera, memory)?;
+    match offset.checked_add(len) {
+        Some(end) if end <= size => Ok(()),
+        _ => Err(Error::OutOfRange { offset, len, size }),
+    }
+}
+
+fn access(
+    camera: &Camera,
+    cmd: PERSISTENT_MEMORY_CMD,
+    offset: usize,
+    len: usize,
+    buffer: *mut char,
+) -> Result<()> {
+    let mut access = IS_PERSISTENT_MEMORY {
+        u32Offset: offset as UINT,
+        u32Count: len as UINT,
+        s32Option: 0,
+        pu8Memory: buffer,
+    };
+    check(unsafe {
+        command!(is_PersistentMemory(
+            camera.handle(),
+            cmd,
+            &mut access as *mut IS_PERSISTENT_MEMORY as *mut void,
+            size_of::<IS_PERSISTENT_MEMORY>() as UINT,
+        ))
+    })
+}
+
+/// Read `buffer.len()` bytes at `offset` of `memory` into `buffer`.
+pub fn read(camera: &Camera, memory: UserMemory, offset: usize, buffer: &mut [u8]) -> Result<()> {
+    check_range(camera, memory, offset, buffer.len())?;
+    access(camera, memory.read_command(), offset, buffer.len(), buffer.as_mut_ptr() as *mut char)
+}
+
+/// Write `data` at `offset` of `memory`.
+pub fn write(camera: &Camera, memory: UserMemory, offset: usize, data: &[u8]) -> Result<()> {
+    check_range(camera, memory, offset, data.len())?;
+
+    // The driver only reads from the buffer when writing
+    access(camera, memory.write_command(), offset, data.len(), data.as_ptr() as *mut char)
+}
+
+/// Read `buffer.len()` bytes at `offset` of the 64-byte user memory into `buffer`.
+#[inline]
+pub fn read_user_memory(camera: &Camera, offset: usize, buffer: &mut [u8]) -> Result<()> {
+    read(camera, UserMemory::User, offset, buffer)
+}
+
+/// Write `data` at `offset` of the 64-byte user memory.
+#[inline]
+pub fn write_user_memory(camera: &Camera, offset: usize, data: &[u8]) -> Result<()> {
+    write(camera, UserMemory::User, offset, data)
+}
+
+/// Read the 64-byte user memory as a UTF-8 string, up to the first NUL byte.
+///
+/// Fails with [`Error::Io`] ([`ErrorKind::InvalidData`]) if the content is not UTF-8.
+pub fn read_user_string(camera: &Camera) -> Result<String> {
+    let mut buffer = vec![0; size(camera, UserMemory::User)?];
+    read_user_memory(camera, 0, &mut buffer)?;
+
+    let len = buffer.iter().position(|&byte| byte == 0).unwrap_or(buffer.len());
+    buffer.truncate(len);
+    String::from_utf8(buffer).map_err(|_| Error::Io(ErrorKind::InvalidData))
+}
+
+/// Write `string` to the 64-byte user memory, padded with NUL bytes.
+///
+/// Fails with [`Error::OutOfRange`] if `string` does not fit.
+pub fn write_user_string(camera: &Camera, string: &str) -> Result<()> {
+    let size = size(camera, UserMemory::User)?;
+    if string.len() > size {
+        return Err(Error::OutOfRange { offset: 0, len: string.len(), size });
+    }
+
+    let mut buffer = string.as_bytes().to_vec();
+    buffer.resize(size, 0);
+    write_user_memory(camera, 0, &buffer)
+}
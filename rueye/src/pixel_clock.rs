@@ -0,0 +1,113 @@
+//! Pixel clock.
+//!
+//! The pixel clock limits the frame rate and the exposure time range, so it should be set before
+//! either of them.
+//!
+//! Depending on the model, a camera supports either discrete pixel clocks or a range with a fixed
+//! increment; [`supported_clocks`] lists the valid values for both.
+//!
+//! # Documentation
+//! [is_PixelClock](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_pixelclock.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    pixel_clock::{is_PixelClock, PIXELCLOCK_CMD},
+    types::{void, IS_RANGE_U32, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+    range::ParamRange,
+};
+
+fn get(camera: &Camera, cmd: PIXELCLOCK_CMD) -> Result<UINT> {
+    let mut value: UINT = 0;
+    check(unsafe {
+        command!(is_PixelClock(
+            camera.handle(),
+            cmd,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
+
+/// Query the current pixel clock (in MHz).
+#[inline]
+pub fn current(camera: &Camera) -> Result<u32> {
+    get(camera, PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_GET)
+}
+
+/// Query the default pixel clock (in MHz).
+#[inline]
+pub fn default(camera: &Camera) -> Result<u32> {
+    get(camera, PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_GET_DEFAULT)
+}
+
+/// Query the pixel clock range (in MHz).
+///
+/// An increment of `0` denotes a camera with discrete pixel clocks.
+pub fn range(camera: &Camera) -> Result<ParamRange<u32>> {
+    let mut range = IS_RANGE_U32 { u32Min: 0, u32Max: 0, u32Inc: 0 };
+    check(unsafe {
+        command!(is_PixelClock(
+            camera.handle(),
+            PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_GET_RANGE,
+            &mut range as *mut IS_RANGE_U32 as *mut void,
+            size_of::<IS_RANGE_U32>() as UINT,
+        ))
+    })?;
+
+    Ok(range.into())
+}
+
+/// Query the pixel clocks supported by the camera (in MHz), in ascending order.
+pub fn supported_clocks(camera: &Camera) -> Result<Vec<u32>> {
+    let range = range(camera)?;
+    if range.inc != 0 {
+        return Ok((range.min..=range.max).step_by(range.inc as usize).collect());
+    }
+
+    let count = get(camera, PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_GET_NUMBER)?;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut clocks: Vec<UINT> = vec![0; count as usize];
+    check(unsafe {
+        command!(is_PixelClock(
+            camera.handle(),
+            PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_GET_LIST,
+            clocks.as_mut_ptr() as *mut void,
+            (clocks.len() * size_of::<UINT>()) as UINT,
+        ))
+    })?;
+
+    clocks.sort_unstable();
+    Ok(clocks)
+}
+
+/// Set the pixel clock (in MHz).
+///
+/// Fails with [`Error::InvalidParameter`] if `mhz` is not one of the [supported
+/// clocks](supported_clocks).
+pub fn set(camera: &Camera, mhz: u32) -> Result<()> {
+    if !supported_clocks(camera)?.contains(&mhz) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut value: UINT = mhz;
+    check(unsafe {
+        command!(is_PixelClock(
+            camera.handle(),
+            PIXELCLOCK_CMD::IS_PIXELCLOCK_CMD_SET,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })
+}
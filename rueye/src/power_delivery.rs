@@ -0,0 +1,212 @@
+//! USB Power Delivery of cameras with a USB Type-C connector.
+//!
+//! A PD-capable camera requests more power from the host than it needs itself, and passes the
+//! surplus to peripheral devices at its I/O connector, e.g. an LED light. Which
+//! [`PowerProfile`]s are available depends on the host and the cable; [`PowerDelivery`] reports
+//! them along with the active profile, so that applications can verify the power budget at
+//! startup.
+//!
+//! The profile falls back to [`PowerProfile::LowPower5V`] after a reconnect, and switching it
+//! briefly stops a running image acquisition.
+//!
+//! # Documentation
+//! [is_PowerDelivery](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_powerdelivery.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    power_delivery::{is_PowerDelivery, IS_POWER_DELIVERY_PROFILES, POWER_DELIVERY_CMD},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// USB Power Delivery profile.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerProfile {
+    /// 5 V without power for peripheral devices (_fallback profile_).
+    #[default]
+    LowPower5V,
+
+    /// 5 V with 1 A for peripheral devices.
+    HighPower5V,
+
+    /// 9 V with 1 A for peripheral devices.
+    Profile9V,
+
+    /// 12 V with 1 A for peripheral devices.
+    Profile12V,
+
+    /// 14.8 V with 1 A for peripheral devices.
+    Profile14V8,
+
+    /// 15 V with 1 A for peripheral devices.
+    Profile15V,
+}
+
+impl PowerProfile {
+    /// All power delivery profiles, by increasing power.
+    pub const ALL: [PowerProfile; 6] = [
+        PowerProfile::LowPower5V,
+        PowerProfile::HighPower5V,
+        PowerProfile::Profile9V,
+        PowerProfile::Profile12V,
+        PowerProfile::Profile14V8,
+        PowerProfile::Profile15V,
+    ];
+
+    /// Bits of the profile for [`is_PowerDelivery`].
+    pub const fn bits(self) -> IS_POWER_DELIVERY_PROFILES {
+        match self {
+            PowerProfile::LowPower5V => {
+                IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_5V_LOW_POWER
+            },
+            PowerProfile::HighPower5V => {
+                IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_5V_HIGH_POWER
+            },
+            PowerProfile::Profile9V => IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_9V,
+            PowerProfile::Profile12V => IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_12V,
+            PowerProfile::Profile14V8 => IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_14V8,
+            PowerProfile::Profile15V => IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_15V,
+        }
+    }
+
+    /// Supply voltage (in volts).
+    pub const fn voltage(self) -> f32 {
+        match self {
+            PowerProfile::LowPower5V | PowerProfile::HighPower5V => 5.0,
+            PowerProfile::Profile9V => 9.0,
+            PowerProfile::Profile12V => 12.0,
+            PowerProfile::Profile14V8 => 14.8,
+            PowerProfile::Profile15V => 15.0,
+        }
+    }
+
+    /// Current available to peripheral devices (in amperes).
+    pub const fn peripheral_current(self) -> f32 {
+        match self {
+            PowerProfile::LowPower5V => 0.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Power available to peripheral devices (in watts).
+    #[inline]
+    pub fn peripheral_power(self) -> f32 {
+        self.voltage() * self.peripheral_current()
+    }
+}
+
+impl TryFrom<IS_POWER_DELIVERY_PROFILES> for PowerProfile {
+    type Error = Error;
+
+    fn try_from(profile: IS_POWER_DELIVERY_PROFILES) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.bits() == profile)
+            .ok_or(Error::UnexpectedValue(profile.bits() as INT))
+    }
+}
+
+/// Power delivery state of a camera.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PowerDelivery {
+    /// Profiles supported by the camera in combination with the host, by increasing power.
+    pub supported: Vec<PowerProfile>,
+
+    /// Active profile, or [`None`] if the camera reports no valid profile.
+    pub active: Option<PowerProfile>,
+}
+
+impl PowerDelivery {
+    /// Query the power delivery state of `camera`.
+    ///
+    /// Fails with [`Error::NotSupported`] if the camera does not support power delivery.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        if get(camera, POWER_DELIVERY_CMD::IS_POWER_DELIVERY_CMD_GET_SUPPORTED)? == 0 {
+            return Err(Error::NotSupported);
+        }
+
+        let supported = IS_POWER_DELIVERY_PROFILES::from_bits_retain(get(
+            camera,
+            POWER_DELIVERY_CMD::IS_POWER_DELIVERY_CMD_GET_SUPPORTED_PROFILES,
+        )?);
+        let active = IS_POWER_DELIVERY_PROFILES::from_bits_retain(get(
+            camera,
+            POWER_DELIVERY_CMD::IS_POWER_DELIVERY_CMD_GET_PROFILE,
+        )?);
+
+        Ok(Self {
+            supported: PowerProfile::ALL
+                .into_iter()
+                .filter(|profile| supported.contains(profile.bits()))
+                .collect(),
+            active: PowerProfile::try_from(active).ok(),
+        })
+    }
+
+    /// Whether `profile` is supported.
+    #[inline]
+    pub fn supports(&self, profile: PowerProfile) -> bool {
+        self.supported.contains(&profile)
+    }
+
+    /// Most powerful supported profile.
+    #[inline]
+    pub fn best(&self) -> Option<PowerProfile> {
+        self.supported.last().copied()
+    }
+
+    /// Whether the active profile supplies at least the power of `profile` to peripheral
+    /// devices.
+    pub fn provides(&self, profile: PowerProfile) -> bool {
+        self.active.is_some_and(|active| active.peripheral_power() >= profile.peripheral_power())
+    }
+}
+
+impl Camera {
+    /// Query the power delivery state of the camera, see [`PowerDelivery::query`].
+    #[inline]
+    pub fn power_delivery(&self) -> Result<PowerDelivery> {
+        PowerDelivery::query(self)
+    }
+}
+
+/// Switch `camera` to `profile`.
+///
+/// Fails with [`Error::NotSupported`] if the camera does not support power delivery, and with
+/// [`Error::InvalidParameter`] if the host does not support `profile`.
+pub fn set_profile(camera: &Camera, profile: PowerProfile) -> Result<()> {
+    if !PowerDelivery::query(camera)?.supports(profile) {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut bits = profile.bits().bits();
+    check(unsafe {
+        command!(is_PowerDelivery(
+            camera.handle(),
+            POWER_DELIVERY_CMD::IS_POWER_DELIVERY_CMD_SET_PROFILE,
+            &mut bits as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })
+}
+
+fn get(camera: &Camera, cmd: POWER_DELIVERY_CMD) -> Result<UINT> {
+    let mut value: UINT = 0;
+    check(unsafe {
+        command!(is_PowerDelivery(
+            camera.handle(),
+            cmd,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
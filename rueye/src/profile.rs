@@ -0,0 +1,268 @@
+//! Camera profiles stored as TOML.
+//!
+//! A [`CameraProfile`] captures the settings of a camera in a readable, versionable TOML file,
+//! independent of the binary `.ini` parameter sets of the driver. Settings the camera does not
+//! support are left out when reading the profile, and skipped when applying it.
+//!
+//! A profile can also be [persisted](CameraProfile::persist) in the [parameter set](parameter_set)
+//! in the user memory of the camera, and [read back](CameraProfile::read_persisted) from it.
+//!
+//! ```toml
+//! pixel_clock_mhz = 86
+//! trigger = "Off"
+//! exposure_ms = 10.0
+//! gain = 0
+//!
+//! [aoi]
+//! x = 0
+//! y = 0
+//! width = 1280
+//! height = 1024
+//!
+//! [flash]
+//! output = "TriggerHighActive"
+//! gpios = [1]
+//! pwm = false
+//! delay_us = 0
+//! duration_us = 1000
+//!
+//! [gpio]
+//! outputs = [2]
+//! high = [2]
+//! ```
+
+use std::{fs, io::ErrorKind, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    aoi::{self, Aoi},
+    camera::Camera,
+    error::{optional, Error, Result},
+    exposure,
+    flash::{self, FlashMode, FlashOutput, FlashParams},
+    gain, gpio, parameter_set, pixel_clock,
+    trigger::TriggerMode,
+};
+
+/// Flash strobe settings of a profile.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FlashProfile {
+    /// Behavior of the flash output.
+    pub output: FlashOutput,
+
+    /// Numbers of the GPIOs used as flash output in addition to the flash output itself.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpios: Vec<u8>,
+
+    /// Whether the flash GPIOs output pulse-width modulation (PWM).
+    #[serde(default)]
+    pub pwm: bool,
+
+    /// Flash delay and duration.
+    #[serde(flatten)]
+    pub params: FlashParams,
+}
+
+impl FlashProfile {
+    /// Flash mode of the profile.
+    ///
+    /// Fails with [`Error::InvalidParameter`] for numbers of GPIOs the driver does not know.
+    pub fn mode(&self) -> Result<FlashMode> {
+        Ok(FlashMode {
+            output: self.output,
+            gpios: gpio::from_numbers(&self.gpios)?,
+            pwm: self.pwm,
+        })
+    }
+}
+
+/// GPIO settings of a profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GpioProfile {
+    /// Numbers of the GPIOs configured as output; all others are inputs.
+    #[serde(default)]
+    pub outputs: Vec<u8>,
+
+    /// Numbers of the outputs at high level; all other outputs are low.
+    #[serde(default)]
+    pub high: Vec<u8>,
+}
+
+/// Settings of a camera.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraProfile {
+    /// Pixel clock (in MHz).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_clock_mhz: Option<u32>,
+
+    /// Image AOI.
+    pub aoi: Aoi,
+
+    /// Trigger mode.
+    pub trigger: TriggerMode,
+
+    /// Exposure time (in ms).
+    pub exposure_ms: f64,
+
+    /// Master gain (`0`…`100`).
+    pub gain: i32,
+
+    /// Flash strobe output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flash: Option<FlashProfile>,
+
+    /// GPIO directions and output levels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpio: Option<GpioProfile>,
+}
+
+impl CameraProfile {
+    /// Read the current settings of `camera`.
+    pub fn read(camera: &Camera) -> Result<Self> {
+        let flash = match optional(flash::mode(camera))? {
+            Some(mode) => Some(FlashProfile {
+                output: mode.output,
+                gpios: gpio::numbers(mode.gpios),
+                pwm: mode.pwm,
+                params: flash::params(camera)?,
+            }),
+            None => None,
+        };
+
+        let gpio = match optional(gpio::outputs(camera))? {
+            Some(outputs) => Some(GpioProfile {
+                outputs: gpio::numbers(outputs),
+                high: gpio::numbers(gpio::high(camera)? & outputs),
+            }),
+            None => None,
+        };
+
+        Ok(Self {
+            pixel_clock_mhz: optional(pixel_clock::current(camera))?,
+            aoi: aoi::current(camera)?.into(),
+            trigger: TriggerMode::current(camera)?,
+            exposure_ms: exposure::current(camera)?,
+            gain: gain::current(camera)?,
+            flash,
+            gpio,
+        })
+    }
+
+    /// Read the settings of the parameter set in the user memory of `camera`, by loading it into
+    /// the camera; see [`parameter_set::load_from_camera`].
+    pub fn read_persisted(camera: &Camera) -> Result<Self> {
+        parameter_set::load_from_camera(camera)?;
+        Self::read(camera)
+    }
+
+    /// Apply the settings to `camera`, as a whole.
+    ///
+    /// The pixel clock is set first, since it limits the exposure time range. If any setting
+    /// fails, the settings applied before are rolled back, see [`Camera::transaction`].
+    pub fn apply(&self, camera: &Camera) -> Result<()> {
+        camera.transaction(|tx| {
+            if let Some(mhz) = self.pixel_clock_mhz {
+                tx.apply(pixel_clock::current, pixel_clock::set, mhz)?;
+            }
+
+            tx.set_aoi(self.aoi)?;
+            tx.set_trigger(self.trigger)?;
+            tx.set_exposure(self.exposure_ms)?;
+            tx.set_gain(self.gain)?;
+
+            if let Some(profile) = &self.flash {
+                tx.apply(flash::params, flash::set_params, profile.params)?;
+                tx.apply(flash::mode, flash::set_mode, profile.mode()?)?;
+            }
+
+            // Levels before directions, so new outputs start at their level
+            if let Some(profile) = &self.gpio {
+                let outputs = gpio::from_numbers(&profile.outputs)?;
+                tx.apply(gpio::high, gpio::set_high, gpio::from_numbers(&profile.high)? & outputs)?;
+                tx.apply(gpio::outputs, gpio::set_outputs, outputs)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Apply the settings to `camera` and save them to the parameter set in its user memory; see
+    /// [`parameter_set::save_to_camera`].
+    ///
+    /// Long exposure and color mode settings are not stored in the user memory.
+    pub fn persist(&self, camera: &Camera) -> Result<()> {
+        self.apply(camera)?;
+        parameter_set::save_to_camera(camera)
+    }
+
+    /// Serialize the profile as TOML.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|_| Error::Io(ErrorKind::InvalidData))
+    }
+
+    /// Deserialize a profile from TOML.
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|_| Error::Io(ErrorKind::InvalidData))
+    }
+
+    /// Save the profile as a TOML file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, self.to_toml()?)?)
+    }
+
+    /// Load a profile from the TOML file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+        trigger = "Off"
+        exposure_ms = 10.0
+        gain = 0
+
+        [aoi]
+        x = 0
+        y = 0
+        width = 1280
+        height = 1024
+
+        [flash]
+        output = "TriggerHighActive"
+        gpios = [1]
+        delay_us = 0
+        duration_us = 1000
+
+        [gpio]
+        outputs = [2]
+        high = [2]
+    "#;
+
+    #[test]
+    fn from_toml_reads_flash_and_gpio() {
+        let profile = CameraProfile::from_toml(TOML).unwrap();
+        let flash = profile.flash.as_ref().unwrap();
+        assert_eq!(
+            flash.mode(),
+            Ok(FlashMode {
+                output: FlashOutput::TriggerHighActive,
+                gpios: ueye_sys::io::IO_GPIO::IO_GPIO_1,
+                pwm: false,
+            })
+        );
+        assert_eq!(flash.params, FlashParams { delay_us: 0, duration_us: 1000 });
+        assert_eq!(profile.gpio, Some(GpioProfile { outputs: vec![2], high: vec![2] }));
+        assert_eq!(profile.pixel_clock_mhz, None);
+    }
+
+    #[test]
+    fn toml_round_trips() {
+        let profile = CameraProfile::from_toml(TOML).unwrap();
+        assert_eq!(CameraProfile::from_toml(&profile.to_toml().unwrap()), Ok(profile));
+    }
+}
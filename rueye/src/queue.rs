@@ -0,0 +1,336 @@
+//! Bounded frame queues with explicit backpressure.
+//!
+//! When frames arrive faster than they are consumed, a [`FrameQueue`] applies its
+//! [`DropPolicy`] to choose between latency and completeness. The effect of the policy is
+//! visible through the counters returned by [`FrameQueue::stats`].
+//!
+//! [`FrameIterator::forward`](crate::capture::FrameIterator::forward) feeds a queue from live
+//! capture, e.g. on a capture thread while another thread processes the frames.
+
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// Behaviour of [`FrameQueue::push`] when the queue is full.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DropPolicy {
+    /// Discard the oldest queued frame to make room (_lowest latency_).
+    #[default]
+    DropOldest,
+
+    /// Discard the frame being pushed.
+    DropNewest,
+
+    /// Wait until the consumer makes room (_no frames lost_).
+    Block,
+}
+
+/// Counters of a [`FrameQueue`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct QueueStats {
+    /// Frames accepted into the queue.
+    pub pushed: u64,
+
+    /// Frames taken out of the queue.
+    pub popped: u64,
+
+    /// Queued frames discarded by [`DropPolicy::DropOldest`].
+    pub dropped_oldest: u64,
+
+    /// Incoming frames discarded by [`DropPolicy::DropNewest`] or because the queue was closed.
+    pub dropped_newest: u64,
+
+    /// Pushes that had to wait under [`DropPolicy::Block`].
+    pub blocked: u64,
+
+    /// Largest number of frames queued at once.
+    pub high_water_mark: usize,
+}
+
+impl QueueStats {
+    /// Total number of discarded frames.
+    #[inline]
+    pub const fn dropped(&self) -> u64 {
+        self.dropped_oldest + self.dropped_newest
+    }
+}
+
+#[derive(Debug)]
+struct State<T> {
+    items: VecDeque<T>,
+    stats: QueueStats,
+    closed: bool,
+}
+
+/// Bounded, thread-safe queue of frames.
+#[derive(Debug)]
+pub struct FrameQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    policy: DropPolicy,
+}
+
+impl<T> FrameQueue<T> {
+    /// Create a queue holding up to `capacity` frames (at least one).
+    pub fn new(capacity: usize, policy: DropPolicy) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            state: Mutex::new(State {
+                items: VecDeque::with_capacity(capacity),
+                stats: QueueStats::default(),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+            policy,
+        }
+    }
+
+    /// Maximum number of queued frames.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Policy applied when the queue is full.
+    #[inline]
+    pub const fn policy(&self) -> DropPolicy {
+        self.policy
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Queue a frame, applying the [`DropPolicy`] if the queue is full.
+    ///
+    /// # Return values
+    /// The frame discarded to honour the policy, if any. After [`FrameQueue::close`], `frame`
+    /// itself is returned.
+    pub fn push(&self, frame: T) -> Option<T> {
+        let mut state = self.lock();
+        let mut dropped = None;
+
+        if state.items.len() >= self.capacity && !state.closed {
+            match self.policy {
+                DropPolicy::DropOldest => {
+                    dropped = state.items.pop_front();
+                    state.stats.dropped_oldest += 1;
+                },
+                DropPolicy::DropNewest => {
+                    state.stats.dropped_newest += 1;
+                    return Some(frame);
+                },
+                DropPolicy::Block => {
+                    state.stats.blocked += 1;
+                    while state.items.len() >= self.capacity && !state.closed {
+                        state = self.not_full.wait(state).unwrap_or_else(|e| e.into_inner());
+                    }
+                },
+            }
+        }
+
+        if state.closed {
+            state.stats.dropped_newest += 1;
+            return Some(frame);
+        }
+
+        state.items.push_back(frame);
+        state.stats.pushed += 1;
+        state.stats.high_water_mark = state.stats.high_water_mark.max(state.items.len());
+        drop(state);
+
+        self.not_empty.notify_one();
+        dropped
+    }
+
+    /// Take the oldest frame without waiting.
+    pub fn try_pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        let frame = state.items.pop_front();
+        if frame.is_some() {
+            state.stats.popped += 1;
+            drop(state);
+            self.not_full.notify_one();
+        }
+
+        frame
+    }
+
+    /// Take the oldest frame, waiting until one is available.
+    ///
+    /// # Return values
+    /// [`None`] once the queue is closed and empty.
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        while state.items.is_empty() && !state.closed {
+            state = self.not_empty.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+
+        let frame = state.items.pop_front();
+        if frame.is_some() {
+            state.stats.popped += 1;
+            drop(state);
+            self.not_full.notify_one();
+        }
+
+        frame
+    }
+
+    /// Take the oldest frame, waiting at most `timeout` for one to become available.
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let state = self.lock();
+        let (mut state, _) = self
+            .not_empty
+            .wait_timeout_while(state, timeout, |state| state.items.is_empty() && !state.closed)
+            .unwrap_or_else(|e| e.into_inner());
+
+        let frame = state.items.pop_front();
+        if frame.is_some() {
+            state.stats.popped += 1;
+            drop(state);
+            self.not_full.notify_one();
+        }
+
+        frame
+    }
+
+    /// Number of queued frames.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.lock().items.len()
+    }
+
+    /// Whether no frames are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lock().items.is_empty()
+    }
+
+    /// Snapshot of the queue counters.
+    #[inline]
+    pub fn stats(&self) -> QueueStats {
+        self.lock().stats
+    }
+
+    /// Reset the queue counters.
+    #[inline]
+    pub fn reset_stats(&self) {
+        self.lock().stats = QueueStats::default();
+    }
+
+    /// Close the queue, waking all waiting producers and consumers.
+    ///
+    /// Frames already queued can still be popped; new frames are rejected.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Whether [`FrameQueue::close`] has been called.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    /// Wait until `condition` holds for the state of `queue`.
+    fn wait_for<T>(queue: &FrameQueue<T>, condition: impl Fn(&State<T>) -> bool) {
+        while !condition(&queue.lock()) {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn drop_oldest_discards_queued_frames() {
+        let queue = FrameQueue::new(2, DropPolicy::DropOldest);
+        assert_eq!(queue.push(1), None);
+        assert_eq!(queue.push(2), None);
+        assert_eq!(queue.push(3), Some(1));
+
+        assert_eq!(queue.try_pop(), Some(2));
+        assert_eq!(queue.try_pop(), Some(3));
+        assert_eq!(queue.try_pop(), None);
+
+        let stats = queue.stats();
+        assert_eq!((stats.pushed, stats.popped, stats.dropped_oldest), (3, 2, 1));
+        assert_eq!((stats.dropped_newest, stats.high_water_mark), (0, 2));
+    }
+
+    #[test]
+    fn drop_newest_discards_pushed_frame() {
+        let queue = FrameQueue::new(2, DropPolicy::DropNewest);
+        queue.push(1);
+        queue.push(2);
+        assert_eq!(queue.push(3), Some(3));
+
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+
+        let stats = queue.stats();
+        assert_eq!((stats.pushed, stats.dropped_newest, stats.dropped_oldest), (2, 1, 0));
+        assert_eq!(stats.dropped(), 1);
+    }
+
+    #[test]
+    fn block_waits_for_pop() {
+        let queue = FrameQueue::new(1, DropPolicy::Block);
+        queue.push(1);
+
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| queue.push(2));
+            wait_for(&queue, |state| state.stats.blocked == 1);
+            assert_eq!(queue.len(), 1);
+
+            assert_eq!(queue.pop(), Some(1));
+            assert_eq!(producer.join().unwrap(), None);
+        });
+
+        assert_eq!(queue.try_pop(), Some(2));
+        assert_eq!(queue.stats().dropped(), 0);
+    }
+
+    #[test]
+    fn close_releases_blocked_producer() {
+        let queue = FrameQueue::new(1, DropPolicy::Block);
+        queue.push(1);
+
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| queue.push(2));
+            wait_for(&queue, |state| state.stats.blocked == 1);
+
+            queue.close();
+            assert_eq!(producer.join().unwrap(), Some(2));
+        });
+
+        assert_eq!(queue.stats().dropped_newest, 1);
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn close_releases_waiting_consumer() {
+        let queue = FrameQueue::<u32>::new(1, DropPolicy::Block);
+
+        thread::scope(|scope| {
+            let consumer = scope.spawn(|| queue.pop());
+            queue.close();
+            assert_eq!(consumer.join().unwrap(), None);
+        });
+
+        assert_eq!(queue.pop_timeout(Duration::from_millis(1)), None);
+    }
+}
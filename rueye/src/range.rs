@@ -0,0 +1,235 @@
+//! Ranges of parameter values.
+//!
+//! The driver reports the valid values of many parameters as a minimum, a maximum and an
+//! increment between them, e.g. as [`IS_RANGE_S32`], [`IS_RANGE_U32`] or [`IS_RANGE_F64`].
+//! [`ParamRange`] holds any of them, along with the default value where the driver reports one.
+
+use ueye_sys::types::{IS_RANGE_F64, IS_RANGE_S32, IS_RANGE_U32, RANGE_OF_VALUES_U32};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+    impl Sealed for f64 {}
+}
+
+/// Value type of a [`ParamRange`].
+pub trait RangeValue: sealed::Sealed + Copy + PartialOrd {
+    /// Whether `self` lies a whole number of increments `inc` above `min`.
+    #[doc(hidden)]
+    fn is_on_step(self, min: Self, inc: Self) -> bool;
+
+    /// Round `self`, within `min`…`max`, to the nearest whole number of increments `inc` above
+    /// `min` that does not exceed `max`.
+    #[doc(hidden)]
+    fn snap(self, min: Self, max: Self, inc: Self) -> Self;
+}
+
+macro_rules! integer_range_value {
+    ($($integer:ty),*) => {$(
+        impl RangeValue for $integer {
+            #[inline]
+            fn is_on_step(self, min: Self, inc: Self) -> bool {
+                inc == 0 || self.abs_diff(min) % inc.abs_diff(0) == 0
+            }
+
+            fn snap(self, min: Self, max: Self, inc: Self) -> Self {
+                // offsets from `min` are unsigned, so they cover the whole span of the type
+                let inc = inc.abs_diff(0);
+                if inc == 0 {
+                    return self;
+                }
+
+                let offset = self.abs_diff(min);
+                let remainder = offset % inc;
+                let down = offset - remainder;
+                let up = match remainder >= inc - remainder {
+                    true => down.checked_add(inc).filter(|&up| up <= max.abs_diff(min)),
+                    false => None,
+                };
+
+                min.wrapping_add(up.unwrap_or(down) as Self)
+            }
+        }
+    )*};
+}
+
+integer_range_value!(i32, u32);
+
+impl RangeValue for f64 {
+    fn is_on_step(self, min: Self, inc: Self) -> bool {
+        if inc <= 0.0 {
+            return true;
+        }
+
+        let steps = (self - min) / inc;
+        (steps - steps.round()).abs() < 1e-9
+    }
+
+    fn snap(self, min: Self, max: Self, inc: Self) -> Self {
+        if inc <= 0.0 {
+            return self;
+        }
+
+        let value = min + ((self - min) / inc).round() * inc;
+        match value > max {
+            true => (value - inc).max(min),
+            false => value,
+        }
+    }
+}
+
+/// Range of the values of a parameter, with an increment between two values.
+///
+/// An increment of zero denotes a continuous range, or one whose values are listed separately.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamRange<T> {
+    /// Smallest value.
+    pub min: T,
+
+    /// Largest value.
+    pub max: T,
+
+    /// Step between two values.
+    pub inc: T,
+
+    /// Default value, where reported by the driver.
+    pub default: Option<T>,
+}
+
+impl<T: RangeValue> ParamRange<T> {
+    /// Create a range without default value.
+    #[inline]
+    pub const fn new(min: T, max: T, inc: T) -> Self {
+        Self { min, max, inc, default: None }
+    }
+
+    /// Set the default value of the range.
+    #[inline]
+    pub const fn with_default(self, default: T) -> Self {
+        Self { default: Some(default), ..self }
+    }
+
+    /// Whether `value` lies within the range and on one of its increments.
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max && value.is_on_step(self.min, self.inc)
+    }
+
+    /// Clamp `value` into the range.
+    pub fn clamp(&self, value: T) -> T {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Clamp `value` into the range and round it to the nearest of its increments.
+    pub fn snap_to_increment(&self, value: T) -> T {
+        self.clamp(value).snap(self.min, self.max, self.inc)
+    }
+}
+
+impl From<IS_RANGE_S32> for ParamRange<i32> {
+    #[inline]
+    fn from(range: IS_RANGE_S32) -> Self {
+        Self::new(range.s32Min, range.s32Max, range.s32Inc)
+    }
+}
+
+impl From<IS_RANGE_U32> for ParamRange<u32> {
+    #[inline]
+    fn from(range: IS_RANGE_U32) -> Self {
+        Self::new(range.u32Min, range.u32Max, range.u32Inc)
+    }
+}
+
+impl From<IS_RANGE_F64> for ParamRange<f64> {
+    #[inline]
+    fn from(range: IS_RANGE_F64) -> Self {
+        Self::new(range.f64Min, range.f64Max, range.f64Inc)
+    }
+}
+
+impl From<RANGE_OF_VALUES_U32> for ParamRange<u32> {
+    #[inline]
+    fn from(range: RANGE_OF_VALUES_U32) -> Self {
+        Self::new(range.u32Minimum, range.u32Maximum, range.u32Increment)
+            .with_default(range.u32Default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let range = ParamRange::new(10u32, 48, 4);
+        assert!(range.contains(10));
+        assert!(range.contains(46));
+        assert!(!range.contains(48));
+        assert!(!range.contains(50));
+        assert!(!range.contains(6));
+
+        let full = ParamRange::new(i32::MIN, i32::MAX, 1);
+        assert!(full.contains(i32::MIN));
+        assert!(full.contains(i32::MAX));
+
+        let continuous = ParamRange::new(-1.0, 1.0, 0.0);
+        assert!(continuous.contains(0.3));
+        assert!(!continuous.contains(1.5));
+    }
+
+    #[test]
+    fn clamp() {
+        let range = ParamRange::new(-5i32, 5, 1);
+        assert_eq!(range.clamp(-10), -5);
+        assert_eq!(range.clamp(3), 3);
+        assert_eq!(range.clamp(10), 5);
+    }
+
+    #[test]
+    fn snap_to_increment() {
+        let range = ParamRange::new(10u32, 48, 4);
+        assert_eq!(range.snap_to_increment(0), 10);
+        assert_eq!(range.snap_to_increment(11), 10);
+        assert_eq!(range.snap_to_increment(12), 14);
+        assert_eq!(range.snap_to_increment(49), 46);
+        assert_eq!(range.snap_to_increment(100), 46);
+
+        let signed = ParamRange::new(-7i32, 7, 5);
+        assert_eq!(signed.snap_to_increment(-5), -7);
+        assert_eq!(signed.snap_to_increment(0), -2);
+        assert_eq!(signed.snap_to_increment(7), 3);
+
+        let float = ParamRange::new(0.5, 2.0, 0.25);
+        assert_eq!(float.snap_to_increment(1.1), 1.0);
+        assert_eq!(float.snap_to_increment(3.0), 2.0);
+    }
+
+    #[test]
+    fn snap_to_increment_near_type_limits() {
+        let range = ParamRange::new(0, u32::MAX, 10);
+        assert_eq!(range.snap_to_increment(u32::MAX), u32::MAX - 5);
+        assert_eq!(range.snap_to_increment(u32::MAX - 10), u32::MAX - 5);
+        assert_eq!(range.snap_to_increment(u32::MAX - 11), u32::MAX - 15);
+
+        let full = ParamRange::new(i32::MIN, i32::MAX, 1 << 30);
+        assert_eq!(full.snap_to_increment(i32::MAX), 1 << 30);
+        assert!(full.contains(1 << 30));
+    }
+
+    #[test]
+    fn snap_to_increment_stays_within_narrow_range() {
+        let range = ParamRange::new(3u32, 5, 10);
+        assert_eq!(range.snap_to_increment(5), 3);
+
+        let float = ParamRange::new(3.0, 5.0, 10.0);
+        assert_eq!(float.snap_to_increment(5.0), 3.0);
+    }
+}
@@ -0,0 +1,390 @@
+//! Automatic reconnection of a camera.
+//!
+//! A [`ReconnectingCamera`] holds a camera, identified by its serial number, and follows the device
+//! events of a [`DeviceWatcher`]. When the camera is removed, its handle is released; when it is
+//! connected again, it is opened again with backoff on a separate thread, since it is listed
+//! before it is ready, and the saved [`CameraProfile`] is applied to it. Arrivals of the camera
+//! while it is open, or while it is being opened, are ignored. Every change of its [`ConnectionState`] is
+//! reported to a callback.
+//!
+//! Image memories, captures and events of the camera are lost along with its handle, and must be
+//! set up again once it is [`ConnectionState::Connected`].
+//!
+//! # Documentation
+//! * [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+//! * [is_InitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_initcamera.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::{
+    camera::{Camera, CameraInfo},
+    device_info::{self, c_string},
+    error::{Error, Result},
+    hotplug::{DeviceEvent, DeviceWatcher},
+    profile::CameraProfile,
+};
+
+/// Connection state of a [`ReconnectingCamera`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The camera is open.
+    Connected,
+
+    /// The camera was removed.
+    Disconnected,
+
+    /// The camera was connected again, and is being opened; `attempt` counts from `1`.
+    Reconnecting {
+        /// Number of the current attempt.
+        attempt: u32,
+    },
+
+    /// The camera could not be opened again after [`ReconnectOptions::attempts`]; it is retried
+    /// the next time it is connected.
+    Failed(Error),
+}
+
+impl Display for ConnectionState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionState::Connected => f.write_str("connected"),
+            ConnectionState::Disconnected => f.write_str("disconnected"),
+            ConnectionState::Reconnecting { attempt } => {
+                write!(f, "reconnecting (attempt {attempt})")
+            },
+            ConnectionState::Failed(error) => write!(f, "reconnection failed: {error}"),
+        }
+    }
+}
+
+/// Options of a [`ReconnectingCamera`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReconnectOptions {
+    /// Number of attempts to open the camera once it is connected again.
+    pub attempts: u32,
+
+    /// Delay before the second attempt, doubled after every attempt.
+    pub backoff: Duration,
+
+    /// Upper bound of the delay between attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            attempts: 10,
+            backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+type Callback = Box<dyn FnMut(ConnectionState) + Send>;
+
+/// Interruption of the backoff between attempts.
+#[derive(Debug, Default)]
+struct Control {
+    /// The camera is being released; no further attempt is made.
+    stopped: bool,
+
+    /// Incremented on removal, cancelling the attempts for an earlier arrival.
+    epoch: u64,
+}
+
+struct Shared {
+    serial: String,
+    options: ReconnectOptions,
+    camera: Mutex<Option<Camera>>,
+    profile: Mutex<Option<CameraProfile>>,
+    state: Mutex<ConnectionState>,
+    callback: Mutex<Callback>,
+    control: Mutex<Control>,
+    wake: Condvar,
+    reconnecting: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl std::fmt::Debug for Shared {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Shared")
+            .field("serial", &self.serial)
+            .field("options", &self.options)
+            .field("camera", &self.camera)
+            .field("profile", &self.profile)
+            .field("state", &self.state)
+            .field("control", &self.control)
+            .finish_non_exhaustive()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Shared {
+    fn set_state(&self, state: ConnectionState) {
+        *lock(&self.state) = state;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(serial = %self.serial, %state, "connection state changed");
+
+        (lock(&self.callback))(state);
+    }
+
+    /// Whether attempts started in `epoch` are still wanted.
+    fn is_current(&self, epoch: u64) -> bool {
+        let control = lock(&self.control);
+        !control.stopped && control.epoch == epoch
+    }
+
+    /// Wait for `timeout`, returning whether attempts started in `epoch` are still wanted.
+    fn wait(&self, epoch: u64, timeout: Duration) -> bool {
+        let control = lock(&self.control);
+        let (control, _) = self
+            .wake
+            .wait_timeout_while(control, timeout, |control| {
+                !control.stopped && control.epoch == epoch
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+
+        !control.stopped && control.epoch == epoch
+    }
+
+    /// Cancel the attempts of the current epoch, stopping all attempts if `stop` is set.
+    fn interrupt(&self, stop: bool) {
+        let mut control = lock(&self.control);
+        control.epoch += 1;
+        control.stopped |= stop;
+        self.wake.notify_all();
+    }
+
+    /// Handle the removal of the camera.
+    fn disconnect(&self) {
+        self.interrupt(false);
+
+        // the handle is no longer usable
+        drop(lock(&self.camera).take());
+        self.set_state(ConnectionState::Disconnected);
+    }
+
+    /// Start opening the camera listed as `info` on a separate thread, unless it is open or
+    /// already being opened.
+    fn spawn_reconnect(self: &Arc<Self>, info: CameraInfo) {
+        if lock(&self.camera).is_some() {
+            return;
+        }
+
+        let mut reconnecting = lock(&self.reconnecting);
+        if reconnecting.as_ref().is_some_and(|thread| !thread.is_finished()) {
+            return;
+        }
+        if let Some(thread) = reconnecting.take() {
+            let _ = thread.join();
+        }
+
+        let epoch = lock(&self.control).epoch;
+        let shared = Arc::clone(self);
+        *reconnecting = Some(thread::spawn(move || shared.reconnect(&info, epoch)));
+    }
+
+    /// Open the camera listed as `info`, retrying with backoff, and apply the saved profile.
+    ///
+    /// Gives up early once the camera is removed again or released.
+    fn reconnect(&self, info: &CameraInfo, epoch: u64) {
+        let mut backoff = self.options.backoff;
+        let mut error = Error::CameraNotFound;
+
+        for attempt in 1..=self.options.attempts {
+            if !self.is_current(epoch) {
+                return;
+            }
+
+            self.set_state(ConnectionState::Reconnecting { attempt });
+            match self.restore(info) {
+                Ok(camera) => {
+                    // checked under the lock, so that a removal meanwhile releases the camera
+                    let control = lock(&self.control);
+                    if control.stopped || control.epoch != epoch {
+                        return;
+                    }
+
+                    *lock(&self.camera) = Some(camera);
+                    drop(control);
+                    return self.set_state(ConnectionState::Connected);
+                },
+                Err(e) => error = e,
+            }
+
+            if attempt < self.options.attempts {
+                if !self.wait(epoch, backoff) {
+                    return;
+                }
+                backoff = (backoff * 2).min(self.options.max_backoff);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(serial = %self.serial, %error, "camera not reconnected");
+
+        self.set_state(ConnectionState::Failed(error));
+    }
+
+    fn restore(&self, info: &CameraInfo) -> Result<Camera> {
+        let camera = info.open()?;
+        if let Some(profile) = lock(&self.profile).as_ref() {
+            profile.apply(&camera)?;
+        }
+
+        Ok(camera)
+    }
+
+    /// Stop all attempts and wait for the reconnection thread.
+    fn shutdown(&self) {
+        self.interrupt(true);
+        if let Some(thread) = lock(&self.reconnecting).take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Camera opened again whenever it is reconnected, with its settings restored.
+///
+/// The camera is closed on drop. Attempts to open it again run on a separate thread, so that
+/// removal is still noticed, and dropping the camera does not wait for the backoff.
+#[derive(Debug)]
+pub struct ReconnectingCamera {
+    // the watcher thread is stopped before the camera is released
+    watcher: Option<DeviceWatcher<'static>>,
+    shared: Arc<Shared>,
+}
+
+impl ReconnectingCamera {
+    /// Take over `camera`, saving its current settings as the profile to restore, and call
+    /// `callback` for every change of the connection state.
+    ///
+    /// The callback is called from the watcher thread on removal, and from the reconnection
+    /// thread while the camera is opened again.
+    pub fn spawn(
+        camera: Camera,
+        options: ReconnectOptions,
+        callback: impl FnMut(ConnectionState) + Send + 'static,
+    ) -> Result<Self> {
+        let shared = Arc::new(Shared {
+            serial: c_string(&device_info::camera_info(&camera)?.SerNo),
+            options,
+            profile: Mutex::new(Some(CameraProfile::read(&camera)?)),
+            camera: Mutex::new(Some(camera)),
+            state: Mutex::new(ConnectionState::Connected),
+            callback: Mutex::new(Box::new(callback)),
+            control: Mutex::new(Control::default()),
+            wake: Condvar::new(),
+            reconnecting: Mutex::new(None),
+        });
+
+        let watcher = DeviceWatcher::spawn({
+            let shared = Arc::clone(&shared);
+            move |event| match event {
+                DeviceEvent::Removed(info) if info.serial_number == shared.serial => {
+                    shared.disconnect();
+                },
+                DeviceEvent::Arrived(info) if info.serial_number == shared.serial => {
+                    shared.spawn_reconnect(info);
+                },
+                _ => {},
+            }
+        })?;
+
+        Ok(Self { watcher: Some(watcher), shared })
+    }
+
+    /// Take over `camera`, sending every change of the connection state to the returned receiver.
+    pub fn channel(
+        camera: Camera,
+        options: ReconnectOptions,
+    ) -> Result<(Self, Receiver<ConnectionState>)> {
+        let (sender, receiver) = mpsc::channel();
+        let camera = Self::spawn(camera, options, move |state| {
+            // states are dropped once the receiver is gone
+            let _ = sender.send(state);
+        })?;
+
+        Ok((camera, receiver))
+    }
+
+    /// Serial number of the camera.
+    #[inline]
+    pub fn serial(&self) -> &str {
+        &self.shared.serial
+    }
+
+    /// Current connection state.
+    #[inline]
+    pub fn state(&self) -> ConnectionState {
+        *lock(&self.shared.state)
+    }
+
+    /// Whether the camera is open.
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.state() == ConnectionState::Connected
+    }
+
+    /// Call `f` with the camera, holding off reconnection meanwhile.
+    ///
+    /// Fails with [`Error::CameraNotConnected`] while the camera is not open.
+    pub fn with<R>(&self, f: impl FnOnce(&Camera) -> Result<R>) -> Result<R> {
+        match lock(&self.shared.camera).as_ref() {
+            Some(camera) => f(camera),
+            None => Err(Error::CameraNotConnected),
+        }
+    }
+
+    /// Profile applied to the camera when it is reconnected.
+    #[inline]
+    pub fn profile(&self) -> Option<CameraProfile> {
+        lock(&self.shared.profile).clone()
+    }
+
+    /// Set the profile applied to the camera when it is reconnected, or [`None`] to keep the
+    /// defaults of the camera.
+    #[inline]
+    pub fn set_profile(&self, profile: Option<CameraProfile>) {
+        *lock(&self.shared.profile) = profile;
+    }
+
+    /// Save the current settings of the camera as the profile to restore.
+    ///
+    /// Fails with [`Error::CameraNotConnected`] while the camera is not open.
+    pub fn save_profile(&self) -> Result<()> {
+        let profile = self.with(CameraProfile::read)?;
+        self.set_profile(Some(profile));
+        Ok(())
+    }
+
+    /// Stop watching for reconnection and release the camera, if it is open.
+    pub fn into_inner(mut self) -> Option<Camera> {
+        self.shutdown();
+        let camera = lock(&self.shared.camera).take();
+        camera
+    }
+
+    /// Stop the watcher thread, then the reconnection thread.
+    fn shutdown(&mut self) {
+        drop(self.watcher.take());
+        self.shared.shutdown();
+    }
+}
+
+impl Drop for ReconnectingCamera {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
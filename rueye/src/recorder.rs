@@ -0,0 +1,497 @@
+//! Recording of frame sequences to raw segment files.
+//!
+//! A [`Recorder`] writes the pixel data of each frame, without line padding, to segment files in a
+//! directory, starting a new segment every [`RecorderOptions::frames_per_segment`] frames. Each
+//! segment file is pre-allocated for its frames, sized by the first frame written to it, and
+//! truncated to the frames actually written once it is completed. With
+//! [`RecorderOptions::max_segments`], the oldest segments are removed as new ones are started,
+//! keeping a ring of the most recent frames.
+//!
+//! Every segment `segment-NNNNNNNN.raw` is accompanied by an index `segment-NNNNNNNN.idx`, which
+//! records the offset, geometry, timestamp and frame number of each frame in the segment. A
+//! [`Playback`] reads the index of a recording and plays its frames back as [`FrameBuf`]s.
+//!
+//! The index is a little-endian binary file starting with [`INDEX_MAGIC`], followed by one
+//! [`INDEX_ENTRY_SIZE`] byte entry per frame: the offset (`u64`), timestamp (`u64`) and frame
+//! number (`u64`), followed by the width, height, bit depth and length (`u32` each).
+
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{Error, Result},
+    frame::{Frame, FrameBuf},
+};
+
+/// Magic number at the start of each index file.
+pub const INDEX_MAGIC: [u8; 8] = *b"RUEYEIDX";
+
+/// Size of an index entry, in bytes.
+pub const INDEX_ENTRY_SIZE: usize = 40;
+
+/// Configuration of a [`Recorder`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RecorderOptions {
+    /// Number of frames written to each segment file (at least one).
+    pub frames_per_segment: usize,
+
+    /// Largest number of segments kept, removing the oldest ones, or `None` to keep all.
+    pub max_segments: Option<usize>,
+}
+
+impl Default for RecorderOptions {
+    fn default() -> Self {
+        Self { frames_per_segment: 256, max_segments: None }
+    }
+}
+
+/// Index entry of a recorded frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedFrame {
+    /// Number of the segment holding the frame.
+    pub segment: u64,
+
+    /// Offset of the pixel data in the segment file, in bytes.
+    pub offset: u64,
+
+    /// Timestamp of the frame, as passed to [`Recorder::record`].
+    pub timestamp: u64,
+
+    /// Frame number, as passed to [`Recorder::record`].
+    pub frame_number: u64,
+
+    /// Image width, in pixels.
+    pub width: u32,
+
+    /// Image height, in lines.
+    pub height: u32,
+
+    /// Image bit depth (bits per pixel).
+    pub bits_per_pixel: u32,
+
+    /// Length of the pixel data, in bytes.
+    pub len: u32,
+}
+
+impl RecordedFrame {
+    fn to_bytes(self) -> [u8; INDEX_ENTRY_SIZE] {
+        let mut bytes = [0; INDEX_ENTRY_SIZE];
+        bytes[0..8].copy_from_slice(&self.offset.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.timestamp.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.frame_number.to_le_bytes());
+        bytes[24..28].copy_from_slice(&self.width.to_le_bytes());
+        bytes[28..32].copy_from_slice(&self.height.to_le_bytes());
+        bytes[32..36].copy_from_slice(&self.bits_per_pixel.to_le_bytes());
+        bytes[36..40].copy_from_slice(&self.len.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes(segment: u64, bytes: &[u8; INDEX_ENTRY_SIZE]) -> Self {
+        let u64_at = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        let u32_at = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+
+        Self {
+            segment,
+            offset: u64_at(0),
+            timestamp: u64_at(8),
+            frame_number: u64_at(16),
+            width: u32_at(24),
+            height: u32_at(28),
+            bits_per_pixel: u32_at(32),
+            len: u32_at(36),
+        }
+    }
+}
+
+/// Prefix of the names of segment files.
+const SEGMENT_PREFIX: &str = "segment-";
+
+/// Path of the file of `segment` with `extension` in `dir`.
+fn segment_path(dir: &Path, segment: u64, extension: &str) -> PathBuf {
+    dir.join(format!("{SEGMENT_PREFIX}{segment:08}.{extension}"))
+}
+
+/// Numbers of the segment files with `extension` in `dir`, in ascending order.
+fn segments(dir: &Path, extension: &str) -> Result<Vec<u64>> {
+    let mut segments: Vec<u64> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == extension).then_some(())?;
+            path.file_stem()?.to_str()?.strip_prefix(SEGMENT_PREFIX)?.parse().ok()
+        })
+        .collect();
+    segments.sort_unstable();
+
+    Ok(segments)
+}
+
+/// Segment being written.
+#[derive(Debug)]
+struct Segment {
+    number: u64,
+    data: BufWriter<File>,
+    index: BufWriter<File>,
+    frames: usize,
+    offset: u64,
+}
+
+impl Segment {
+    /// Create the files of segment `number`, pre-allocating `capacity` bytes for the pixel data.
+    fn create(dir: &Path, number: u64, capacity: u64) -> Result<Self> {
+        let data = File::create(segment_path(dir, number, "raw"))?;
+        data.set_len(capacity)?;
+
+        let mut index = BufWriter::new(File::create(segment_path(dir, number, "idx"))?);
+        index.write_all(&INDEX_MAGIC)?;
+
+        Ok(Self { number, data: BufWriter::new(data), index, frames: 0, offset: 0 })
+    }
+
+    /// Flush the segment files, truncating the pixel data to the frames written.
+    fn finish(mut self) -> Result<()> {
+        self.index.flush()?;
+        self.data.flush()?;
+        self.data.get_ref().set_len(self.offset)?;
+        Ok(())
+    }
+}
+
+/// Recorder writing frames to raw segment files.
+///
+/// The current segment is completed when the recorder is dropped; use [`Recorder::finish`] to
+/// handle the errors of completing it.
+#[derive(Debug)]
+pub struct Recorder {
+    dir: PathBuf,
+    options: RecorderOptions,
+    segment: Option<Segment>,
+    next_segment: u64,
+    frames: u64,
+}
+
+impl Recorder {
+    /// Start a recording into `dir`, creating the directory if needed.
+    ///
+    /// Segments of an earlier recording in `dir` are removed, so that a [`Playback`] of the
+    /// directory only holds the frames of this recording. Other files, including those without the
+    /// `segment-` prefix of segment files, are left in place.
+    pub fn create(dir: impl AsRef<Path>, options: &RecorderOptions) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        for extension in ["raw", "idx"] {
+            for segment in segments(&dir, extension)? {
+                fs::remove_file(segment_path(&dir, segment, extension))?;
+            }
+        }
+
+        Ok(Self {
+            dir,
+            options: RecorderOptions {
+                frames_per_segment: options.frames_per_segment.max(1),
+                max_segments: options.max_segments.map(|max| max.max(1)),
+            },
+            segment: None,
+            next_segment: 0,
+            frames: 0,
+        })
+    }
+
+    /// Directory of the recording.
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Number of frames recorded, including those in removed segments.
+    #[inline]
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Record the pixel data of `frame`, without line padding.
+    ///
+    /// # Input parameters
+    /// * `frame` - Frame to record.
+    /// * `timestamp` - Timestamp stored in the index, e.g. the device timestamp from
+    ///   [`image_info::device_timestamp`](crate::image_info::device_timestamp).
+    /// * `frame_number` - Frame number stored in the index.
+    pub fn record(&mut self, frame: &Frame<'_>, timestamp: u64, frame_number: u64) -> Result<()> {
+        let line = frame.width() * frame.bytes_per_pixel();
+        let len = line * frame.height();
+        let invalid = || Error::Io(ErrorKind::InvalidInput);
+
+        let entry = RecordedFrame {
+            segment: 0,
+            offset: 0,
+            timestamp,
+            frame_number,
+            width: u32::try_from(frame.width()).map_err(|_| invalid())?,
+            height: u32::try_from(frame.height()).map_err(|_| invalid())?,
+            bits_per_pixel: u32::try_from(frame.bits_per_pixel()).map_err(|_| invalid())?,
+            len: u32::try_from(len).map_err(|_| invalid())?,
+        };
+
+        if self
+            .segment
+            .as_ref()
+            .is_none_or(|segment| segment.frames >= self.options.frames_per_segment)
+        {
+            self.start_segment(len as u64)?;
+        }
+
+        let segment = self.segment.as_mut().expect("a segment was just started");
+        if frame.is_contiguous() {
+            segment.data.write_all(&frame.as_bytes()[..len])?;
+        } else {
+            for row in frame.rows() {
+                segment.data.write_all(row)?;
+            }
+        }
+
+        let entry = RecordedFrame { segment: segment.number, offset: segment.offset, ..entry };
+        segment.index.write_all(&entry.to_bytes())?;
+        segment.offset += len as u64;
+        segment.frames += 1;
+        self.frames += 1;
+
+        Ok(())
+    }
+
+    /// Complete the current segment and start the next one, pre-allocated for frames of
+    /// `frame_len` bytes.
+    fn start_segment(&mut self, frame_len: u64) -> Result<()> {
+        if let Some(segment) = self.segment.take() {
+            segment.finish()?;
+        }
+
+        let number = self.next_segment;
+        if let Some(stale) =
+            self.options.max_segments.and_then(|max| number.checked_sub(max as u64))
+        {
+            for extension in ["raw", "idx"] {
+                match fs::remove_file(segment_path(&self.dir, stale, extension)) {
+                    Err(error) if error.kind() != ErrorKind::NotFound => return Err(error.into()),
+                    _ => {},
+                }
+            }
+        }
+
+        // not pre-allocated if the size of the segment overflows
+        let capacity =
+            frame_len.checked_mul(self.options.frames_per_segment as u64).unwrap_or_default();
+        self.segment = Some(Segment::create(&self.dir, number, capacity)?);
+        self.next_segment += 1;
+
+        Ok(())
+    }
+
+    /// Complete the current segment and end the recording.
+    pub fn finish(mut self) -> Result<()> {
+        match self.segment.take() {
+            Some(segment) => segment.finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Some(segment) = self.segment.take() {
+            let _ = segment.finish();
+        }
+    }
+}
+
+/// Playback of a recording made by a [`Recorder`].
+#[derive(Debug)]
+pub struct Playback {
+    dir: PathBuf,
+    frames: Vec<RecordedFrame>,
+}
+
+impl Playback {
+    /// Open the recording in `dir`, reading the index of every segment.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+
+        let mut frames = Vec::new();
+        for segment in segments(&dir, "idx")? {
+            read_index(&segment_path(&dir, segment, "idx"), segment, &mut frames)?;
+        }
+
+        Ok(Self { dir, frames })
+    }
+
+    /// Number of recorded frames.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the recording holds no frames.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Index entries of all recorded frames, in recording order.
+    #[inline]
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    /// Read the frame at `position`, in recording order.
+    pub fn read(&self, position: usize) -> Result<FrameBuf> {
+        let entry = self.frames.get(position).ok_or(Error::InvalidParameter)?;
+
+        let mut file = File::open(segment_path(&self.dir, entry.segment, "raw"))?;
+        file.seek(SeekFrom::Start(entry.offset))?;
+
+        let mut data = vec![0; entry.len as usize];
+        file.read_exact(&mut data)?;
+
+        FrameBuf::from_vec(
+            data,
+            entry.width as usize,
+            entry.height as usize,
+            entry.bits_per_pixel as usize,
+        )
+        .ok_or(Error::Io(ErrorKind::InvalidData))
+    }
+
+    /// Iterate over all frames in recording order, along with their index entries.
+    pub fn iter(&self) -> impl Iterator<Item = Result<(RecordedFrame, FrameBuf)>> + '_ {
+        (0..self.frames.len()).map(|position| Ok((self.frames[position], self.read(position)?)))
+    }
+}
+
+/// Read the entries of the index file at `path` of `segment` into `frames`.
+fn read_index(path: &Path, segment: u64, frames: &mut Vec<RecordedFrame>) -> Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut magic = [0; INDEX_MAGIC.len()];
+    reader.read_exact(&mut magic)?;
+    if magic != INDEX_MAGIC {
+        return Err(Error::Io(ErrorKind::InvalidData));
+    }
+
+    let mut bytes = [0; INDEX_ENTRY_SIZE];
+    loop {
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => frames.push(RecordedFrame::from_bytes(segment, &bytes)),
+            // a truncated last entry is left by a recording that was interrupted
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(()),
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty directory for the test `name`.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rueye-recorder-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Mono 8-bit frame of `width` × `height` pixels, all set to `value`.
+    fn frame(width: usize, height: usize, value: u8) -> FrameBuf {
+        FrameBuf::from_vec(vec![value; width * height], width, height, 8).unwrap()
+    }
+
+    fn record(dir: &Path, options: &RecorderOptions, count: u8) {
+        let mut recorder = Recorder::create(dir, options).unwrap();
+        for number in 0..count {
+            let frame = frame(4, 3, number);
+            recorder.record(&frame.as_frame(), number as u64 * 10, number as u64).unwrap();
+        }
+        recorder.finish().unwrap();
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = test_dir("round-trip");
+        record(&dir, &RecorderOptions { frames_per_segment: 2, max_segments: None }, 5);
+
+        let playback = Playback::open(&dir).unwrap();
+        assert_eq!(playback.len(), 5);
+        for (number, entry) in playback.iter().enumerate() {
+            let (entry, frame) = entry.unwrap();
+            assert_eq!(entry.segment, number as u64 / 2);
+            assert_eq!(entry.frame_number, number as u64);
+            assert_eq!(entry.timestamp, number as u64 * 10);
+            assert_eq!((entry.width, entry.height, entry.bits_per_pixel), (4, 3, 8));
+            assert_eq!(frame.data(), [number as u8; 12]);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ring_keeps_latest_segments() {
+        let dir = test_dir("ring");
+        record(&dir, &RecorderOptions { frames_per_segment: 2, max_segments: Some(2) }, 7);
+
+        let playback = Playback::open(&dir).unwrap();
+        let numbers: Vec<u64> = playback.frames().iter().map(|entry| entry.frame_number).collect();
+        assert_eq!(numbers, [4, 5, 6]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn create_removes_earlier_recording() {
+        let dir = test_dir("overwrite");
+        record(&dir, &RecorderOptions { frames_per_segment: 1, max_segments: None }, 10);
+        record(&dir, &RecorderOptions { frames_per_segment: 1, max_segments: None }, 3);
+
+        let playback = Playback::open(&dir).unwrap();
+        assert_eq!(playback.len(), 3);
+        assert!(!segment_path(&dir, 3, "raw").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn create_keeps_other_files() {
+        let dir = test_dir("other-files");
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["00000000.raw", "00000000.idx", "notes.txt"] {
+            fs::write(dir.join(name), name).unwrap();
+        }
+
+        record(&dir, &RecorderOptions { frames_per_segment: 1, max_segments: None }, 2);
+        for name in ["00000000.raw", "00000000.idx", "notes.txt"] {
+            assert_eq!(fs::read_to_string(dir.join(name)).unwrap(), name);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn segments_are_preallocated() {
+        let dir = test_dir("preallocated");
+        let options = RecorderOptions { frames_per_segment: 4, max_segments: None };
+        let mut recorder = Recorder::create(&dir, &options).unwrap();
+        recorder.record(&frame(4, 3, 1).as_frame(), 0, 0).unwrap();
+
+        let len = fs::metadata(segment_path(&dir, 0, "raw")).unwrap().len();
+        assert_eq!(len, 4 * 12);
+
+        // truncated to the frames written once completed
+        recorder.finish().unwrap();
+        let len = fs::metadata(segment_path(&dir, 0, "raw")).unwrap().len();
+        assert_eq!(len, 12);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
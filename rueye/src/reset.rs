@@ -0,0 +1,92 @@
+//! Reset of the camera settings and firmware.
+//!
+//! [`Camera::reset_to_default`] resets every parameter to the defaults of the sensor while the
+//! camera stays open; its [`ResetOutcome`] tells whether the image format, i.e. the AOI or the
+//! [color mode](crate::color_mode::ColorMode) and with it the bit depth, changed with it, which invalidates the image
+//! memories allocated for the previous format.
+//!
+//! GigE cameras whose starter firmware does not match the driver fail to open with
+//! [`Error::StarterFwUploadNeeded`]. [`CameraInfo::open_with_firmware_upload`] allows the driver to
+//! upload it, after which the camera reboots; [`Camera::reopen_with_firmware_upload`] does so for a
+//! camera already open, which invalidates its handle.
+//!
+//! # Documentation
+//! * [is_ResetToDefault](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_resettodefault.html)
+//! * [is_InitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_initcamera.html)
+
+use ueye_sys::{
+    camera::{is_ResetToDefault, IS_ALLOW_STARTER_FW_UPLOAD, IS_USE_DEVICE_ID},
+    types::HIDS,
+};
+
+use crate::{
+    aoi,
+    camera::{list_cameras, Camera, CameraInfo},
+    device_info::{self, c_string},
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// State of the camera after [`Camera::reset_to_default`].
+///
+/// The image format covers the AOI and the color mode, which sets the bit depth; other parameters,
+/// such as exposure or gain, do not affect the image memories.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResetOutcome {
+    /// Neither the AOI nor the color mode changed; image memories remain usable.
+    Unchanged,
+
+    /// The AOI or the color mode changed; image memories must be allocated again.
+    FormatChanged,
+}
+
+impl ResetOutcome {
+    /// Whether image memories must be allocated again.
+    #[inline]
+    pub const fn needs_realloc(self) -> bool {
+        matches!(self, ResetOutcome::FormatChanged)
+    }
+}
+
+impl Camera {
+    /// Reset all parameters to the defaults of the sensor.
+    ///
+    /// The camera handle stays valid. Capture should be stopped beforehand, since the image format
+    /// may change with the AOI and the color mode.
+    pub fn reset_to_default(&self) -> Result<ResetOutcome> {
+        let before = (aoi::current(self)?, self.color_mode()?);
+        check(unsafe { call!(is_ResetToDefault(self.handle())) })?;
+
+        Ok(match (aoi::current(self)?, self.color_mode()?) == before {
+            true => ResetOutcome::Unchanged,
+            false => ResetOutcome::FormatChanged,
+        })
+    }
+
+    /// Close the camera and open it again, allowing the driver to upload a new starter firmware.
+    ///
+    /// The camera reboots if the firmware is uploaded, which takes several seconds. The handle of
+    /// this camera becomes invalid either way, hence it is consumed; settings are lost.
+    ///
+    /// Fails with [`Error::CameraNotFound`] if the camera is not listed, e.g. while it reboots.
+    pub fn reopen_with_firmware_upload(self) -> Result<Camera> {
+        let serial = c_string(&device_info::camera_info(&self)?.SerNo);
+        let info = list_cameras()?
+            .into_iter()
+            .find(|info| info.serial_number == serial)
+            .ok_or(Error::CameraNotFound)?;
+
+        self.close()?;
+        info.open_with_firmware_upload()
+    }
+}
+
+impl CameraInfo {
+    /// Open the camera, allowing the driver to upload a new starter firmware if required.
+    ///
+    /// The camera reboots if the firmware is uploaded, which takes several seconds.
+    #[inline]
+    pub fn open_with_firmware_upload(&self) -> Result<Camera> {
+        Camera::init(self.device_id as HIDS | IS_USE_DEVICE_ID | IS_ALLOW_STARTER_FW_UPLOAD)
+    }
+}
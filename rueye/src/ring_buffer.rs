@@ -0,0 +1,257 @@
+//! Ring buffers of image memories.
+//!
+//! A [`RingBuffer`] allocates its image memories with [`is_AllocImageMem`] and adds them to the
+//! sequence of the camera with [`is_AddToSequence`], so the driver fills them in turn during
+//! live capture. Memories can be locked with [`is_LockSeqBuf`] to keep the driver from
+//! overwriting them while they are being read.
+//!
+//! The driver refuses to clear the sequence or free a memory while it is locked. When dropped, the
+//! ring buffer therefore unlocks its locked memories first, then clears the sequence and only then
+//! frees the memories.
+//!
+//! # Documentation
+//! * [is_AddToSequence](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_addtosequence.html)
+//! * [is_ClearSequence](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_clearsequence.html)
+//! * [is_LockSeqBuf](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_lockseqbuf.html)
+
+use std::{
+    marker::PhantomData,
+    ptr,
+    sync::atomic::{AtomicBool, Ordering},
+    time::SystemTime,
+};
+
+use ueye_sys::{
+    image_mem::{
+        is_AddToSequence, is_AllocImageMem, is_ClearSequence, is_FreeImageMem, is_GetActSeqBuf,
+        is_LockSeqBuf, is_UnlockSeqBuf,
+    },
+    types::{char, HIDS, INT},
+};
+
+use crate::{
+    aoi,
+    camera::Camera,
+    color_mode::ColorMode,
+    error::{check, Error, Result},
+    frame::{Frame, MemGeometry},
+    image_info::{self, FrameInfo},
+    metadata::ImageMetadata,
+    observer::call,
+};
+
+/// Image memory of a ring buffer.
+#[derive(Debug)]
+struct Buffer {
+    mem: *mut char,
+    id: INT,
+    locked: AtomicBool,
+}
+
+/// Image memories in the sequence of a camera, freed when dropped.
+///
+/// Positions index the memories in the order they were added to the sequence, starting at `0`.
+#[derive(Debug)]
+pub struct RingBuffer<'c> {
+    hcam: HIDS,
+    buffers: Vec<Buffer>,
+    geometry: MemGeometry,
+    len: usize,
+    _camera: PhantomData<&'c Camera>,
+}
+
+// SAFETY: the image memories are owned by the driver and only accessed through the camera handle,
+// which may be used from any thread.
+unsafe impl Send for RingBuffer<'_> {}
+
+impl<'c> RingBuffer<'c> {
+    /// Allocate `buffers` image memories (at least one) for the current AOI in color mode `mode`
+    /// and add them to the sequence.
+    ///
+    /// Fails with [`Error::InvalidImageSize`] if the driver reports a geometry for any of the
+    /// memories that differs from the requested one, or whose size overflows [`usize`], and with
+    /// [`Error::NotSupported`] for [planar](ColorMode::is_planar) modes.
+    pub fn alloc(camera: &'c Camera, buffers: usize, mode: ColorMode) -> Result<Self> {
+        if mode.is_planar() {
+            return Err(Error::NotSupported);
+        }
+
+        let rect = aoi::current(camera)?;
+        let mut ring = Self {
+            hcam: camera.handle(),
+            buffers: Vec::with_capacity(buffers.max(1)),
+            geometry: MemGeometry {
+                width: usize::try_from(rect.s32Width).unwrap_or(0),
+                height: usize::try_from(rect.s32Height).unwrap_or(0),
+                bits_per_pixel: mode.bits_per_pixel(),
+                pitch: 0,
+            },
+            len: 0,
+            _camera: PhantomData,
+        };
+
+        for _ in 0..buffers.max(1) {
+            let mut mem: *mut char = ptr::null_mut();
+            let mut id: INT = 0;
+            check(unsafe {
+                call!(is_AllocImageMem(
+                    ring.hcam,
+                    rect.s32Width,
+                    rect.s32Height,
+                    mode.bits_per_pixel() as INT,
+                    &mut mem,
+                    &mut id,
+                ))
+            })?;
+
+            // registered before adding, so that `Drop` frees it if adding fails
+            ring.buffers.push(Buffer { mem, id, locked: AtomicBool::new(false) });
+            check(unsafe { call!(is_AddToSequence(ring.hcam, mem, id)) })?;
+
+            // the pitch of the active image memory may differ, so every memory is inquired
+            let geometry = MemGeometry::inquire(ring.hcam, mem, id)?;
+            if ring.buffers.len() == 1 {
+                ring.geometry.pitch = geometry.pitch;
+                ring.len = ring.geometry.len()?;
+            }
+            if geometry != ring.geometry {
+                return Err(Error::InvalidImageSize);
+            }
+        }
+
+        Ok(ring)
+    }
+
+    /// Number of image memories.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Whether there are no image memories, which never holds for an allocated ring buffer.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// ID of the image memory at `position`.
+    #[inline]
+    pub fn id(&self, position: usize) -> Option<INT> {
+        self.buffers.get(position).map(|buffer| buffer.id)
+    }
+
+    /// IDs of the image memories, in sequence order.
+    pub fn ids(&self) -> impl Iterator<Item = INT> + '_ {
+        self.buffers.iter().map(|buffer| buffer.id)
+    }
+
+    /// Position of the image memory starting at `mem`.
+    #[inline]
+    fn position(&self, mem: *mut char) -> Option<usize> {
+        self.buffers.iter().position(|buffer| buffer.mem == mem)
+    }
+
+    fn buffer(&self, position: usize) -> Result<&Buffer> {
+        self.buffers.get(position).ok_or(Error::InvalidParameter)
+    }
+
+    /// Whether the image memory at `position` is locked.
+    #[inline]
+    pub fn is_locked(&self, position: usize) -> bool {
+        self.buffers.get(position).is_some_and(|buffer| buffer.locked.load(Ordering::Acquire))
+    }
+
+    /// Lock the image memory at `position`, so the driver skips it.
+    ///
+    /// Fails with [`Error::SequenceBufAlreadyLocked`] if the memory is locked already.
+    pub fn lock(&self, position: usize) -> Result<()> {
+        let buffer = self.buffer(position)?;
+        if buffer.locked.load(Ordering::Acquire) {
+            return Err(Error::SequenceBufAlreadyLocked);
+        }
+
+        check(unsafe { call!(is_LockSeqBuf(self.hcam, position as INT + 1, buffer.mem)) })?;
+        buffer.locked.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Unlock the image memory at `position`, locked by [`RingBuffer::lock`] or
+    /// [`RingBuffer::lock_last`].
+    pub fn unlock(&self, position: usize) -> Result<()> {
+        let buffer = self.buffer(position)?;
+        check(unsafe { call!(is_UnlockSeqBuf(self.hcam, position as INT + 1, buffer.mem)) })?;
+        buffer.locked.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    /// Record the image memory starting at `mem` as locked by the driver, returning its position.
+    pub(crate) fn adopt_lock(&self, mem: *mut char) -> Result<usize> {
+        let position = self.position(mem).ok_or(Error::InvalidMemoryPointer)?;
+        self.buffers[position].locked.store(true, Ordering::Release);
+        Ok(position)
+    }
+
+    /// Find and lock the image memory that was last filled, returning its position.
+    pub fn lock_last(&self) -> Result<usize> {
+        let mut num: INT = 0;
+        let mut mem: *mut char = ptr::null_mut();
+        let mut last: *mut char = ptr::null_mut();
+        check(unsafe { call!(is_GetActSeqBuf(self.hcam, &mut num, &mut mem, &mut last)) })?;
+
+        let position = self.position(last).ok_or(Error::InvalidMemoryPointer)?;
+        self.lock(position)?;
+
+        Ok(position)
+    }
+
+    /// Query the metadata of the image in the memory at `position`.
+    pub fn metadata(&self, position: usize) -> Result<ImageMetadata> {
+        let buffer = self.buffer(position)?;
+        image_info::query_handle(self.hcam, buffer.id).map(|info| ImageMetadata::from(&info))
+    }
+
+    /// Query the information of the frame in the memory at `position`, received at host time
+    /// `received`.
+    pub(crate) fn frame_info(&self, position: usize, received: SystemTime) -> Result<FrameInfo> {
+        let buffer = self.buffer(position)?;
+        FrameInfo::query_handle(self.hcam, buffer.id, received)
+    }
+
+    /// Borrow the image data of the locked memory at `position`.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `position` is out of range or the memory is not
+    /// locked.
+    ///
+    /// # Safety
+    /// The memory must stay locked while the frame is borrowed, i.e. it must not be
+    /// [unlocked](RingBuffer::unlock) before the frame is dropped; the driver would otherwise
+    /// write to it during the borrow.
+    pub unsafe fn frame(&self, position: usize) -> Result<Frame<'_>> {
+        let buffer = self.buffer(position)?;
+        if !buffer.locked.load(Ordering::Acquire) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let MemGeometry { width, height, bits_per_pixel, pitch } = self.geometry;
+
+        // SAFETY: the driver reported that the image memory spans `len` bytes, and it stays
+        // allocated while the ring buffer is alive; the caller keeps it locked against writes.
+        let data = unsafe { std::slice::from_raw_parts(buffer.mem as *const u8, self.len) };
+        Frame::new(data, width, height, pitch, bits_per_pixel).ok_or(Error::InvalidImageSize)
+    }
+}
+
+impl Drop for RingBuffer<'_> {
+    fn drop(&mut self) {
+        for position in 0..self.buffers.len() {
+            if self.is_locked(position) {
+                let _ = self.unlock(position);
+            }
+        }
+
+        unsafe { call!(is_ClearSequence(self.hcam)) };
+        for buffer in &self.buffers {
+            unsafe { call!(is_FreeImageMem(self.hcam, buffer.mem, buffer.id)) };
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Scene presets of the sensor.
+//!
+//! A [`ScenePreset`] hands exposure, white balance and color rendering to the sensor, tuned for a
+//! kind of scene such as portraits or night shots. Few sensors provide presets, and each supports
+//! its own selection: [`ScenePreset::supported`] lists them, and is empty on other cameras.
+//!
+//! # Documentation
+//! [is_ScenePreset](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_scenepreset.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    mem::size_of,
+};
+
+#[allow(deprecated)]
+use ueye_sys::scene_preset::is_ScenePreset;
+use ueye_sys::{
+    scene_preset::{SCENE_CMD, SCENE_PRESET},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+#[allow(deprecated)]
+fn scene_preset(camera: &Camera, cmd: SCENE_CMD, preset: &mut SCENE_PRESET) -> Result<()> {
+    check(unsafe {
+        command!(is_ScenePreset(
+            camera.handle(),
+            cmd,
+            preset as *mut SCENE_PRESET as *mut void,
+            size_of::<SCENE_PRESET>() as UINT,
+        ))
+    })
+}
+
+fn get(camera: &Camera, cmd: SCENE_CMD) -> Result<SCENE_PRESET> {
+    let mut preset = SCENE_PRESET::SCENE_INVALID;
+    scene_preset(camera, cmd, &mut preset)?;
+    Ok(preset)
+}
+
+/// Scene presets for [`is_ScenePreset`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScenePreset {
+    /// Tuned by the sensor for the scene at hand.
+    Automatic,
+
+    /// People in the foreground.
+    Portrait,
+
+    /// Bright daylight.
+    Sunny,
+
+    /// Indoor lighting, e.g. for video conferences.
+    Entertainment,
+
+    /// Low light.
+    Night,
+
+    /// Fast motion.
+    Sports,
+
+    /// Distant scenery.
+    Landscape,
+}
+
+impl ScenePreset {
+    /// Every scene preset.
+    pub const ALL: [ScenePreset; 7] = [
+        ScenePreset::Automatic,
+        ScenePreset::Portrait,
+        ScenePreset::Sunny,
+        ScenePreset::Entertainment,
+        ScenePreset::Night,
+        ScenePreset::Sports,
+        ScenePreset::Landscape,
+    ];
+
+    /// Bits of the preset for [`is_ScenePreset`].
+    pub const fn bits(self) -> SCENE_PRESET {
+        match self {
+            ScenePreset::Automatic => SCENE_PRESET::SCENE_SENSOR_AUTOMATIC,
+            ScenePreset::Portrait => SCENE_PRESET::SCENE_SENSOR_PORTRAIT,
+            ScenePreset::Sunny => SCENE_PRESET::SCENE_SENSOR_SUNNY,
+            ScenePreset::Entertainment => SCENE_PRESET::SCENE_SENSOR_ENTERTAINMENT,
+            ScenePreset::Night => SCENE_PRESET::SCENE_SENSOR_NIGHT,
+            ScenePreset::Sports => SCENE_PRESET::SCENE_SENSOR_SPORTS,
+            ScenePreset::Landscape => SCENE_PRESET::SCENE_SENSOR_LANDSCAPE,
+        }
+    }
+
+    /// Query the scene presets supported by the camera.
+    ///
+    /// Cameras without scene presets support none of them.
+    pub fn supported(camera: &Camera) -> Result<Vec<ScenePreset>> {
+        let presets = match get(camera, SCENE_CMD::SCENE_CMD_GET_SUPPORTED_PRESETS) {
+            Ok(presets) => presets,
+            Err(Error::NotSupported) => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+
+        Ok(Self::ALL.into_iter().filter(|preset| presets.contains(preset.bits())).collect())
+    }
+
+    /// Query the current scene preset of the camera.
+    #[inline]
+    pub fn current(camera: &Camera) -> Result<Self> {
+        get(camera, SCENE_CMD::SCENE_CMD_GET_PRESET)?.try_into()
+    }
+
+    /// Query the default scene preset of the camera.
+    #[inline]
+    pub fn default(camera: &Camera) -> Result<Self> {
+        get(camera, SCENE_CMD::SCENE_CMD_GET_DEFAULT_PRESET)?.try_into()
+    }
+
+    /// Select the scene preset on the camera.
+    ///
+    /// Fails with [`Error::NotSupported`] if the camera does not support the preset.
+    pub fn apply(self, camera: &Camera) -> Result<()> {
+        if !Self::supported(camera)?.contains(&self) {
+            return Err(Error::NotSupported);
+        }
+
+        scene_preset(camera, SCENE_CMD::SCENE_CMD_SET_PRESET, &mut self.bits())
+    }
+}
+
+impl TryFrom<SCENE_PRESET> for ScenePreset {
+    type Error = Error;
+
+    fn try_from(preset: SCENE_PRESET) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.bits() == preset)
+            .ok_or(Error::UnexpectedValue(preset.bits() as INT))
+    }
+}
+
+impl Display for ScenePreset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ScenePreset::Automatic => "automatic",
+            ScenePreset::Portrait => "portrait",
+            ScenePreset::Sunny => "sunny",
+            ScenePreset::Entertainment => "entertainment",
+            ScenePreset::Night => "night",
+            ScenePreset::Sports => "sports",
+            ScenePreset::Landscape => "landscape",
+        })
+    }
+}
+
+impl Camera {
+    /// Query the current scene preset, see [`ScenePreset::current`].
+    #[inline]
+    pub fn scene_preset(&self) -> Result<ScenePreset> {
+        ScenePreset::current(self)
+    }
+
+    /// Select a scene preset, see [`ScenePreset::apply`].
+    #[inline]
+    pub fn set_scene_preset(&self, preset: ScenePreset) -> Result<()> {
+        preset.apply(self)
+    }
+}
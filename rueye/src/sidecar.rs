@@ -0,0 +1,178 @@
+//! Per-frame metadata sidecars.
+//!
+//! A sidecar is a JSON file stored next to a saved image, recording the acquisition settings
+//! of the frame for traceable datasets.
+
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    aoi::{self, Aoi},
+    camera::Camera,
+    device_info,
+    error::Result,
+    exposure, gain,
+    image_info::FrameInfo,
+    image_mem,
+};
+
+/// Metadata of a single frame.
+///
+/// Values which could not be queried from the camera are [`None`] and omitted from the sidecar.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameMetadata {
+    /// Frame number within the capture session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_number: Option<u64>,
+
+    /// Exposure time (in ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure_ms: Option<f64>,
+
+    /// Master gain factor (`0`…`100`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gain: Option<i32>,
+
+    /// Image AOI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aoi: Option<Aoi>,
+
+    /// Device timestamp of the frame, in 0.1 µs ticks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_timestamp: Option<u64>,
+
+    /// Host time at which the frame was received, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_timestamp: Option<f64>,
+
+    /// Camera temperature in °Celsius, if the camera has a temperature sensor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature_celsius: Option<f32>,
+}
+
+impl FrameMetadata {
+    /// Gather the current settings of the camera, along with the information of the frame in its
+    /// active image memory, which is taken to be received now.
+    ///
+    /// Queries which fail, e.g. because the camera does not support them, are left as [`None`].
+    pub fn gather(camera: &Camera) -> Self {
+        let frame = image_mem::active(camera)
+            .and_then(|(_, id)| FrameInfo::query(camera, id, SystemTime::now()))
+            .ok();
+
+        Self {
+            exposure_ms: exposure::current(camera).ok(),
+            gain: gain::current(camera).ok(),
+            aoi: aoi::current(camera).ok().map(Aoi::from),
+            temperature_celsius: device_info::temperature(camera).ok().flatten(),
+            ..Self::default()
+        }
+        .with_frame_info(frame)
+    }
+
+    /// Set the frame number and the device and host timestamps from the information of a frame,
+    /// or clear them for [`None`].
+    pub fn with_frame_info(mut self, info: Option<FrameInfo>) -> Self {
+        self.frame_number = info.map(|info| info.frame_number);
+        self.device_timestamp = info.map(|info| info.device_timestamp);
+        self.host_timestamp = info.and_then(|info| {
+            info.host_timestamp.duration_since(UNIX_EPOCH).ok().map(|elapsed| elapsed.as_secs_f64())
+        });
+        self
+    }
+
+    /// Set the frame number.
+    #[inline]
+    pub fn with_frame_number(mut self, frame_number: u64) -> Self {
+        self.frame_number = Some(frame_number);
+        self
+    }
+
+    /// Set the device timestamp, in 0.1 µs ticks.
+    #[inline]
+    pub fn with_device_timestamp(mut self, device_timestamp: u64) -> Self {
+        self.device_timestamp = Some(device_timestamp);
+        self
+    }
+
+    /// Location of the sidecar for the image at `image_path`: the same path with a `.json`
+    /// extension.
+    #[inline]
+    pub fn sidecar_path(image_path: impl AsRef<Path>) -> PathBuf {
+        image_path.as_ref().with_extension("json")
+    }
+
+    /// Write the sidecar for the image at `image_path`.
+    pub fn write_sidecar(&self, image_path: impl AsRef<Path>) -> Result<()> {
+        let file = File::create(Self::sidecar_path(image_path))?;
+        serde_json::to_writer_pretty(BufWriter::new(file), self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let metadata = FrameMetadata {
+            frame_number: Some(42),
+            exposure_ms: Some(12.5),
+            gain: Some(30),
+            aoi: Some(Aoi { x: 8, y: 4, width: 640, height: 480 }),
+            device_timestamp: Some(123_456_789),
+            host_timestamp: Some(1_700_000_000.25),
+            temperature_celsius: Some(41.5),
+        };
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(serde_json::from_str::<FrameMetadata>(&json).unwrap(), metadata);
+    }
+
+    #[test]
+    fn omits_missing_values() {
+        let metadata = FrameMetadata { gain: Some(30), ..FrameMetadata::default() };
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(json, r#"{"gain":30}"#);
+        assert_eq!(serde_json::from_str::<FrameMetadata>(&json).unwrap(), metadata);
+    }
+
+    #[test]
+    fn frame_info_sets_number_and_timestamps() {
+        let info = FrameInfo {
+            mem_id: 1,
+            frame_number: 7,
+            device_timestamp: 5_000,
+            host_timestamp: UNIX_EPOCH + Duration::from_millis(1_500),
+            io_status: 0,
+        };
+
+        let metadata = FrameMetadata::default().with_frame_info(Some(info));
+        assert_eq!(metadata.frame_number, Some(7));
+        assert_eq!(metadata.device_timestamp, Some(5_000));
+        assert_eq!(metadata.host_timestamp, Some(1.5));
+
+        let metadata = metadata.with_frame_info(None);
+        assert_eq!(metadata, FrameMetadata::default());
+    }
+
+    #[test]
+    fn sidecar_replaces_extension() {
+        assert_eq!(FrameMetadata::sidecar_path("frames/0001.png"), Path::new("frames/0001.json"));
+        assert_eq!(FrameMetadata::sidecar_path("frame"), Path::new("frame.json"));
+        assert_eq!(
+            FrameMetadata::sidecar_path("a.b/frame.tar.gz"),
+            Path::new("a.b/frame.tar.json")
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Per-frame image statistics.
+//!
+//! Samples are interpreted from the packed pixel layout of the [`Frame`]:
+//! * 1 byte per pixel: one 8-bit channel (mono, raw Bayer).
+//! * 2 bytes per pixel: one 16-bit little-endian channel (mono or raw Bayer in a 16-bit
+//!   container).
+//! * 3 bytes per pixel: three 8-bit channels, in memory order (B, G, R).
+//! * 4 bytes per pixel: three 8-bit channels, in memory order (B, G, R); the fourth byte is
+//!   ignored.
+//! * 6 bytes per pixel: three 16-bit little-endian channels, in memory order (B, G, R).
+//! * 8 bytes per pixel: three 16-bit little-endian channels, in memory order (B, G, R); the
+//!   fourth channel is ignored.
+//!
+//! All computations honour the line increment of the frame.
+//!
+//! The statistics of a [`Frame`] each compute its [`Histogram`] anew. To take several statistics of
+//! the same frame, compute the histogram once with [`Frame::histogram`] and take them from it.
+
+use crate::frame::Frame;
+
+/// Histogram of every channel in a frame.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Histogram {
+    channels: Vec<Vec<u64>>,
+}
+
+impl Histogram {
+    /// Number of channels.
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Bins of `channel`, indexed by sample value.
+    ///
+    /// # Panics
+    /// If `channel` is not less than [`Histogram::channels`].
+    #[inline]
+    pub fn channel(&self, channel: usize) -> &[u64] {
+        &self.channels[channel]
+    }
+
+    /// Number of samples per channel.
+    #[inline]
+    pub fn samples(&self) -> u64 {
+        self.channels.first().map_or(0, |bins| bins.iter().sum())
+    }
+
+    /// Mean sample value of every channel.
+    pub fn mean(&self) -> Vec<f64> {
+        let samples = self.samples();
+        self.channels
+            .iter()
+            .map(|bins| {
+                if samples == 0 {
+                    return 0.0;
+                }
+
+                let sum: f64 = bins.iter().enumerate().map(|(v, &n)| v as f64 * n as f64).sum();
+                sum / samples as f64
+            })
+            .collect()
+    }
+
+    /// Minimum and maximum sample value of every channel, or [`None`] for an empty frame.
+    pub fn min_max(&self) -> Vec<Option<(u16, u16)>> {
+        self.channels
+            .iter()
+            .map(|bins| {
+                let min = bins.iter().position(|&n| n > 0)?;
+                let max = bins.iter().rposition(|&n| n > 0)?;
+                Some((min as u16, max as u16))
+            })
+            .collect()
+    }
+
+    /// Percentage (`0.0`…`100.0`) of samples at or above `threshold`, for every channel.
+    pub fn saturation(&self, threshold: u16) -> Vec<f64> {
+        let samples = self.samples();
+        self.channels
+            .iter()
+            .map(|bins| {
+                if samples == 0 {
+                    return 0.0;
+                }
+
+                let saturated: u64 = bins.iter().skip(threshold as usize).sum();
+                saturated as f64 * 100.0 / samples as f64
+            })
+            .collect()
+    }
+}
+
+impl Frame<'_> {
+    /// Number of channels in each pixel.
+    ///
+    /// See the [module documentation][crate::statistics] for the supported layouts.
+    #[inline]
+    pub const fn channels(&self) -> usize {
+        match self.bytes_per_pixel() {
+            3 | 4 | 6 | 8 => 3,
+            _ => 1,
+        }
+    }
+
+    /// Largest value a sample can hold in this frame.
+    #[inline]
+    pub const fn max_sample(&self) -> u16 {
+        match self.bytes_per_pixel() {
+            2 | 6 | 8 => u16::MAX,
+            _ => u8::MAX as u16,
+        }
+    }
+
+    /// Compute the histogram of every channel.
+    pub fn histogram(&self) -> Histogram {
+        let bins = self.max_sample() as usize + 1;
+        let mut channels = vec![vec![0u64; bins]; self.channels()];
+        let size = self.bytes_per_pixel();
+
+        for row in self.rows() {
+            for pixel in row.chunks_exact(size) {
+                match size {
+                    2 => channels[0][u16::from_le_bytes([pixel[0], pixel[1]]) as usize] += 1,
+                    3 | 4 => {
+                        for (bins, &value) in channels.iter_mut().zip(pixel) {
+                            bins[value as usize] += 1;
+                        }
+                    },
+                    6 | 8 => {
+                        for (bins, sample) in channels.iter_mut().zip(pixel.chunks_exact(2)) {
+                            bins[u16::from_le_bytes([sample[0], sample[1]]) as usize] += 1;
+                        }
+                    },
+                    _ => channels[0][pixel[0] as usize] += 1,
+                }
+            }
+        }
+
+        Histogram { channels }
+    }
+
+    /// Mean sample value of every channel.
+    ///
+    /// Computes the [histogram](Frame::histogram); see [`Histogram::mean`] to reuse one.
+    #[inline]
+    pub fn mean(&self) -> Vec<f64> {
+        self.histogram().mean()
+    }
+
+    /// Minimum and maximum sample value of every channel, or [`None`] for an empty frame.
+    ///
+    /// Computes the [histogram](Frame::histogram); see [`Histogram::min_max`] to reuse one.
+    #[inline]
+    pub fn min_max(&self) -> Vec<Option<(u16, u16)>> {
+        self.histogram().min_max()
+    }
+
+    /// Percentage (`0.0`…`100.0`) of samples at the largest representable value, for every
+    /// channel.
+    ///
+    /// Computes the [histogram](Frame::histogram); use [`Histogram::saturation`] to reuse one, or
+    /// to test against a lower threshold, e.g. for 12-bit data in a 16-bit container.
+    #[inline]
+    pub fn saturation(&self) -> Vec<f64> {
+        self.histogram().saturation(self.max_sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mono16() {
+        let data = [0x00, 0x01, 0xFF, 0xFF, 0x02, 0x00, 0x00, 0x00];
+        let frame = Frame::new(&data, 2, 2, 4, 16).unwrap();
+        assert_eq!(frame.channels(), 1);
+        assert_eq!(frame.max_sample(), u16::MAX);
+
+        let histogram = frame.histogram();
+        assert_eq!(histogram.samples(), 4);
+        assert_eq!(histogram.min_max(), [Some((0, u16::MAX))]);
+        assert_eq!(histogram.channel(0)[0x100], 1);
+        assert_eq!(frame.saturation(), [25.0]);
+    }
+
+    #[test]
+    fn bgra8_ignores_alpha() {
+        let data = [10, 20, 30, 255, 12, 22, 32, 0];
+        let frame = Frame::new(&data, 2, 1, 8, 32).unwrap();
+        assert_eq!(frame.channels(), 3);
+        assert_eq!(frame.max_sample(), u8::MAX as u16);
+        assert_eq!(frame.mean(), [11.0, 21.0, 31.0]);
+    }
+
+    #[test]
+    fn bgr12_unpacked() {
+        let data = [0x00, 0x01, 0x00, 0x02, 0xFF, 0x0F, 0x02, 0x01, 0x00, 0x00, 0x01, 0x0F];
+        let frame = Frame::new(&data, 2, 1, 12, 48).unwrap();
+        assert_eq!(frame.channels(), 3);
+        assert_eq!(frame.max_sample(), u16::MAX);
+        assert_eq!(frame.min_max(), [Some((0x100, 0x102)), Some((0, 0x200)), Some((0xF01, 0xFFF))]);
+        assert_eq!(frame.histogram().saturation(0xFFF), [0.0, 0.0, 50.0]);
+    }
+
+    #[test]
+    fn bgra12_unpacked_ignores_alpha() {
+        let data = [
+            0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0xFF, 0xFF, //
+            0x03, 0x00, 0x04, 0x00, 0x05, 0x00, 0x00, 0x00,
+        ];
+        let frame = Frame::new(&data, 1, 2, 8, 64).unwrap();
+        assert_eq!(frame.channels(), 3);
+
+        let histogram = frame.histogram();
+        assert_eq!(histogram.channels(), 3);
+        assert_eq!(histogram.samples(), 2);
+        assert_eq!(histogram.mean(), [2.0, 3.0, 4.0]);
+    }
+}
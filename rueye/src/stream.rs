@@ -0,0 +1,104 @@
+//! Asynchronous capture into a ring buffer sequence.
+//!
+//! [`FrameStream`] is the asynchronous counterpart of [`FrameIterator`](crate::capture::FrameIterator).
+//! Waiting for the uEye frame event is a blocking call, so each wait runs on the blocking thread
+//! pool of the [`tokio`] runtime and wakes the stream once the event is signaled. No thread is
+//! held while the stream is not polled.
+//!
+//! Since a frame cannot outlive the poll that produced it, each frame is copied out of the
+//! sequence into a [`FrameBuf`] and its image memory is released straight away.
+//!
+//! # Documentation
+//! [is_Event](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_event.html)
+
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use futures_core::Stream;
+use tokio::task::{spawn_blocking, JoinHandle};
+
+use crate::{
+    camera::Camera,
+    capture::{self, CaptureOptions},
+    error::{Error, Result},
+    frame::FrameBuf,
+    ring_buffer::RingBuffer,
+};
+
+impl Camera {
+    /// Start live capture with the default [`CaptureOptions`], yielding frames as a [`Stream`].
+    ///
+    /// Must be called within a [`tokio`] runtime.
+    #[inline]
+    pub fn frames(&self) -> Result<FrameStream<'_>> {
+        FrameStream::new(self, &CaptureOptions::default())
+    }
+}
+
+/// Stream of frames captured in live mode.
+///
+/// The stream never ends; failures such as [`Error::TimedOut`] are yielded as items, leaving it to
+/// the caller whether to continue. Live capture is stopped when the stream is dropped.
+#[derive(Debug)]
+pub struct FrameStream<'c> {
+    camera: &'c Camera,
+    ring: RingBuffer<'c>,
+    timeout: Duration,
+    pending: Option<JoinHandle<Result<()>>>,
+}
+
+impl<'c> FrameStream<'c> {
+    /// Allocate the sequence for the current AOI and start live capture.
+    pub fn new(camera: &'c Camera, options: &CaptureOptions) -> Result<Self> {
+        let ring = RingBuffer::alloc(camera, options.buffers, options.color_mode)?;
+
+        // from here on, `Drop` undoes the event registration and the live capture
+        let stream = Self { camera, ring, timeout: options.timeout, pending: None };
+        capture::start(camera)?;
+
+        Ok(stream)
+    }
+
+    /// Copy the frame last captured out of the ring buffer.
+    fn take(&self) -> Result<FrameBuf> {
+        let position = self.ring.lock_last()?;
+        // SAFETY: the memory is unlocked only once the frame has been copied.
+        let frame = unsafe { self.ring.frame(position) }.map(|frame| FrameBuf::copy_from(&frame));
+        self.ring.unlock(position)?;
+        let frame = frame?;
+
+        Ok(frame)
+    }
+}
+
+impl Stream for FrameStream<'_> {
+    type Item = Result<FrameBuf>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let (hcam, timeout) = (this.camera.handle(), this.timeout);
+        let pending = this
+            .pending
+            .get_or_insert_with(|| spawn_blocking(move || capture::wait_frame(hcam, timeout)));
+
+        let signaled = ready!(Pin::new(pending).poll(cx));
+        this.pending = None;
+
+        Poll::Ready(Some(match signaled {
+            Ok(signaled) => signaled.and_then(|()| this.take()),
+            Err(join) => Err(Error::from(io::Error::from(join))),
+        }))
+    }
+}
+
+impl Drop for FrameStream<'_> {
+    fn drop(&mut self) {
+        // a pending wait only holds the camera handle and returns once the event is released
+        capture::stop(self.camera);
+    }
+}
@@ -0,0 +1,227 @@
+//! Support bundles for bug reports.
+//!
+//! A [`SupportBundle`] collects everything usually asked for when reporting a problem with a
+//! camera: versions, camera and sensor information, supported device features, current settings
+//! and, with the `profile` feature, the current profile, capture error counters and the last driver
+//! error. With the `serde` feature, the bundle can be
+//! serialized to attach it to a report.
+
+use std::{collections::BTreeMap, ptr};
+
+use ueye_sys::types::TRUE;
+
+#[cfg(feature = "profile")]
+use crate::profile::CameraProfile;
+use crate::{
+    aoi::{self, Aoi},
+    camera::Camera,
+    capture_status::CaptureStats,
+    device_info::{self, c_string},
+    error::{last_error, Result},
+    eth, exposure, gain,
+    operating_mode::OperatingMode,
+};
+
+/// Camera information stored in the camera EEPROM.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraDetails {
+    /// Serial number of the camera.
+    pub serial_number: String,
+
+    /// Manufacturer of the camera.
+    pub manufacturer: String,
+
+    /// Camera hardware version.
+    pub hardware_version: String,
+
+    /// Date of the final quality check.
+    pub date: String,
+}
+
+/// Sensor information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorDetails {
+    /// Sensor type.
+    pub sensor_id: u16,
+
+    /// Camera model.
+    pub name: String,
+
+    /// Largest image width, in pixels.
+    pub max_width: u32,
+
+    /// Largest image height, in lines.
+    pub max_height: u32,
+
+    /// Whether the sensor has a global shutter.
+    pub global_shutter: bool,
+
+    /// Pixel size, in µm × 100.
+    pub pixel_size: u16,
+}
+
+/// Current settings of the camera.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Settings {
+    /// Operating mode of the camera, e.g. free run or trigger.
+    pub operating_mode: Option<String>,
+
+    /// Exposure time (in ms).
+    pub exposure_ms: Option<f64>,
+
+    /// Master gain factor (`0`…`100`).
+    pub gain: Option<i32>,
+
+    /// Image AOI.
+    pub aoi: Option<Aoi>,
+
+    /// Camera temperature in °Celsius, if the camera has a temperature sensor.
+    pub temperature_celsius: Option<f32>,
+}
+
+/// Capture errors counted since the camera was opened.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureErrors {
+    /// Total number of capture errors.
+    pub total: u32,
+
+    /// Non-zero counters, by error name.
+    pub counts: BTreeMap<String, u32>,
+}
+
+/// Error last reported by the driver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverError {
+    /// Driver return code, if the error is a driver error.
+    pub code: Option<i32>,
+
+    /// Name of the return code constant, for documented driver errors.
+    pub name: Option<String>,
+
+    /// Error message of the driver.
+    pub message: String,
+}
+
+/// Driver information of a GigE camera.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EthDriverDetails {
+    /// Oldest starter firmware version the driver works with.
+    pub min_starter_firmware: u32,
+
+    /// Newest starter firmware version the driver works with.
+    pub max_starter_firmware: u32,
+}
+
+/// Diagnostic report of a camera.
+///
+/// Queries which fail, e.g. because the camera does not support them, are left as [`None`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupportBundle {
+    /// Version of the uEye API library.
+    pub api_version: String,
+
+    /// Version of the uEye driver.
+    ///
+    /// The driver reports no version of its own; it is installed along with the API library and
+    /// shares its version.
+    pub driver_version: String,
+
+    /// Driver information of a GigE camera.
+    pub eth_driver: Option<EthDriverDetails>,
+
+    /// Runtime firmware version of the camera.
+    pub firmware_version: Option<u32>,
+
+    /// Camera information stored in the camera EEPROM.
+    pub camera: Option<CameraDetails>,
+
+    /// Sensor information.
+    pub sensor: Option<SensorDetails>,
+
+    /// Names of the supported device features.
+    pub device_features: Option<Vec<String>>,
+
+    /// Current settings of the camera.
+    pub settings: Settings,
+
+    /// Current settings of the camera, as restored by a profile.
+    #[cfg(feature = "profile")]
+    pub profile: Option<CameraProfile>,
+
+    /// Capture errors counted since the camera was opened.
+    pub capture_errors: Option<CaptureErrors>,
+
+    /// Error last reported by the driver.
+    pub last_error: Option<DriverError>,
+}
+
+/// Gather a [`SupportBundle`] for `camera`.
+pub fn support_bundle(camera: &Camera) -> SupportBundle {
+    SupportBundle {
+        api_version: device_info::api_version(),
+        driver_version: device_info::api_version(),
+        eth_driver: eth_driver(camera).ok(),
+        firmware_version: device_info::query(camera)
+            .ok()
+            .map(|info| info.infoDevHeartbeat.dwRuntimeFirmwareVersion),
+        camera: device_info::camera_info(camera).ok().map(|info| CameraDetails {
+            serial_number: c_string(&info.SerNo),
+            manufacturer: c_string(&info.ID),
+            hardware_version: c_string(&info.Version),
+            date: c_string(&info.Date),
+        }),
+        sensor: device_info::sensor_info(camera).ok().map(|info| SensorDetails {
+            sensor_id: info.SensorID,
+            name: c_string(&info.strSensorName),
+            max_width: info.nMaxWidth,
+            max_height: info.nMaxHeight,
+            global_shutter: info.bGlobShutter == TRUE,
+            pixel_size: info.wPixelSize,
+        }),
+        device_features: camera.device_features().capabilities().ok().map(|capabilities| {
+            capabilities.iter().map(|capability| format!("{capability:?}")).collect()
+        }),
+        settings: Settings {
+            operating_mode: OperatingMode::current(camera).ok().map(|mode| format!("{mode:?}")),
+            exposure_ms: exposure::current(camera).ok(),
+            gain: gain::current(camera).ok(),
+            aoi: aoi::current(camera).ok().map(Aoi::from),
+            temperature_celsius: device_info::temperature(camera).ok().flatten(),
+        },
+        #[cfg(feature = "profile")]
+        profile: CameraProfile::read(camera).ok(),
+        capture_errors: capture_errors(camera).ok(),
+        last_error: last_error(camera).ok().flatten().map(|(error, message)| DriverError {
+            code: error.code(),
+            name: error.name().map(str::to_owned),
+            message,
+        }),
+    }
+}
+
+fn capture_errors(camera: &Camera) -> Result<CaptureErrors> {
+    let stats = CaptureStats::query(camera)?;
+    let counts = stats.errors().map(|(cause, count)| (format!("{cause:?}"), count)).collect();
+
+    Ok(CaptureErrors { total: stats.total, counts })
+}
+
+fn eth_driver(camera: &Camera) -> Result<EthDriverDetails> {
+    let info = eth::device_info(camera.handle())?;
+
+    // SAFETY: the structure is zero-initialized and packed, so its fields are read unaligned
+    let driver = unsafe { ptr::addr_of!((*info.as_ptr()).infoDriver) };
+    Ok(unsafe {
+        EthDriverDetails {
+            min_starter_firmware: ptr::addr_of!((*driver).dwMinVerStarterFirmware).read_unaligned(),
+            max_starter_firmware: ptr::addr_of!((*driver).dwMaxVerStarterFirmware).read_unaligned(),
+        }
+    })
+}
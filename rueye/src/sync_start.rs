@@ -0,0 +1,105 @@
+//! Synchronized start of camera arrays.
+//!
+//! [`SyncStart`] turns one camera into the trigger master of an array: every camera is armed for
+//! hardware triggering, then a GPIO output of the master is pulsed. With the output wired to the
+//! trigger inputs of all cameras, including the master's own if it takes part in the capture,
+//! they all expose on the same edge, without an external signal generator.
+//!
+//! # Documentation
+//! * [Using GPIO](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_iogpio.html)
+//! * [is_SetExternalTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setexternaltrigger.html)
+
+use std::{thread, time::Duration};
+
+use ueye_sys::{
+    io::IO_GPIO,
+    types::INT,
+    video::{is_CaptureVideo, is_StopLiveVideo, IS_DONT_WAIT, IS_FORCE_VIDEO_STOP},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    gpio,
+    observer::call,
+    trigger::TriggerMode,
+};
+
+/// Hardware-triggered start of several cameras from a GPIO output of a master camera.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SyncStart {
+    /// GPIO output of the master camera driving the trigger inputs.
+    pub output: IO_GPIO,
+
+    /// Trigger edge the cameras are armed for, either [`TriggerMode::RisingEdge`] or
+    /// [`TriggerMode::FallingEdge`].
+    pub edge: TriggerMode,
+
+    /// How long the output is held at the active level.
+    pub pulse: Duration,
+}
+
+impl Default for SyncStart {
+    fn default() -> Self {
+        Self {
+            output: IO_GPIO::IO_GPIO_1,
+            edge: TriggerMode::RisingEdge,
+            pulse: Duration::from_millis(1),
+        }
+    }
+}
+
+impl SyncStart {
+    /// Output level triggering the cameras.
+    fn active_level(self) -> Result<bool> {
+        match self.edge {
+            TriggerMode::RisingEdge | TriggerMode::PreRisingEdge => Ok(true),
+            TriggerMode::FallingEdge | TriggerMode::PreFallingEdge => Ok(false),
+            _ => Err(Error::InvalidParameter),
+        }
+    }
+
+    /// Drive the output of `master` to its idle level, so that no edge is emitted while arming.
+    pub fn prepare(self, master: &Camera) -> Result<()> {
+        gpio::set_output(master, self.output, !self.active_level()?)
+    }
+
+    /// Set the trigger edge on every camera and start capturing on triggers.
+    ///
+    /// If a camera fails, capture is stopped again on the cameras armed before it.
+    pub fn arm(self, cameras: &[&Camera]) -> Result<()> {
+        self.active_level()?;
+
+        for (armed, camera) in cameras.iter().enumerate() {
+            let result = self.edge.apply(camera).and_then(|()| {
+                check(unsafe { call!(is_CaptureVideo(camera.handle(), IS_DONT_WAIT as INT)) })
+            });
+
+            if let Err(error) = result {
+                for camera in &cameras[..armed] {
+                    unsafe { call!(is_StopLiveVideo(camera.handle(), IS_FORCE_VIDEO_STOP as INT)) };
+                }
+
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emit one trigger pulse on the output of `master`.
+    pub fn fire(self, master: &Camera) -> Result<()> {
+        let active = self.active_level()?;
+
+        gpio::set_output(master, self.output, active)?;
+        thread::sleep(self.pulse);
+        gpio::set_output(master, self.output, !active)
+    }
+
+    /// Arm `cameras` and trigger them all at once from `master`.
+    pub fn start(self, master: &Camera, cameras: &[&Camera]) -> Result<()> {
+        self.prepare(master)?;
+        self.arm(cameras)?;
+        self.fire(master)
+    }
+}
@@ -0,0 +1,378 @@
+//! Camera temperature trend.
+//!
+//! A [`TemperatureTracker`] samples the camera temperature and the temperature state at a fixed
+//! interval and keeps a bounded history of the samples. From the history it fits a linear trend,
+//! and raises a [`TemperatureWarning`] once the camera is expected to reach the critical
+//! temperature soon, before the camera itself reports the critical state.
+//!
+//! The tracker does not spawn a thread on its own; [`TemperatureTracker::run`] samples until told
+//! to stop, e.g. from a [scoped thread](std::thread::scope) borrowing the camera.
+//!
+//! # Documentation
+//! * [is_DeviceInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_deviceinfo.html)
+//! * [Querying the temperature state](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature-status-temperature.html)
+
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ueye_sys::device_feature::IS_TEMPERATURE_CONTROL_STATUS;
+
+use crate::{
+    camera::Camera,
+    device_info,
+    error::{optional, Error, Result},
+};
+
+/// Configuration of a [`TemperatureTracker`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TemperatureOptions {
+    /// Time between two samples in [`TemperatureTracker::run`].
+    pub interval: Duration,
+
+    /// Number of samples kept in the history (at least two for a trend).
+    pub history: usize,
+
+    /// Temperature (in °C) at which the camera reports the critical state.
+    pub critical_celsius: f32,
+
+    /// Warn if the trend reaches the critical temperature within this time.
+    pub lookahead: Duration,
+}
+
+impl Default for TemperatureOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            history: 60,
+            critical_celsius: 80.0,
+            lookahead: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Temperature sample of a camera.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TemperatureSample {
+    /// Time of the sample.
+    pub at: Instant,
+
+    /// Camera temperature in °C, if the camera has a temperature sensor.
+    pub celsius: Option<f32>,
+
+    /// Temperature state, if supported by the camera.
+    pub status: Option<IS_TEMPERATURE_CONTROL_STATUS>,
+}
+
+/// Warning raised by a [`TemperatureTracker`], in increasing severity.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TemperatureWarning {
+    /// The temperature is expected to reach the critical temperature within `eta`.
+    Approaching {
+        /// Last sampled temperature in °C.
+        celsius: f32,
+
+        /// Rise of the temperature, in °C per minute.
+        slope: f32,
+
+        /// Expected time until the critical temperature is reached.
+        eta: Duration,
+    },
+
+    /// The camera reports the warning state (above 75 °C).
+    Warning,
+
+    /// The camera reports the critical state (above 80 °C).
+    Critical,
+}
+
+impl TemperatureWarning {
+    /// Severity, for raising each level only once.
+    const fn level(&self) -> u8 {
+        match self {
+            TemperatureWarning::Approaching { .. } => 1,
+            TemperatureWarning::Warning => 2,
+            TemperatureWarning::Critical => 3,
+        }
+    }
+}
+
+/// Sampler of the camera temperature with a bounded history.
+pub struct TemperatureTracker {
+    options: TemperatureOptions,
+    samples: VecDeque<TemperatureSample>,
+    callback: Option<Box<dyn FnMut(TemperatureWarning) + Send>>,
+    level: u8,
+}
+
+impl std::fmt::Debug for TemperatureTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemperatureTracker")
+            .field("options", &self.options)
+            .field("samples", &self.samples)
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TemperatureTracker {
+    /// Create a tracker with an empty history.
+    pub fn new(options: TemperatureOptions) -> Self {
+        Self {
+            options,
+            samples: VecDeque::with_capacity(options.history),
+            callback: None,
+            level: 0,
+        }
+    }
+
+    /// Call `callback` whenever a more severe warning than the last one is raised.
+    ///
+    /// Once the temperature is back to normal and no longer approaching the critical temperature,
+    /// warnings are raised again.
+    pub fn on_warning(&mut self, callback: impl FnMut(TemperatureWarning) + Send + 'static) {
+        self.callback = Some(Box::new(callback));
+    }
+
+    /// Configuration.
+    #[inline]
+    pub fn options(&self) -> &TemperatureOptions {
+        &self.options
+    }
+
+    /// Samples in the history, oldest first.
+    #[inline]
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = &TemperatureSample> {
+        self.samples.iter()
+    }
+
+    /// Most recent sample.
+    #[inline]
+    pub fn last(&self) -> Option<&TemperatureSample> {
+        self.samples.back()
+    }
+
+    /// Sample the temperature of `camera`, returning the warning raised, if any.
+    ///
+    /// Fails with [`Error::NotSupported`] if the camera provides neither a temperature nor a
+    /// temperature state.
+    pub fn sample(&mut self, camera: &Camera) -> Result<Option<TemperatureWarning>> {
+        let celsius = optional(device_info::temperature(camera))?.flatten();
+        let status = optional(camera.device_features().temperature_status())?;
+
+        if celsius.is_none() && status.is_none() {
+            return Err(Error::NotSupported);
+        }
+
+        Ok(self.record(TemperatureSample { at: Instant::now(), celsius, status }))
+    }
+
+    /// Add a sample to the history, returning the warning raised, if any.
+    pub fn record(&mut self, sample: TemperatureSample) -> Option<TemperatureWarning> {
+        if self.samples.len() == self.options.history.max(1) {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+
+        let warning = self.assess();
+        let level = warning.as_ref().map_or(0, TemperatureWarning::level);
+        if level <= self.level {
+            self.level = level;
+            return None;
+        }
+
+        self.level = level;
+        if let (Some(callback), Some(warning)) = (&mut self.callback, warning) {
+            callback(warning);
+        }
+
+        warning
+    }
+
+    /// Rise of the temperature over the history by a least-squares fit, in °C per second.
+    pub fn slope(&self) -> Option<f32> {
+        let start = self.samples.front()?.at;
+        let points: Vec<(f64, f64)> = self
+            .samples
+            .iter()
+            .filter_map(|sample| {
+                let celsius = f64::from(sample.celsius?);
+                Some((sample.at.duration_since(start).as_secs_f64(), celsius))
+            })
+            .collect();
+
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / n;
+        let mean_c = points.iter().map(|(_, c)| c).sum::<f64>() / n;
+        let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), (t, c)| {
+            (cov + (t - mean_t) * (c - mean_c), var + (t - mean_t).powi(2))
+        });
+
+        (variance > 0.0).then(|| (covariance / variance) as f32)
+    }
+
+    /// Current warning from the last sample and the trend.
+    fn assess(&self) -> Option<TemperatureWarning> {
+        let last = self.samples.back()?;
+        match last.status {
+            Some(IS_TEMPERATURE_CONTROL_STATUS::TEMPERATURE_CONTROL_STATUS_CRITICAL) => {
+                return Some(TemperatureWarning::Critical);
+            },
+            Some(IS_TEMPERATURE_CONTROL_STATUS::TEMPERATURE_CONTROL_STATUS_WARNING) => {
+                return Some(TemperatureWarning::Warning);
+            },
+            _ => {},
+        }
+
+        let celsius = last.celsius?;
+        let slope = self.slope().filter(|&slope| slope > 0.0)?;
+        let eta = Duration::try_from_secs_f32((self.options.critical_celsius - celsius) / slope)
+            .unwrap_or(Duration::ZERO);
+
+        (eta <= self.options.lookahead).then_some(TemperatureWarning::Approaching {
+            celsius,
+            slope: slope * 60.0,
+            eta,
+        })
+    }
+
+    /// Sample `camera` every [interval](TemperatureOptions::interval) until `stop` is set.
+    ///
+    /// Failing samples are skipped, except [`Error::NotSupported`], which is returned.
+    pub fn run(&mut self, camera: &Camera, stop: &AtomicBool) -> Result<()> {
+        while !stop.load(Ordering::Acquire) {
+            match self.sample(camera) {
+                Err(Error::NotSupported) => return Err(Error::NotSupported),
+                _ => thread::sleep(self.options.interval),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use IS_TEMPERATURE_CONTROL_STATUS::*;
+
+    /// Sample `seconds` after `start`.
+    fn sample(
+        start: Instant,
+        seconds: u64,
+        celsius: Option<f32>,
+        status: Option<IS_TEMPERATURE_CONTROL_STATUS>,
+    ) -> TemperatureSample {
+        TemperatureSample { at: start + Duration::from_secs(seconds), celsius, status }
+    }
+
+    #[test]
+    fn history_evicts_oldest() {
+        let options = TemperatureOptions { history: 3, ..TemperatureOptions::default() };
+        let mut tracker = TemperatureTracker::new(options);
+        let start = Instant::now();
+
+        for seconds in 0..5 {
+            tracker.record(sample(start, seconds, Some(40.0), None));
+        }
+
+        let kept: Vec<Instant> = tracker.samples().map(|sample| sample.at).collect();
+        assert_eq!(kept, [2, 3, 4].map(|seconds| start + Duration::from_secs(seconds)));
+    }
+
+    #[test]
+    fn slope_fits_least_squares() {
+        let mut tracker = TemperatureTracker::new(TemperatureOptions::default());
+        let start = Instant::now();
+        assert_eq!(tracker.slope(), None);
+
+        // about 0.5 °C/s with noise; samples without a temperature are skipped
+        for (seconds, celsius) in [(0, 40.1), (2, 40.9), (4, 42.1), (6, 42.9)] {
+            tracker.record(sample(start, seconds, Some(celsius), None));
+        }
+        tracker.record(sample(start, 7, None, Some(TEMPERATURE_CONTROL_STATUS_NORMAL)));
+
+        let slope = tracker.slope().unwrap();
+        assert!((slope - 0.48).abs() < 1e-4, "{slope}");
+    }
+
+    #[test]
+    fn slope_needs_two_times() {
+        let mut tracker = TemperatureTracker::new(TemperatureOptions::default());
+        let start = Instant::now();
+        tracker.record(sample(start, 0, Some(40.0), None));
+        assert_eq!(tracker.slope(), None);
+
+        tracker.record(sample(start, 0, Some(41.0), None));
+        assert_eq!(tracker.slope(), None);
+    }
+
+    #[test]
+    fn approaching_within_lookahead() {
+        let options = TemperatureOptions {
+            critical_celsius: 80.0,
+            lookahead: Duration::from_secs(30),
+            ..TemperatureOptions::default()
+        };
+        let mut tracker = TemperatureTracker::new(options);
+        let start = Instant::now();
+
+        // 1 °C/s: 40 s to go at 40 °C, outside of the lookahead
+        assert_eq!(tracker.record(sample(start, 0, Some(39.0), None)), None);
+        assert_eq!(tracker.record(sample(start, 1, Some(40.0), None)), None);
+
+        // 20 s to go at 60 °C
+        let warning = tracker.record(sample(start, 21, Some(60.0), None));
+        let Some(TemperatureWarning::Approaching { celsius, slope, eta }) = warning else {
+            panic!("expected approaching, got {warning:?}");
+        };
+        assert_eq!(celsius, 60.0);
+        assert!((slope - 60.0).abs() < 1e-3, "{slope}");
+        assert!((eta.as_secs_f32() - 20.0).abs() < 1e-3, "{eta:?}");
+
+        // raised once per level
+        assert_eq!(tracker.record(sample(start, 22, Some(61.0), None)), None);
+    }
+
+    #[test]
+    fn warnings_rearm_after_drop() {
+        let mut tracker = TemperatureTracker::new(TemperatureOptions::default());
+        let start = Instant::now();
+
+        let raised = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        tracker.on_warning({
+            let raised = std::sync::Arc::clone(&raised);
+            move |warning| raised.lock().unwrap().push(warning)
+        });
+
+        let statuses = [
+            (TEMPERATURE_CONTROL_STATUS_WARNING, Some(TemperatureWarning::Warning)),
+            (TEMPERATURE_CONTROL_STATUS_WARNING, None),
+            (TEMPERATURE_CONTROL_STATUS_CRITICAL, Some(TemperatureWarning::Critical)),
+            (TEMPERATURE_CONTROL_STATUS_WARNING, None),
+            (TEMPERATURE_CONTROL_STATUS_CRITICAL, Some(TemperatureWarning::Critical)),
+            (TEMPERATURE_CONTROL_STATUS_NORMAL, None),
+            (TEMPERATURE_CONTROL_STATUS_WARNING, Some(TemperatureWarning::Warning)),
+        ];
+        for (seconds, (status, expected)) in statuses.into_iter().enumerate() {
+            assert_eq!(tracker.record(sample(start, seconds as u64, None, Some(status))), expected);
+        }
+
+        assert_eq!(
+            *raised.lock().unwrap(),
+            [
+                TemperatureWarning::Warning,
+                TemperatureWarning::Critical,
+                TemperatureWarning::Critical,
+                TemperatureWarning::Warning,
+            ]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Upload of frames into [`wgpu`] textures for live display.
+//!
+//! A [`FrameTexture`] holds a texture in the [format](texture_format) matching a color mode and
+//! uploads a frame into it on every [`upload`](FrameTexture::upload), for display by GUI toolkits
+//! built on [`wgpu`] such as egui or iced. The texture is recreated when the frame size changes.
+//!
+//! Frames are written through [`wgpu::Queue::write_texture`], which takes the line padding of the
+//! image memory as is; frames are only repacked when their pitch does not fit the texture format,
+//! or when 24-bit color is expanded to the 32-bit texture formats of [`wgpu`]. Sample values are
+//! uploaded unchanged, so 10 and 12-bit grayscale appears darker unless scaled in the shader.
+
+use wgpu::{
+    Device, Extent3d, Origin3d, Queue, TexelCopyBufferLayout, TexelCopyTextureInfo, Texture,
+    TextureAspect, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+};
+
+use crate::{
+    color_mode::ColorMode,
+    error::{Error, Result},
+    frame::Frame,
+};
+
+/// Texture format holding frames of `mode`, or [`None`] if frames of `mode` cannot be displayed.
+///
+/// Grayscale above 8 bits maps to [`TextureFormat::R16Unorm`], which requires
+/// [`wgpu::Features::TEXTURE_FORMAT_16BIT_NORM`]. 24-bit color maps to the 32-bit format of the
+/// same channel order.
+pub const fn texture_format(mode: ColorMode) -> Option<TextureFormat> {
+    match mode {
+        ColorMode::Mono8 => Some(TextureFormat::R8Unorm),
+        ColorMode::Mono10 | ColorMode::Mono12 | ColorMode::Mono16 => Some(TextureFormat::R16Unorm),
+        ColorMode::Rgb8Packed | ColorMode::Rgba8Packed => Some(TextureFormat::Rgba8Unorm),
+        ColorMode::Bgr8Packed | ColorMode::Bgra8Packed => Some(TextureFormat::Bgra8Unorm),
+        _ => None,
+    }
+}
+
+/// Texture displaying the frames of a camera.
+#[derive(Debug)]
+pub struct FrameTexture {
+    texture: Texture,
+    color_mode: ColorMode,
+    format: TextureFormat,
+    staging: Vec<u8>,
+}
+
+impl FrameTexture {
+    /// Create a texture of `width` × `height` pixels for frames of `mode`.
+    ///
+    /// Fails with [`Error::InvalidColorFormat`] if frames of `mode` cannot be displayed.
+    pub fn new(device: &Device, mode: ColorMode, width: u32, height: u32) -> Result<Self> {
+        let format = texture_format(mode).ok_or(Error::InvalidColorFormat)?;
+        Ok(Self {
+            texture: create_texture(device, format, width, height),
+            color_mode: mode,
+            format,
+            staging: Vec::new(),
+        })
+    }
+
+    /// The texture, e.g. for creating a view to bind.
+    #[inline]
+    pub const fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Color mode of the frames.
+    #[inline]
+    pub const fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Format of the texture.
+    #[inline]
+    pub const fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// Upload `frame` into the texture.
+    ///
+    /// If the size of `frame` differs from the texture, the texture is recreated first and `true`
+    /// is returned: views and bind groups of the previous texture must then be recreated too.
+    ///
+    /// Fails with [`Error::InvalidColorFormat`] if `frame` does not match the color mode.
+    pub fn upload(&mut self, device: &Device, queue: &Queue, frame: &Frame<'_>) -> Result<bool> {
+        if frame.bits_per_pixel() != self.color_mode.bits_per_pixel() {
+            return Err(Error::InvalidColorFormat);
+        }
+
+        let width = u32::try_from(frame.width()).map_err(|_| Error::InvalidParameter)?;
+        let height = u32::try_from(frame.height()).map_err(|_| Error::InvalidParameter)?;
+
+        let resized = self.texture.width() != width || self.texture.height() != height;
+        if resized {
+            self.texture = create_texture(device, self.format, width, height);
+        }
+
+        if width == 0 || height == 0 {
+            return Ok(resized);
+        }
+
+        let (data, pitch) = texels(self.format, &mut self.staging, frame);
+        let pitch = u32::try_from(pitch).map_err(|_| Error::InvalidParameter)?;
+
+        queue.write_texture(
+            TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            data,
+            TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(pitch), rows_per_image: None },
+            Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+
+        Ok(resized)
+    }
+}
+
+/// Texels of `frame` in `format`, along with their line increment in bytes, repacked into
+/// `staging` where needed.
+fn texels<'a>(
+    format: TextureFormat,
+    staging: &'a mut Vec<u8>,
+    frame: &Frame<'a>,
+) -> (&'a [u8], usize) {
+    let texel = format.block_copy_size(None).unwrap_or(1) as usize;
+
+    if frame.bytes_per_pixel() == 3 {
+        // expand to 32 bits, with opaque alpha
+        staging.clear();
+        for row in frame.rows() {
+            for pixel in row.chunks_exact(3) {
+                staging.extend_from_slice(&[pixel[0], pixel[1], pixel[2], u8::MAX]);
+            }
+        }
+        (staging, frame.width() * texel)
+    } else if frame.pitch().is_multiple_of(texel) {
+        (frame.as_bytes(), frame.pitch())
+    } else {
+        staging.clear();
+        frame.rows().for_each(|row| staging.extend_from_slice(row));
+        (staging, frame.width() * texel)
+    }
+}
+
+/// Create a texture of `format`, to be sampled and written.
+fn create_texture(device: &Device, format: TextureFormat, width: u32, height: u32) -> Texture {
+    device.create_texture(&TextureDescriptor {
+        label: Some("rueye frame"),
+        size: Extent3d { width, height, depth_or_array_layers: 1 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format,
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        view_formats: &[],
+    })
+}
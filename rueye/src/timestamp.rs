@@ -0,0 +1,278 @@
+//! Conversion of image timestamps to host time.
+//!
+//! The driver stamps each image twice: with the device timestamp, a tick count of the free-running
+//! camera clock (in 0.1 µs steps), and with the system time of the PC, derived from it as a
+//! [`UEYETIME`] calendar time in the local time zone with millisecond resolution.
+//!
+//! [`system_time`] converts the calendar time. The device timestamp has no epoch of its own, so a
+//! [`DeviceClock`] relates it to the host clock through an origin, the host time of tick `0`,
+//! which an [`OffsetEstimator`] estimates from device timestamps of images paired with the host
+//! time at which they arrived. With the `chrono` feature, both also convert to [`DateTime`]s.
+//!
+//! # Documentation
+//! [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+//!
+//! [`DateTime`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ueye_sys::{image_info::UEYETIME, types::INT};
+
+use crate::{
+    camera::Camera,
+    error::{Error, Result},
+    image_info,
+};
+
+/// Device timestamp ticks per second.
+pub const TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// Seconds per day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Duration of `ticks` device timestamp ticks.
+#[inline]
+pub const fn ticks_to_duration(ticks: u64) -> Duration {
+    Duration::new(ticks / TICKS_PER_SECOND, (ticks % TICKS_PER_SECOND * 100) as u32)
+}
+
+/// Number of days of `month` in `year`.
+const fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days of the proleptic Gregorian date since the Unix epoch, after Howard Hinnant's
+/// `days_from_civil`.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Check each field of `time` against its range.
+fn validate(time: &UEYETIME) -> Result<()> {
+    let check = |value: u16, range: std::ops::RangeInclusive<u16>| match range.contains(&value) {
+        true => Ok(()),
+        false => Err(Error::UnexpectedValue(value as INT)),
+    };
+
+    check(time.wMonth, 1..=12)?;
+    check(time.wDay, 1..=days_in_month(time.wYear as i64, time.wMonth as i64) as u16)?;
+    check(time.wHour, 0..=23)?;
+    check(time.wMinute, 0..=59)?;
+    check(time.wSecond, 0..=59)?;
+    check(time.wMilliseconds, 0..=999)
+}
+
+/// Convert the system time of an image to a [`SystemTime`].
+///
+/// The driver reports the time in the local time zone of the PC, which lies `utc_offset` seconds
+/// east of UTC, e.g. `3600` for UTC+1.
+///
+/// Fails with [`Error::UnexpectedValue`] if a field of `time` is out of its range, e.g. for an
+/// image without system time, or if the time is out of the range of [`SystemTime`].
+pub fn system_time(time: &UEYETIME, utc_offset: i32) -> Result<SystemTime> {
+    validate(time)?;
+
+    let days = days_from_civil(time.wYear as i64, time.wMonth as i64, time.wDay as i64);
+    let seconds = days * SECONDS_PER_DAY
+        + time.wHour as i64 * 3600
+        + time.wMinute as i64 * 60
+        + time.wSecond as i64
+        - utc_offset as i64;
+    let whole_seconds = Duration::from_secs(seconds.unsigned_abs());
+    let second = match seconds >= 0 {
+        true => UNIX_EPOCH.checked_add(whole_seconds),
+        false => UNIX_EPOCH.checked_sub(whole_seconds),
+    };
+
+    second
+        .and_then(|second| second.checked_add(Duration::from_millis(time.wMilliseconds as u64)))
+        .ok_or(Error::UnexpectedValue(time.wYear as INT))
+}
+
+/// Convert the system time of an image to a [`chrono::DateTime`] in the local time zone.
+///
+/// Fails with [`Error::UnexpectedValue`] if a field of `time` is out of its range, and with
+/// [`Error::InvalidParameter`] if the time does not exist in the local time zone, e.g. within a
+/// daylight saving time gap.
+#[cfg(feature = "chrono")]
+pub fn local_datetime(time: &UEYETIME) -> Result<chrono::DateTime<chrono::Local>> {
+    use chrono::{NaiveDate, TimeZone};
+
+    validate(time)?;
+
+    let naive = NaiveDate::from_ymd_opt(time.wYear as i32, time.wMonth as u32, time.wDay as u32)
+        .and_then(|date| {
+            date.and_hms_milli_opt(
+                time.wHour as u32,
+                time.wMinute as u32,
+                time.wSecond as u32,
+                time.wMilliseconds as u32,
+            )
+        })
+        .ok_or(Error::UnexpectedValue(time.wDay as INT))?;
+
+    chrono::Local.from_local_datetime(&naive).earliest().ok_or(Error::InvalidParameter)
+}
+
+/// Device clock of a camera, related to the host clock.
+///
+/// The device clock drifts against the host clock and is reset along with the camera, so the
+/// relation holds only for a while and should be estimated again periodically.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceClock {
+    origin: SystemTime,
+}
+
+impl DeviceClock {
+    /// Device clock whose tick `0` occurred at host time `origin`.
+    #[inline]
+    pub const fn new(origin: SystemTime) -> Self {
+        Self { origin }
+    }
+
+    /// Host time of device tick `0`.
+    #[inline]
+    pub const fn origin(&self) -> SystemTime {
+        self.origin
+    }
+
+    /// Host time of the device timestamp `ticks`.
+    #[inline]
+    pub fn system_time(&self, ticks: u64) -> SystemTime {
+        self.origin + ticks_to_duration(ticks)
+    }
+
+    /// Host time of the device timestamp `ticks`, as a [`chrono::DateTime`] in UTC.
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn datetime(&self, ticks: u64) -> chrono::DateTime<chrono::Utc> {
+        self.system_time(ticks).into()
+    }
+}
+
+/// Estimator of the [`DeviceClock`] of a camera.
+///
+/// Each sample pairs the device timestamp of an image with the host time at which the image was
+/// received, which lags the timestamp by the transfer and processing latency. The estimate keeps
+/// the sample with the lowest latency, i.e. the earliest origin; the more samples, the closer it
+/// gets to the actual origin.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OffsetEstimator {
+    origin: Option<SystemTime>,
+    samples: usize,
+}
+
+impl OffsetEstimator {
+    /// Create an estimator without samples.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { origin: None, samples: 0 }
+    }
+
+    /// Record an image with device timestamp `ticks`, received at host time `received`.
+    pub fn record(&mut self, ticks: u64, received: SystemTime) {
+        let Some(origin) = received.checked_sub(ticks_to_duration(ticks)) else {
+            return;
+        };
+
+        self.origin = Some(self.origin.map_or(origin, |earliest| earliest.min(origin)));
+        self.samples += 1;
+    }
+
+    /// Record the image in the image memory `mem_id`, received just now.
+    ///
+    /// Call it as soon as the image is received, e.g. right after waiting for it.
+    pub fn record_image(&mut self, camera: &Camera, mem_id: INT) -> Result<()> {
+        let received = SystemTime::now();
+        self.record(image_info::device_timestamp(camera, mem_id)?, received);
+        Ok(())
+    }
+
+    /// Number of recorded samples.
+    #[inline]
+    pub const fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Discard all recorded samples, e.g. after the camera was reset.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Estimated device clock, if any sample was recorded.
+    #[inline]
+    pub fn clock(&self) -> Option<DeviceClock> {
+        self.origin.map(DeviceClock::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calendar time of the given date and time of day.
+    fn time(year: u16, month: u16, day: u16, hms: (u16, u16, u16), millis: u16) -> UEYETIME {
+        let mut time = UEYETIME::default();
+        time.wYear = year;
+        time.wMonth = month;
+        time.wDay = day;
+        (time.wHour, time.wMinute, time.wSecond) = hms;
+        time.wMilliseconds = millis;
+        time
+    }
+
+    #[test]
+    fn epoch() {
+        assert_eq!(system_time(&time(1970, 1, 1, (0, 0, 0), 0), 0), Ok(UNIX_EPOCH));
+    }
+
+    #[test]
+    fn before_epoch() {
+        let time = time(1969, 12, 31, (23, 59, 59), 500);
+        assert_eq!(system_time(&time, 0), Ok(UNIX_EPOCH - Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn leap_days() {
+        let leap = time(2024, 2, 29, (0, 0, 0), 0);
+        assert_eq!(system_time(&leap, 0), Ok(UNIX_EPOCH + Duration::from_secs(1_709_164_800)));
+
+        let century = time(2000, 2, 29, (0, 0, 0), 0);
+        assert_eq!(system_time(&century, 0), Ok(UNIX_EPOCH + Duration::from_secs(951_782_400)));
+    }
+
+    #[test]
+    fn rejects_missing_leap_days() {
+        let common = time(2023, 2, 29, (0, 0, 0), 0);
+        assert_eq!(system_time(&common, 0), Err(Error::UnexpectedValue(29)));
+
+        let century = time(1900, 2, 29, (0, 0, 0), 0);
+        assert_eq!(system_time(&century, 0), Err(Error::UnexpectedValue(29)));
+    }
+
+    #[test]
+    fn rejects_31_april() {
+        assert_eq!(
+            system_time(&time(2024, 4, 31, (0, 0, 0), 0), 0),
+            Err(Error::UnexpectedValue(31))
+        );
+    }
+
+    #[test]
+    fn west_of_utc() {
+        // midnight in UTC-5 is 5 AM in UTC
+        let time = time(1970, 1, 1, (0, 0, 0), 0);
+        assert_eq!(system_time(&time, -5 * 3600), Ok(UNIX_EPOCH + Duration::from_secs(5 * 3600)));
+    }
+}
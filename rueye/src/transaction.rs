@@ -0,0 +1,108 @@
+//! All-or-nothing changes of camera settings.
+//!
+//! [`Camera::transaction`] runs a closure with a [`Transaction`], whose setters snapshot the
+//! current value of a setting before changing it. If the closure fails, the snapshots are restored
+//! in reverse order, so the camera is never left with a half-applied configuration.
+
+use ueye_sys::types::INT;
+
+use crate::{
+    aoi::{self, Aoi},
+    camera::Camera,
+    error::Result,
+    exposure, gain,
+    trigger::TriggerMode,
+};
+
+/// Undo action of an applied setting.
+type Undo<'c> = Box<dyn FnOnce(&Camera) -> Result<()> + 'c>;
+
+/// Settings changed within [`Camera::transaction`].
+pub struct Transaction<'c> {
+    camera: &'c Camera,
+    undo: Vec<Undo<'c>>,
+}
+
+impl std::fmt::Debug for Transaction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction").field("applied", &self.undo.len()).finish_non_exhaustive()
+    }
+}
+
+impl Camera {
+    /// Apply the settings changed by `f` as a whole.
+    ///
+    /// If `f` fails, every setting changed through the [`Transaction`] is restored to its value
+    /// before the transaction, and the error of `f` is returned. Errors while restoring are
+    /// ignored, so that the original cause is reported.
+    pub fn transaction<T>(&self, f: impl FnOnce(&mut Transaction<'_>) -> Result<T>) -> Result<T> {
+        let mut transaction = Transaction { camera: self, undo: Vec::new() };
+
+        f(&mut transaction).inspect_err(|_| transaction.rollback())
+    }
+}
+
+impl<'c> Transaction<'c> {
+    /// Camera the settings are applied to.
+    #[inline]
+    pub fn camera(&self) -> &'c Camera {
+        self.camera
+    }
+
+    /// Apply a setting, snapshotting its current value first.
+    ///
+    /// # Input parameters
+    /// * `get` - Query the current value of the setting.
+    /// * `set` - Apply a value of the setting, used for both `value` and the rollback.
+    /// * `value` - New value of the setting.
+    pub fn apply<V, R>(
+        &mut self,
+        get: impl FnOnce(&Camera) -> Result<V>,
+        set: impl Fn(&Camera, V) -> Result<R> + 'c,
+        value: V,
+    ) -> Result<R>
+    where
+        V: 'c,
+    {
+        let previous = get(self.camera)?;
+        let result = set(self.camera, value)?;
+        self.undo.push(Box::new(move |camera| set(camera, previous).map(drop)));
+
+        Ok(result)
+    }
+
+    /// Set the exposure time (in ms), returning the exposure time actually set.
+    #[inline]
+    pub fn set_exposure(&mut self, ms: f64) -> Result<f64> {
+        self.apply(exposure::current, exposure::set, ms)
+    }
+
+    /// Set the master gain (`0`…`100`).
+    #[inline]
+    pub fn set_gain(&mut self, master: INT) -> Result<()> {
+        self.apply(gain::current, gain::set, master)
+    }
+
+    /// Set the image AOI.
+    #[inline]
+    pub fn set_aoi(&mut self, aoi: Aoi) -> Result<()> {
+        self.apply(
+            |camera| aoi::current(camera).map(Aoi::from),
+            |camera, aoi| aoi::set(camera, &aoi),
+            aoi,
+        )
+    }
+
+    /// Set the trigger mode.
+    #[inline]
+    pub fn set_trigger(&mut self, mode: TriggerMode) -> Result<()> {
+        self.apply(TriggerMode::current, |camera, mode| mode.apply(camera), mode)
+    }
+
+    /// Restore the snapshots of all applied settings, most recent first.
+    fn rollback(&mut self) {
+        while let Some(undo) = self.undo.pop() {
+            let _ = undo(self.camera);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Tuning of the image transfer.
+//!
+//! Several cameras triggered at once share the bandwidth of their connection. Delaying the
+//! transfer of each image, or spacing out its packets, spreads the load, so that no camera loses
+//! images on a saturated link. Cameras with an image memory of their own can also keep images
+//! there until they are requested, instead of transferring them right away.
+//!
+//! A [`TransferConfig`] holds these settings; each is [`None`] for cameras that do not support
+//! it, as reported by [`TransferCapabilities`]. Pairing of GigE cameras with this PC is set up
+//! system-wide with [`set_trusted_pairing`].
+//!
+//! # Documentation
+//! * [is_Transfer](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_transfer.html)
+//! * [is_Configuration: Trusted pairing](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_configurationtrustedpairing.html)
+
+use std::{
+    fmt::{Display, Formatter},
+    mem::size_of,
+};
+
+use ueye_sys::{
+    configuration::{CONFIGURATION_CMD, CONFIGURATION_SEL_TRUSTED_PAIRING},
+    transfer::{is_Transfer, TRANSFER_CAPABILITY_FLAGS, TRANSFER_CMD, TRANSFER_TARGET},
+    types::{void, INT, RANGE_OF_VALUES_U32, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    configuration,
+    error::{check, Error, Result},
+    observer::command,
+    range::ParamRange,
+};
+
+fn transfer<T>(camera: &Camera, cmd: TRANSFER_CMD, param: &mut T) -> Result<()> {
+    check(unsafe {
+        command!(is_Transfer(
+            camera.handle(),
+            cmd,
+            param as *mut T as *mut void,
+            size_of::<T>() as UINT,
+        ))
+    })
+}
+
+fn get(camera: &Camera, cmd: TRANSFER_CMD) -> Result<u32> {
+    let mut value: UINT = 0;
+    transfer(camera, cmd, &mut value)?;
+    Ok(value)
+}
+
+fn get_range(camera: &Camera, cmd: TRANSFER_CMD) -> Result<ParamRange<u32>> {
+    // SAFETY: RANGE_OF_VALUES_U32 is plain data, for which all-zero is a valid value.
+    let mut range: RANGE_OF_VALUES_U32 = unsafe { std::mem::zeroed() };
+    transfer(camera, cmd, &mut range)?;
+    Ok(range.into())
+}
+
+/// Memory the camera transfers its images to.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageDestination {
+    /// The image memory of the camera, keeping images until they are requested.
+    DeviceMemory,
+
+    /// The image memories of the PC (_default_).
+    #[default]
+    UserMemory,
+}
+
+impl ImageDestination {
+    /// Every image destination.
+    pub const ALL: [ImageDestination; 2] =
+        [ImageDestination::DeviceMemory, ImageDestination::UserMemory];
+
+    /// Bits of the destination for [`is_Transfer`].
+    pub const fn bits(self) -> TRANSFER_TARGET {
+        match self {
+            ImageDestination::DeviceMemory => {
+                TRANSFER_TARGET::IS_TRANSFER_DESTINATION_DEVICE_MEMORY
+            },
+            ImageDestination::UserMemory => TRANSFER_TARGET::IS_TRANSFER_DESTINATION_USER_MEMORY,
+        }
+    }
+}
+
+impl TryFrom<TRANSFER_TARGET> for ImageDestination {
+    type Error = Error;
+
+    fn try_from(target: TRANSFER_TARGET) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|destination| destination.bits() == target)
+            .ok_or(Error::UnexpectedValue(target.bits() as INT))
+    }
+}
+
+impl Display for ImageDestination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ImageDestination::DeviceMemory => "device memory",
+            ImageDestination::UserMemory => "user memory",
+        })
+    }
+}
+
+/// Transfer settings supported by a camera.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferCapabilities {
+    /// Whether the transfer of each image can be delayed.
+    pub image_delay: bool,
+
+    /// Whether the interval between two packets can be set.
+    pub packet_interval: bool,
+
+    /// Supported image destinations.
+    pub destinations: Vec<ImageDestination>,
+}
+
+impl TransferCapabilities {
+    /// Query the transfer settings supported by `camera`.
+    ///
+    /// Cameras without transfer settings support none of them.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        let flags = match get(camera, TRANSFER_CMD::TRANSFER_CMD_QUERY_CAPABILITIES) {
+            Ok(flags) => TRANSFER_CAPABILITY_FLAGS::from_bits_retain(flags),
+            Err(Error::NotSupported) => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+
+        let destinations =
+            match get(camera, TRANSFER_CMD::TRANSFER_CMD_GET_IMAGE_DESTINATION_CAPABILITIES) {
+                Ok(targets) => ImageDestination::ALL
+                    .into_iter()
+                    .filter(|destination| {
+                        TRANSFER_TARGET::from_bits_retain(targets).contains(destination.bits())
+                    })
+                    .collect(),
+                Err(Error::NotSupported) => Vec::new(),
+                Err(error) => return Err(error),
+            };
+
+        Ok(Self {
+            image_delay: flags.contains(TRANSFER_CAPABILITY_FLAGS::TRANSFER_CAP_IMAGEDELAY),
+            packet_interval: flags.contains(TRANSFER_CAPABILITY_FLAGS::TRANSFER_CAP_PACKETINTERVAL),
+            destinations,
+        })
+    }
+}
+
+/// Query the delay of the image transfer after the sensor begins the readout (in µs).
+#[inline]
+pub fn image_delay(camera: &Camera) -> Result<u32> {
+    get(camera, TRANSFER_CMD::TRANSFER_CMD_GET_IMAGEDELAY_US)
+}
+
+/// Query the range of the image transfer delay (in µs).
+#[inline]
+pub fn image_delay_range(camera: &Camera) -> Result<ParamRange<u32>> {
+    get_range(camera, TRANSFER_CMD::TRANSFER_CMD_GETRANGE_IMAGEDELAY_US)
+}
+
+/// Set the delay of the image transfer after the sensor begins the readout (in µs).
+///
+/// Fails with [`Error::InvalidParameter`] if `us` is not [within](ParamRange::contains) the
+/// [range](image_delay_range).
+pub fn set_image_delay(camera: &Camera, us: u32) -> Result<()> {
+    if !image_delay_range(camera)?.contains(us) {
+        return Err(Error::InvalidParameter);
+    }
+
+    transfer(camera, TRANSFER_CMD::TRANSFER_CMD_SET_IMAGEDELAY_US, &mut { us })
+}
+
+/// Query the interval between two packets of an image (in µs).
+#[inline]
+pub fn packet_interval(camera: &Camera) -> Result<u32> {
+    get(camera, TRANSFER_CMD::TRANSFER_CMD_GET_PACKETINTERVAL_US)
+}
+
+/// Query the range of the packet interval (in µs).
+#[inline]
+pub fn packet_interval_range(camera: &Camera) -> Result<ParamRange<u32>> {
+    get_range(camera, TRANSFER_CMD::TRANSFER_CMD_GETRANGE_PACKETINTERVAL_US)
+}
+
+/// Set the interval between two packets of an image (in µs).
+///
+/// Higher intervals reduce the transfer speed considerably; around 20 µs is usual on Gigabit
+/// Ethernet. Fails with [`Error::InvalidParameter`] if `us` is not [within](ParamRange::contains)
+/// the [range](packet_interval_range).
+pub fn set_packet_interval(camera: &Camera, us: u32) -> Result<()> {
+    if !packet_interval_range(camera)?.contains(us) {
+        return Err(Error::InvalidParameter);
+    }
+
+    transfer(camera, TRANSFER_CMD::TRANSFER_CMD_SET_PACKETINTERVAL_US, &mut { us })
+}
+
+/// Query the memory the camera transfers its images to.
+pub fn image_destination(camera: &Camera) -> Result<ImageDestination> {
+    let mut target = TRANSFER_TARGET::empty();
+    transfer(camera, TRANSFER_CMD::TRANSFER_CMD_GET_IMAGE_DESTINATION, &mut target)?;
+    target.try_into()
+}
+
+/// Set the memory the camera transfers its images to.
+#[inline]
+pub fn set_image_destination(camera: &Camera, destination: ImageDestination) -> Result<()> {
+    transfer(camera, TRANSFER_CMD::TRANSFER_CMD_SET_IMAGE_DESTINATION, &mut destination.bits())
+}
+
+/// Transfer settings of a camera.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferConfig {
+    /// Delay of the image transfer after the sensor begins the readout (in µs).
+    pub image_delay_us: Option<u32>,
+
+    /// Interval between two packets of an image (in µs).
+    pub packet_interval_us: Option<u32>,
+
+    /// Memory the camera transfers its images to.
+    pub destination: Option<ImageDestination>,
+}
+
+impl TransferConfig {
+    /// Query the transfer settings of `camera`, leaving those it does not support as [`None`].
+    pub fn query(camera: &Camera) -> Result<Self> {
+        let capabilities = TransferCapabilities::query(camera)?;
+        Ok(Self {
+            image_delay_us: capabilities.image_delay.then(|| image_delay(camera)).transpose()?,
+            packet_interval_us: capabilities
+                .packet_interval
+                .then(|| packet_interval(camera))
+                .transpose()?,
+            destination: (!capabilities.destinations.is_empty())
+                .then(|| image_destination(camera))
+                .transpose()?,
+        })
+    }
+
+    /// Apply the settings other than [`None`] to `camera`, stopping at the first failure.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if a value is out of its range.
+    pub fn apply(&self, camera: &Camera) -> Result<()> {
+        if let Some(us) = self.image_delay_us {
+            set_image_delay(camera, us)?;
+        }
+
+        if let Some(us) = self.packet_interval_us {
+            set_packet_interval(camera, us)?;
+        }
+
+        match self.destination {
+            Some(destination) => set_image_destination(camera, destination),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Query whether GigE cameras stay paired with this PC although their heartbeat times out, e.g.
+/// while debugging.
+///
+/// Fails with [`Error::NotSupported`] if the driver does not support trusted pairing.
+pub fn trusted_pairing() -> Result<bool> {
+    configuration::require(|caps| caps.trusted_pairing)?;
+    configuration::get_switch(CONFIGURATION_CMD::IS_CONFIG_CMD_TRUSTED_PAIRING_GET)
+}
+
+/// Query whether trusted pairing is enabled by default.
+///
+/// Fails with [`Error::NotSupported`] if the driver does not support trusted pairing.
+pub fn default_trusted_pairing() -> Result<bool> {
+    configuration::require(|caps| caps.trusted_pairing)?;
+    configuration::get_switch(CONFIGURATION_CMD::IS_CONFIG_CMD_TRUSTED_PAIRING_GET_DEFAULT)
+}
+
+/// Enable or disable trusted pairing of GigE cameras opened afterwards on this PC.
+///
+/// Fails with [`Error::NotSupported`] if the driver does not support trusted pairing.
+pub fn set_trusted_pairing(enable: bool) -> Result<()> {
+    configuration::require(|caps| caps.trusted_pairing)?;
+    configuration::configuration(
+        CONFIGURATION_CMD::IS_CONFIG_CMD_TRUSTED_PAIRING_SET,
+        &mut CONFIGURATION_SEL_TRUSTED_PAIRING::from(enable),
+    )
+}
@@ -0,0 +1,92 @@
+//! Trigger mode selection.
+//!
+//! # Documentation
+//! [is_SetExternalTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setexternaltrigger.html)
+
+use ueye_sys::{
+    external_trigger::{
+        is_ForceTrigger, is_SetExternalTrigger, IS_GET_EXTERNALTRIGGER, IS_SET_TRIGGER_HI_LO,
+        IS_SET_TRIGGER_LO_HI, IS_SET_TRIGGER_OFF, IS_SET_TRIGGER_PRE_HI_LO,
+        IS_SET_TRIGGER_PRE_LO_HI, IS_SET_TRIGGER_SOFTWARE,
+    },
+    types::INT,
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::call,
+};
+
+/// Trigger modes for [`is_SetExternalTrigger`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerMode {
+    /// Trigger disabled (_freerun_).
+    #[default]
+    Off,
+
+    /// Hardware trigger on falling signal edge.
+    FallingEdge,
+
+    /// Hardware trigger on rising signal edge.
+    RisingEdge,
+
+    /// Pre-trigger on falling signal edge.
+    PreFallingEdge,
+
+    /// Pre-trigger on rising signal edge.
+    PreRisingEdge,
+
+    /// Software trigger; [`is_FreezeVideo`][ueye_sys::video::is_FreezeVideo] captures
+    /// immediately.
+    Software,
+}
+
+impl TriggerMode {
+    /// Value passed to [`is_SetExternalTrigger`] for this mode.
+    #[inline]
+    pub const fn bits(self) -> INT {
+        match self {
+            TriggerMode::Off => IS_SET_TRIGGER_OFF,
+            TriggerMode::FallingEdge => IS_SET_TRIGGER_HI_LO,
+            TriggerMode::RisingEdge => IS_SET_TRIGGER_LO_HI,
+            TriggerMode::PreFallingEdge => IS_SET_TRIGGER_PRE_HI_LO,
+            TriggerMode::PreRisingEdge => IS_SET_TRIGGER_PRE_LO_HI,
+            TriggerMode::Software => IS_SET_TRIGGER_SOFTWARE,
+        }
+    }
+
+    /// Query the trigger mode currently set on the camera.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        Self::try_from(unsafe {
+            call!(is_SetExternalTrigger(camera.handle(), IS_GET_EXTERNALTRIGGER))
+        })
+    }
+
+    /// Apply this trigger mode to the camera.
+    pub fn apply(self, camera: &Camera) -> Result<()> {
+        check(unsafe { call!(is_SetExternalTrigger(camera.handle(), self.bits())) })
+    }
+}
+
+impl TryFrom<INT> for TriggerMode {
+    type Error = Error;
+
+    fn try_from(value: INT) -> Result<Self> {
+        match value {
+            IS_SET_TRIGGER_OFF => Ok(TriggerMode::Off),
+            IS_SET_TRIGGER_HI_LO => Ok(TriggerMode::FallingEdge),
+            IS_SET_TRIGGER_LO_HI => Ok(TriggerMode::RisingEdge),
+            IS_SET_TRIGGER_PRE_HI_LO => Ok(TriggerMode::PreFallingEdge),
+            IS_SET_TRIGGER_PRE_LO_HI => Ok(TriggerMode::PreRisingEdge),
+            IS_SET_TRIGGER_SOFTWARE => Ok(TriggerMode::Software),
+            value => Err(Error::UnexpectedValue(value)),
+        }
+    }
+}
+
+/// Force a trigger during a hardware triggered image capture.
+pub fn force(camera: &Camera) -> Result<()> {
+    check(unsafe { call!(is_ForceTrigger(camera.handle())) })
+}
@@ -0,0 +1,233 @@
+//! Trigger input debouncing.
+//!
+//! A signal at the trigger input only triggers a capture once it stayed at the target level for
+//! the debounce delay; shorter pulses are ignored as disturbances. [`TriggerDebounce`] describes
+//! the mode and delay, and clamps the delay to the range supported by the camera when applied.
+//!
+//! # Documentation
+//! [is_TriggerDebounce](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_triggerdebounce.html)
+
+use std::{mem::size_of, time::Duration};
+
+use ueye_sys::{
+    trigger_debounce::{is_TriggerDebounce, TRIGGER_DEBOUNCE_CMD, TRIGGER_DEBOUNCE_MODE},
+    types::{void, INT, UINT},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Debounce modes for [`is_TriggerDebounce`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DebounceMode {
+    /// Debouncing disabled.
+    None,
+
+    /// Debounce falling signal edges.
+    FallingEdge,
+
+    /// Debounce rising signal edges.
+    RisingEdge,
+
+    /// Debounce both signal edges.
+    BothEdges,
+
+    /// Debounce the edge of the [trigger mode](crate::trigger::TriggerMode) (_recommended_).
+    #[default]
+    Automatic,
+}
+
+impl DebounceMode {
+    /// All debounce modes.
+    pub const ALL: [DebounceMode; 5] = [
+        DebounceMode::None,
+        DebounceMode::FallingEdge,
+        DebounceMode::RisingEdge,
+        DebounceMode::BothEdges,
+        DebounceMode::Automatic,
+    ];
+
+    /// Bits of the mode for [`is_TriggerDebounce`].
+    pub const fn bits(self) -> TRIGGER_DEBOUNCE_MODE {
+        match self {
+            DebounceMode::None => TRIGGER_DEBOUNCE_MODE::TRIGGER_DEBOUNCE_MODE_NONE,
+            DebounceMode::FallingEdge => TRIGGER_DEBOUNCE_MODE::TRIGGER_DEBOUNCE_MODE_FALLING_EDGE,
+            DebounceMode::RisingEdge => TRIGGER_DEBOUNCE_MODE::TRIGGER_DEBOUNCE_MODE_RISING_EDGE,
+            DebounceMode::BothEdges => TRIGGER_DEBOUNCE_MODE::TRIGGER_DEBOUNCE_MODE_BOTH_EDGES,
+            DebounceMode::Automatic => TRIGGER_DEBOUNCE_MODE::TRIGGER_DEBOUNCE_MODE_AUTOMATIC,
+        }
+    }
+
+    /// Query the debounce modes supported by the camera.
+    pub fn supported(camera: &Camera) -> Result<Vec<DebounceMode>> {
+        let modes = TRIGGER_DEBOUNCE_MODE::from_bits_retain(get(
+            camera,
+            TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_SUPPORTED_MODES,
+        )?);
+
+        // `None` has no bit of its own and is always supported
+        Ok(Self::ALL.into_iter().filter(|mode| modes.contains(mode.bits())).collect())
+    }
+}
+
+impl TryFrom<TRIGGER_DEBOUNCE_MODE> for DebounceMode {
+    type Error = Error;
+
+    fn try_from(mode: TRIGGER_DEBOUNCE_MODE) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.bits() == mode)
+            .ok_or(Error::UnexpectedValue(mode.bits() as INT))
+    }
+}
+
+/// Supported range of the debounce delay.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DebounceRange {
+    /// Shortest delay.
+    pub min: Duration,
+
+    /// Longest delay.
+    pub max: Duration,
+
+    /// Step between two delays.
+    pub increment: Duration,
+}
+
+impl DebounceRange {
+    /// Query the delay range of the camera.
+    pub fn query(camera: &Camera) -> Result<Self> {
+        Ok(Self {
+            min: micros(get(
+                camera,
+                TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME_MIN,
+            )?),
+            max: micros(get(
+                camera,
+                TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME_MAX,
+            )?),
+            increment: micros(get(
+                camera,
+                TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME_INC,
+            )?),
+        })
+    }
+
+    /// Clamp `delay` into the range and round it down to a whole increment above the minimum.
+    pub fn clamp(&self, delay: Duration) -> Duration {
+        let delay = delay.clamp(self.min, self.max);
+        if self.increment.is_zero() {
+            return delay;
+        }
+
+        let steps = (delay - self.min).as_micros() / self.increment.as_micros();
+        self.min + self.increment * steps as u32
+    }
+}
+
+/// Debounce configuration of the trigger input.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerDebounce {
+    /// Debounce mode.
+    pub mode: DebounceMode,
+
+    /// Time the signal must stay at the target level.
+    pub delay: Duration,
+}
+
+impl TriggerDebounce {
+    /// Debounce in `mode`, with no delay.
+    #[inline]
+    pub const fn new(mode: DebounceMode) -> Self {
+        Self { mode, delay: Duration::ZERO }
+    }
+
+    /// Set the debounce delay.
+    #[inline]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Query the debounce configuration of the camera.
+    pub fn current(camera: &Camera) -> Result<Self> {
+        let mode = TRIGGER_DEBOUNCE_MODE::from_bits_retain(get(
+            camera,
+            TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_MODE,
+        )?);
+
+        Ok(Self {
+            mode: mode.try_into()?,
+            delay: micros(get(camera, TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME)?),
+        })
+    }
+
+    /// Query the default debounce configuration of the camera.
+    pub fn default_for(camera: &Camera) -> Result<Self> {
+        let mode = TRIGGER_DEBOUNCE_MODE::from_bits_retain(get(
+            camera,
+            TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_MODE_DEFAULT,
+        )?);
+
+        Ok(Self {
+            mode: mode.try_into()?,
+            delay: micros(get(
+                camera,
+                TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME_DEFAULT,
+            )?),
+        })
+    }
+
+    /// Apply the configuration to `camera`, returning it with the delay clamped to the
+    /// [range](DebounceRange) of the camera.
+    ///
+    /// The delay is left unchanged for [`DebounceMode::None`].
+    pub fn apply(self, camera: &Camera) -> Result<Self> {
+        set(camera, TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_SET_MODE, self.mode.bits().bits())?;
+        if self.mode == DebounceMode::None {
+            return Ok(self);
+        }
+
+        let delay = DebounceRange::query(camera)?.clamp(self.delay);
+        let us = UINT::try_from(delay.as_micros()).unwrap_or(UINT::MAX);
+        set(camera, TRIGGER_DEBOUNCE_CMD::TRIGGER_DEBOUNCE_CMD_SET_DELAY_TIME, us)?;
+
+        Ok(Self { delay, ..self })
+    }
+}
+
+#[inline]
+const fn micros(us: UINT) -> Duration {
+    Duration::from_micros(us as u64)
+}
+
+fn get(camera: &Camera, cmd: TRIGGER_DEBOUNCE_CMD) -> Result<UINT> {
+    let mut value: UINT = 0;
+    check(unsafe {
+        command!(is_TriggerDebounce(
+            camera.handle(),
+            cmd,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })?;
+
+    Ok(value)
+}
+
+fn set(camera: &Camera, cmd: TRIGGER_DEBOUNCE_CMD, value: UINT) -> Result<()> {
+    let mut value = value;
+    check(unsafe {
+        command!(is_TriggerDebounce(
+            camera.handle(),
+            cmd,
+            &mut value as *mut UINT as *mut void,
+            size_of::<UINT>() as UINT,
+        ))
+    })
+}
@@ -0,0 +1,225 @@
+//! Publishing frames to V4L2 loopback devices on Linux.
+//!
+//! A [`LoopbackPublisher`] writes frames into the output side of a [v4l2loopback] device, whose
+//! capture side can then be opened by any V4L2 consumer, such as browsers for WebRTC. The format
+//! of the device is negotiated when the publisher is opened; the device may pick a line increment
+//! of its own, which the frames are repacked to.
+//!
+//! [v4l2loopback]: https://github.com/umlaeute/v4l2loopback
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    mem::size_of,
+    os::fd::AsRawFd,
+    path::Path,
+};
+
+use libc::{c_ulong, c_void};
+
+use crate::{
+    color_mode::ColorMode,
+    error::{Error, Result},
+    frame::Frame,
+};
+
+/// `V4L2_BUF_TYPE_VIDEO_OUTPUT`.
+const BUF_TYPE_VIDEO_OUTPUT: u32 = 2;
+
+/// `V4L2_FIELD_NONE`.
+const FIELD_NONE: u32 = 1;
+
+/// `V4L2_COLORSPACE_SRGB`.
+const COLORSPACE_SRGB: u32 = 8;
+
+/// `V4L2_CAP_VIDEO_OUTPUT`.
+const CAP_VIDEO_OUTPUT: u32 = 0x0000_0002;
+
+/// `V4L2_CAP_DEVICE_CAPS`.
+const CAP_DEVICE_CAPS: u32 = 0x8000_0000;
+
+/// `struct v4l2_capability`.
+#[repr(C)]
+#[derive(Default)]
+struct Capability {
+    driver: [u8; 16],
+    card: [u8; 32],
+    bus_info: [u8; 32],
+    version: u32,
+    capabilities: u32,
+    device_caps: u32,
+    reserved: [u32; 3],
+}
+
+/// `struct v4l2_pix_format`.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct PixFormat {
+    width: u32,
+    height: u32,
+    pixelformat: u32,
+    field: u32,
+    bytesperline: u32,
+    sizeimage: u32,
+    colorspace: u32,
+    private: u32,
+    flags: u32,
+    ycbcr_enc: u32,
+    quantization: u32,
+    xfer_func: u32,
+}
+
+/// `struct v4l2_format`, with the `fmt` union reduced to its single-planar member.
+#[repr(C)]
+struct Format {
+    kind: u32,
+    fmt: FormatUnion,
+}
+
+/// `fmt` union of `struct v4l2_format`, 200 bytes aligned for pointers.
+#[repr(C)]
+union FormatUnion {
+    pix: PixFormat,
+    raw_data: [u8; 200],
+    _align: [*mut c_void; 0],
+}
+
+/// Encode an ioctl request number of the `'V'` type, as `_IOC` does.
+const fn ioc(dir: c_ulong, nr: c_ulong, size: usize) -> c_ulong {
+    (dir << 30) | ((size as c_ulong) << 16) | ((b'V' as c_ulong) << 8) | nr
+}
+
+/// `VIDIOC_QUERYCAP`.
+const VIDIOC_QUERYCAP: c_ulong = ioc(2, 0, size_of::<Capability>());
+
+/// `VIDIOC_S_FMT`.
+const VIDIOC_S_FMT: c_ulong = ioc(3, 5, size_of::<Format>());
+
+fn ioctl<T>(file: &File, request: c_ulong, arg: &mut T) -> Result<()> {
+    match unsafe { libc::ioctl(file.as_raw_fd(), request as _, arg as *mut T) } {
+        -1 => Err(std::io::Error::last_os_error().into()),
+        _ => Ok(()),
+    }
+}
+
+/// Build a V4L2 four-character code.
+const fn fourcc(code: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*code)
+}
+
+/// V4L2 pixel format of frames of `mode`, or [`None`] if V4L2 has no matching format.
+pub const fn pixel_format(mode: ColorMode) -> Option<u32> {
+    match mode {
+        ColorMode::Mono8 => Some(fourcc(b"GREY")),
+        ColorMode::Mono10 => Some(fourcc(b"Y10 ")),
+        ColorMode::Mono12 => Some(fourcc(b"Y12 ")),
+        ColorMode::Mono16 => Some(fourcc(b"Y16 ")),
+        ColorMode::Bgr5Packed => Some(fourcc(b"RGBO")),
+        ColorMode::Bgr565Packed => Some(fourcc(b"RGBP")),
+        ColorMode::Rgb8Packed => Some(fourcc(b"RGB3")),
+        ColorMode::Bgr8Packed => Some(fourcc(b"BGR3")),
+        ColorMode::Rgba8Packed => Some(fourcc(b"AB24")),
+        ColorMode::Bgra8Packed => Some(fourcc(b"AR24")),
+        ColorMode::UyvyPacked | ColorMode::CbYCrYPacked => Some(fourcc(b"UYVY")),
+        _ => None,
+    }
+}
+
+/// Publisher of frames to a V4L2 loopback device.
+#[derive(Debug)]
+pub struct LoopbackPublisher {
+    device: File,
+    color_mode: ColorMode,
+    width: usize,
+    height: usize,
+    pitch: usize,
+    buffer: Vec<u8>,
+}
+
+impl LoopbackPublisher {
+    /// Open the loopback device at `path`, e.g. `/dev/video10`, for frames of `mode` and size
+    /// `width` × `height`.
+    ///
+    /// Fails with [`Error::InvalidColorFormat`] if V4L2 has no format matching `mode` or the
+    /// device rejects it, with [`Error::InvalidParameter`] if the device rejects the size, with
+    /// [`Error::NotSupported`] if the device takes no output, and with [`Error::Io`] if it cannot
+    /// be opened.
+    pub fn open(path: impl AsRef<Path>, mode: ColorMode, width: u32, height: u32) -> Result<Self> {
+        let pixelformat = pixel_format(mode).ok_or(Error::InvalidColorFormat)?;
+        let device = OpenOptions::new().write(true).open(path)?;
+
+        let mut capability = Capability::default();
+        ioctl(&device, VIDIOC_QUERYCAP, &mut capability)?;
+        let capabilities = match capability.capabilities & CAP_DEVICE_CAPS {
+            0 => capability.capabilities,
+            _ => capability.device_caps,
+        };
+        if capabilities & CAP_VIDEO_OUTPUT == 0 {
+            return Err(Error::NotSupported);
+        }
+
+        let mut format = Format {
+            kind: BUF_TYPE_VIDEO_OUTPUT,
+            fmt: FormatUnion {
+                pix: PixFormat {
+                    width,
+                    height,
+                    pixelformat,
+                    field: FIELD_NONE,
+                    bytesperline: mode.line_bytes(width as usize) as u32,
+                    sizeimage: (mode.line_bytes(width as usize) * height as usize) as u32,
+                    colorspace: COLORSPACE_SRGB,
+                    ..Default::default()
+                },
+            },
+        };
+        ioctl(&device, VIDIOC_S_FMT, &mut format)?;
+
+        // the device may adjust the format it was asked for
+        // SAFETY: `VIDIOC_S_FMT` fills the single-planar member for output buffers.
+        let pix = unsafe { format.fmt.pix };
+        if pix.pixelformat != pixelformat {
+            return Err(Error::InvalidColorFormat);
+        }
+        if (pix.width, pix.height) != (width, height) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let (width, height) = (width as usize, height as usize);
+        let pitch = (pix.bytesperline as usize).max(mode.line_bytes(width));
+        let size = (pix.sizeimage as usize).max(pitch * height);
+
+        Ok(Self { device, color_mode: mode, width, height, pitch, buffer: vec![0; size] })
+    }
+
+    /// Color mode of the frames.
+    #[inline]
+    pub const fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Line increment negotiated with the device, in bytes.
+    #[inline]
+    pub const fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// Write `frame` to the device.
+    ///
+    /// Fails with [`Error::InvalidParameter`] if `frame` does not match the size, and with
+    /// [`Error::InvalidColorFormat`] if it does not match the color mode of the publisher.
+    pub fn publish(&mut self, frame: &Frame<'_>) -> Result<()> {
+        if frame.bits_per_pixel() != self.color_mode.bits_per_pixel() {
+            return Err(Error::InvalidColorFormat);
+        }
+        if (frame.width(), frame.height()) != (self.width, self.height) {
+            return Err(Error::InvalidParameter);
+        }
+
+        for (line, row) in self.buffer.chunks_exact_mut(self.pitch).zip(frame.rows()) {
+            line[..row.len()].copy_from_slice(row);
+        }
+
+        Ok(self.device.write_all(&self.buffer)?)
+    }
+}
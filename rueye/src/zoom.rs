@@ -0,0 +1,114 @@
+//! Digital zoom of the sensor.
+//!
+//! Cameras with digital zoom, such as the UI-1007XS, crop and scale the image on the sensor by one
+//! of a fixed list of zoom factors. [`supported_zoom_factors`] reads that list, and [`set_zoom`]
+//! only accepts factors from it.
+//!
+//! # Documentation
+//! [is_Zoom](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_zoom.html)
+
+use std::mem::size_of;
+
+use ueye_sys::{
+    types::{void, UINT},
+    zoom::{is_Zoom, ZOOM_CAPABILITY_FLAGS, ZOOM_CMD},
+};
+
+use crate::{
+    camera::Camera,
+    error::{check, Error, Result},
+    observer::command,
+};
+
+/// Largest difference between a requested zoom factor and a listed one to be taken as equal.
+const FACTOR_TOLERANCE: f64 = 1e-6;
+
+fn zoom_cmd<T>(camera: &Camera, cmd: ZOOM_CMD, param: *mut T, size: usize) -> Result<()> {
+    check(unsafe { command!(is_Zoom(camera.handle(), cmd, param as *mut void, size as UINT)) })
+}
+
+fn get(camera: &Camera, cmd: ZOOM_CMD) -> Result<f64> {
+    let mut factor = 0.0;
+    zoom_cmd(camera, cmd, &mut factor, size_of::<f64>())?;
+    Ok(factor)
+}
+
+/// Query the zoom functions supported by the camera.
+pub fn capabilities(camera: &Camera) -> Result<ZOOM_CAPABILITY_FLAGS> {
+    let mut caps: UINT = 0;
+    zoom_cmd(camera, ZOOM_CMD::ZOOM_CMD_GET_CAPABILITIES, &mut caps, size_of::<UINT>())?;
+    Ok(ZOOM_CAPABILITY_FLAGS::from_bits_retain(caps))
+}
+
+/// Query whether the camera supports digital zoom.
+pub fn is_supported(camera: &Camera) -> Result<bool> {
+    match capabilities(camera) {
+        Ok(caps) => Ok(caps.contains(ZOOM_CAPABILITY_FLAGS::ZOOM_CAP_DIGITAL_ZOOM)),
+        Err(Error::NotSupported) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+/// Query the zoom factors supported by the camera, in the order listed by the driver.
+///
+/// Fails with [`Error::NotSupported`] if the camera has no digital zoom.
+pub fn supported_zoom_factors(camera: &Camera) -> Result<Vec<f64>> {
+    let mut count: UINT = 0;
+    zoom_cmd(
+        camera,
+        ZOOM_CMD::ZOOM_CMD_DIGITAL_GET_NUM_LIST_ENTRIES,
+        &mut count,
+        size_of::<UINT>(),
+    )?;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut factors = vec![0.0; count as usize];
+    zoom_cmd(
+        camera,
+        ZOOM_CMD::ZOOM_CMD_DIGITAL_GET_LIST,
+        factors.as_mut_ptr(),
+        factors.len() * size_of::<f64>(),
+    )?;
+    Ok(factors)
+}
+
+/// Query the current zoom factor.
+#[inline]
+pub fn current(camera: &Camera) -> Result<f64> {
+    get(camera, ZOOM_CMD::ZOOM_CMD_DIGITAL_GET_VALUE)
+}
+
+/// Query the default zoom factor.
+#[inline]
+pub fn default(camera: &Camera) -> Result<f64> {
+    get(camera, ZOOM_CMD::ZOOM_CMD_DIGITAL_GET_VALUE_DEFAULT)
+}
+
+/// Set the zoom factor.
+///
+/// Fails with [`Error::InvalidParameter`] if `factor` is not one of the
+/// [supported zoom factors](supported_zoom_factors).
+pub fn set_zoom(camera: &Camera, factor: f64) -> Result<()> {
+    let mut factor = supported_zoom_factors(camera)?
+        .into_iter()
+        .find(|supported| (supported - factor).abs() <= FACTOR_TOLERANCE)
+        .ok_or(Error::InvalidParameter)?;
+
+    zoom_cmd(camera, ZOOM_CMD::ZOOM_CMD_DIGITAL_SET_VALUE, &mut factor, size_of::<f64>())
+}
+
+impl Camera {
+    /// Query the current zoom factor, see [`current`].
+    #[inline]
+    pub fn zoom(&self) -> Result<f64> {
+        current(self)
+    }
+
+    /// Set the zoom factor, see [`set_zoom`].
+    #[inline]
+    pub fn set_zoom(&self, factor: f64) -> Result<()> {
+        set_zoom(self, factor)
+    }
+}
@@ -0,0 +1,290 @@
+//! Capture through the mock backend of `ueye-sys`, without a camera attached.
+
+#![cfg(feature = "mock")]
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use rueye::{
+    camera::Camera,
+    capture::{CaptureOptions, FrameIterator},
+    capture_state::{CaptureControl, CaptureState},
+    color_mode::ColorMode,
+    error::Error,
+    frame::{Frame, FrameBuf},
+    image_mem::ImageMemory,
+    observer,
+    queue::{DropPolicy, FrameQueue},
+    ring_buffer::RingBuffer,
+    trigger::TriggerMode,
+};
+use ueye_sys::{
+    constants::return_values::IS_SUCCESS,
+    mock::{self, SENSOR_HEIGHT, SENSOR_WIDTH},
+    types::INT,
+    video::{is_FreezeVideo, IS_WAIT},
+};
+
+/// Assert that `frame` holds the test pattern of frame `number`.
+fn assert_pattern(frame: &Frame<'_>, number: u64) {
+    assert_eq!((frame.width(), frame.height()), (SENSOR_WIDTH as usize, SENSOR_HEIGHT as usize));
+
+    let bytes_per_pixel = frame.bytes_per_pixel();
+    for (y, row) in frame.rows().enumerate() {
+        for (index, &value) in row.iter().enumerate() {
+            let expected =
+                mock::pattern(index / bytes_per_pixel, y, number, index % bytes_per_pixel);
+            assert_eq!(value, expected, "byte {index} of row {y} of frame {number}");
+        }
+    }
+}
+
+#[test]
+fn ring_buffer_captures_pattern() {
+    let camera = Camera::open_first().unwrap();
+    let ring = RingBuffer::alloc(&camera, 3, ColorMode::Mono8).unwrap();
+    assert_eq!(ring.len(), 3);
+
+    for number in 0..4 {
+        let code = unsafe { is_FreezeVideo(camera.handle(), IS_WAIT as INT) };
+        assert_eq!(code, IS_SUCCESS);
+
+        let position = ring.lock_last().unwrap();
+        assert!(ring.is_locked(position));
+        assert_pattern(unsafe { &ring.frame(position).unwrap() }, number);
+        assert_eq!(ring.lock(position), Err(Error::SequenceBufAlreadyLocked));
+        ring.unlock(position).unwrap();
+        assert_eq!(unsafe { ring.frame(position) }.unwrap_err(), Error::InvalidParameter);
+    }
+
+    assert_eq!(unsafe { ring.frame(3) }.unwrap_err(), Error::InvalidParameter);
+}
+
+#[test]
+fn planar_memories_are_not_supported() {
+    let camera = Camera::open_first().unwrap();
+    let error = RingBuffer::alloc(&camera, 1, ColorMode::Rgb8Planar).unwrap_err();
+    assert_eq!(error, Error::NotSupported);
+
+    let error = ImageMemory::alloc(&camera, 4, 4, ColorMode::Rgb8Planar).unwrap_err();
+    assert_eq!(error, Error::NotSupported);
+}
+
+#[cfg(feature = "sidecar")]
+#[test]
+fn sidecar_describes_active_memory() {
+    use rueye::sidecar::FrameMetadata;
+
+    let camera = Camera::open_first().unwrap();
+    let metadata = FrameMetadata::gather(&camera);
+    assert_eq!((metadata.frame_number, metadata.device_timestamp), (None, None));
+
+    let _memory =
+        ImageMemory::alloc(&camera, SENSOR_WIDTH, SENSOR_HEIGHT, ColorMode::Mono8).unwrap();
+    for _ in 0..2 {
+        let code = unsafe { is_FreezeVideo(camera.handle(), IS_WAIT as INT) };
+        assert_eq!(code, IS_SUCCESS);
+    }
+
+    let metadata = FrameMetadata::gather(&camera);
+    assert_eq!(metadata.frame_number, Some(1));
+    assert!(metadata.device_timestamp.is_some());
+    assert!(metadata.host_timestamp.is_some());
+}
+
+#[test]
+fn skew_estimator_rejects_missing_frames() {
+    use rueye::clock_sync::SkewEstimator;
+
+    let camera = Camera::open_first().unwrap();
+    let mut estimator = SkewEstimator::new(2);
+    assert_eq!(estimator.record_frames(&[(&camera, 1)]), Err(Error::InvalidParameter));
+    assert_eq!(estimator.events(), 0);
+}
+
+#[test]
+fn image_memory_writes_frames_line_by_line() {
+    let camera = Camera::open_first().unwrap();
+    let mut memory = ImageMemory::alloc(&camera, 3, 2, ColorMode::Mono8).unwrap();
+
+    let frame = FrameBuf::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2, 8).unwrap();
+    memory.write_frame(&frame.as_frame()).unwrap();
+    let written = unsafe { memory.as_frame() }.unwrap();
+    assert_eq!(written.to_vec(), [1, 2, 3, 4, 5, 6]);
+
+    let frame = FrameBuf::from_vec(vec![1, 2, 3, 4], 2, 2, 8).unwrap();
+    assert_eq!(memory.write_frame(&frame.as_frame()), Err(Error::InvalidImageSize));
+}
+
+#[test]
+fn frame_iterator_delivers_consecutive_frames() {
+    let camera = Camera::open_first().unwrap();
+    let options = CaptureOptions { buffers: 2, ..CaptureOptions::default() };
+    let mut frames = FrameIterator::new(&camera, &options).unwrap();
+
+    let mut previous = None;
+    for _ in 0..5 {
+        let frame = frames.next_frame().unwrap();
+        let info = frame.info();
+        assert_eq!(info.mem_id, frame.mem_id());
+        assert_pattern(&frame.as_frame().unwrap(), info.frame_number);
+
+        if let Some((number, timestamp)) = previous {
+            assert_eq!(info.frame_number, number + 1);
+            assert!(info.device_timestamp >= timestamp);
+        }
+        previous = Some((info.frame_number, info.device_timestamp));
+    }
+}
+
+#[test]
+fn frame_iterator_forwards_to_queue() {
+    let camera = Camera::open_first().unwrap();
+    let queue = FrameQueue::new(2, DropPolicy::Block);
+
+    thread::scope(|scope| {
+        let producer = scope.spawn(|| {
+            let mut frames = FrameIterator::new(&camera, &CaptureOptions::default())?;
+            frames.forward(&queue)
+        });
+
+        for number in 0..4 {
+            let (info, frame) = queue.pop().unwrap();
+            assert_eq!(info.frame_number, number);
+            assert_pattern(&frame.as_frame(), number);
+        }
+
+        queue.close();
+        producer.join().unwrap().unwrap();
+    });
+
+    assert_eq!(queue.stats().dropped_oldest, 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn frame_stream_yields_pattern() {
+    use std::{future::poll_fn, pin::Pin};
+
+    use futures_core::Stream;
+    use rueye::stream::FrameStream;
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let camera = Camera::open_first().unwrap();
+
+    runtime.block_on(async {
+        let mut stream = FrameStream::new(&camera, &CaptureOptions::default()).unwrap();
+        for _ in 0..3 {
+            let frame = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await.unwrap().unwrap();
+            let frame = frame.as_frame();
+
+            // the pattern starts at the frame number in the top left pixel
+            let number = frame.row(0)[0] as u64;
+            assert_pattern(&frame, number);
+        }
+    });
+}
+
+/// Run `f`, returning the API functions it called.
+fn calls_of(f: impl FnOnce()) -> Vec<&'static str> {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&calls);
+    let caller = thread::current().id();
+
+    // other tests call the driver concurrently
+    observer::set_call_observer(move |record| {
+        if thread::current().id() == caller {
+            recorded.lock().unwrap().push(record.function);
+        }
+    });
+    f();
+    observer::clear_call_observer();
+
+    let calls = calls.lock().unwrap();
+    calls.clone()
+}
+
+#[test]
+fn capture_control_follows_allowed_transitions() {
+    let camera = Camera::open_first().unwrap();
+    let mut control = CaptureControl::new(&camera).unwrap();
+    assert_eq!(control.state(), CaptureState::Stopped);
+
+    let expect = |control: &CaptureControl<'_>, state| {
+        assert_eq!(control.state(), state);
+        assert_eq!(CaptureState::current(&camera), Ok(state));
+    };
+
+    control.start_live().unwrap();
+    expect(&control, CaptureState::Live);
+    control.stop().unwrap();
+    expect(&control, CaptureState::Stopped);
+    control.arm(TriggerMode::Software).unwrap();
+    expect(&control, CaptureState::Armed(TriggerMode::Software));
+    control.stop().unwrap();
+    expect(&control, CaptureState::Stopped);
+    control.enter_standby().unwrap();
+    expect(&control, CaptureState::Standby);
+    control.wake().unwrap();
+    expect(&control, CaptureState::Stopped);
+}
+
+#[test]
+fn capture_control_rejects_disallowed_transitions() {
+    let camera = Camera::open_first().unwrap();
+    let mut control = CaptureControl::new(&camera).unwrap();
+
+    assert_eq!(control.stop(), Err(Error::InvalidCaptureMode));
+    assert_eq!(control.wake(), Err(Error::InvalidCaptureMode));
+    assert_eq!(control.arm(TriggerMode::Off), Err(Error::InvalidParameter));
+    assert_eq!(control.state(), CaptureState::Stopped);
+
+    control.start_live().unwrap();
+    assert_eq!(control.start_live(), Err(Error::CaptureRunning));
+    assert_eq!(control.arm(TriggerMode::Software), Err(Error::CaptureRunning));
+    assert_eq!(control.enter_standby(), Err(Error::CaptureRunning));
+    assert_eq!(control.wake(), Err(Error::InvalidCaptureMode));
+    assert_eq!(CaptureState::current(&camera), Ok(CaptureState::Live));
+
+    control.stop().unwrap();
+    control.enter_standby().unwrap();
+    assert_eq!(control.start_live(), Err(Error::InvalidCaptureMode));
+    assert_eq!(control.arm(TriggerMode::Software), Err(Error::InvalidCaptureMode));
+    assert_eq!(control.stop(), Err(Error::InvalidCaptureMode));
+    assert_eq!(CaptureState::current(&camera), Ok(CaptureState::Standby));
+}
+
+#[test]
+fn capture_control_transition_skips_driver_when_settled() {
+    let camera = Camera::open_first().unwrap();
+    let mut control = CaptureControl::new(&camera).unwrap();
+
+    // rejected transitions and the current state as target leave the driver alone
+    let calls = calls_of(|| {
+        assert_eq!(control.transition(CaptureState::Stopped), Ok(()));
+        assert_eq!(control.stop(), Err(Error::InvalidCaptureMode));
+        assert_eq!(control.arm(TriggerMode::Off), Err(Error::InvalidParameter));
+    });
+    assert_eq!(calls, [] as [&str; 0]);
+
+    control.transition(CaptureState::Armed(TriggerMode::Software)).unwrap();
+    let calls = calls_of(|| {
+        let armed = CaptureState::Armed(TriggerMode::Software);
+        assert_eq!(control.transition(armed), Ok(()));
+    });
+    assert_eq!(calls, [] as [&str; 0]);
+
+    // switching the trigger mode passes through stopped
+    let calls = calls_of(|| control.transition(CaptureState::Live).unwrap());
+    assert_eq!(calls, ["is_StopLiveVideo", "is_SetExternalTrigger", "is_CaptureVideo"]);
+    assert_eq!(control.state(), CaptureState::Live);
+
+    let calls = calls_of(|| control.transition(CaptureState::Standby).unwrap());
+    assert_eq!(calls, ["is_StopLiveVideo", "is_CameraStatus", "is_CameraStatus"]);
+
+    let calls = calls_of(|| control.transition(CaptureState::Stopped).unwrap());
+    assert_eq!(calls, ["is_CameraStatus", "is_CameraStatus"]);
+    assert_eq!(CaptureState::current(&camera), Ok(CaptureState::Stopped));
+}
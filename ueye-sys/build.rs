@@ -1,13 +1,26 @@
-use std::env;
+use std::{env, fs, path::Path};
 
 fn main() {
     let target = env::var("TARGET").unwrap_or_else(|_| String::new());
     let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_else(|_| String::new());
+    let mock = env::var_os("CARGO_FEATURE_MOCK").is_some();
+    let dlopen = env::var_os("CARGO_FEATURE_DLOPEN").is_some();
 
-    if target.contains("windows") {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if mock {
+        // The extern blocks are replaced with shims calling the fake implementation. This takes
+        // precedence over `dlopen`, whose module is left out by `lib.rs` in that case.
+        println!("cargo:rerun-if-changed=src/mock.rs");
+        let source = fs::read_to_string("src/mock.rs").expect("read the mock backend");
+        generate_shims(&Backend::Mock(parse_mocked(&source)));
+    } else if dlopen {
+        // The library is loaded at runtime, so the extern blocks are replaced with shims
+        // resolving each function from it.
+        generate_shims(&Backend::Dlopen);
+    } else if target.contains("windows") {
         // On Windows, the DLL will be found at runtime.
         println!("cargo:rustc-link-lib=dylib=uEye_api");
-        println!("cargo:rerun-if-changed=build.rs");
     } else if target.contains("linux") {
         if !arch.contains("x86") {
             panic!("Unsupported Linux architecture: only x86 and x86_64 are supported");
@@ -15,8 +28,221 @@ fn main() {
 
         println!("cargo:rustc-link-search=native=/usr/lib");
         println!("cargo:rustc-link-lib=dylib=ueye_api");
-        println!("cargo:rerun-if-changed=build.rs");
     } else {
         panic!("Unsupported platform: only Windows and Linux are supported");
     }
 }
+
+/// Implementation behind the generated shims.
+enum Backend {
+    /// Functions are resolved from the library loaded at runtime.
+    Dlopen,
+
+    /// Functions with the given names are implemented in `src/mock.rs`; the others report that
+    /// they are not supported.
+    Mock(Vec<String>),
+}
+
+/// Function declared in an `unsafe extern "C"` block.
+struct Function {
+    /// Doc comments and attributes preceding the declaration.
+    attributes: Vec<String>,
+    name: String,
+    parameters: Vec<(String, String)>,
+    output: Option<String>,
+}
+
+impl Function {
+    /// Attributes which must also apply to the function table entry.
+    fn cfgs(&self) -> impl Iterator<Item = &String> {
+        self.attributes.iter().filter(|attribute| attribute.starts_with("#[cfg"))
+    }
+
+    fn signature(&self) -> String {
+        let parameters: Vec<String> =
+            self.parameters.iter().map(|(name, ty)| format!("{name}: {ty}")).collect();
+        let output = self.output.as_ref().map(|output| format!(" -> {output}")).unwrap_or_default();
+        format!("({}){output}", parameters.join(", "))
+    }
+}
+
+/// Write `$OUT_DIR/shims/<module>.rs` for every source file declaring extern functions.
+fn generate_shims(backend: &Backend) {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let out_dir = Path::new(&out_dir).join("shims");
+    fs::create_dir_all(&out_dir).expect("create the shims output directory");
+
+    println!("cargo:rerun-if-changed=src");
+    for entry in fs::read_dir("src").expect("read the source directory") {
+        let path = entry.expect("read a source directory entry").path();
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            continue;
+        }
+
+        let source = fs::read_to_string(&path).expect("read a source file");
+        let functions = parse_extern_blocks(&source);
+        if functions.is_empty() {
+            continue;
+        }
+
+        let file_name = path.file_name().expect("source files have a name");
+        let shims = match backend {
+            Backend::Dlopen => render_dlopen(&functions),
+            Backend::Mock(mocked) => render_mock(&functions, mocked),
+        };
+        fs::write(out_dir.join(file_name), shims).expect("write the shims");
+    }
+}
+
+/// Collect the functions of all top-level `unsafe extern "C"` blocks in `source`.
+fn parse_extern_blocks(source: &str) -> Vec<Function> {
+    let mut functions = Vec::new();
+    let mut lines = source.lines();
+
+    while lines.by_ref().any(|line| line == "unsafe extern \"C\" {") {
+        let mut attributes = Vec::new();
+        let mut declaration = String::new();
+
+        for line in lines.by_ref().map(str::trim) {
+            if line == "}" && declaration.is_empty() {
+                break;
+            }
+
+            if !declaration.is_empty() || line.starts_with("pub fn ") {
+                declaration.push_str(line);
+                declaration.push(' ');
+                if line.ends_with(';') {
+                    functions.push(parse_function(&declaration, std::mem::take(&mut attributes)));
+                    declaration.clear();
+                }
+            } else if line.starts_with("///") || line.starts_with("#[") {
+                attributes.push(line.to_owned());
+            }
+        }
+    }
+
+    functions
+}
+
+/// Parse `pub fn name(parameters) -> output;`.
+fn parse_function(declaration: &str, attributes: Vec<String>) -> Function {
+    let declaration = declaration.trim().trim_start_matches("pub fn ").trim_end_matches(';');
+    let (name, rest) = declaration.split_once('(').expect("functions have a parameter list");
+    let (parameters, output) = rest.rsplit_once(')').expect("parameter lists are closed");
+
+    let parameters = parameters
+        .split(',')
+        .map(str::trim)
+        .filter(|parameter| !parameter.is_empty())
+        .map(|parameter| {
+            let (name, ty) = parameter.split_once(':').expect("parameters are typed");
+            (name.trim().to_owned(), ty.trim().to_owned())
+        })
+        .collect();
+
+    let output = output.trim().strip_prefix("->").map(|output| output.trim().to_owned());
+
+    Function { attributes, name: name.trim().to_owned(), parameters, output }
+}
+
+/// Collect the names of the functions implemented by the mock backend.
+fn parse_mocked(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub(crate) unsafe fn "))
+        .filter_map(|rest| rest.split_once('(').map(|(name, _)| name.trim().to_owned()))
+        .collect()
+}
+
+/// Write the attributes and signature of the shim for `function`.
+///
+/// Doc comments are left out, since their examples would otherwise run as doctests once more.
+fn render_shim(out: &mut String, function: &Function, body: &str) {
+    out.push('\n');
+    for attribute in function.attributes.iter().filter(|attribute| !attribute.starts_with("///")) {
+        out.push_str(attribute);
+        out.push('\n');
+    }
+
+    out.push_str(&format!("pub unsafe fn {}{} {{\n", function.name, function.signature()));
+    out.push_str(body);
+    out.push_str("}\n");
+}
+
+/// Comma-separated parameter names of `function`.
+fn arguments(function: &Function) -> String {
+    let arguments: Vec<&str> = function.parameters.iter().map(|(name, _)| name.as_str()).collect();
+    arguments.join(", ")
+}
+
+/// Render a shim for every function, forwarding to the mock backend where implemented.
+fn render_mock(functions: &[Function], mocked: &[String]) -> String {
+    let mut out =
+        String::from("// @generated by build.rs from the extern blocks of this module.\n");
+
+    for function in functions {
+        let body = if mocked.contains(&function.name) {
+            format!("    unsafe {{ crate::mock::{}({}) }}\n", function.name, arguments(function))
+        } else {
+            let output = function.output.as_deref().unwrap_or("()");
+            let ignored = match function.parameters.len() {
+                1 => arguments(function),
+                _ => format!("({})", arguments(function)),
+            };
+            format!(
+                "    let _ = {ignored};\n    <{output} as crate::mock::Unsupported>::unsupported()\n"
+            )
+        };
+        render_shim(&mut out, function, &body);
+    }
+
+    out
+}
+
+/// Render the function table of a module along with a shim for every function.
+fn render_dlopen(functions: &[Function]) -> String {
+    let mut out =
+        String::from("// @generated by build.rs from the extern blocks of this module.\n\n");
+
+    out.push_str("struct DlopenApi {\n");
+    for function in functions {
+        for cfg in function.cfgs() {
+            out.push_str(&format!("    {cfg}\n"));
+        }
+        out.push_str(&format!(
+            "    {}: Option<unsafe extern \"C\" fn{}>,\n",
+            function.name,
+            function.signature()
+        ));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("fn dlopen_api() -> &'static DlopenApi {\n");
+    out.push_str(
+        "    static API: ::std::sync::OnceLock<DlopenApi> = ::std::sync::OnceLock::new();\n",
+    );
+    out.push_str("    API.get_or_init(|| {\n");
+    out.push_str("        let library = crate::dlopen::Library::require();\n");
+    out.push_str("        DlopenApi {\n");
+    for function in functions {
+        for cfg in function.cfgs() {
+            out.push_str(&format!("            {cfg}\n"));
+        }
+        out.push_str(&format!(
+            "            {name}: unsafe {{ library.symbol(b\"{name}\\0\") }},\n",
+            name = function.name
+        ));
+    }
+    out.push_str("        }\n    })\n}\n");
+
+    for function in functions {
+        let body = format!(
+            "    let function = dlopen_api().{name}.expect(\"`{name}` is missing from the uEye API library\");\n    unsafe {{ function({arguments}) }}\n",
+            name = function.name,
+            arguments = arguments(function)
+        );
+        render_shim(&mut out, function, &body);
+    }
+
+    out
+}
@@ -1,7 +1,8 @@
 #![allow(non_camel_case_types)]
 
 use std::mem::MaybeUninit;
-use crate::types::{double, BYTE, INT, UINT};
+use crate::constants::return_values::*;
+use crate::types::{double, void, BYTE, HIDS, INT, UINT};
 use bitflags::bitflags;
 
 #[derive(Debug, Clone, Copy,  PartialEq, Eq, Hash)]
@@ -135,3 +136,28 @@ impl Clone for AOI_SEQUENCE_PARAMS {
         other
     }
 }
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Set the size and position of an area of interest (AOI) within an image.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nCommand` - Command. See [`IS_AOI_CMD`].
+    /// * `pParam` - Pointer to a function parameter, whose function depends on `nCommand`.
+    /// * `SizeOfParam` - Size (in bytes) of the memory area to which `pParam` refers.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Documentation
+    /// [is_AOI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_aoi.html)
+    pub fn is_AOI(hCam: HIDS, nCommand: IS_AOI_CMD, pParam: *mut void, SizeOfParam: UINT) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/aoi.rs"));
@@ -9,6 +9,9 @@
 //!     In addition to the older white balance with the Gray-World algorithm, there is also a
 //!     color temperature control according to Kelvin. In addition to the function the
 //!     supported color spaces are queried and set.
+//! * [is_SetAutoParameter](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setautoparameter.html):
+//!     Legacy function controlling auto exposure shutter, auto gain, auto white balance and
+//!     auto frame rate individually, along with the brightness reference, speed and hysteresis.
 
 #![allow(non_camel_case_types)]
 
@@ -231,6 +234,8 @@ pub enum IS_AUTOPARAMETER_ENABLE {
 /// Enumeration of commands of function [`is_AutoParameter`].
 ///
 ///
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[repr(u32)]
 pub enum IS_AUTOPARAMETER_CMD {
     /// Returns the supported types for auto white balance.
     ///
@@ -394,6 +399,71 @@ pub enum IS_AUTOPARAMETER_CMD {
     IS_AES_CMD_GET_CONFIGURATION_RANGE = 17,
 }
 
+/// Returns whether auto gain is enabled.
+pub const IS_GET_ENABLE_AUTO_GAIN: INT = 0x8800;
+
+/// Enables/disables auto gain.
+pub const IS_SET_ENABLE_AUTO_GAIN: INT = 0x8801;
+
+/// Returns whether auto exposure shutter is enabled.
+pub const IS_GET_ENABLE_AUTO_SHUTTER: INT = 0x8802;
+
+/// Enables/disables auto exposure shutter.
+pub const IS_SET_ENABLE_AUTO_SHUTTER: INT = 0x8803;
+
+/// Returns whether auto white balance is enabled.
+pub const IS_GET_ENABLE_AUTO_WHITEBALANCE: INT = 0x8804;
+
+/// Enables/disables auto white balance.
+pub const IS_SET_ENABLE_AUTO_WHITEBALANCE: INT = 0x8805;
+
+/// Returns whether auto frame rate is enabled.
+pub const IS_GET_ENABLE_AUTO_FRAMERATE: INT = 0x8806;
+
+/// Enables/disables auto frame rate.
+pub const IS_SET_ENABLE_AUTO_FRAMERATE: INT = 0x8807;
+
+/// Returns the brightness reference value of the auto control.
+pub const IS_GET_AUTO_REFERENCE: INT = 0x8000;
+
+/// Sets the brightness reference value of the auto control
+/// ([`IS_MIN_AUTO_BRIGHT_REFERENCE`]…[`IS_MAX_AUTO_BRIGHT_REFERENCE`]).
+pub const IS_SET_AUTO_REFERENCE: INT = 0x8001;
+
+/// Returns the speed of the auto control.
+pub const IS_GET_AUTO_SPEED: INT = 0x8006;
+
+/// Sets the speed of the auto control ([`IS_MIN_AUTO_SPEED`]…[`IS_MAX_AUTO_SPEED`]).
+pub const IS_SET_AUTO_SPEED: INT = 0x8007;
+
+/// Returns the hysteresis of the auto control.
+pub const IS_GET_AUTO_HYSTERESIS: INT = 0x8010;
+
+/// Sets the hysteresis of the auto control.
+pub const IS_SET_AUTO_HYSTERESIS: INT = 0x8011;
+
+/// Returns the range of the hysteresis of the auto control (minimum and maximum).
+pub const IS_GET_AUTO_HYSTERESIS_RANGE: INT = 0x8012;
+
+/// Default brightness reference value of the auto control.
+pub const IS_DEFAULT_AUTO_BRIGHT_REFERENCE: INT = 128;
+
+/// Minimum brightness reference value of the auto control.
+pub const IS_MIN_AUTO_BRIGHT_REFERENCE: INT = 0;
+
+/// Maximum brightness reference value of the auto control.
+pub const IS_MAX_AUTO_BRIGHT_REFERENCE: INT = 255;
+
+/// Default speed of the auto control.
+pub const IS_DEFAULT_AUTO_SPEED: INT = 50;
+
+/// Minimum speed of the auto control.
+pub const IS_MIN_AUTO_SPEED: INT = 0;
+
+/// Maximum speed of the auto control.
+pub const IS_MAX_AUTO_SPEED: INT = 100;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Manage different automatic controls.
     ///
@@ -417,4 +487,30 @@ unsafe extern "C" {
         pParam: *mut void,
         cbSizeOfParam: UINT,
     ) -> INT;
+
+    /// Control the legacy automatic controls of the camera.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `param` - One of the `IS_GET_*`/`IS_SET_*` parameters, e.g.
+    ///     [`IS_SET_ENABLE_AUTO_SHUTTER`].
+    /// * `pval1` - Pointer to the value to be set or returned (`1.0` enables, `0.0` disables).
+    /// * `pval2` - Pointer to a second value, e.g. the maximum of a range.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_AutoParameter`]
+    ///
+    /// # Documentation
+    /// [is_SetAutoParameter](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setautoparameter.html)
+    pub fn is_SetAutoParameter(hCam: HIDS, param: INT, pval1: *mut double, pval2: *mut double) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/auto_parameter.rs"));
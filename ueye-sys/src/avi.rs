@@ -0,0 +1,338 @@
+//! AVI recording of the uEye tools library.
+//!
+//! The functions record images of an image memory into an AVI file, compressing them as Motion
+//! JPEG. They are part of the separate uEye tools library (`ueye_tools`), which is linked with the
+//! `tools` feature. With the `dlopen` feature, only the uEye API library is loaded, so the
+//! functions of this module are missing at runtime.
+//!
+//! A recording runs through [`isavi_InitAVI`], [`isavi_SetImageSize`], [`isavi_OpenAVI`],
+//! [`isavi_StartAVI`], any number of [`isavi_AddFrame`], [`isavi_StopAVI`], [`isavi_CloseAVI`]
+//! and [`isavi_ExitAVI`].
+//!
+//! # Documentation
+//! [AVI functions](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_avi_funktionen.html)
+
+use crate::types::{char, double, float, long, HIDS, INT, ULONG};
+
+/// No error.
+pub const IS_AVI_NO_ERR: INT = 0;
+
+/// The file is not a valid AVI file.
+pub const IS_AVI_ERR_INVALID_FILE: INT = 300;
+
+/// The AVI file could not be created.
+pub const IS_AVI_ERR_NEW_FILE: INT = 301;
+
+/// The AVI stream could not be created.
+pub const IS_AVI_ERR_CREATE_STREAM: INT = 302;
+
+/// Invalid parameter.
+pub const IS_AVI_ERR_PARAMETER: INT = 303;
+
+/// No codec is available.
+pub const IS_AVI_ERR_NO_CODEC_AVAIL: INT = 304;
+
+/// Invalid AVI instance ID.
+pub const IS_AVI_ERR_INVALID_ID: INT = 305;
+
+/// Compression of the frame failed.
+pub const IS_AVI_ERR_COMPRESS: INT = 306;
+
+/// Decompression of the frame failed.
+pub const IS_AVI_ERR_DECOMPRESS: INT = 307;
+
+/// The recording is running.
+pub const IS_AVI_ERR_CAPTURE_RUNNING: INT = 308;
+
+/// The recording is not running.
+pub const IS_AVI_ERR_CAPTURE_NOT_RUNNING: INT = 309;
+
+/// The playback is running.
+pub const IS_AVI_ERR_PLAY_RUNNING: INT = 310;
+
+/// The playback is not running.
+pub const IS_AVI_ERR_PLAY_NOT_RUNNING: INT = 311;
+
+/// Writing the header of the AVI file failed.
+pub const IS_AVI_ERR_WRITE_INFO: INT = 312;
+
+/// Invalid value.
+pub const IS_AVI_ERR_INVALID_VALUE: INT = 313;
+
+/// Memory could not be allocated.
+pub const IS_AVI_ERR_ALLOC_MEMORY: INT = 314;
+
+/// The color mode is not supported.
+pub const IS_AVI_ERR_INVALID_CM: INT = 315;
+
+/// A compression is still running.
+pub const IS_AVI_ERR_COMPRESSION_RUN: INT = 316;
+
+/// Invalid image size.
+pub const IS_AVI_ERR_INVALID_SIZE: INT = 317;
+
+/// Invalid image position.
+pub const IS_AVI_ERR_INVALID_POSITION: INT = 318;
+
+/// Invalid camera handle.
+pub const IS_AVI_ERR_INVALID_UEYE: INT = 319;
+
+/// Creating the event failed.
+pub const IS_AVI_ERR_EVENT_FAILED: INT = 320;
+
+/// An exception occurred.
+pub const IS_AVI_ERR_EXCEPTION: INT = 321;
+
+/// No data is available.
+pub const IS_AVI_ERR_NODATA: INT = 322;
+
+/// Invalid size.
+pub const IS_AVI_ERR_SIZE: INT = 323;
+
+/// The version of the AVI file is not supported.
+pub const IS_AVI_ERR_WRONGVERSION: INT = 324;
+
+/// All AVI instances are in use.
+pub const IS_AVI_ERR_NO_FREE_HANDLE: INT = 325;
+
+/// Invalid frame number.
+pub const IS_AVI_ERR_INVALID_FRAME_NUMBER: INT = 326;
+
+/// Color mode RGB32 (matching `IS_CM_BGRA8_PACKED`) for [`isavi_SetImageSize`].
+pub const IS_AVI_CM_RGB32: INT = 0;
+
+/// Color mode RGB24 (matching `IS_CM_BGR8_PACKED`) for [`isavi_SetImageSize`].
+pub const IS_AVI_CM_RGB24: INT = 1;
+
+/// Color mode Y8 (matching `IS_CM_MONO8`) for [`isavi_SetImageSize`].
+pub const IS_AVI_CM_Y8: INT = 6;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+#[cfg_attr(target_os = "windows", link(name = "uEye_tools"))]
+#[cfg_attr(not(target_os = "windows"), link(name = "ueye_tools"))]
+unsafe extern "C" {
+    /// Create an AVI instance.
+    ///
+    /// # Input parameters
+    /// * `pnAviID` - Returns the ID of the instance.
+    /// * `hu` - Camera handle.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_UEYE`]
+    /// * [`IS_AVI_ERR_NO_FREE_HANDLE`]
+    ///
+    /// # Documentation
+    /// [isavi_InitAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_initavi.html)
+    pub fn isavi_InitAVI(pnAviID: *mut INT, hu: HIDS) -> INT;
+
+    /// Release an AVI instance, closing its file if necessary.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_ExitAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_exitavi.html)
+    pub fn isavi_ExitAVI(nAviID: INT) -> INT;
+
+    /// Create the AVI file `strFileName` for recording.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `strFileName` - NUL-terminated file name.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_NEW_FILE`]
+    /// * [`IS_AVI_ERR_CREATE_STREAM`]
+    /// * [`IS_AVI_ERR_CAPTURE_RUNNING`]
+    ///
+    /// # Documentation
+    /// [isavi_OpenAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_openavi.html)
+    pub fn isavi_OpenAVI(nAviID: INT, strFileName: *const char) -> INT;
+
+    /// Start the recording; from then on, [`isavi_AddFrame`] adds frames to the file.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_INVALID_FILE`]
+    ///
+    /// # Documentation
+    /// [isavi_StartAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_startavi.html)
+    pub fn isavi_StartAVI(nAviID: INT) -> INT;
+
+    /// Stop the recording; [`isavi_AddFrame`] no longer adds frames to the file.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_StopAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_stopavi.html)
+    pub fn isavi_StopAVI(nAviID: INT) -> INT;
+
+    /// Compress the image at `pcImageMem` and add it to the file.
+    ///
+    /// A frame is dropped, and counted by [`isavi_GetnLostFrames`], while the previous frame is
+    /// still being compressed.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `pcImageMem` - Image data in the format set with [`isavi_SetImageSize`].
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_CAPTURE_NOT_RUNNING`]
+    /// * [`IS_AVI_ERR_COMPRESSION_RUN`]
+    /// * [`IS_AVI_ERR_COMPRESS`]
+    ///
+    /// # Documentation
+    /// [isavi_AddFrame](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_addframe.html)
+    pub fn isavi_AddFrame(nAviID: INT, pcImageMem: *mut char) -> INT;
+
+    /// Set the frame rate the file is played back at.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `fr` - Frame rate, in frames per second.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_INVALID_VALUE`]
+    ///
+    /// # Documentation
+    /// [isavi_SetFrameRate](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_setframerate.html)
+    pub fn isavi_SetFrameRate(nAviID: INT, fr: double) -> INT;
+
+    /// Set the JPEG quality of the compression.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `q` - Quality (_range: `1`…`100`_).
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_INVALID_VALUE`]
+    ///
+    /// # Documentation
+    /// [isavi_SetImageQuality](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_setimagequality.html)
+    pub fn isavi_SetImageQuality(nAviID: INT, q: INT) -> INT;
+
+    /// Set the format of the images passed to [`isavi_AddFrame`].
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `cMode` - Color mode: [`IS_AVI_CM_RGB32`], [`IS_AVI_CM_RGB24`] or [`IS_AVI_CM_Y8`].
+    /// * `Width` - Width of the recorded area, in pixels.
+    /// * `Height` - Height of the recorded area, in lines.
+    /// * `PosX` - Horizontal position of the recorded area in the image.
+    /// * `PosY` - Vertical position of the recorded area in the image.
+    /// * `LineOffset` - Padding at the end of each line, in bytes.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_INVALID_CM`]
+    /// * [`IS_AVI_ERR_INVALID_SIZE`]
+    /// * [`IS_AVI_ERR_INVALID_POSITION`]
+    /// * [`IS_AVI_ERR_CAPTURE_RUNNING`]
+    ///
+    /// # Documentation
+    /// [isavi_SetImageSize](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_setimagesize.html)
+    pub fn isavi_SetImageSize(
+        nAviID: INT,
+        cMode: INT,
+        Width: long,
+        Height: long,
+        PosX: long,
+        PosY: long,
+        LineOffset: long,
+    ) -> INT;
+
+    /// Query the size of the file written so far.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `size` - Returns the size, in kilobytes.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_GetAVISize](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_getavisize.html)
+    pub fn isavi_GetAVISize(nAviID: INT, size: *mut float) -> INT;
+
+    /// Query the number of frames added to the file.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `nFrames` - Returns the number of frames.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_GetnCompressedFrames](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_getncompressedframes.html)
+    pub fn isavi_GetnCompressedFrames(nAviID: INT, nFrames: *mut ULONG) -> INT;
+
+    /// Query the number of frames dropped because the previous frame was still being compressed.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    /// * `nLostFrames` - Returns the number of frames.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_GetnLostFrames](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_getnlostframes.html)
+    pub fn isavi_GetnLostFrames(nAviID: INT, nLostFrames: *mut ULONG) -> INT;
+
+    /// Reset the counters of compressed and dropped frames.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    ///
+    /// # Documentation
+    /// [isavi_ResetFrameCounters](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_resetframecounters.html)
+    pub fn isavi_ResetFrameCounters(nAviID: INT) -> INT;
+
+    /// Close the file, writing its header.
+    ///
+    /// # Input parameters
+    /// * `nAviID` - Instance ID.
+    ///
+    /// # Return values
+    /// * [`IS_AVI_NO_ERR`]
+    /// * [`IS_AVI_ERR_INVALID_ID`]
+    /// * [`IS_AVI_ERR_WRITE_INFO`]
+    ///
+    /// # Documentation
+    /// [isavi_CloseAVI](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/isavi_closeavi.html)
+    pub fn isavi_CloseAVI(nAviID: INT) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/avi.rs"));
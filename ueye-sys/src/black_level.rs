@@ -102,6 +102,7 @@ pub enum BLACKLEVEL_CMD {
 
 
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Controls the black level correction of the camera which might improve the image quality
     /// under certain circumstances.
@@ -125,3 +126,6 @@ unsafe extern "C" {
     /// [`is_Blacklevel`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_blacklevel.html)
     pub fn is_Blacklevel(hCam: HIDS, nCommand: BLACKLEVEL_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/black_level.rs"));
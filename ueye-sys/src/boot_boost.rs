@@ -146,6 +146,7 @@ pub enum IS_BOOTBOOST_CMD {
     IS_BOOTBOOST_CMD_GET_IDLIST_SIZE = 0x20100022,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Opens the camera at the system start and allows a faster access to the camera in the running
     /// application (see [boot boost](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/cm_addfunc_boot-boost.html)).
@@ -173,3 +174,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/boot_boost.rs"));
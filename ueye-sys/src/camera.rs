@@ -0,0 +1,192 @@
+//! Camera initialization and release.
+//!
+//! # Documentation
+//! * [is_InitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_initcamera.html)
+//! * [is_ExitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_exitcamera.html)
+//! * [is_GetNumberOfCameras](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getnumberofcameras.html)
+//! * [is_GetCameraList](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+//! * [is_ResetToDefault](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_resettodefault.html)
+
+#![allow(non_camel_case_types)]
+
+use crate::constants::return_values::*;
+use crate::types::{char, DWORD, HIDS, HWND, INT, ULONG};
+
+/// Interpret the camera handle passed to [`is_InitCamera`] as a device ID instead of a
+/// camera ID.
+pub const IS_USE_DEVICE_ID: HIDS = 0x8000;
+
+/// Allow [`is_InitCamera`] to upload a new starter firmware, if required.
+pub const IS_ALLOW_STARTER_FW_UPLOAD: HIDS = 0x10000;
+
+/// Information on a camera connected to the system.
+///
+/// # Documentation
+/// [Contents of the `UEYE_CAMERA_INFO` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+#[derive(Debug, Copy, Clone, Default)]
+#[repr(C)]
+pub struct UEYE_CAMERA_INFO {
+    /// Customizable camera ID.
+    pub dwCameraID: DWORD,
+
+    /// Internal device ID of the camera.
+    pub dwDeviceID: DWORD,
+
+    /// Sensor ID.
+    pub dwSensorID: DWORD,
+
+    /// Whether the camera is in use (`1`) or not (`0`).
+    pub dwInUse: DWORD,
+
+    /// Serial number of the camera.
+    pub SerNo: [char; 16],
+
+    /// Camera model.
+    pub Model: [char; 16],
+
+    /// Various flags with the camera status.
+    pub dwStatus: DWORD,
+
+    /// (**reserved**)
+    dwReserved: [DWORD; 2],
+
+    /// Full camera model name.
+    pub FullModelName: [char; 32],
+
+    /// (**reserved**)
+    dwReserved2: [DWORD; 5],
+}
+
+/// Camera list with space for `N` entries.
+///
+/// The C header declares a single entry; larger lists are passed to [`is_GetCameraList`] by
+/// choosing `N` from [`is_GetNumberOfCameras`] and setting [`dwCount`][Self::dwCount] to it.
+///
+/// # Documentation
+/// [Contents of the `UEYE_CAMERA_LIST` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct UEYE_CAMERA_LIST<const N: usize = 1> {
+    /// Number of entries in [`uci`][Self::uci] on input; number of cameras connected on output.
+    pub dwCount: ULONG,
+
+    /// Camera information entries.
+    pub uci: [UEYE_CAMERA_INFO; N],
+}
+
+impl<const N: usize> Default for UEYE_CAMERA_LIST<N> {
+    fn default() -> Self {
+        Self { dwCount: N as ULONG, uci: [UEYE_CAMERA_INFO::default(); N] }
+    }
+}
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Open the camera and initialize the driver.
+    ///
+    /// If `*phCam` is `0` on input, the first available camera is opened. To open a specific
+    /// camera, set `*phCam` to its camera ID, or to its device ID combined with
+    /// [`IS_USE_DEVICE_ID`]. On success, `*phCam` receives the camera handle.
+    ///
+    /// # Input parameters
+    /// * `phCam` - Pointer to the camera handle.
+    ///     * `0` = The first available camera is initialized.
+    ///     * `1`…`254` = The camera with the specified camera ID is initialized.
+    /// * `hWnd` - Handle to the window in which the image is to be displayed, or `NULL` for the
+    ///     bitmap (DIB) mode.
+    ///
+    /// # Return values
+    /// * [`IS_ALL_DEVICES_BUSY`]
+    /// * [`IS_CANT_OPEN_DEVICE`]
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_STARTER_FW_UPLOAD_NEEDED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_ExitCamera`]
+    ///
+    /// # Documentation
+    /// [is_InitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_initcamera.html)
+    pub fn is_InitCamera(phCam: *mut HIDS, hWnd: HWND) -> INT;
+
+    /// Disable the camera handle and release the data structures and memory areas taken up by
+    /// the camera.
+    ///
+    /// Image memory allocated by the driver using [`is_AllocImageMem`] is released as well.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_InitCamera`]
+    ///
+    /// # Documentation
+    /// [is_ExitCamera](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_exitcamera.html)
+    pub fn is_ExitCamera(hCam: HIDS) -> INT;
+
+    /// Query the number of uEye cameras connected to the PC.
+    ///
+    /// # Input parameters
+    /// * `pnNumCams` - Receives the number of connected cameras.
+    ///
+    /// # Return values
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetCameraList`]
+    ///
+    /// # Documentation
+    /// [is_GetNumberOfCameras](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getnumberofcameras.html)
+    pub fn is_GetNumberOfCameras(pnNumCams: *mut INT) -> INT;
+
+    /// Query information on the connected cameras.
+    ///
+    /// If [`dwCount`][UEYE_CAMERA_LIST::dwCount] is `0` on input, only the number of connected
+    /// cameras is returned in it. If it does not match the number of connected cameras, the
+    /// function returns [`IS_ACCESS_VIOLATION`] and the list is not filled.
+    ///
+    /// # Input parameters
+    /// * `pucl` - Pointer to a [`UEYE_CAMERA_LIST`] with as many entries as its
+    ///     [`dwCount`][UEYE_CAMERA_LIST::dwCount].
+    ///
+    /// # Return values
+    /// * [`IS_ACCESS_VIOLATION`]
+    /// * [`IS_CANT_OPEN_DEVICE`]
+    /// * [`IS_IO_REQUEST_FAILED`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetNumberOfCameras`]
+    /// * [`is_InitCamera`]
+    ///
+    /// # Documentation
+    /// [is_GetCameraList](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcameralist.html)
+    pub fn is_GetCameraList(pucl: *mut UEYE_CAMERA_LIST) -> INT;
+
+    /// Reset all parameters of the camera to the defaults of the sensor.
+    ///
+    /// The image size and color mode may change, so image memories must be allocated again.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Documentation
+    /// [is_ResetToDefault](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_resettodefault.html)
+    pub fn is_ResetToDefault(hCam: HIDS) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/camera.rs"));
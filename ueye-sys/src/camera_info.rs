@@ -0,0 +1,153 @@
+//! Camera and sensor information.
+//!
+//! # Documentation
+//! * [is_GetCameraInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcamerainfo.html)
+//! * [is_GetSensorInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getsensorinfo.html)
+
+use crate::constants::return_values::*;
+use crate::types::{char, BOOL, BYTE, DWORD, HIDS, INT, WORD};
+
+/// Invalid sensor color mode, in [`SENSORINFO::nColorMode`].
+pub const IS_COLORMODE_INVALID: char = 0;
+
+/// Monochrome sensor, in [`SENSORINFO::nColorMode`].
+pub const IS_COLORMODE_MONOCHROME: char = 1;
+
+/// Color sensor with Bayer filter, in [`SENSORINFO::nColorMode`].
+pub const IS_COLORMODE_BAYER: char = 2;
+
+/// Color sensor with CBYCRY output, in [`SENSORINFO::nColorMode`].
+pub const IS_COLORMODE_CBYCRY: char = 4;
+
+/// Color sensor with JPEG output, in [`SENSORINFO::nColorMode`].
+pub const IS_COLORMODE_JPEG: char = 8;
+
+/// Red top left pixel of the Bayer pattern, in [`SENSORINFO::nUpperLeftBayerPixel`].
+pub const BAYER_PIXEL_RED: char = 0;
+
+/// Green top left pixel of the Bayer pattern, in [`SENSORINFO::nUpperLeftBayerPixel`].
+pub const BAYER_PIXEL_GREEN: char = 1;
+
+/// Blue top left pixel of the Bayer pattern, in [`SENSORINFO::nUpperLeftBayerPixel`].
+pub const BAYER_PIXEL_BLUE: char = 2;
+
+/// Camera information, read from the camera EEPROM.
+///
+/// # Documentation
+/// [Contents of the `CAMINFO` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcamerainfo.html)
+#[derive(Debug, Copy, Clone, Default)]
+#[repr(C)]
+pub struct CAMINFO {
+    /// Serial number of the camera.
+    pub SerNo: [char; 12],
+
+    /// Manufacturer of the camera.
+    pub ID: [char; 20],
+
+    /// Camera hardware version.
+    pub Version: [char; 10],
+
+    /// System date of the final quality check.
+    pub Date: [char; 12],
+
+    /// Customizable camera ID.
+    pub Select: BYTE,
+
+    /// Camera type.
+    pub Type: BYTE,
+
+    /// (**reserved**)
+    Reserved: [char; 8],
+}
+
+/// Sensor information.
+///
+/// # Documentation
+/// [Contents of the `SENSORINFO` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getsensorinfo.html)
+#[derive(Debug, Copy, Clone, Default)]
+#[repr(C)]
+pub struct SENSORINFO {
+    /// Sensor type.
+    pub SensorID: WORD,
+
+    /// Camera model.
+    pub strSensorName: [char; 32],
+
+    /// Sensor color mode, one of the `IS_COLORMODE_…` values.
+    pub nColorMode: char,
+
+    /// Maximum image width.
+    pub nMaxWidth: DWORD,
+
+    /// Maximum image height.
+    pub nMaxHeight: DWORD,
+
+    /// Whether the sensor has a master gain.
+    pub bMasterGain: BOOL,
+
+    /// Whether the sensor has a red gain.
+    pub bRGain: BOOL,
+
+    /// Whether the sensor has a green gain.
+    pub bGGain: BOOL,
+
+    /// Whether the sensor has a blue gain.
+    pub bBGain: BOOL,
+
+    /// Whether the sensor has a global shutter.
+    pub bGlobShutter: BOOL,
+
+    /// Pixel size, in µm × 100.
+    pub wPixelSize: WORD,
+
+    /// Color of the first pixel (top left) of the Bayer pattern, one of the `BAYER_PIXEL_…`
+    /// values.
+    pub nUpperLeftBayerPixel: char,
+
+    /// (**reserved**)
+    Reserved: [char; 13],
+}
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Read the data hard-coded in the non-volatile camera memory.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `pInfo` - Pointer to a [`CAMINFO`] structure.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetSensorInfo`]
+    /// * [`is_DeviceInfo`]
+    ///
+    /// # Documentation
+    /// [is_GetCameraInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcamerainfo.html)
+    pub fn is_GetCameraInfo(hCam: HIDS, pInfo: *mut CAMINFO) -> INT;
+
+    /// Query information about the sensor type used in the camera.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `pInfo` - Pointer to a [`SENSORINFO`] structure.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetCameraInfo`]
+    ///
+    /// # Documentation
+    /// [is_GetSensorInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getsensorinfo.html)
+    pub fn is_GetSensorInfo(hCam: HIDS, pInfo: *mut SENSORINFO) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/camera_info.rs"));
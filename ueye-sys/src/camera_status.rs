@@ -0,0 +1,86 @@
+//! Camera status and standby.
+//!
+//! # Documentation
+//! [is_CameraStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_camerastatus.html)
+
+use crate::constants::return_values::*;
+use crate::types::{HIDS, INT, ULONG};
+
+/// Number of transfer errors due to an overflow of the camera memory.
+pub const IS_FIFO_OVR_CNT: INT = 0;
+
+/// Number of the image memory of the sequence that was last used.
+pub const IS_SEQUENCE_CNT: INT = 1;
+
+/// Number of image memories in the sequence.
+pub const IS_SEQUENCE_SIZE: INT = 2;
+
+/// Number of trigger signals received while in trigger mode.
+pub const IS_EXT_TRIGGER_EVENT_CNT: INT = 3;
+
+/// Number of trigger signals that were not processed while in trigger mode.
+pub const IS_TRIGGER_MISSED: INT = 4;
+
+/// Last image capture error.
+pub const IS_LAST_CAPTURE_ERROR: INT = 5;
+
+/// Parameter set 1 in the camera EEPROM.
+pub const IS_PARAMETER_SET_1: INT = 6;
+
+/// Parameter set 2 in the camera EEPROM.
+pub const IS_PARAMETER_SET_2: INT = 7;
+
+/// Sets the camera to standby mode (`TRUE`) or freerun mode (`FALSE`).
+pub const IS_STANDBY: INT = 8;
+
+/// Whether the camera supports standby mode.
+pub const IS_STANDBY_SUPPORTED: INT = 9;
+
+/// Number of images in the image queue.
+pub const IS_QUEUED_IMAGE_EVENT_CNT: INT = 10;
+
+/// Extended parameter set of the camera EEPROM.
+pub const IS_PARAMETER_EXT: INT = 11;
+
+/// Returns the current value of the `nInfo` parameter instead of setting it.
+pub const IS_GET_STATUS: ULONG = 0x8000;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Return various status information and settings, and set the camera to standby mode.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nInfo` - One of the status parameters:
+    ///     * [`IS_FIFO_OVR_CNT`]
+    ///     * [`IS_SEQUENCE_CNT`]
+    ///     * [`IS_SEQUENCE_SIZE`]
+    ///     * [`IS_EXT_TRIGGER_EVENT_CNT`]
+    ///     * [`IS_TRIGGER_MISSED`]
+    ///     * [`IS_LAST_CAPTURE_ERROR`]
+    ///     * [`IS_PARAMETER_SET_1`]
+    ///     * [`IS_PARAMETER_SET_2`]
+    ///     * [`IS_STANDBY`]
+    ///     * [`IS_STANDBY_SUPPORTED`]
+    ///     * [`IS_QUEUED_IMAGE_EVENT_CNT`]
+    ///     * [`IS_PARAMETER_EXT`]
+    /// * `ulValue` - Value to be set, or [`IS_GET_STATUS`] to query the current value.
+    ///
+    /// # Return values
+    /// * _When used with [`IS_GET_STATUS`]:_ Current value of `nInfo`.
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_CaptureStatus`]
+    /// * [`is_GetError`]
+    ///
+    /// # Documentation
+    /// [is_CameraStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_camerastatus.html)
+    pub fn is_CameraStatus(hCam: HIDS, nInfo: INT, ulValue: ULONG) -> ULONG;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/camera_status.rs"));
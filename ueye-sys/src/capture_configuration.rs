@@ -14,6 +14,8 @@ use crate::types::{void, HIDS, INT, UINT};
 ///
 /// # Documentation
 /// [is_CaptureConfiguration](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_captureconfiguration.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum CAPTURE_CONFIGURATION_CMD {
     /// Limits the size of the internal image memory queue.
     ///
@@ -67,6 +69,7 @@ pub enum CAPTURE_CONFIGURATION_CMD {
     IS_CAPTURE_CONFIGURATION_CMD_GET_INTERNAL_BUFFER_COUNT_DEFAULT = 8,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Defines the settings of the internal image memory queue.
     ///
@@ -84,7 +87,7 @@ unsafe extern "C" {
     /// * [`IS_SUCCESS`]
     ///
     /// # Related functions
-    /// * [`is_ImageQueue`]
+    /// * [`is_ImageQueue`](crate::image_queue::is_ImageQueue)
     ///
     /// # Documentation
     /// [is_CaptureConfiguration](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_captureconfiguration.html)
@@ -95,3 +98,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/capture_configuration.rs"));
@@ -1,27 +1,122 @@
+//! Capture errors counted by the driver.
+//!
+//! # Documentation
+//! [is_CaptureStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturestatus.html)
 
+#![allow(non_camel_case_types)]
 
+use crate::constants::return_values::*;
+use crate::types::{void, BYTE, DWORD, HIDS, INT, UINT};
 
+/// Enumeration of capture errors, used as index into
+/// [`adwCapStatusCnt_Detail`][UEYE_CAPTURE_STATUS_INFO::adwCapStatusCnt_Detail].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum UEYE_CAPTURE_STATUS {
-    IS_CAP_STATUS_API_NO_DEST_MEM           =   0xa2,
-    IS_CAP_STATUS_API_CONVERSION_FAILED     =   0xa3,
-    IS_CAP_STATUS_API_IMAGE_LOCKED          =   0xa5,
+    /// No destination memory for copying: all image memories are locked or the application did
+    /// not allocate any.
+    IS_CAP_STATUS_API_NO_DEST_MEM = 0xa2,
+
+    /// The conversion of the image failed, e.g. because of a wrong color mode.
+    IS_CAP_STATUS_API_CONVERSION_FAILED = 0xa3,
+
+    /// The destination image memory is locked.
+    IS_CAP_STATUS_API_IMAGE_LOCKED = 0xa5,
+
+    /// No free internal buffers are available in the driver.
+    IS_CAP_STATUS_DRV_OUT_OF_BUFFERS = 0xb2,
 
-    IS_CAP_STATUS_DRV_OUT_OF_BUFFERS        =   0xb2,
-    IS_CAP_STATUS_DRV_DEVICE_NOT_READY      =   0xb4,
+    /// The camera is no longer available.
+    IS_CAP_STATUS_DRV_DEVICE_NOT_READY = 0xb4,
 
-    IS_CAP_STATUS_TRANSFER_FAILED           =   0xc7,
+    /// The image was not transferred completely, e.g. because the bandwidth was exceeded.
+    IS_CAP_STATUS_TRANSFER_FAILED = 0xc7,
 
-    IS_CAP_STATUS_DEV_MISSED_IMAGES         =   0xe5,
-    IS_CAP_STATUS_DEV_TIMEOUT               =   0xd6,
-    IS_CAP_STATUS_DEV_FRAME_CAPTURE_FAILED  =   0xd9,
+    /// The camera did not capture some images since the previous one was still being transferred.
+    IS_CAP_STATUS_DEV_MISSED_IMAGES = 0xe5,
 
-    IS_CAP_STATUS_ETH_BUFFER_OVERRUN        =   0xe4,
+    /// The maximum allowable time for image capturing in the camera was exceeded.
+    IS_CAP_STATUS_DEV_TIMEOUT = 0xd6,
+
+    /// The camera failed to capture the image.
+    IS_CAP_STATUS_DEV_FRAME_CAPTURE_FAILED = 0xd9,
+
+    /// The sensor transfers more data than the internal camera memory can accept.
+    IS_CAP_STATUS_ETH_BUFFER_OVERRUN = 0xe4,
 }
 
+/// Enumeration of commands supported by [`is_CaptureStatus`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum IS_CAPTURE_STATUS_CMD {
+    /// Reset all error counters to zero.
     IS_CAPTURE_STATUS_INFO_CMD_RESET = 1,
-    IS_CAPTURE_STATUS_INFO_CMD_GET   = 2,
-    IS_CAPTURE_STATUS_CRC_ERROR_COUNT_GET  = 3
+
+    /// Return the error counters in a [`UEYE_CAPTURE_STATUS_INFO`].
+    IS_CAPTURE_STATUS_INFO_CMD_GET = 2,
+
+    /// Return the number of CRC errors as [`UINT`] (USB 3 cameras only).
+    IS_CAPTURE_STATUS_CRC_ERROR_COUNT_GET = 3,
 }
+
+/// Capture error counters.
+///
+/// # Related commands
+/// * [`IS_CAPTURE_STATUS_CMD::IS_CAPTURE_STATUS_INFO_CMD_GET`]
+///
+/// # Documentation
+/// [Contents of the `UEYE_CAPTURE_STATUS_INFO` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturestatus.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct UEYE_CAPTURE_STATUS_INFO {
+    /// Total number of capture errors.
+    pub dwCapStatusCnt_Total: DWORD,
+
+    /// (**reserved**)
+    reserved: [BYTE; 60],
+
+    /// Number of errors per [`UEYE_CAPTURE_STATUS`], indexed by its value.
+    pub adwCapStatusCnt_Detail: [DWORD; 256],
+}
+
+impl Default for UEYE_CAPTURE_STATUS_INFO {
+    fn default() -> Self {
+        Self { dwCapStatusCnt_Total: 0, reserved: [0; 60], adwCapStatusCnt_Detail: [0; 256] }
+    }
+}
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Return information on errors that occurred during image capture.
+    ///
+    /// All errors are counted from the moment the camera was opened, or the counters were last
+    /// reset with [`IS_CAPTURE_STATUS_CMD::IS_CAPTURE_STATUS_INFO_CMD_RESET`].
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nCommand` - Command.
+    /// * `pParam` - Pointer to a function parameter, whose function depends on `nCommand`.
+    /// * `nSizeOfParam` - Size (in bytes) of the memory area to which `pParam` refers.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetError`]
+    /// * [`is_CameraStatus`]
+    ///
+    /// # Documentation
+    /// [is_CaptureStatus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_capturestatus.html)
+    pub fn is_CaptureStatus(
+        hCam: HIDS,
+        nCommand: IS_CAPTURE_STATUS_CMD,
+        pParam: *mut void,
+        nSizeOfParam: UINT,
+    ) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/capture_status.rs"));
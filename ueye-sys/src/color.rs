@@ -1,7 +1,7 @@
 //! Common color functions.
 
 use crate::constants::return_values::*;
-use crate::types::{HIDS, INT};
+use crate::types::{double, HIDS, INT};
 
 /// Read current color format in function [`is_SetColorMode`].
 pub const IS_GET_COLOR_MODE: INT = 0x8000;
@@ -25,93 +25,94 @@ pub const IS_CM_ORDER_RGB: INT = 0x0080;
 pub const IS_CM_ORDER_MASK: INT = 0x0080;
 
 /// Raw sensor data (8), for monochrome and color cameras, LUT/gamma not active.
-const IS_CM_SENSOR_RAW8: INT = 11;
+pub const IS_CM_SENSOR_RAW8: INT = 11;
 
 /// Raw sensor data (10), for monochrome and color cameras, LUT/gamma not active.
-const IS_CM_SENSOR_RAW10: INT = 33;
+pub const IS_CM_SENSOR_RAW10: INT = 33;
 
 /// Raw sensor data (12), for monochrome and color cameras, LUT/gamma not active.
-const IS_CM_SENSOR_RAW12: INT = 27;
+pub const IS_CM_SENSOR_RAW12: INT = 27;
 
 /// Raw sensor data (16), for monochrome and color cameras, LUT/gamma not active.
-const IS_CM_SENSOR_RAW16: INT = 29;
+pub const IS_CM_SENSOR_RAW16: INT = 29;
 
 /// Grayscale (8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_MONO8: INT = 6;
+pub const IS_CM_MONO8: INT = 6;
 
 /// Grayscale (10), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_MONO10: INT = 34;
+pub const IS_CM_MONO10: INT = 34;
 
 /// Grayscale (12), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_MONO12: INT = 26;
+pub const IS_CM_MONO12: INT = 26;
 
 /// Grayscale (16), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_MONO16: INT = 28;
+pub const IS_CM_MONO16: INT = 28;
 
 /// BGR (5 5 5), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR5_PACKED: INT = (3 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR5_PACKED: INT = (3 | IS_CM_ORDER_BGR);
 
 /// BGR (5 6 5), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR565_PACKED: INT = (2 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR565_PACKED: INT = (2 | IS_CM_ORDER_BGR);
 
 /// RGB (8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGB8_PACKED: INT = (1 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGB8_PACKED: INT = (1 | IS_CM_ORDER_RGB);
 
 /// BGR (8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR8_PACKED: INT = (1 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR8_PACKED: INT = (1 | IS_CM_ORDER_BGR);
 
 /// RGB (8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGBA8_PACKED: INT = (0 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGBA8_PACKED: INT = (0 | IS_CM_ORDER_RGB);
 
 /// BGR (8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGRA8_PACKED: INT = (0 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGRA8_PACKED: INT = (0 | IS_CM_ORDER_BGR);
 
 /// RGBY (8 8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGBY8_PACKED: INT = (24 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGBY8_PACKED: INT = (24 | IS_CM_ORDER_RGB);
 
 /// BGRY (8 8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGRY8_PACKED: INT = (24 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGRY8_PACKED: INT = (24 | IS_CM_ORDER_BGR);
 
 /// RGB (10 10 10), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGB10_PACKED: INT = (25 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGB10_PACKED: INT = (25 | IS_CM_ORDER_RGB);
 
 /// BGR (10 10 10), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR10_PACKED: INT = (25 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR10_PACKED: INT = (25 | IS_CM_ORDER_BGR);
 
 /// Unpacked RGB (10 10 10), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGB10_UNPACKED: INT = (35 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGB10_UNPACKED: INT = (35 | IS_CM_ORDER_RGB);
 
 /// BGR (10 10 10), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR10_UNPACKED: INT = (35 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR10_UNPACKED: INT = (35 | IS_CM_ORDER_BGR);
 
 /// Unpacked RGB (12 12 12), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGB12_UNPACKED: INT = (30 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGB12_UNPACKED: INT = (30 | IS_CM_ORDER_RGB);
 
 /// Unpacked BGR (12 12 12), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGR12_UNPACKED: INT = (30 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGR12_UNPACKED: INT = (30 | IS_CM_ORDER_BGR);
 
 /// Unpacked RGB (12 12 12), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_RGBA12_UNPACKED: INT = (31 | IS_CM_ORDER_RGB);
+pub const IS_CM_RGBA12_UNPACKED: INT = (31 | IS_CM_ORDER_RGB);
 
 /// Unpacked BGR (12 12 12), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_BGRA12_UNPACKED: INT = (31 | IS_CM_ORDER_BGR);
+pub const IS_CM_BGRA12_UNPACKED: INT = (31 | IS_CM_ORDER_BGR);
 
 /// JPEG for USB _uEye XS_.
-const IS_CM_JPEG: INT = 32;
+pub const IS_CM_JPEG: INT = 32;
 
 /// YUV 4:2:2 (8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_UYVY_PACKED: INT = 12;
+pub const IS_CM_UYVY_PACKED: INT = 12;
 
 /// YUV 4:2:2 (8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_UYVY_MONO_PACKED: INT = 13;
+pub const IS_CM_UYVY_MONO_PACKED: INT = 13;
 
 /// YUV 4:2:2 (8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_UYVY_BAYER_PACKED: INT = 14;
+pub const IS_CM_UYVY_BAYER_PACKED: INT = 14;
 
 /// YCbCr 4:2:2 (8 8), for monochrome and color cameras, LUT/gamma active.
-const IS_CM_CBYCRY_PACKED: INT = 23;
+pub const IS_CM_CBYCRY_PACKED: INT = 23;
 
-const IS_CM_RGB8_PLANAR: INT = (1 | IS_CM_ORDER_RGB | IS_CM_FORMAT_PLANAR);
+/// Planar RGB (8 8 8), for monochrome and color cameras, LUT/gamma active.
+pub const IS_CM_RGB8_PLANAR: INT = (1 | IS_CM_ORDER_RGB | IS_CM_FORMAT_PLANAR);
 
 /// All possible color modes.
 pub const IS_CM_ALL_POSSIBLE: INT = 0xFFFF;
@@ -123,6 +124,53 @@ pub const IS_CM_MODE_MASK: INT = 0x007F;
 /// (also for the debayered pixel formats).
 pub const IS_CM_PREFER_PACKED_SOURCE_FORMAT: INT = 0x4000;
 
+/// Return the current color correction mode in function [`is_SetColorCorrection`].
+pub const IS_GET_CCOR_MODE: INT = 0x8000;
+
+/// Return the supported color correction modes in function [`is_SetColorCorrection`].
+pub const IS_GET_SUPPORTED_CCOR_MODE: INT = 0x8001;
+
+/// Return the default color correction mode in function [`is_SetColorCorrection`].
+pub const IS_GET_DEFAULT_CCOR_MODE: INT = 0x8002;
+
+/// Return the current color correction factor in function [`is_SetColorCorrection`].
+pub const IS_GET_CCOR_FACTOR: INT = 0x8003;
+
+/// Return the minimum color correction factor in function [`is_SetColorCorrection`].
+pub const IS_GET_CCOR_FACTOR_MIN: INT = 0x8004;
+
+/// Return the maximum color correction factor in function [`is_SetColorCorrection`].
+pub const IS_GET_CCOR_FACTOR_MAX: INT = 0x8005;
+
+/// Return the default color correction factor in function [`is_SetColorCorrection`].
+pub const IS_GET_CCOR_FACTOR_DEFAULT: INT = 0x8006;
+
+/// Disable color correction.
+pub const IS_CCOR_DISABLE: INT = 0x0000;
+
+/// Enable color correction.
+pub const IS_CCOR_ENABLE: INT = 0x0001;
+
+/// Enable the normal color correction, for cameras without IR filter glass.
+pub const IS_CCOR_ENABLE_NORMAL: INT = IS_CCOR_ENABLE;
+
+/// Enable the color correction enhanced for cameras with BG40 optical filter.
+pub const IS_CCOR_ENABLE_BG40_ENHANCED: INT = 0x0002;
+
+/// Enable the color correction enhanced for cameras with HQ optical filter.
+pub const IS_CCOR_ENABLE_HQ_ENHANCED: INT = 0x0004;
+
+/// Select the color correction automatically for the IR filter of the camera.
+pub const IS_CCOR_SET_IR_AUTOMATIC: INT = 0x0080;
+
+/// Color correction factor.
+pub const IS_CCOR_FACTOR: INT = 0x0100;
+
+/// Mask of the modes enabling color correction.
+pub const IS_CCOR_ENABLE_MASK: INT =
+    IS_CCOR_ENABLE_NORMAL | IS_CCOR_ENABLE_BG40_ENHANCED | IS_CCOR_ENABLE_HQ_ENHANCED;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Sets the color mode to be used when image data are saved or displayed by the graphics card.
     ///
@@ -231,4 +279,42 @@ unsafe extern "C" {
     /// # Documentation
     /// [is_GetColorDepth](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcolordepth.html)
     pub fn is_GetColorDepth(hCam: HIDS, pnCol: *mut INT, pnColMode: *mut INT) -> INT;
+
+    /// Enable the color correction of color cameras, which improves the color rendering by
+    /// compensating the crosstalk between the color channels of the sensor.
+    ///
+    /// The color correction is applied in software when the image is converted, so it is not
+    /// available for raw sensor data.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nEnable` - Color correction mode:
+    ///     * [`IS_CCOR_DISABLE`] disables the color correction.
+    ///     * [`IS_CCOR_ENABLE_NORMAL`], [`IS_CCOR_ENABLE_BG40_ENHANCED`] or
+    ///         [`IS_CCOR_ENABLE_HQ_ENHANCED`] enable it with the given strength.
+    ///     * [`IS_CCOR_SET_IR_AUTOMATIC`] selects the mode for the IR filter of the camera.
+    ///     * [`IS_GET_CCOR_MODE`], [`IS_GET_SUPPORTED_CCOR_MODE`] and
+    ///         [`IS_GET_DEFAULT_CCOR_MODE`] return the respective modes.
+    ///     * [`IS_GET_CCOR_FACTOR`], [`IS_GET_CCOR_FACTOR_MIN`], [`IS_GET_CCOR_FACTOR_MAX`] and
+    ///         [`IS_GET_CCOR_FACTOR_DEFAULT`] return the respective strength in `factors`.
+    /// * `factors` - Strength of the color correction (_range: `0.0`…`1.0`_).
+    ///
+    /// # Return values
+    /// * [`IS_CANT_COMMUNICATE_WITH_DRIVER`]
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    /// * Current setting when used together with a `IS_GET_…` parameter.
+    ///
+    /// # Related functions
+    /// * [`is_SetColorMode`]
+    ///
+    /// # Documentation
+    /// [is_SetColorCorrection](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setcolorcorrection.html)
+    pub fn is_SetColorCorrection(hCam: HIDS, nEnable: INT, factors: *mut double) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/color.rs"));
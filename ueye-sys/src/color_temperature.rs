@@ -184,6 +184,7 @@ pub enum COLOR_TEMPERATURE_CMD {
     COLOR_TEMPERATURE_CMD_GET_LENS_SHADING_MODEL_DEFAULT = 13,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Fix a setting (in kelvins) for the color temperature of an image when you are using a
     /// color camera.
@@ -215,3 +216,6 @@ unsafe extern "C" {
         nSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/color_temperature.rs"));
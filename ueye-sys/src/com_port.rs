@@ -13,6 +13,7 @@ pub struct UEYE_COMPORT_CONFIGURATION {
     pub wComportNumber: WORD,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Returns the current COM port number of a USB 3 uEye CP/ML camera.
     ///
@@ -42,3 +43,6 @@ unsafe extern "C" {
     /// [is_GetComportNumber](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getcomportnumber.html)
     pub fn is_GetComportNumber(hCam: HCAM, pComportNumber: *mut UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/com_port.rs"));
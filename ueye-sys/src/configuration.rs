@@ -419,6 +419,7 @@ pub enum CONFIGURATION_CAPS {
     IS_CONFIG_TRUSTED_PAIRING_CAP_SUPPORTED                = 0x00000010
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
 
@@ -453,3 +454,6 @@ unsafe extern "C" {
     pub fn is_Configuration(nCommand: CONFIGURATION_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/configuration.rs"));
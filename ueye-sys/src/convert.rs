@@ -59,11 +59,23 @@ pub struct BUFFER_CONVERSION_PARAMS {
 ///
 /// # Documentation
 /// [`is_Convert`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_convert.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum CONVERT_CMD {
     /// Converts a raw Bayer buffer with the passed conversion parameters.
     IS_CONVERT_CMD_APPLY_PARAMS_AND_CONVERT_BUFFER = 1
 }
 
+/// Software conversion with a 3×3 filter mask, for [`BUFFER_CONVERSION_PARAMS::nDestPixelConverter`].
+pub const IS_CONV_MODE_SOFTWARE_3X3: INT = 0x0002;
+
+/// Software conversion with a 5×5 filter mask, for [`BUFFER_CONVERSION_PARAMS::nDestPixelConverter`].
+pub const IS_CONV_MODE_SOFTWARE_5X5: INT = 0x0004;
+
+/// Software conversion with a 2×2 filter mask, for [`BUFFER_CONVERSION_PARAMS::nDestPixelConverter`].
+pub const IS_CONV_MODE_SOFTWARE_2X2: INT = 0x0200;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// General function to convert a raw Bayer image to the desired format.
@@ -100,3 +112,6 @@ unsafe extern "C" {
     /// [`is_Convert`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_convert.html)
     pub fn is_Convert(hCam: HIDS, nCommand: CONVERT_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/convert.rs"));
@@ -24,6 +24,7 @@
 
 use crate::constants::return_values::*;
 use crate::types::{double, void, BOOL, BYTE, HIDS, INT, UINT, WORD, IS_RANGE_S32};
+use bitflags::bitflags;
 use std::mem::MaybeUninit;
 
 /// Enumeration of commands for [`is_DeviceFeature`].
@@ -862,104 +863,106 @@ pub enum DEVICE_FEATURE_CMD {
     IS_DEVICE_FEATURE_CMD_SET_AOI_CONSTANT_FRAMERATE_ENABLE = 116,
 }
 
-/// Enumeration of device feature capability flags.
-///
-/// Used with: [`IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES`][DEVICE_FEATURE_CMD::IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES].
-///
-/// # Documentation
-/// [is_DeviceFeature: Status flags from DEVICE_FEATURE_MODE_CAPS](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature.html#device_feature_mode_caps)
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-#[repr(u32)]
-pub enum DEVICE_FEATURE_MODE_CAPS {
-    /// Rolling shutter mode is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING = 0x00000001,
+bitflags! {
+    /// Device feature capability flags (_supports bitmask_).
+    ///
+    /// Used with: [`IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES`][DEVICE_FEATURE_CMD::IS_DEVICE_FEATURE_CMD_GET_SUPPORTED_FEATURES].
+    ///
+    /// # Documentation
+    /// [is_DeviceFeature: Status flags from DEVICE_FEATURE_MODE_CAPS](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeature.html#device_feature_mode_caps)
+    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct DEVICE_FEATURE_MODE_CAPS: UINT {
+        /// Rolling shutter mode is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING = 0x00000001;
 
-    /// Global shutter mode is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL = 0x00000002,
+        /// Global shutter mode is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL = 0x00000002;
 
-    /// Fast line scan mode is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_LINESCAN_MODE_FAST = 0x00000004,
+        /// Fast line scan mode is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_LINESCAN_MODE_FAST = 0x00000004;
 
-    /// Line number at fast line scan mode is supported/Set number.
-    IS_DEVICE_FEATURE_CAP_LINESCAN_NUMBER = 0x00000008,
-    IS_DEVICE_FEATURE_CAP_PREFER_XS_HS_MODE = 0x00000010,
+        /// Line number at fast line scan mode is supported/Set number.
+        const IS_DEVICE_FEATURE_CAP_LINESCAN_NUMBER = 0x00000008;
+        const IS_DEVICE_FEATURE_CAP_PREFER_XS_HS_MODE = 0x00000010;
 
-    /// Log mode is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_LOG_MODE = 0x00000020,
+        /// Log mode is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_LOG_MODE = 0x00000020;
 
-    /// Rolling shutter mode with global start is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING_GLOBAL_START = 0x00000040,
+        /// Rolling shutter mode with global start is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_ROLLING_GLOBAL_START = 0x00000040;
 
-    /// Global shutter mode with different timing parameters is supported/Set mode.
-    IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL_ALTERNATIVE_TIMING = 0x00000080,
+        /// Global shutter mode with different timing parameters is supported/Set mode.
+        const IS_DEVICE_FEATURE_CAP_SHUTTER_MODE_GLOBAL_ALTERNATIVE_TIMING = 0x00000080;
 
-    /// Special [AOI merge mode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeatureaoimergemode.html)
-    /// which combines the lines of an AOI to a new image.
-    IS_DEVICE_FEATURE_CAP_VERTICAL_AOI_MERGE = 0x00000100,
+        /// Special [AOI merge mode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_devicefeatureaoimergemode.html)
+        /// which combines the lines of an AOI to a new image.
+        const IS_DEVICE_FEATURE_CAP_VERTICAL_AOI_MERGE = 0x00000100;
 
-    /// Fixed pattern noise correction is supported.
-    IS_DEVICE_FEATURE_CAP_FPN_CORRECTION = 0x00000200,
+        /// Fixed pattern noise correction is supported.
+        const IS_DEVICE_FEATURE_CAP_FPN_CORRECTION = 0x00000200;
 
-    /// Analog sensor source gain is supported.
-    IS_DEVICE_FEATURE_CAP_SENSOR_SOURCE_GAIN = 0x00000400,
+        /// Analog sensor source gain is supported.
+        const IS_DEVICE_FEATURE_CAP_SENSOR_SOURCE_GAIN = 0x00000400;
 
-    /// Displays the black level reference in the image which can be used to calibrate the
-    /// black level.
-    IS_DEVICE_FEATURE_CAP_BLACK_REFERENCE = 0x00000800,
+        /// Displays the black level reference in the image which can be used to calibrate the
+        /// black level.
+        const IS_DEVICE_FEATURE_CAP_BLACK_REFERENCE = 0x00000800;
 
-    /// Sets the bit depth of the sensor.
-    ///
-    /// With the [`SENSOR_BIT_DEPTH::IS_SENSOR_BIT_DEPTH_AUTO`] setting the software selects the
-    /// appropriate sensor bit depth to the chosen image format. The bit depth can also be selected
-    /// independently from the image format.
-    ///
-    /// <div class="warning">
-    /// As you can choose combinations that does not fit, this function should be used by experts
-    /// only. In most cases the auto control are sufficient.
-    /// </div>
-    IS_DEVICE_FEATURE_CAP_SENSOR_BIT_DEPTH = 0x00001000,
+        /// Sets the bit depth of the sensor.
+        ///
+        /// With the [`SENSOR_BIT_DEPTH::IS_SENSOR_BIT_DEPTH_AUTO`] setting the software selects the
+        /// appropriate sensor bit depth to the chosen image format. The bit depth can also be selected
+        /// independently from the image format.
+        ///
+        /// <div class="warning">
+        /// As you can choose combinations that does not fit, this function should be used by experts
+        /// only. In most cases the auto control are sufficient.
+        /// </div>
+        const IS_DEVICE_FEATURE_CAP_SENSOR_BIT_DEPTH = 0x00001000;
 
-    /// Display of the internal camera temperature is supported.
-    IS_DEVICE_FEATURE_CAP_TEMPERATURE = 0x00002000,
+        /// Display of the internal camera temperature is supported.
+        const IS_DEVICE_FEATURE_CAP_TEMPERATURE = 0x00002000;
 
-    /// JPEG compression is supported.
-    IS_DEVICE_FEATURE_CAP_JPEG_COMPRESSION = 0x00004000,
+        /// JPEG compression is supported.
+        const IS_DEVICE_FEATURE_CAP_JPEG_COMPRESSION = 0x00004000;
 
-    /// Noise suppression is supported.
-    IS_DEVICE_FEATURE_CAP_NOISE_REDUCTION = 0x00008000,
+        /// Noise suppression is supported.
+        const IS_DEVICE_FEATURE_CAP_NOISE_REDUCTION = 0x00008000;
 
-    /// Configuration of the timestamp
-    /// (e.g. reset the timestamp to `0` at a signal on the trigger pin).
-    IS_DEVICE_FEATURE_CAP_TIMESTAMP_CONFIGURATION = 0x00010000,
+        /// Configuration of the timestamp
+        /// (e.g. reset the timestamp to `0` at a signal on the trigger pin).
+        const IS_DEVICE_FEATURE_CAP_TIMESTAMP_CONFIGURATION = 0x00010000;
 
-    /// Image effects are supported.
-    IS_DEVICE_FEATURE_CAP_IMAGE_EFFECT = 0x00020000,
+        /// Image effects are supported.
+        const IS_DEVICE_FEATURE_CAP_IMAGE_EFFECT = 0x00020000;
 
-    /// The use of the extended pixel clock range is supported.
-    IS_DEVICE_FEATURE_CAP_EXTENDED_PIXELCLOCK_RANGE = 0x00040000,
+        /// The use of the extended pixel clock range is supported.
+        const IS_DEVICE_FEATURE_CAP_EXTENDED_PIXELCLOCK_RANGE = 0x00040000;
 
-    /// Multi integration mode is supported.
-    IS_DEVICE_FEATURE_CAP_MULTI_INTEGRATION = 0x00080000,
+        /// Multi integration mode is supported.
+        const IS_DEVICE_FEATURE_CAP_MULTI_INTEGRATION = 0x00080000;
 
-    /// The use of the wide dynamic range is supported.
-    IS_DEVICE_FEATURE_CAP_WIDE_DYNAMIC_RANGE = 0x00100000,
+        /// The use of the wide dynamic range is supported.
+        const IS_DEVICE_FEATURE_CAP_WIDE_DYNAMIC_RANGE = 0x00100000;
 
-    /// The use of the level controlled trigger is supported.
-    IS_DEVICE_FEATURE_CAP_LEVEL_CONTROLLED_TRIGGER = 0x00200000,
+        /// The use of the level controlled trigger is supported.
+        const IS_DEVICE_FEATURE_CAP_LEVEL_CONTROLLED_TRIGGER = 0x00200000;
 
-    /// Enabling/disabling the I<sup>2</sup>C stop bit for the read command is supported.
-    IS_DEVICE_FEATURE_CAP_REPEATED_START_CONDITION_I2C = 0x00400000,
+        /// Enabling/disabling the I<sup>2</sup>C stop bit for the read command is supported.
+        const IS_DEVICE_FEATURE_CAP_REPEATED_START_CONDITION_I2C = 0x00400000;
 
-    /// Monitoring the temperature status is supported.
-    IS_DEVICE_FEATURE_CAP_TEMPERATURE_STATUS = 0x00800000,
+        /// Monitoring the temperature status is supported.
+        const IS_DEVICE_FEATURE_CAP_TEMPERATURE_STATUS = 0x00800000;
 
-    /// Internal image memory is supported.
-    IS_DEVICE_FEATURE_CAP_MEMORY_MODE = 0x01000000,
-    IS_DEVICE_FEATURE_CAP_SEND_EXTERNAL_INTERFACE_DATA = 0x02000000,
+        /// Internal image memory is supported.
+        const IS_DEVICE_FEATURE_CAP_MEMORY_MODE = 0x01000000;
+        const IS_DEVICE_FEATURE_CAP_SEND_EXTERNAL_INTERFACE_DATA = 0x02000000;
 
-    /// The camera model supports the [`IS_SET_EVENT_END_OF_EXPOSURE`] event.
-    /// See [`is_Event`].
-    IS_DEVICE_FEATURE_CAP_END_OF_EXPOSURE = 0x04000000,
+        /// The camera model supports the [`IS_SET_EVENT_END_OF_EXPOSURE`] event.
+        /// See [`is_Event`].
+        const IS_DEVICE_FEATURE_CAP_END_OF_EXPOSURE = 0x04000000;
+    }
 }
 
 /// Enumeration of temperature states.
@@ -1511,6 +1514,7 @@ pub struct IS_EXTERNAL_INTERFACE_CONFIGURATION {
     pub wDataSelection: WORD,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Interface to control various device features.
     ///
@@ -1541,3 +1545,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/device_feature.rs"));
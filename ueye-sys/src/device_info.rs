@@ -44,6 +44,57 @@ pub struct IS_DEVICE_INFO_HEARTBEAT {
     pub wComportOffset: WORD,
 }
 
+impl IS_DEVICE_INFO_HEARTBEAT {
+    /// Value of [`wTemperature`][IS_DEVICE_INFO_HEARTBEAT::wTemperature] reported by cameras
+    /// without a temperature sensor, i.e. "-127.9 °C".
+    pub const TEMPERATURE_NO_SENSOR: WORD = TEMPERATURE_NO_SENSOR;
+
+    /// Camera temperature in °Celsius, decoded from
+    /// [`wTemperature`][IS_DEVICE_INFO_HEARTBEAT::wTemperature].
+    ///
+    /// Cameras without a temperature sensor report `-127.9`, see
+    /// [`temperature_celsius`][IS_DEVICE_INFO_HEARTBEAT::temperature_celsius].
+    pub const fn temperature(&self) -> f32 {
+        decode_temperature(self.wTemperature)
+    }
+
+    /// Camera temperature in °Celsius, decoded from
+    /// [`wTemperature`][IS_DEVICE_INFO_HEARTBEAT::wTemperature].
+    ///
+    /// Returns `None` if the camera does not have a temperature sensor.
+    pub const fn temperature_celsius(&self) -> Option<f32> {
+        decode_sensor_temperature(self.wTemperature)
+    }
+}
+
+/// Temperature word reported by cameras without a temperature sensor, i.e. "-127.9 °C".
+pub(crate) const TEMPERATURE_NO_SENSOR: WORD = 0xFFF9;
+
+/// Decode a temperature word in °Celsius.
+///
+/// # Layout
+/// * **Bit 15**: algebraic sign
+/// * **Bits 14…11**: filled according to algebraic sign
+/// * **Bits 10…4**: temperature (_places before the decimal point_)
+/// * **Bits 3…0**: temperature (_places after the decimal point_)
+pub(crate) const fn decode_temperature(raw: WORD) -> f32 {
+    let value = ((raw >> 4) & 0x7F) as f32 + (raw & 0x0F) as f32 / 10.0;
+
+    if raw & 0x8000 != 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Decode a temperature word in °Celsius, or `None` for [`TEMPERATURE_NO_SENSOR`].
+pub(crate) const fn decode_sensor_temperature(raw: WORD) -> Option<f32> {
+    match raw {
+        TEMPERATURE_NO_SENSOR => None,
+        raw => Some(decode_temperature(raw)),
+    }
+}
+
 /// Definition of the uEye device info / control.
 ///
 /// This data is provided by the uEye driver.
@@ -84,6 +135,8 @@ pub struct IS_DEVICE_INFO {
 ///
 /// # Documentation
 /// [`is_DeviceInfo`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_deviceinfo.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum IS_DEVICE_INFO_CMD {
     /// Returns an information structure about the specified device.
     ///
@@ -92,6 +145,37 @@ pub enum IS_DEVICE_INFO_CMD {
     IS_DEVICE_INFO_CMD_GET_DEVICE_INFO  = 0x02010001
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     pub fn is_DeviceInfo(hcam: HCAM, nCommand: IS_DEVICE_INFO_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/device_info.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_positive_temperature() {
+        assert_eq!(decode_temperature(0x0000), 0.0);
+        assert_eq!(decode_temperature((42 << 4) | 5), 42.5);
+        assert_eq!(decode_temperature((127 << 4) | 9), 127.9);
+    }
+
+    #[test]
+    fn decode_negative_temperature() {
+        // sign bit, bits 14…11 filled according to the sign
+        assert_eq!(decode_temperature(0xF800 | (10 << 4) | 3), -10.3);
+        assert_eq!(decode_temperature(0x8000 | (1 << 4)), -1.0);
+    }
+
+    #[test]
+    fn decode_no_sensor() {
+        assert_eq!(decode_temperature(TEMPERATURE_NO_SENSOR), -127.9);
+        assert_eq!(decode_sensor_temperature(TEMPERATURE_NO_SENSOR), None);
+        assert_eq!(decode_sensor_temperature(0xF800 | (127 << 4) | 8), Some(-127.8));
+        assert_eq!(decode_sensor_temperature((36 << 4) | 1), Some(36.1));
+    }
+}
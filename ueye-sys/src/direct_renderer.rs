@@ -265,6 +265,7 @@ pub enum DR_CMD {
     DR_GET_SUPPORTED = 30,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Provides a set of advanced rendering functions and allows inserting overlay data into the
     /// camera's live image without flicker.
@@ -306,3 +307,6 @@ unsafe extern "C" {
     /// [is_DirectRenderer](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_directrenderer.html)
     pub fn is_DirectRenderer(hCam: HIDS, nMode: DR_CMD, pParam: *mut void, nSize: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/direct_renderer.rs"));
@@ -188,6 +188,7 @@ impl IS_VERT_FILTER_MODE {
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Output an image from an image memory in the specified window.
     ///
@@ -322,7 +323,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_SetDisplayMode](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setdisplaymode.html)
-    pub fn is_SetDisplayMode(hwnd: HWND, Mode: IS_SET_DM) -> INT;
+    pub fn is_SetDisplayMode(hCam: HIDS, Mode: IS_SET_DM) -> INT;
 
     /// Move an area of interest when rendering images using [`is_RenderBitmap`].
     ///
@@ -435,7 +436,7 @@ unsafe extern "C" {
     /// * [`is_DirectRenderer`]
     #[cfg(target_os = "windows")]
     #[deprecated]
-    pub fn is_LockDDOverlayMem(hf: HIDS, ppMem: *mut *const void, pPitch: *mut INT) -> INT;
+    pub fn is_LockDDOverlayMem(hf: HIDS, ppMem: *mut *mut void, pPitch: *mut INT) -> INT;
 
     /// **Obsolete:** Disables access to the overlay buffer in DirectDraw back buffer mode.
     ///
@@ -480,7 +481,7 @@ unsafe extern "C" {
     /// * [`is_DirectRenderer`]
     #[cfg(target_os = "windows")]
     #[deprecated]
-    pub fn is_LockDDMem(hf: HIDS, ppMem: *mut *const void, pPitch: *mut INT) -> INT;
+    pub fn is_LockDDMem(hf: HIDS, ppMem: *mut *mut void, pPitch: *mut INT) -> INT;
 
     /// **Obsolete:** Disables access to the image memory in DirectDraw mode.
     ///
@@ -514,7 +515,7 @@ unsafe extern "C" {
     /// * [`is_DirectRenderer`]
     #[cfg(target_os = "windows")]
     #[deprecated]
-    pub fn is_GetDDOvlSurface(hf: HIDS, ppDDSurf: *mut *const void) -> INT;
+    pub fn is_GetDDOvlSurface(hf: HIDS, ppDDSurf: *mut *mut void) -> INT;
 
     /// **Obsolete:** Identify the keying color for the DirectDraw overlay surface mode.
     ///
@@ -868,3 +869,6 @@ unsafe extern "C" {
     #[deprecated]
     pub fn is_SetVertFilter(hf: HIDS, Mode: IS_VERT_FILTER_MODE) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/display.rs"));
@@ -0,0 +1,129 @@
+//! Runtime loading of the uEye API library.
+//!
+//! With the `dlopen` feature, this crate does not link against the uEye API library. Instead, the
+//! library is loaded the first time any of its functions is called, so binaries start on machines
+//! without the IDS Software Suite installed.
+//!
+//! This module is left out when the `mock` feature is enabled as well, since the mock backend
+//! replaces the library altogether.
+//!
+//! Calling a function when the library cannot be loaded panics; call [`Library::load`] beforehand
+//! to handle a missing installation gracefully:
+//!
+//! ```rust,no_run
+//! use ueye_sys::dlopen::Library;
+//!
+//! if let Err(error) = Library::load() {
+//!     eprintln!("{error}");
+//!     std::process::exit(1);
+//! }
+//! ```
+
+use std::{
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt::{Display, Formatter},
+    sync::OnceLock,
+};
+
+/// File name of the uEye API library on this platform.
+#[cfg(all(target_os = "windows", target_pointer_width = "64"))]
+pub const LIBRARY_NAME: &str = "ueye_api_64.dll";
+
+/// File name of the uEye API library on this platform.
+#[cfg(all(target_os = "windows", not(target_pointer_width = "64")))]
+pub const LIBRARY_NAME: &str = "ueye_api.dll";
+
+/// File name of the uEye API library on this platform.
+#[cfg(not(target_os = "windows"))]
+pub const LIBRARY_NAME: &str = "libueye_api.so";
+
+static LIBRARY: OnceLock<Library> = OnceLock::new();
+
+/// Loaded uEye API library.
+#[derive(Debug)]
+pub struct Library {
+    library: libloading::Library,
+}
+
+impl Library {
+    /// Load the uEye API library from the default search path, using [`LIBRARY_NAME`].
+    ///
+    /// Returns the already loaded library on subsequent calls.
+    #[inline]
+    pub fn load() -> Result<&'static Library, LoadError> {
+        Self::load_from(LIBRARY_NAME)
+    }
+
+    /// Load the uEye API library from `path`.
+    ///
+    /// Returns the already loaded library if any was loaded before, regardless of `path`.
+    pub fn load_from(path: impl AsRef<OsStr>) -> Result<&'static Library, LoadError> {
+        if let Some(library) = LIBRARY.get() {
+            return Ok(library);
+        }
+
+        let path = path.as_ref();
+        // SAFETY: loading the uEye API library runs no initialization routines with preconditions.
+        let library = unsafe { libloading::Library::new(path) }
+            .map_err(|source| LoadError { path: path.to_owned(), source })?;
+
+        // another thread may have won the race, in which case ours is simply unloaded again
+        Ok(LIBRARY.get_or_init(|| Library { library }))
+    }
+
+    /// The loaded library, if any.
+    #[inline]
+    pub fn get() -> Option<&'static Library> {
+        LIBRARY.get()
+    }
+
+    /// The loaded library, loading it from the default search path if necessary.
+    ///
+    /// # Panics
+    /// If the library cannot be loaded.
+    pub(crate) fn require() -> &'static Library {
+        Self::load().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Resolve the function `name`, given as a null-terminated symbol name.
+    ///
+    /// # Safety
+    /// `T` must be the function pointer type matching the signature of the symbol.
+    pub(crate) unsafe fn symbol<T: Copy>(&self, name: &[u8]) -> Option<T> {
+        unsafe { self.library.get::<T>(name) }.ok().map(|symbol| *symbol)
+    }
+}
+
+/// Error loading the uEye API library.
+#[derive(Debug)]
+pub struct LoadError {
+    path: OsString,
+    source: libloading::Error,
+}
+
+impl LoadError {
+    /// Path the library was loaded from.
+    #[inline]
+    pub fn path(&self) -> &OsStr {
+        &self.path
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not load the uEye API library `{}`; is the IDS Software Suite installed? ({})",
+            self.path.to_string_lossy(),
+            self.source
+        )
+    }
+}
+
+impl Error for LoadError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -54,6 +54,7 @@ pub enum IS_EDGE_ENHANCEMENT_CMD {
     IS_EDGE_ENHANCEMENT_CMD_SET = 4,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Enables/disables a software edge filter.
     ///
@@ -81,3 +82,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/edge_enhancement.rs"));
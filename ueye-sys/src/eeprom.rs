@@ -1,6 +1,7 @@
 //! **Obsolete:** Common EEPROM functions.
 use crate::types::{char, HIDS, INT};
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// **Obsolete:** Read from the EEPROM.
@@ -45,5 +46,8 @@ unsafe extern "C" {
     /// * [`IS_SUCCESS`]
     /// * [`IS_NO_SUCCESS`]
     #[deprecated]
-    pub fn is_WriteEEPROM(hCam: HIDS, Adr: INT, pcString: *mut char, Count: INT) -> INT;
+    pub fn is_WriteEEPROM(hCam: HIDS, Adr: INT, pcString: *const char, Count: INT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/eeprom.rs"));
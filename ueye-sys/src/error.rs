@@ -1,6 +1,6 @@
 //! Common error functions.
 
-use crate::types::{HIDS, INT};
+use crate::types::{char, HIDS, INT};
 
 /// Current status of error reporting.
 pub const IS_GET_ERR_REP_MODE: INT = 0x8000;
@@ -11,6 +11,7 @@ pub const IS_ENABLE_ERR_REP: INT = 1;
 /// Disables error reporting.
 pub const IS_DISABLE_ERR_REP: INT = 0;
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Queries the last error that occurred and returns the associated error code and message.
     ///
@@ -64,3 +65,6 @@ unsafe extern "C" {
     /// [is_SetErrorReport](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_seterrorreport.html)
     pub fn is_SetErrorReport(hCam: HIDS, Mode: INT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/error.rs"));
@@ -1,9 +1,12 @@
 #![allow(non_camel_case_types)]
 
 use crate::constants::return_values::*;
-use crate::types::{void, BOOL, BYTE, DWORD, HCAM, INT, UINT, WORD};
+use crate::device_info::{decode_sensor_temperature, TEMPERATURE_NO_SENSOR};
+use crate::types::{void, BOOL, BYTE, DWORD, HCAM, HIDS, INT, UINT, WORD};
+use bitflags::bitflags;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::net::Ipv4Addr;
 
 /// [`BYTE`]-wise IPv4 address representation structure.
 #[repr(C, packed(1))]
@@ -63,6 +66,22 @@ impl Hash for UEYE_ETH_ADDR_IPV4 {
     }
 }
 
+impl From<Ipv4Addr> for UEYE_ETH_ADDR_IPV4 {
+    /// The most significant octet is stored in [`by4`][UEYE_ETH_ADDR_IPV4_by::by4], so that
+    /// [`dwAddr`][UEYE_ETH_ADDR_IPV4::dwAddr] holds the address as a number (_little-endian_).
+    fn from(addr: Ipv4Addr) -> Self {
+        let [by4, by3, by2, by1] = addr.octets();
+        Self { by: UEYE_ETH_ADDR_IPV4_by { by1, by2, by3, by4 } }
+    }
+}
+
+impl From<UEYE_ETH_ADDR_IPV4> for Ipv4Addr {
+    fn from(addr: UEYE_ETH_ADDR_IPV4) -> Self {
+        let by = unsafe { addr.by };
+        Ipv4Addr::new(by.by4, by.by3, by.by2, by.by1)
+    }
+}
+
 /// Ethernet address.
 ///
 /// # Documentation
@@ -74,6 +93,18 @@ pub struct UEYE_ETH_ADDR_MAC {
     pub abyOctet: [BYTE; 6],
 }
 
+impl From<[BYTE; 6]> for UEYE_ETH_ADDR_MAC {
+    fn from(abyOctet: [BYTE; 6]) -> Self {
+        Self { abyOctet }
+    }
+}
+
+impl From<UEYE_ETH_ADDR_MAC> for [BYTE; 6] {
+    fn from(mac: UEYE_ETH_ADDR_MAC) -> Self {
+        mac.abyOctet
+    }
+}
+
 /// IP configuration.
 ///
 /// # Documentation
@@ -267,6 +298,20 @@ pub struct UEYE_ETH_DEVICE_INFO_HEARTBEAT {
     reserved_6: [BYTE; 64],
 }
 
+impl UEYE_ETH_DEVICE_INFO_HEARTBEAT {
+    /// Value of [`wTemperature`][UEYE_ETH_DEVICE_INFO_HEARTBEAT::wTemperature] reported by
+    /// cameras without a temperature sensor, i.e. "-127.9 °C".
+    pub const TEMPERATURE_NO_SENSOR: WORD = TEMPERATURE_NO_SENSOR;
+
+    /// Camera temperature in °Celsius, decoded from
+    /// [`wTemperature`][UEYE_ETH_DEVICE_INFO_HEARTBEAT::wTemperature].
+    ///
+    /// Returns `None` if the camera does not have a temperature sensor.
+    pub const fn temperature_celsius(&self) -> Option<f32> {
+        decode_sensor_temperature(self.wTemperature)
+    }
+}
+
 impl Debug for UEYE_ETH_DEVICE_INFO_HEARTBEAT {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let abySerialNumber_aligned = self.abySerialNumber;
@@ -353,69 +398,71 @@ impl PartialEq for UEYE_ETH_DEVICE_INFO_HEARTBEAT {
     }
 }
 
-/// Status word for driver-based camera management.
-///
-/// # Documentation
-/// [Contents of the `UEYE_ETH_DEVICE_INFO::UEYE_ETH_DEVICE_INFO_CONTROL` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_deviceinfo.html#ueye_eth_device_info_control)
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-#[repr(u32)]
-pub enum UEYE_ETH_CONTROLSTATUS {
-    /// The camera is available.
-    IS_ETH_CTRLSTATUS_AVAILABLE = 0x00000001,
+bitflags! {
+    /// Status word for driver-based camera management (_supports bitmask_).
+    ///
+    /// # Documentation
+    /// [Contents of the `UEYE_ETH_DEVICE_INFO::UEYE_ETH_DEVICE_INFO_CONTROL` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_deviceinfo.html#ueye_eth_device_info_control)
+    #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct UEYE_ETH_CONTROLSTATUS: DWORD {
+        /// The camera is available.
+        const IS_ETH_CTRLSTATUS_AVAILABLE = 0x00000001;
 
-    /// Camera has valid IP address and can be accessed over the network.
-    IS_ETH_CTRLSTATUS_ACCESSIBLE1 = 0x00000002,
+        /// Camera has valid IP address and can be accessed over the network.
+        const IS_ETH_CTRLSTATUS_ACCESSIBLE1 = 0x00000002;
 
-    /// Camera has no persistent IP address; the auto IP range is valid.
-    IS_ETH_CTRLSTATUS_ACCESSIBLE2 = 0x00000004,
+        /// Camera has no persistent IP address; the auto IP range is valid.
+        const IS_ETH_CTRLSTATUS_ACCESSIBLE2 = 0x00000004;
 
-    /// Camera can be accessed over the network by its persistent IP address/
-    IS_ETH_CTRLSTATUS_PERSISTENT_IP_USED = 0x00000010,
+        /// Camera can be accessed over the network by its persistent IP address.
+        const IS_ETH_CTRLSTATUS_PERSISTENT_IP_USED = 0x00000010;
 
-    /// Camera is compatible with the installed driver.
-    IS_ETH_CTRLSTATUS_COMPATIBLE = 0x00000020,
+        /// Camera is compatible with the installed driver.
+        const IS_ETH_CTRLSTATUS_COMPATIBLE = 0x00000020;
 
-    /// DHCP is enabled on the PC network card.
-    IS_ETH_CTRLSTATUS_ADAPTER_ON_DHCP = 0x00000040,
+        /// DHCP is enabled on the PC network card.
+        const IS_ETH_CTRLSTATUS_ADAPTER_ON_DHCP = 0x00000040;
 
-    /// The PC network card setup is OK with respect to uEye needs.
-    IS_ETH_CTRLSTATUS_ADAPTER_SETUP_OK = 0x00000080,
+        /// The PC network card setup is OK with respect to uEye needs.
+        const IS_ETH_CTRLSTATUS_ADAPTER_SETUP_OK = 0x00000080;
 
-    /// Camera is being closed on this PC.
-    IS_ETH_CTRLSTATUS_UNPAIRING_IN_PROGRESS = 0x00000100,
+        /// Camera is being closed on this PC.
+        const IS_ETH_CTRLSTATUS_UNPAIRING_IN_PROGRESS = 0x00000100;
 
-    /// Camera is being initialized on this PC.
-    IS_ETH_CTRLSTATUS_PAIRING_IN_PROGRESS = 0x00000200,
+        /// Camera is being initialized on this PC.
+        const IS_ETH_CTRLSTATUS_PAIRING_IN_PROGRESS = 0x00000200;
 
-    /// Camera has been initialized on this PC.
-    IS_ETH_CTRLSTATUS_PAIRED = 0x00001000,
+        /// Camera has been initialized on this PC.
+        const IS_ETH_CTRLSTATUS_PAIRED = 0x00001000;
 
-    /// Camera has been opened on this PC.
-    IS_ETH_CTRLSTATUS_OPENED = 0x00004000,
+        /// Camera has been opened on this PC.
+        const IS_ETH_CTRLSTATUS_OPENED = 0x00004000;
 
-    /// Starter firmware is being loaded onto the camera.
-    IS_ETH_CTRLSTATUS_FW_UPLOAD_STARTER = 0x00010000,
+        /// Starter firmware is being loaded onto the camera.
+        const IS_ETH_CTRLSTATUS_FW_UPLOAD_STARTER = 0x00010000;
 
-    /// Runtime firmware is being loaded onto the camera.
-    IS_ETH_CTRLSTATUS_FW_UPLOAD_RUNTIME = 0x00020000,
+        /// Runtime firmware is being loaded onto the camera.
+        const IS_ETH_CTRLSTATUS_FW_UPLOAD_RUNTIME = 0x00020000;
 
-    /// Camera is rebooting.
-    IS_ETH_CTRLSTATUS_REBOOTING = 0x00100000,
+        /// Camera is rebooting.
+        const IS_ETH_CTRLSTATUS_REBOOTING = 0x00100000;
 
-    /// Boot-boosting is enabled for this camera.
-    IS_ETH_CTRLSTATUS_BOOTBOOST_ENABLED = 0x01000000,
+        /// Boot-boosting is enabled for this camera.
+        const IS_ETH_CTRLSTATUS_BOOTBOOST_ENABLED = 0x01000000;
 
-    /// Boot-boosting is active for this camera.
-    IS_ETH_CTRLSTATUS_BOOTBOOST_ACTIVE = 0x02000000,
+        /// Boot-boosting is active for this camera.
+        const IS_ETH_CTRLSTATUS_BOOTBOOST_ACTIVE = 0x02000000;
 
-    /// Camera has been initialized in the driver.
-    IS_ETH_CTRLSTATUS_INITIALIZED = 0x08000000,
+        /// Camera has been initialized in the driver.
+        const IS_ETH_CTRLSTATUS_INITIALIZED = 0x08000000;
 
-    /// Camera is being removed from driver management.
-    IS_ETH_CTRLSTATUS_TO_BE_DELETED = 0x40000000,
+        /// Camera is being removed from driver management.
+        const IS_ETH_CTRLSTATUS_TO_BE_DELETED = 0x40000000;
 
-    /// Camera is being removed from driver management.
-    IS_ETH_CTRLSTATUS_TO_BE_REMOVED = 0x80000000,
+        /// Camera is being removed from driver management.
+        const IS_ETH_CTRLSTATUS_TO_BE_REMOVED = 0x80000000;
+    }
 }
 
 /// Control information for a listed camera.
@@ -625,6 +672,7 @@ pub struct UEYE_ETH_DEVICE_INFO {
     pub infoDriver: UEYE_ETH_DRIVER_INFO,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Set the packet filter for a network adapter.
     ///
@@ -649,6 +697,35 @@ unsafe extern "C" {
     /// # Documentation
     /// [`is_SetPacketFilter`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setpacketfilter.html)
     pub fn is_SetPacketFilter(iAdapterID: INT, uFilterSetting: UEYE_ETH_PACKETFILTER_SETUP) -> INT;
+
+    /// Read information on a GigE uEye camera and the network adapter it is connected to.
+    ///
+    /// The camera does not need to be opened: `hCam` may be its device ID combined with
+    /// [`IS_USE_DEVICE_ID`][crate::camera::IS_USE_DEVICE_ID].
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle, or device ID combined with
+    ///     [`IS_USE_DEVICE_ID`][crate::camera::IS_USE_DEVICE_ID].
+    /// * `pDeviceInfo` - Pointer to a [`UEYE_ETH_DEVICE_INFO`] structure.
+    /// * `uStructSize` - Size (in bytes) of the [`UEYE_ETH_DEVICE_INFO`] structure.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_DeviceInfo`]
+    /// * [`is_GetCameraList`]
+    ///
+    /// # Documentation
+    /// [is_GetEthDeviceInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getethdeviceinfo.html)
+    pub fn is_GetEthDeviceInfo(
+        hCam: HIDS,
+        pDeviceInfo: *mut UEYE_ETH_DEVICE_INFO,
+        uStructSize: UINT,
+    ) -> INT;
 }
 
 /// Enumeration of IP configuration capability flags.
@@ -777,6 +854,7 @@ pub enum IPCONFIG_CMD {
     IPCONFIG_CMD_GET_AUTOCONFIG_IP_BYDEVICE = 0x02040100,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Allows configuring the IP settings of GigE uEye cameras and the associated
     /// GigE network adapters
@@ -852,3 +930,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/eth.rs"));
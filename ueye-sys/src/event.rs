@@ -111,7 +111,7 @@ pub struct IS_INIT_EVENT {
 #[repr(C)]
 pub struct IS_WAIT_EVENT {
     /// ID of the event object to be waited for.
-    nEvent: UINT,
+    pub nEvent: UINT,
 
     /// Timeout in milliseconds.
     ///
@@ -121,16 +121,16 @@ pub struct IS_WAIT_EVENT {
     /// * Timeout = `0`: The function returns immediately.
     /// * Timeout = [`INFINITE_UINT`]: The function does not return until the event object
     ///     is signalled.
-    nTimeoutMilliseconds: UINT,
+    pub nTimeoutMilliseconds: UINT,
 
     /// If the function returns with [`IS_SUCCESS`], the parameter contains the ID of the
     /// signaled event object.
-    nSignaled: UINT,
+    pub nSignaled: UINT,
 
     /// If the function returns with [`IS_SUCCESS`], the parameter contains the number of
     /// signalings of the event object defined by [`nSignaled`][IS_WAIT_EVENT::nSignaled] since
     /// the last query with the [`IS_EVENT_CMD_WAIT`][IS_EVENT_CMD::IS_EVENT_CMD_WAIT] command.
-    nSetCount: UINT,
+    pub nSetCount: UINT,
 }
 
 /// Structure for waiting on multiple events.
@@ -182,6 +182,7 @@ pub struct IS_WAIT_EVENTS {
     pub nSetCount: UINT,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Register, manage, and wait for event object signals.
     ///
@@ -208,3 +209,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/event.rs"));
@@ -284,6 +284,7 @@ pub enum EXPOSURE_CAPS {
     IS_EXPOSURE_CAP_DUAL_EXPOSURE = 0x00000008,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Query the exposure time ranges available in your camera and set new exposure times.
     ///
@@ -324,3 +325,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/exposure.rs"));
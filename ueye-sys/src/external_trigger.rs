@@ -0,0 +1,163 @@
+//! Common trigger functions.
+//!
+//! # Documentation
+//! * [is_SetExternalTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setexternaltrigger.html)
+//! * [is_ForceTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_forcetrigger.html)
+//! * [is_SetTriggerDelay](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_settriggerdelay.html)
+
+use crate::constants::return_values::*;
+use crate::types::{HIDS, INT};
+
+/// Returns the trigger mode setting.
+pub const IS_GET_EXTERNALTRIGGER: INT = 0x8000;
+
+/// Returns the current signal level at the trigger input.
+pub const IS_GET_TRIGGER_STATUS: INT = 0x8001;
+pub const IS_GET_TRIGGER_MASK: INT = 0x8002;
+pub const IS_GET_TRIGGER_INPUTS: INT = 0x8003;
+
+/// Returns the supported trigger modes.
+pub const IS_GET_SUPPORTED_TRIGGER_MODE: INT = 0x8004;
+pub const IS_GET_TRIGGER_COUNTER: INT = 0x8000;
+
+pub const IS_SET_TRIGGER_MASK: INT = 0x0100;
+
+/// Continuous trigger flag, combined with the trigger modes.
+pub const IS_SET_TRIGGER_CONTINUOUS: INT = 0x1000;
+
+/// Disables trigger.
+pub const IS_SET_TRIGGER_OFF: INT = 0x0000;
+
+/// Hardware trigger on falling signal edge.
+pub const IS_SET_TRIGGER_HI_LO: INT = IS_SET_TRIGGER_CONTINUOUS | 0x0001;
+
+/// Hardware trigger on rising signal edge.
+pub const IS_SET_TRIGGER_LO_HI: INT = IS_SET_TRIGGER_CONTINUOUS | 0x0002;
+
+/// Software trigger.
+pub const IS_SET_TRIGGER_SOFTWARE: INT = IS_SET_TRIGGER_CONTINUOUS | 0x0008;
+
+/// Freerun synchronization, falling signal edge.
+pub const IS_SET_TRIGGER_HI_LO_SYNC: INT = 0x0010;
+
+/// Freerun synchronization, rising signal edge.
+pub const IS_SET_TRIGGER_LO_HI_SYNC: INT = 0x0020;
+
+/// Pre-trigger on falling signal edge.
+pub const IS_SET_TRIGGER_PRE_HI_LO: INT = IS_SET_TRIGGER_CONTINUOUS | 0x0040;
+
+/// Pre-trigger on rising signal edge.
+pub const IS_SET_TRIGGER_PRE_LO_HI: INT = IS_SET_TRIGGER_CONTINUOUS | 0x0080;
+
+/// Returns the set trigger delay.
+pub const IS_GET_TRIGGER_DELAY: INT = 0x8000;
+
+/// Returns the minimum value for parameter `nTriggerDelay`.
+pub const IS_GET_MIN_TRIGGER_DELAY: INT = 0x8001;
+
+/// Returns the maximum value for parameter `nTriggerDelay`.
+pub const IS_GET_MAX_TRIGGER_DELAY: INT = 0x8002;
+
+/// Returns the increment for parameter `nTriggerDelay`.
+pub const IS_GET_TRIGGER_DELAY_GRANULARITY: INT = 0x8003;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Activates the trigger input.
+    ///
+    /// If the camera is in standby mode, it quits this mode and activates trigger mode. In
+    /// hardware trigger mode, image capture is delayed for each function call until the selected
+    /// trigger event has occurred. In software trigger mode, an image is captured immediately
+    /// when [`is_FreezeVideo`] is called.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nTriggerMode` - Trigger mode:
+    ///     * [`IS_SET_TRIGGER_OFF`]
+    ///     * [`IS_SET_TRIGGER_HI_LO`]
+    ///     * [`IS_SET_TRIGGER_LO_HI`]
+    ///     * [`IS_SET_TRIGGER_PRE_HI_LO`]
+    ///     * [`IS_SET_TRIGGER_PRE_LO_HI`]
+    ///     * [`IS_SET_TRIGGER_SOFTWARE`]
+    ///     * [`IS_GET_EXTERNALTRIGGER`]: Returns the trigger mode setting.
+    ///     * [`IS_GET_TRIGGER_STATUS`]: Returns the current signal level at the trigger input.
+    ///     * [`IS_GET_SUPPORTED_TRIGGER_MODE`]: Returns the supported trigger modes.
+    ///
+    /// # Return values
+    /// * _When used with [`IS_GET_EXTERNALTRIGGER`]:_ Current setting.
+    /// * _When used with [`IS_GET_TRIGGER_STATUS`]:_ Current signal level.
+    /// * _When used with [`IS_GET_SUPPORTED_TRIGGER_MODE`]:_ Supported modes.
+    /// * [`IS_CANT_COMMUNICATE_WITH_DRIVER`]
+    /// * [`IS_CANT_OPEN_DEVICE`]
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_IO_REQUEST_FAILED`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_ForceTrigger`]
+    /// * [`is_SetTriggerDelay`]
+    /// * [`is_FreezeVideo`]
+    /// * [`is_CaptureVideo`]
+    ///
+    /// # Documentation
+    /// [is_SetExternalTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setexternaltrigger.html)
+    pub fn is_SetExternalTrigger(hCam: HIDS, nTriggerMode: INT) -> INT;
+
+    /// Force a trigger during a hardware triggered image capture.
+    ///
+    /// An image is captured even if the trigger signal has not occurred yet.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetExternalTrigger`]
+    /// * [`is_FreezeVideo`]
+    /// * [`is_CaptureVideo`]
+    ///
+    /// # Documentation
+    /// [is_ForceTrigger](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_forcetrigger.html)
+    pub fn is_ForceTrigger(hCam: HIDS) -> INT;
+
+    /// Set the delay time between the arrival of a trigger signal and the start of exposure.
+    ///
+    /// The trigger signal can be initiated by hardware or by software. The delay time is set in
+    /// microseconds.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nTriggerDelay` - Time by which the image capture is delayed (in µs).
+    ///     * `0`: Disables trigger delay.
+    ///     * [`IS_GET_TRIGGER_DELAY`]: Returns the set trigger delay.
+    ///     * [`IS_GET_MIN_TRIGGER_DELAY`]: Returns the minimum value for `nTriggerDelay`.
+    ///     * [`IS_GET_MAX_TRIGGER_DELAY`]: Returns the maximum value for `nTriggerDelay`.
+    ///     * [`IS_GET_TRIGGER_DELAY_GRANULARITY`]: Returns the increment for `nTriggerDelay`.
+    ///
+    /// # Return values
+    /// * _When used with one of the `IS_GET_*` parameters:_ Requested value.
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetExternalTrigger`]
+    /// * [`is_TriggerDebounce`]
+    ///
+    /// # Documentation
+    /// [is_SetTriggerDelay](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_settriggerdelay.html)
+    pub fn is_SetTriggerDelay(hCam: HIDS, nTriggerDelay: INT) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/external_trigger.rs"));
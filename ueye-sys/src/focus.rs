@@ -3,7 +3,8 @@
 
 use std::cmp::Ordering;
 use bitflags::bitflags;
-use crate::types::{INT, IS_RECT, UINT, void};
+use crate::constants::return_values::*;
+use crate::types::{HIDS, INT, IS_RECT, UINT, void};
 
 bitflags! {
     /// Focus capability flags (_supports bitmask_).
@@ -220,16 +221,12 @@ impl PartialOrd for AUTOFOCUS_LIMIT {
 /// [Focus settings for uEye LE USB 3.1 Gen 1 AF: Contents of the AUTOFOCUS_CALLBACK structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus_le_af.html#autofocus_callback)
 pub type IS_AUTOFOCUS_CALLBACK_FUNC = Option<unsafe extern "C" fn(UINT, INT, *mut void)>;
 
-/// Example debug line-printing autofocus callback function.
-unsafe extern "C" fn print_autofocus_callback(focus: UINT, sharpness: INT, context: *mut void) {
-    println!("Autofocus callback triggered: focus={}, sharpness={}, context={:?}",
-             focus, sharpness, context);
-}
-
 /// Autofocus callback structure.
 ///
 /// # Documentation
 /// [Focus settings for uEye LE USB 3.1 Gen 1 AF: Contents of the AUTOFOCUS_CALLBACK structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus_le_af.html#autofocus_callback)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
 pub struct AUTOFOCUS_CALLBACK {
     /// Callback function.
     pub pfFunc: IS_AUTOFOCUS_CALLBACK_FUNC,
@@ -238,6 +235,12 @@ pub struct AUTOFOCUS_CALLBACK {
     pub pContext: *mut void
 }
 
+/// Enumeration of commands of function [`is_Focus`].
+///
+/// # Documentation
+/// [is_Focus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus.html)
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[repr(u32)]
 pub enum FOCUS_CMD {
     /// Returns the focus functions supported by the camera.
     ///
@@ -345,3 +348,31 @@ pub enum FOCUS_CMD {
 
 
 }
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Control the manual focus and the autofocus of cameras with a focus lens.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nCommand` - Command. See [`FOCUS_CMD`].
+    /// * `pParam` - Pointer to a function parameter, whose function depends on `nCommand`.
+    /// * `nSizeOfParam` - Size (in bytes) of the memory area to which `pParam` refers.
+    ///
+    /// # Return values
+    /// * [`IS_CANT_COMMUNICATE_WITH_DRIVER`]
+    /// * [`IS_CANT_OPEN_DEVICE`]
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_IO_REQUEST_FAILED`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Documentation
+    /// [is_Focus](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_focus.html)
+    pub fn is_Focus(hCam: HIDS, nCommand: FOCUS_CMD, pParam: *mut void, nSizeOfParam: UINT) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/focus.rs"));
@@ -0,0 +1,96 @@
+//! Frame rate and frame time.
+//!
+//! # Documentation
+//! * [is_SetFrameRate](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setframerate.html)
+//! * [is_GetFramesPerSecond](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframespersecond.html)
+//! * [is_GetFrameTimeRange](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframetimerange.html)
+
+use crate::constants::return_values::*;
+use crate::types::{double, HIDS, INT};
+
+/// Returns the current frame rate in `newFPS`, without changing it.
+pub const IS_GET_FRAMERATE: double = 32768.0;
+
+/// Returns the default frame rate in `newFPS`, without changing it.
+pub const IS_GET_DEFAULT_FRAMERATE: double = 32769.0;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Set the frame rate in freerun mode.
+    ///
+    /// The frame rate is limited by the pixel clock and the image size; the exposure time is in
+    /// turn limited by the frame rate. The value actually set is returned in `newFPS`.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `FPS` - Desired frame rate (in frames per second), or one of:
+    ///     * [`IS_GET_FRAMERATE`]
+    ///     * [`IS_GET_DEFAULT_FRAMERATE`]
+    /// * `newFPS` - Returns the frame rate actually set.
+    ///
+    /// # Return values
+    /// * [`IS_CANT_COMMUNICATE_WITH_DRIVER`]
+    /// * [`IS_CANT_OPEN_DEVICE`]
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_IO_REQUEST_FAILED`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_GetFrameTimeRange`]
+    /// * [`is_GetFramesPerSecond`]
+    ///
+    /// # Documentation
+    /// [is_SetFrameRate](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setframerate.html)
+    pub fn is_SetFrameRate(hCam: HIDS, FPS: double, newFPS: *mut double) -> INT;
+
+    /// Return the frame rate currently achieved in live mode.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `dblFPS` - Returns the current frame rate (in frames per second).
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetFrameRate`]
+    ///
+    /// # Documentation
+    /// [is_GetFramesPerSecond](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframespersecond.html)
+    pub fn is_GetFramesPerSecond(hCam: HIDS, dblFPS: *mut double) -> INT;
+
+    /// Return the range of the frame time, i.e. the inverse of the frame rate.
+    ///
+    /// The range depends on the pixel clock and the image size.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `min` - Returns the shortest frame time (in seconds).
+    /// * `max` - Returns the longest frame time (in seconds).
+    /// * `intervall` - Returns the increment of the frame time (in seconds).
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetFrameRate`]
+    ///
+    /// # Documentation
+    /// [is_GetFrameTimeRange](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getframetimerange.html)
+    pub fn is_GetFrameTimeRange(
+        hCam: HIDS,
+        min: *mut double,
+        max: *mut double,
+        intervall: *mut double,
+    ) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/frame_rate.rs"));
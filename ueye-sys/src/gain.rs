@@ -0,0 +1,189 @@
+//! Common gain functions.
+//!
+//! # Documentation
+//! * [is_SetHardwareGain](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethardwaregain.html)
+//! * [is_SetHWGainFactor](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethwgainfactor.html)
+//! * [is_SetGainBoost](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setgainboost.html)
+
+use crate::constants::return_values::*;
+use crate::types::{HIDS, INT};
+
+/// Returns the master gain factor.
+pub const IS_GET_MASTER_GAIN: INT = 0x8000;
+
+/// Returns the red gain factor.
+pub const IS_GET_RED_GAIN: INT = 0x8001;
+
+/// Returns the green gain factor.
+pub const IS_GET_GREEN_GAIN: INT = 0x8002;
+
+/// Returns the blue gain factor.
+pub const IS_GET_BLUE_GAIN: INT = 0x8003;
+
+/// Returns the master gain factor default value.
+pub const IS_GET_DEFAULT_MASTER: INT = 0x8004;
+
+/// Returns the red gain factor default value.
+pub const IS_GET_DEFAULT_RED: INT = 0x8005;
+
+/// Returns the green gain factor default value.
+pub const IS_GET_DEFAULT_GREEN: INT = 0x8006;
+
+/// Returns the blue gain factor default value.
+pub const IS_GET_DEFAULT_BLUE: INT = 0x8007;
+
+/// Returns the current gain boost setting.
+pub const IS_GET_GAINBOOST: INT = 0x8008;
+
+/// Enables the gain boost.
+pub const IS_SET_GAINBOOST_ON: INT = 0x0001;
+
+/// Disables the gain boost.
+pub const IS_SET_GAINBOOST_OFF: INT = 0x0000;
+
+/// Returns whether the camera supports the gain boost.
+pub const IS_GET_SUPPORTED_GAINBOOST: INT = 0x0002;
+
+/// Minimum gain factor for [`is_SetHardwareGain`].
+pub const IS_MIN_GAIN: INT = 0;
+
+/// Maximum gain factor for [`is_SetHardwareGain`].
+pub const IS_MAX_GAIN: INT = 100;
+
+/// Leaves a gain factor unchanged.
+pub const IS_IGNORE_PARAMETER: INT = -1;
+
+/// Returns the master gain factor (`100` = `1.00`).
+pub const IS_GET_MASTER_GAIN_FACTOR: INT = 0x8000;
+
+/// Returns the red gain factor (`100` = `1.00`).
+pub const IS_GET_RED_GAIN_FACTOR: INT = 0x8001;
+
+/// Returns the green gain factor (`100` = `1.00`).
+pub const IS_GET_GREEN_GAIN_FACTOR: INT = 0x8002;
+
+/// Returns the blue gain factor (`100` = `1.00`).
+pub const IS_GET_BLUE_GAIN_FACTOR: INT = 0x8003;
+
+/// Sets the master gain factor (`100` = `1.00`).
+pub const IS_SET_MASTER_GAIN_FACTOR: INT = 0x8004;
+
+/// Sets the red gain factor (`100` = `1.00`).
+pub const IS_SET_RED_GAIN_FACTOR: INT = 0x8005;
+
+/// Sets the green gain factor (`100` = `1.00`).
+pub const IS_SET_GREEN_GAIN_FACTOR: INT = 0x8006;
+
+/// Sets the blue gain factor (`100` = `1.00`).
+pub const IS_SET_BLUE_GAIN_FACTOR: INT = 0x8007;
+
+/// Returns the master gain factor default value.
+pub const IS_GET_DEFAULT_MASTER_GAIN_FACTOR: INT = 0x8008;
+
+/// Returns the red gain factor default value.
+pub const IS_GET_DEFAULT_RED_GAIN_FACTOR: INT = 0x8009;
+
+/// Returns the green gain factor default value.
+pub const IS_GET_DEFAULT_GREEN_GAIN_FACTOR: INT = 0x800a;
+
+/// Returns the blue gain factor default value.
+pub const IS_GET_DEFAULT_BLUE_GAIN_FACTOR: INT = 0x800b;
+
+/// Returns the master gain factor that corresponds to `nFactor`, without setting it.
+pub const IS_INQUIRE_MASTER_GAIN_FACTOR: INT = 0x800c;
+
+/// Returns the red gain factor that corresponds to `nFactor`, without setting it.
+pub const IS_INQUIRE_RED_GAIN_FACTOR: INT = 0x800d;
+
+/// Returns the green gain factor that corresponds to `nFactor`, without setting it.
+pub const IS_INQUIRE_GREEN_GAIN_FACTOR: INT = 0x800e;
+
+/// Returns the blue gain factor that corresponds to `nFactor`, without setting it.
+pub const IS_INQUIRE_BLUE_GAIN_FACTOR: INT = 0x800f;
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Control the sensor gain channels.
+    ///
+    /// The gain factors are set in percent of the maximum gain, in the range from
+    /// [`IS_MIN_GAIN`] to [`IS_MAX_GAIN`]. Pass [`IS_IGNORE_PARAMETER`] to leave a channel
+    /// unchanged.
+    ///
+    /// Enabling hardware gain increases not only the image brightness, but also the image noise.
+    /// We recommend using gain values below `50` for normal operation.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nMaster` - Sets the overall gain factor (`0`…`100`), or one of the `IS_GET_*`
+    ///     parameters to query a setting.
+    /// * `nRed` - Sets the red channel gain factor (`0`…`100`).
+    /// * `nGreen` - Sets the green channel gain factor (`0`…`100`).
+    /// * `nBlue` - Sets the blue channel gain factor (`0`…`100`).
+    ///
+    /// # Return values
+    /// * _When used with one of the `IS_GET_*` parameters:_ Current setting.
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetHWGainFactor`]
+    /// * [`is_SetGainBoost`]
+    ///
+    /// # Documentation
+    /// [is_SetHardwareGain](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethardwaregain.html)
+    pub fn is_SetHardwareGain(hCam: HIDS, nMaster: INT, nRed: INT, nGreen: INT, nBlue: INT) -> INT;
+
+    /// Control the sensor gain channels using multiplication factors.
+    ///
+    /// A factor of `100` corresponds to a gain of `1.00`, i.e. no amplification.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nMode` - One of the `IS_GET_*_GAIN_FACTOR`, `IS_SET_*_GAIN_FACTOR` or
+    ///     `IS_INQUIRE_*_GAIN_FACTOR` parameters.
+    /// * `nFactor` - Gain factor to be set (`100` = `1.00`).
+    ///
+    /// # Return values
+    /// * Current setting, set value or inquired value, depending on `nMode`.
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetHardwareGain`]
+    ///
+    /// # Documentation
+    /// [is_SetHWGainFactor](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_sethwgainfactor.html)
+    pub fn is_SetHWGainFactor(hCam: HIDS, nMode: INT, nFactor: INT) -> INT;
+
+    /// Enable/disable an additional analog hardware gain boost feature on the sensor.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `mode` - Mode:
+    ///     * [`IS_SET_GAINBOOST_ON`]
+    ///     * [`IS_SET_GAINBOOST_OFF`]
+    ///     * [`IS_GET_GAINBOOST`]
+    ///     * [`IS_GET_SUPPORTED_GAINBOOST`]
+    ///
+    /// # Return values
+    /// * _When used with [`IS_GET_GAINBOOST`]:_ Current setting.
+    /// * _When used with [`IS_GET_SUPPORTED_GAINBOOST`]:_ [`IS_SET_GAINBOOST_ON`] if supported,
+    ///     [`IS_SET_GAINBOOST_OFF`] otherwise.
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Related functions
+    /// * [`is_SetHardwareGain`]
+    ///
+    /// # Documentation
+    /// [is_SetGainBoost](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setgainboost.html)
+    pub fn is_SetGainBoost(hCam: HIDS, mode: INT) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/gain.rs"));
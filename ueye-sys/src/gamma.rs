@@ -50,6 +50,7 @@ pub enum GAMMA_CMD {
     IS_GAMMA_CMD_GET = 0x0003,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Enables digital gamma correction which applies a gamma characteristic to the image.
     ///
@@ -77,3 +78,6 @@ unsafe extern "C" {
         cbSizeOfParams: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/gamma.rs"));
@@ -425,6 +425,7 @@ pub enum IS_HOTPIXEL_CMD {
     IS_HOTPIXEL_ADAPTIVE_CORRECTION_GET_NUMBER_DETECTED_CLUSTER = 0x8127,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Configures the correction of sensor hot pixels.
     ///
@@ -458,3 +459,6 @@ unsafe extern "C" {
         SizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/hot_pixel.rs"));
@@ -46,10 +46,10 @@ pub enum IMGBUF_CMD {
 #[repr(C)]
 pub struct ID_RANGE {
     /// First image ID.
-    s32First: INT,
+    pub s32First: INT,
 
     /// Last image ID
-    s32Last: INT,
+    pub s32Last: INT,
 }
 
 impl ID_RANGE {
@@ -130,6 +130,7 @@ pub struct IMGBUF_ITEM {
     pub s32ImageID: INT
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Allows accessing the captured images in the camera memory
     /// (see [Using the camera memory (GigE uEye cameras)](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/sdk_gige_cameramemory.html)).
@@ -151,3 +152,6 @@ unsafe extern "C" {
     /// [`is_ImageBuffer`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagebuffer.html)
     pub fn is_ImageBuffer(hCam: HIDS, nCommand: IMGBUF_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_buffer.rs"));
@@ -83,6 +83,20 @@ pub struct IMAGE_FILE_PARAMS {
     reserved: [BYTE; 32],
 }
 
+impl IMAGE_FILE_PARAMS {
+    /// Parameters for loading into or saving from the active image memory.
+    pub const fn new(pwchFileName: *mut wchar_t, nFileType: IMG, nQuality: UINT) -> Self {
+        Self {
+            pwchFileName,
+            nFileType,
+            nQuality,
+            ppcImageMem: std::ptr::null_mut(),
+            pnImageID: std::ptr::null_mut(),
+            reserved: [0; 32],
+        }
+    }
+}
+
 /// Enumeration of commands of function [`is_ImageFile`].
 ///
 /// # Documentation
@@ -107,6 +121,7 @@ pub enum IMAGE_FILE_CMD {
     IS_IMAGE_FILE_CMD_SAVE = 2,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Loads and save an image from or to a file.
     ///
@@ -137,3 +152,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_file.rs"));
@@ -0,0 +1,154 @@
+//! Additional image information.
+//!
+//! [`is_GetImageInfo`] reads additional information about the image in an image memory, such as
+//! the device timestamp, the system time of capture and the frame number.
+//!
+//! # Documentation
+//! [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+
+#![allow(non_camel_case_types)]
+
+use crate::types::{BYTE, DWORD, HIDS, INT, WORD};
+
+/// System time of the image capture.
+///
+/// # Documentation
+/// [is_GetImageInfo: Contents of the `UEYETIME` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct UEYETIME {
+    /// Year.
+    pub wYear: WORD,
+
+    /// Month (`1`…`12`).
+    pub wMonth: WORD,
+
+    /// Day (`1`…`31`).
+    pub wDay: WORD,
+
+    /// Hour (`0`…`23`).
+    pub wHour: WORD,
+
+    /// Minute (`0`…`59`).
+    pub wMinute: WORD,
+
+    /// Second (`0`…`59`).
+    pub wSecond: WORD,
+
+    /// Millisecond (`0`…`999`).
+    pub wMilliseconds: WORD,
+
+    /// (**reserved**)
+    byReserved: [BYTE; 10],
+}
+
+impl Default for UEYETIME {
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: plain data, for which all-zero is a valid value.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// Additional image information.
+///
+/// # Documentation
+/// [is_GetImageInfo: Contents of the `UEYEIMAGEINFO` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct UEYEIMAGEINFO {
+    /// (**reserved**)
+    pub dwFlags: DWORD,
+
+    /// (**reserved**)
+    byReserved1: [BYTE; 4],
+
+    /// Timestamp of image capture (_device timestamp_), in 0.1 µs steps.
+    ///
+    /// The timestamp is set when the sensor begins to read out the image.
+    pub u64TimestampDevice: u64,
+
+    /// Time of image capture (_system time_, derived from the device timestamp).
+    pub TimestampSystem: UEYETIME,
+
+    /// Status of the digital inputs at the time of image capture.
+    pub dwIoStatus: DWORD,
+
+    /// Index of the AOI in the AOI sequence.
+    pub wAOIIndex: WORD,
+
+    /// Number of AOI sequence cycles.
+    pub wAOICycle: WORD,
+
+    /// Internal image counter, reset by [`is_InitCamera`](crate::camera::is_InitCamera) and when
+    /// the image capture is started.
+    pub u64FrameNumber: u64,
+
+    /// Number of image memories in the sequence.
+    pub dwImageBuffers: DWORD,
+
+    /// Number of image memories in use.
+    pub dwImageBuffersInUse: DWORD,
+
+    /// (**reserved**)
+    dwReserved3: DWORD,
+
+    /// Image height.
+    pub dwImageHeight: DWORD,
+
+    /// Image width.
+    pub dwImageWidth: DWORD,
+
+    /// Host processing time (in 10 µs steps).
+    pub dwHostProcessTime: DWORD,
+
+    /// (**reserved**)
+    byReserved5: [BYTE; 36],
+}
+
+impl Default for UEYEIMAGEINFO {
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: plain data, for which all-zero is a valid value.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Read out additional information about the image in an image memory.
+    ///
+    /// The device timestamp has an accuracy of 0.1 µs and is reset to `0` when the camera is
+    /// initialized, or when a timestamp reset is configured via
+    /// [`is_DeviceFeature`](crate::device_feature::is_DeviceFeature).
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nMemId` - ID of the image memory.
+    /// * `pImageInfo` - Pointer to a [`UEYEIMAGEINFO`] structure.
+    /// * `nImageInfoSize` - Size of the structure.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`](crate::constants::return_values::IS_INVALID_CAMERA_HANDLE)
+    /// * [`IS_INVALID_PARAMETER`](crate::constants::return_values::IS_INVALID_PARAMETER)
+    /// * [`IS_NO_SUCCESS`](crate::constants::return_values::IS_NO_SUCCESS)
+    /// * [`IS_NOT_SUPPORTED`](crate::constants::return_values::IS_NOT_SUPPORTED)
+    /// * [`IS_SUCCESS`](crate::constants::return_values::IS_SUCCESS)
+    ///
+    /// # Related functions
+    /// * [`is_AllocImageMem`](crate::image_mem::is_AllocImageMem)
+    /// * [`is_GetActSeqBuf`](crate::image_mem::is_GetActSeqBuf)
+    /// * [`is_DeviceFeature`](crate::device_feature::is_DeviceFeature)
+    ///
+    /// # Documentation
+    /// [is_GetImageInfo](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimageinfo.html)
+    pub fn is_GetImageInfo(
+        hCam: HIDS,
+        nMemId: INT,
+        pImageInfo: *mut UEYEIMAGEINFO,
+        nImageInfoSize: INT,
+    ) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_info.rs"));
@@ -3,6 +3,7 @@
 use crate::constants::return_values::*;
 use crate::types::{char, void, HIDS, INT, UINT};
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Allocates an image memory for an image having its dimensions defined by `width` and `height`
     /// and its color depth defined by `bitspixel`.
@@ -74,7 +75,7 @@ unsafe extern "C" {
         width: INT,
         height: INT,
         bitspixel: INT,
-        ppcMem: *mut *const char,
+        ppcMem: *mut *mut char,
         pnMemId: *mut INT,
     ) -> INT;
 
@@ -116,7 +117,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_SetImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_setimagemem.html)
-    pub fn is_SetImageMem(hCam: HIDS, pcMem: *const char, nMemId: INT) -> INT;
+    pub fn is_SetImageMem(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT;
 
     /// Releases an image memory that was allocated using [`is_AllocImageMem`] and removes it from
     /// the driver management.
@@ -154,7 +155,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_FreeImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_freeimagemem.html)
-    pub fn is_FreeImageMem(hCam: HIDS, pcMem: *const char, nMemId: INT) -> INT;
+    pub fn is_FreeImageMem(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT;
 
     /// Returns the pointer to the starting address of the active image memory.
     ///
@@ -184,7 +185,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_GetImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getimagemem.html)
-    pub fn is_GetImageMem(hCam: HIDS, pMem: *mut *const void) -> INT;
+    pub fn is_GetImageMem(hCam: HIDS, pMem: *mut *mut void) -> INT;
 
     /// Returns the pointer to the starting address of the active image memory.
     ///
@@ -216,7 +217,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_GetActiveImageMem](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_getactiveimagemem.html)
-    pub fn is_GetActiveImageMem(hCam: HIDS, ppcMem: *mut *const char, pnMemId: *mut INT) -> INT;
+    pub fn is_GetActiveImageMem(hCam: HIDS, ppcMem: *mut *mut char, pnMemId: *mut INT) -> INT;
 
     /// Returns the pointer to the starting address of the active image memory.
     ///
@@ -359,7 +360,7 @@ unsafe extern "C" {
         width: INT,
         height: INT,
         bitspixel: INT,
-        pcMem: *const char,
+        pcMem: *mut char,
         pnMemId: *mut INT,
     ) -> INT;
 
@@ -395,7 +396,7 @@ unsafe extern "C" {
         hCam: HIDS,
         pcMemSrc: *const char,
         nMemId: INT,
-        pcMemDst: *const char,
+        pcMemDst: *mut char,
     ) -> INT;
 
     /// Copies the contents of the image memory described by `pcMemSrc` and `nMemId` to the memory
@@ -434,7 +435,7 @@ unsafe extern "C" {
         pcMemSrc: *const char,
         nMemId: INT,
         nLines: INT,
-        pcMemDst: *const char,
+        pcMemDst: *mut char,
     ) -> INT;
 
     /// Adds an image memory to the list of image memories used for ring buffering.
@@ -463,7 +464,7 @@ unsafe extern "C" {
     ///
     /// # Documentation
     /// [is_AddToSequence](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_addtosequence.html)
-    pub fn is_AddToSequence(hCam: HIDS, pcMem: *const char, nMemId: INT) -> INT;
+    pub fn is_AddToSequence(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT;
 
     /// Removes all image memories from the sequence list that were added using
     /// [`is_AddToSequence`].
@@ -538,8 +539,8 @@ unsafe extern "C" {
     pub fn is_GetActSeqBuf(
         hCam: HIDS,
         pnNum: *mut INT,
-        ppcMem: *mut *const char,
-        ppcMemLast: *mut *const char,
+        ppcMem: *mut *mut char,
+        ppcMemLast: *mut *mut char,
     ) -> INT;
 
     /// Locks write access to an image memory within a sequence.
@@ -635,3 +636,6 @@ unsafe extern "C" {
     /// [is_UnlockSeqBuf](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_unlockseqbuf.html)
     pub fn is_UnlockSeqBuf(hCam: HIDS, nMemId: INT, pcMem: *const char) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_mem.rs"));
@@ -0,0 +1,171 @@
+//! Queue mode for image memories of a sequence.
+//!
+//! In queue mode, the driver queues every image memory of the sequence once it has been filled,
+//! and the application takes them out of the queue in the order they were filled. Image memories
+//! taken out of the queue are locked until they are unlocked with
+//! [`is_UnlockSeqBuf`](crate::image_mem::is_UnlockSeqBuf).
+//!
+//! # Documentation
+//! [is_ImageQueue](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagequeue.html)
+
+#![allow(non_camel_case_types)]
+
+use crate::constants::return_values::*;
+use crate::types::{char, void, HIDS, INT, UINT};
+
+/// Enumeration of commands for [`is_ImageQueue`].
+///
+/// # Documentation
+/// [is_ImageQueue](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagequeue.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum IMAGE_QUEUE_CMD {
+    /// Enable the queue mode for the current sequence.
+    ///
+    /// # Parameter type
+    /// [`UINT`] (_reserved, pass `0`_)
+    IS_IMAGE_QUEUE_CMD_INIT = 1,
+
+    /// Disable the queue mode.
+    ///
+    /// # Parameter type
+    /// [`NULL`](crate::types::NULL)
+    IS_IMAGE_QUEUE_CMD_EXIT = 2,
+
+    /// Wait for the next image memory in the queue, taking it out of the queue and locking it.
+    ///
+    /// # Parameter type
+    /// [`IMAGEQUEUE_WAIT_BUFFER`]
+    IS_IMAGE_QUEUE_CMD_WAIT = 3,
+
+    /// Discard all image memories in the queue.
+    ///
+    /// # Parameter type
+    /// [`NULL`](crate::types::NULL)
+    IS_IMAGE_QUEUE_CMD_FLUSH = 4,
+
+    /// Discard the given number of the oldest image memories in the queue.
+    ///
+    /// # Parameter type
+    /// [`UINT`]
+    IS_IMAGE_QUEUE_CMD_DISCARD_N_ITEMS = 5,
+
+    /// Return the number of image memories in the queue.
+    ///
+    /// # Parameter type
+    /// [`UINT`]
+    IS_IMAGE_QUEUE_CMD_GET_PENDING = 6,
+}
+
+/// Image memory returned by [`IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_WAIT`].
+///
+/// # Related commands
+/// * [`IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_WAIT`]
+///
+/// # Documentation
+/// [is_ImageQueue](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagequeue.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct IMAGEQUEUE_WAIT_BUFFER {
+    /// Longest time to wait for an image memory (in ms).
+    pub timeout: UINT,
+
+    /// Receives the starting address of the image memory.
+    pub ppcMem: *mut *mut char,
+
+    /// Receives the ID of the image memory.
+    pub pnMemId: *mut INT,
+}
+
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
+unsafe extern "C" {
+    /// Control the queue mode for image memories of a sequence.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nCommand` - Command. See [`IMAGE_QUEUE_CMD`].
+    /// * `pParam` - Pointer to a function parameter, whose function depends on `nCommand`.
+    /// * `cbSizeOfParams` - Size (in bytes) of the memory area to which `pParam` refers.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_INVALID_PARAMETER`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_NOT_SUPPORTED`]
+    /// * [`IS_SEQUENCE_LIST_EMPTY`]
+    /// * [`IS_SUCCESS`]
+    /// * [`IS_TIMED_OUT`]
+    ///
+    /// # Related functions
+    /// * [`is_AddToSequence`](crate::image_mem::is_AddToSequence)
+    /// * [`is_CaptureConfiguration`](crate::capture_configuration::is_CaptureConfiguration)
+    /// * [`is_UnlockSeqBuf`](crate::image_mem::is_UnlockSeqBuf)
+    ///
+    /// # Documentation
+    /// [is_ImageQueue](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_imagequeue.html)
+    pub fn is_ImageQueue(
+        hCam: HIDS,
+        nCommand: IMAGE_QUEUE_CMD,
+        pParam: *mut void,
+        cbSizeOfParams: UINT,
+    ) -> INT;
+
+    /// **Obsolete:** Enable the queue mode for the current sequence.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `nMode` - (**reserved**, pass `0`)
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Obsolete replacement
+    /// * [`is_ImageQueue`] with [`IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_INIT`]
+    #[deprecated(note = "Use is_ImageQueue instead")]
+    pub fn is_InitImageQueue(hCam: HIDS, nMode: INT) -> INT;
+
+    /// **Obsolete:** Disable the queue mode.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    ///
+    /// # Obsolete replacement
+    /// * [`is_ImageQueue`] with [`IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_EXIT`]
+    #[deprecated(note = "Use is_ImageQueue instead")]
+    pub fn is_ExitImageQueue(hCam: HIDS) -> INT;
+
+    /// **Obsolete:** Wait for the next image memory in the queue, taking it out of the queue and
+    /// locking it.
+    ///
+    /// # Input parameters
+    /// * `hCam` - Camera handle.
+    /// * `timeout` - Longest time to wait for an image memory (in ms).
+    /// * `ppcMem` - Receives the starting address of the image memory.
+    /// * `pnMemId` - Receives the ID of the image memory.
+    ///
+    /// # Return values
+    /// * [`IS_INVALID_CAMERA_HANDLE`]
+    /// * [`IS_NO_SUCCESS`]
+    /// * [`IS_SUCCESS`]
+    /// * [`IS_TIMED_OUT`]
+    ///
+    /// # Obsolete replacement
+    /// * [`is_ImageQueue`] with [`IMAGE_QUEUE_CMD::IS_IMAGE_QUEUE_CMD_WAIT`]
+    #[deprecated(note = "Use is_ImageQueue instead")]
+    pub fn is_WaitForNextImage(
+        hCam: HIDS,
+        timeout: UINT,
+        ppcMem: *mut *mut char,
+        pnMemId: *mut INT,
+    ) -> INT;
+}
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_queue.rs"));
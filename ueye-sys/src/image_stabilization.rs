@@ -34,6 +34,7 @@ pub enum IS_IMGSTAB_CMD {
     IMGSTAB_CMD_GET_ENABLE = 3,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Configure image stabilization.
     ///
@@ -49,3 +50,6 @@ unsafe extern "C" {
         nSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/image_stabilization.rs"));
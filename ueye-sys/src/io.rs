@@ -19,6 +19,8 @@ use crate::constants::return_values::*;
 ///
 /// # Documentation
 /// [Using flash: Contents of the `IO_FLASH_PARAMS` structure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_ioflash.html#io_flash_params)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct IO_FLASH_PARAMS {
     /// Flash delay (in μs).
     pub s32Delay: INT,
@@ -295,6 +297,8 @@ pub enum IO_LED_STATE {
 }
 
 /// Enumeration of commands of function [`is_IO`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum IO_CMD {
     /// Returns the supported GPIO ports.
     ///
@@ -628,6 +632,7 @@ pub enum IO_CMD {
     IS_IO_CMD_FLASH_SET_AUTO_FREERUN            = 35
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Control all flash functions and the additional digital outputs (GPIOs) of some uEye models.
     ///
@@ -661,3 +666,6 @@ unsafe extern "C" {
     pub fn is_IO(hCam: HCAM, nCommand: IO_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/io.rs"));
@@ -2,8 +2,13 @@
 
 pub mod aoi;
 pub mod auto_parameter;
+#[cfg(feature = "tools")]
+pub mod avi;
 pub mod black_level;
 pub mod boot_boost;
+pub mod camera;
+pub mod camera_info;
+pub mod camera_status;
 pub mod capture_configuration;
 pub mod capture_status;
 #[cfg(target_os = "windows")]
@@ -13,13 +18,17 @@ pub mod constants;
 pub mod convert;
 pub mod device_feature;
 pub mod device_info;
+#[cfg(all(feature = "dlopen", not(feature = "mock")))]
+pub mod dlopen;
 pub mod edge_enhancement;
 pub mod eth;
 pub mod event;
 pub mod exposure;
+pub mod external_trigger;
 pub mod gamma;
 pub mod image_buffer;
 pub mod image_file;
+pub mod image_info;
 pub mod io;
 pub mod lut;
 pub mod measure;
@@ -34,6 +43,8 @@ pub mod sequencer;
 pub mod trigger;
 pub mod types;
 pub mod focus;
+pub mod frame_rate;
+pub mod gain;
 pub mod image_stabilization;
 pub mod scene_preset;
 pub mod zoom;
@@ -45,12 +56,15 @@ pub mod direct_renderer;
 pub mod hot_pixel;
 pub mod transfer;
 pub mod image_mem;
+pub mod image_queue;
 pub mod error;
 pub mod color;
 pub mod display;
 pub mod video;
 pub mod eeprom;
 pub mod meta;
+#[cfg(feature = "mock")]
+pub mod mock;
 
 use constants::*;
 use types::*;
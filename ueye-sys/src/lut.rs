@@ -235,6 +235,7 @@ pub enum LUT_CMD {
     IS_LUT_CMD_SAVE_FILE = 0x0101,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// Enable a hardware or software LUT for uEye cameras which will be applied to the image in
@@ -282,3 +283,6 @@ unsafe extern "C" {
     /// [`is_LUT`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_lut.html)
     pub fn is_LUT(hCam: HIDS, nCommand: LUT_CMD, pParam: *mut void, cbSizeOfParams: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/lut.rs"));
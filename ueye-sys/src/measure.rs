@@ -99,6 +99,8 @@ pub struct MEASURE_SHARPNESS_INFO {
 ///
 /// # Documentation
 /// [is_Measure: Content of the MEASURE_SHARPNESS_AOI_PRESETS enumeration](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_measure.html#measuresharpnessaoipresets)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
 pub enum MEASURE_SHARPNESS_AOI_PRESETS {
     /// Predefined AOI for the sharpness measurement
     /// (5 AOIs, in each of the four image corners and in the center, each of the 5 AOIs has a size
@@ -110,6 +112,8 @@ pub enum MEASURE_SHARPNESS_AOI_PRESETS {
 ///
 /// # Documentation
 /// [is_Measure](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_measure.html)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
 pub enum MEASURE_CMD {
     /// Sets an AOI in which the sharpness is measured.
     ///
@@ -138,6 +142,7 @@ pub enum MEASURE_CMD {
     IS_MEASURE_CMD_SHARPNESS_CALCULATION_ALGORITHM_SET = 4
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Allows the measurement of the sharpness in a defined AOI of the current image.
     ///
@@ -160,3 +165,6 @@ unsafe extern "C" {
 }
 
 
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/measure.rs"));
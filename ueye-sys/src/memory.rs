@@ -40,6 +40,7 @@ pub struct IS_MEMORY_SIZE {
     pub u32SizeBytes: UINT,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// _Obsolete:_ Generic function to access memory.
@@ -53,3 +54,6 @@ unsafe extern "C" {
     pub fn is_Memory(hf: HIDS, nCommand: MEMORY_CMD, pParam: *mut void, cbSizeOfParam: UINT)
         -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/memory.rs"));
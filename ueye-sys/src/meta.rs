@@ -55,6 +55,7 @@ pub enum IS_OS {
     IS_OS_GREATER_THAN_WIN8 = 15,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// Returns the version of the `ueye_api.dll`.
@@ -142,7 +143,10 @@ pub const fn split_version(version: INT) -> (INT, INT, INT) {
 ///
 /// # Return values
 /// Version [`String`]
-pub const fn get_version_string(version: INT) -> String {
+pub fn get_version_string(version: INT) -> String {
     let (major, minor, build) = split_version(version);
     format!("{major}.{minor}.{build}")
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/meta.rs"));
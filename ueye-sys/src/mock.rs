@@ -0,0 +1,734 @@
+//! Hardware-free fake implementation of the core capture path.
+//!
+//! With the `mock` feature, this crate does not link against the uEye API library. The functions
+//! below stand in for their driver counterparts, simulating [`SENSOR_WIDTH`] × [`SENSOR_HEIGHT`]
+//! cameras which render a synthetic test pattern on every capture, so applications can run in CI
+//! without a physical camera. Every other function returns [`IS_NOT_SUPPORTED`].
+//!
+//! The `mock` feature takes precedence over `dlopen`: with both enabled, the uEye API library is
+//! never loaded and the `dlopen` module is left out.
+//!
+//! The simulated functions are:
+//! * [`is_InitCamera`](crate::camera::is_InitCamera) and
+//!   [`is_ExitCamera`](crate::camera::is_ExitCamera).
+//! * [`is_GetNumberOfCameras`](crate::camera::is_GetNumberOfCameras) and
+//!   [`is_GetCameraList`](crate::camera::is_GetCameraList), listing [`LISTED_CAMERAS`] cameras.
+//! * [`is_AllocImageMem`](crate::image_mem::is_AllocImageMem) and the related image memory and
+//!   sequence functions.
+//! * [`is_FreezeVideo`](crate::video::is_FreezeVideo),
+//!   [`is_CaptureVideo`](crate::video::is_CaptureVideo) and
+//!   [`is_StopLiveVideo`](crate::video::is_StopLiveVideo).
+//! * [`is_SetExternalTrigger`](crate::external_trigger::is_SetExternalTrigger), storing the
+//!   trigger mode only.
+//! * [`is_CameraStatus`](crate::camera_status::is_CameraStatus), for standby only.
+//! * [`is_Event`](crate::event::is_Event).
+//! * [`is_AOI`](crate::aoi::is_AOI), getting and setting the image AOI only.
+//! * [`is_GetImageInfo`](crate::image_info::is_GetImageInfo), reporting the frame number, the
+//!   device timestamp since the camera was opened and the system time of capture in UTC.
+//!
+//! In live mode, no images arrive on their own: each wait for [`IS_SET_EVENT_FRAME`] captures the
+//! next image right away, so tests run as fast as they consume frames.
+
+use std::{
+    collections::BTreeMap,
+    mem::size_of,
+    ptr,
+    sync::Mutex,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    aoi::IS_AOI_CMD,
+    camera::{UEYE_CAMERA_INFO, UEYE_CAMERA_LIST},
+    camera_status::{IS_GET_STATUS, IS_STANDBY, IS_STANDBY_SUPPORTED},
+    constants::{event::IS_SET_EVENT_FRAME, return_values::*},
+    event::{IS_EVENT_CMD, IS_INIT_EVENT, IS_WAIT_EVENT},
+    external_trigger::{IS_GET_EXTERNALTRIGGER, IS_SET_TRIGGER_OFF},
+    image_info::{UEYEIMAGEINFO, UEYETIME},
+    meta::IS_OS,
+    types::{char, void, DWORD, FALSE, HIDS, HWND, INT, IS_RECT, TRUE, UINT, ULONG, WORD},
+    video::IS_GET_LIVE,
+};
+
+/// Width of the simulated sensor, in pixels.
+pub const SENSOR_WIDTH: INT = 1280;
+
+/// Height of the simulated sensor, in pixels.
+pub const SENSOR_HEIGHT: INT = 1024;
+
+/// Number of cameras in the camera list, with camera and device IDs `1`…`LISTED_CAMERAS`.
+///
+/// Cameras with other IDs can still be opened.
+pub const LISTED_CAMERAS: INT = 2;
+
+/// Model name of the listed cameras.
+pub const MODEL: &str = "UI-MOCK";
+
+/// Serial number of the listed camera with device ID `device_id`.
+pub fn serial_number(device_id: INT) -> String {
+    format!("MOCK{device_id:06}")
+}
+
+/// Value of byte `byte` of the pixel at (`x`, `y`) in the test pattern of frame `frame`.
+///
+/// The pattern is a diagonal gradient shifted by one step per frame, so consecutive frames differ.
+#[inline]
+pub const fn pattern(x: usize, y: usize, frame: u64, byte: usize) -> u8 {
+    (x.wrapping_add(y).wrapping_add(byte) as u64).wrapping_add(frame) as u8
+}
+
+/// Return value of functions the mock backend does not implement.
+pub(crate) trait Unsupported {
+    fn unsupported() -> Self;
+}
+
+impl Unsupported for INT {
+    #[inline]
+    fn unsupported() -> Self {
+        IS_NOT_SUPPORTED
+    }
+}
+
+impl Unsupported for ULONG {
+    #[inline]
+    fn unsupported() -> Self {
+        IS_NO_SUCCESS as ULONG
+    }
+}
+
+impl Unsupported for IS_OS {
+    #[inline]
+    fn unsupported() -> Self {
+        IS_OS::IS_OS_UNDETERMINED
+    }
+}
+
+/// Image memory allocated by [`is_AllocImageMem`].
+struct Memory {
+    data: Box<[u8]>,
+    width: usize,
+    height: usize,
+    bits_per_pixel: usize,
+    pitch: usize,
+    info: UEYEIMAGEINFO,
+}
+
+impl Memory {
+    #[inline]
+    fn ptr(&mut self) -> *mut char {
+        self.data.as_mut_ptr() as *mut char
+    }
+
+    fn render(&mut self, frame: u64) {
+        let bytes_per_pixel = self.bits_per_pixel.div_ceil(8);
+        for y in 0..self.height {
+            let line = &mut self.data[y * self.pitch..][..self.width * bytes_per_pixel];
+            for (index, value) in line.iter_mut().enumerate() {
+                *value = pattern(index / bytes_per_pixel, y, frame, index % bytes_per_pixel);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Event {
+    enabled: bool,
+    signaled: bool,
+}
+
+struct Camera {
+    opened: Instant,
+    aoi: IS_RECT,
+    memories: BTreeMap<INT, Memory>,
+    next_id: INT,
+    active: Option<INT>,
+    sequence: Vec<INT>,
+    locked: Vec<INT>,
+    next_position: usize,
+    last: Option<INT>,
+    frames: u64,
+    live: bool,
+    trigger: INT,
+    standby: bool,
+    events: BTreeMap<UINT, Event>,
+}
+
+impl Camera {
+    fn new() -> Self {
+        Self {
+            opened: Instant::now(),
+            aoi: IS_RECT { s32X: 0, s32Y: 0, s32Width: SENSOR_WIDTH, s32Height: SENSOR_HEIGHT },
+            memories: BTreeMap::new(),
+            next_id: 1,
+            active: None,
+            sequence: Vec::new(),
+            locked: Vec::new(),
+            next_position: 0,
+            last: None,
+            frames: 0,
+            live: false,
+            trigger: IS_SET_TRIGGER_OFF,
+            standby: false,
+            events: BTreeMap::new(),
+        }
+    }
+
+    fn id_of(&self, mem: *const char) -> Option<INT> {
+        self.memories
+            .iter()
+            .find(|(_, memory)| memory.data.as_ptr() as *const char == mem)
+            .map(|(&id, _)| id)
+    }
+
+    /// Render the next image into the next free memory of the sequence, or the active memory.
+    fn capture(&mut self) -> INT {
+        let id = if self.sequence.is_empty() {
+            match self.active {
+                Some(id) => id,
+                None => return IS_NO_ACTIVE_IMG_MEM,
+            }
+        } else {
+            let count = self.sequence.len();
+            let Some(position) = (0..count)
+                .map(|offset| (self.next_position + offset) % count)
+                .find(|&position| !self.locked.contains(&self.sequence[position]))
+            else {
+                return IS_SEQ_BUFFER_IS_LOCKED;
+            };
+
+            self.next_position = (position + 1) % count;
+            self.sequence[position]
+        };
+
+        let frame = self.frames;
+        self.frames += 1;
+        let timestamp = (self.opened.elapsed().as_nanos() / 100) as u64;
+        if let Some(memory) = self.memories.get_mut(&id) {
+            memory.render(frame);
+            let info = &mut memory.info;
+            info.u64TimestampDevice = timestamp;
+            info.TimestampSystem = system_time(SystemTime::now());
+            info.u64FrameNumber = frame;
+            info.dwImageBuffers = self.sequence.len() as DWORD;
+            info.dwImageBuffersInUse = self.locked.len() as DWORD;
+            info.dwImageHeight = memory.height as DWORD;
+            info.dwImageWidth = memory.width as DWORD;
+        }
+
+        self.last = Some(id);
+        if let Some(event) = self.events.get_mut(&IS_SET_EVENT_FRAME) {
+            event.signaled = event.enabled;
+        }
+
+        IS_SUCCESS
+    }
+}
+
+/// Calendar date and time of `time` in UTC.
+fn system_time(time: SystemTime) -> UEYETIME {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+
+    // civil date of a day count, after Howard Hinnant's `civil_from_days`
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as u64;
+
+    let mut ueye_time = UEYETIME::default();
+    ueye_time.wYear = year as WORD;
+    ueye_time.wMonth = month as WORD;
+    ueye_time.wDay = day as WORD;
+    ueye_time.wHour = (seconds / 3_600) as WORD;
+    ueye_time.wMinute = (seconds / 60 % 60) as WORD;
+    ueye_time.wSecond = (seconds % 60) as WORD;
+    ueye_time.wMilliseconds = since_epoch.subsec_millis() as WORD;
+    ueye_time
+}
+
+static CAMERAS: Mutex<BTreeMap<HIDS, Camera>> = Mutex::new(BTreeMap::new());
+
+/// Run `f` on the simulated camera `hCam`.
+fn with_camera(hCam: HIDS, f: impl FnOnce(&mut Camera) -> INT) -> INT {
+    let mut cameras = CAMERAS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match cameras.get_mut(&hCam) {
+        Some(camera) => f(camera),
+        None => IS_INVALID_CAMERA_HANDLE,
+    }
+}
+
+pub(crate) unsafe fn is_InitCamera(phCam: *mut HIDS, _hWnd: HWND) -> INT {
+    if phCam.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    let mut cameras = CAMERAS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let requested = unsafe { *phCam } & 0x7FFF;
+    let hCam = match requested {
+        0 => (1..).find(|id| !cameras.contains_key(id)).unwrap_or(1),
+        id if cameras.contains_key(&id) => return IS_CANT_OPEN_DEVICE,
+        id => id,
+    };
+
+    cameras.insert(hCam, Camera::new());
+    unsafe { *phCam = hCam };
+    IS_SUCCESS
+}
+
+pub(crate) unsafe fn is_ExitCamera(hCam: HIDS) -> INT {
+    let mut cameras = CAMERAS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match cameras.remove(&hCam) {
+        Some(_) => IS_SUCCESS,
+        None => IS_INVALID_CAMERA_HANDLE,
+    }
+}
+
+pub(crate) unsafe fn is_GetNumberOfCameras(pnNumCams: *mut INT) -> INT {
+    if pnNumCams.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    unsafe { *pnNumCams = LISTED_CAMERAS };
+    IS_SUCCESS
+}
+
+/// Copy `value` into the null-terminated string `chars`, truncating it if necessary.
+fn copy_string(chars: &mut [char], value: &str) {
+    let bytes = value.as_bytes().iter().take(chars.len() - 1).chain([&0]);
+    for (char, &byte) in chars.iter_mut().zip(bytes) {
+        *char = byte as char;
+    }
+}
+
+pub(crate) unsafe fn is_GetCameraList(pucl: *mut UEYE_CAMERA_LIST) -> INT {
+    if pucl.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    let count = unsafe { (*pucl).dwCount };
+    if count == 0 {
+        unsafe { (*pucl).dwCount = LISTED_CAMERAS as ULONG };
+        return IS_SUCCESS;
+    } else if count != LISTED_CAMERAS as ULONG {
+        return IS_ACCESS_VIOLATION;
+    }
+
+    let cameras = CAMERAS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let entries = unsafe { ptr::addr_of_mut!((*pucl).uci) as *mut UEYE_CAMERA_INFO };
+    for id in 1..=LISTED_CAMERAS {
+        let mut info = UEYE_CAMERA_INFO::default();
+        info.dwCameraID = id as DWORD;
+        info.dwDeviceID = id as DWORD;
+        info.dwInUse = cameras.contains_key(&(id as HIDS)) as DWORD;
+        copy_string(&mut info.SerNo, &serial_number(id));
+        copy_string(&mut info.Model, MODEL);
+        copy_string(&mut info.FullModelName, MODEL);
+
+        unsafe { entries.add(id as usize - 1).write(info) };
+    }
+
+    IS_SUCCESS
+}
+
+pub(crate) unsafe fn is_AllocImageMem(
+    hCam: HIDS,
+    width: INT,
+    height: INT,
+    bitspixel: INT,
+    ppcMem: *mut *mut char,
+    pnMemId: *mut INT,
+) -> INT {
+    if ppcMem.is_null() || pnMemId.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    let [Ok(width), Ok(height), Ok(bits_per_pixel)] =
+        [width, height, bitspixel].map(usize::try_from)
+    else {
+        return IS_INVALID_PARAMETER;
+    };
+
+    with_camera(hCam, |camera| {
+        let pitch = width * bits_per_pixel.div_ceil(8);
+        let mut memory = Memory {
+            data: vec![0; pitch * height].into_boxed_slice(),
+            width,
+            height,
+            bits_per_pixel,
+            pitch,
+            info: UEYEIMAGEINFO::default(),
+        };
+
+        let id = camera.next_id;
+        camera.next_id += 1;
+        unsafe {
+            *ppcMem = memory.ptr();
+            *pnMemId = id;
+        }
+
+        camera.memories.insert(id, memory);
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_FreeImageMem(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT {
+    with_camera(hCam, |camera| {
+        if camera.id_of(pcMem) != Some(nMemId) {
+            return IS_INVALID_MEMORY_POINTER;
+        }
+
+        camera.memories.remove(&nMemId);
+        camera.sequence.retain(|&id| id != nMemId);
+        camera.locked.retain(|&id| id != nMemId);
+        if camera.active == Some(nMemId) {
+            camera.active = None;
+        }
+        if camera.last == Some(nMemId) {
+            camera.last = None;
+        }
+
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_SetImageMem(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT {
+    with_camera(hCam, |camera| {
+        if camera.id_of(pcMem) != Some(nMemId) {
+            return IS_INVALID_MEMORY_POINTER;
+        }
+
+        camera.active = Some(nMemId);
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_GetActiveImageMem(
+    hCam: HIDS,
+    ppcMem: *mut *mut char,
+    pnMemId: *mut INT,
+) -> INT {
+    if ppcMem.is_null() || pnMemId.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    with_camera(hCam, |camera| {
+        let Some(id) = camera.active else {
+            return IS_NO_ACTIVE_IMG_MEM;
+        };
+
+        let memory = camera.memories.get_mut(&id).expect("active memory is allocated");
+        unsafe {
+            *ppcMem = memory.ptr();
+            *pnMemId = id;
+        }
+
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_InquireImageMem(
+    hCam: HIDS,
+    pcMem: *const char,
+    nMemId: INT,
+    pnX: *mut INT,
+    pnY: *mut INT,
+    pnBits: *mut INT,
+    pnPitch: *mut INT,
+) -> INT {
+    with_camera(hCam, |camera| {
+        if camera.id_of(pcMem) != Some(nMemId) {
+            return IS_INVALID_MEMORY_POINTER;
+        }
+
+        let memory = &camera.memories[&nMemId];
+        for (output, value) in [
+            (pnX, memory.width),
+            (pnY, memory.height),
+            (pnBits, memory.bits_per_pixel),
+            (pnPitch, memory.pitch),
+        ] {
+            if !output.is_null() {
+                unsafe { *output = value as INT };
+            }
+        }
+
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_GetImageMemPitch(hCam: HIDS, pPitch: *mut INT) -> INT {
+    if pPitch.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    with_camera(hCam, |camera| {
+        let Some(id) = camera.active.or(camera.sequence.first().copied()) else {
+            return IS_NO_ACTIVE_IMG_MEM;
+        };
+
+        unsafe { *pPitch = camera.memories[&id].pitch as INT };
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_GetImageInfo(
+    hCam: HIDS,
+    nMemId: INT,
+    pImageInfo: *mut UEYEIMAGEINFO,
+    nImageInfoSize: INT,
+) -> INT {
+    if pImageInfo.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    if nImageInfoSize < size_of::<UEYEIMAGEINFO>() as INT {
+        return IS_INVALID_PARAMETER;
+    }
+
+    with_camera(hCam, |camera| match camera.memories.get(&nMemId) {
+        Some(memory) => {
+            unsafe { pImageInfo.write(memory.info) };
+            IS_SUCCESS
+        },
+        None => IS_INVALID_PARAMETER,
+    })
+}
+
+pub(crate) unsafe fn is_AddToSequence(hCam: HIDS, pcMem: *mut char, nMemId: INT) -> INT {
+    with_camera(hCam, |camera| {
+        if camera.id_of(pcMem) != Some(nMemId) {
+            return IS_INVALID_MEMORY_POINTER;
+        }
+
+        camera.sequence.push(nMemId);
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_ClearSequence(hCam: HIDS) -> INT {
+    with_camera(hCam, |camera| {
+        camera.sequence.clear();
+        camera.locked.clear();
+        camera.next_position = 0;
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_GetActSeqBuf(
+    hCam: HIDS,
+    pnNum: *mut INT,
+    ppcMem: *mut *mut char,
+    ppcMemLast: *mut *mut char,
+) -> INT {
+    with_camera(hCam, |camera| {
+        if camera.sequence.is_empty() {
+            return IS_SEQUENCE_LIST_EMPTY;
+        }
+
+        let current = camera.sequence[camera.next_position];
+        let current = camera.memories.get_mut(&current).map_or(ptr::null_mut(), Memory::ptr);
+        let last = camera.last.and_then(|id| camera.memories.get_mut(&id));
+        let last = last.map_or(ptr::null_mut(), Memory::ptr);
+
+        unsafe {
+            if !pnNum.is_null() {
+                *pnNum = camera.next_position as INT + 1;
+            }
+            if !ppcMem.is_null() {
+                *ppcMem = current;
+            }
+            if !ppcMemLast.is_null() {
+                *ppcMemLast = last;
+            }
+        }
+
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_LockSeqBuf(hCam: HIDS, _nMemId: INT, pcMem: *const char) -> INT {
+    with_camera(hCam, |camera| match camera.id_of(pcMem) {
+        Some(id) if camera.sequence.contains(&id) => {
+            if !camera.locked.contains(&id) {
+                camera.locked.push(id);
+            }
+            IS_SUCCESS
+        },
+        _ => IS_INVALID_MEMORY_POINTER,
+    })
+}
+
+pub(crate) unsafe fn is_UnlockSeqBuf(hCam: HIDS, _nMemId: INT, pcMem: *const char) -> INT {
+    with_camera(hCam, |camera| match camera.id_of(pcMem) {
+        Some(id) => {
+            camera.locked.retain(|&locked| locked != id);
+            IS_SUCCESS
+        },
+        None => IS_INVALID_MEMORY_POINTER,
+    })
+}
+
+pub(crate) unsafe fn is_FreezeVideo(hCam: HIDS, _Wait: INT) -> INT {
+    with_camera(hCam, |camera| {
+        camera.live = false;
+        camera.capture()
+    })
+}
+
+pub(crate) unsafe fn is_CaptureVideo(hCam: HIDS, Wait: INT) -> INT {
+    with_camera(hCam, |camera| {
+        if Wait == IS_GET_LIVE as INT {
+            return camera.live as INT;
+        }
+
+        camera.live = true;
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_StopLiveVideo(hCam: HIDS, _Wait: INT) -> INT {
+    with_camera(hCam, |camera| {
+        camera.live = false;
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_SetExternalTrigger(hCam: HIDS, nTriggerMode: INT) -> INT {
+    with_camera(hCam, |camera| {
+        if nTriggerMode == IS_GET_EXTERNALTRIGGER {
+            return camera.trigger;
+        }
+
+        camera.trigger = nTriggerMode;
+        IS_SUCCESS
+    })
+}
+
+pub(crate) unsafe fn is_CameraStatus(hCam: HIDS, nInfo: INT, ulValue: ULONG) -> ULONG {
+    with_camera(hCam, |camera| match (nInfo, ulValue) {
+        (IS_STANDBY_SUPPORTED, IS_GET_STATUS) => TRUE as INT,
+        (IS_STANDBY, IS_GET_STATUS) => camera.standby as INT,
+        (IS_STANDBY, value) => {
+            camera.standby = value != FALSE as ULONG;
+            IS_SUCCESS
+        },
+        _ => IS_NO_SUCCESS,
+    }) as ULONG
+}
+
+pub(crate) unsafe fn is_Event(
+    hCam: HIDS,
+    nCommand: IS_EVENT_CMD,
+    pParam: *mut void,
+    cbSizeOfParam: UINT,
+) -> INT {
+    if pParam.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    with_camera(hCam, |camera| match nCommand {
+        IS_EVENT_CMD::IS_EVENT_CMD_INIT => {
+            if (cbSizeOfParam as usize) < size_of::<IS_INIT_EVENT>() {
+                return IS_INVALID_BUFFER_SIZE;
+            }
+
+            let init = unsafe { &*(pParam as *const IS_INIT_EVENT) };
+            camera.events.insert(init.nEvent, Event::default());
+            IS_SUCCESS
+        },
+        IS_EVENT_CMD::IS_EVENT_CMD_WAIT => {
+            if (cbSizeOfParam as usize) < size_of::<IS_WAIT_EVENT>() {
+                return IS_INVALID_BUFFER_SIZE;
+            }
+
+            let wait = unsafe { &mut *(pParam as *mut IS_WAIT_EVENT) };
+            let Some(event) = camera.events.get(&wait.nEvent) else {
+                return IS_INVALID_PARAMETER;
+            };
+
+            let frame = wait.nEvent == IS_SET_EVENT_FRAME;
+            if !event.signaled && frame && event.enabled && camera.live {
+                let captured = camera.capture();
+                if captured != IS_SUCCESS {
+                    return captured;
+                }
+            }
+
+            let event = camera.events.get_mut(&wait.nEvent).expect("event was initialized");
+            if !std::mem::take(&mut event.signaled) {
+                return IS_TIMED_OUT;
+            }
+
+            wait.nSignaled = wait.nEvent;
+            wait.nSetCount = 1;
+            IS_SUCCESS
+        },
+        command => {
+            let count = cbSizeOfParam as usize / size_of::<UINT>();
+            let ids = unsafe { std::slice::from_raw_parts(pParam as *const UINT, count) };
+            for id in ids {
+                let Some(event) = camera.events.get_mut(id) else {
+                    return IS_INVALID_PARAMETER;
+                };
+
+                match command {
+                    IS_EVENT_CMD::IS_EVENT_CMD_ENABLE => event.enabled = true,
+                    IS_EVENT_CMD::IS_EVENT_CMD_DISABLE => event.enabled = false,
+                    IS_EVENT_CMD::IS_EVENT_CMD_SET => event.signaled = true,
+                    IS_EVENT_CMD::IS_EVENT_CMD_RESET => event.signaled = false,
+                    _ => {
+                        camera.events.remove(id);
+                    },
+                }
+            }
+
+            IS_SUCCESS
+        },
+    })
+}
+
+pub(crate) unsafe fn is_AOI(
+    hCam: HIDS,
+    nCommand: IS_AOI_CMD,
+    pParam: *mut void,
+    SizeOfParam: UINT,
+) -> INT {
+    if pParam.is_null() {
+        return IS_NULL_POINTER;
+    }
+
+    if (SizeOfParam as usize) < size_of::<IS_RECT>() {
+        return IS_INVALID_BUFFER_SIZE;
+    }
+
+    let rect = pParam as *mut IS_RECT;
+    with_camera(hCam, |camera| match nCommand {
+        IS_AOI_CMD::IS_AOI_IMAGE_GET_AOI => {
+            unsafe { *rect = camera.aoi };
+            IS_SUCCESS
+        },
+        IS_AOI_CMD::IS_AOI_IMAGE_SET_AOI => {
+            let aoi = unsafe { *rect };
+            let fits = aoi.s32X >= 0
+                && aoi.s32Y >= 0
+                && aoi.s32Width > 0
+                && aoi.s32Height > 0
+                && aoi.s32X + aoi.s32Width <= SENSOR_WIDTH
+                && aoi.s32Y + aoi.s32Height <= SENSOR_HEIGHT;
+            if !fits {
+                return IS_INVALID_PARAMETER;
+            }
+
+            camera.aoi = aoi;
+            IS_SUCCESS
+        },
+        _ => IS_NOT_SUPPORTED,
+    })
+}
@@ -203,6 +203,7 @@ pub enum MULTICAST_CMD {
     IS_AMC_CMD_GET_MC_SUPPORTED = (0x0014 | IS_MC_CMD_FLAG_ACTIVE),
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Configure the multicast mode of a _GigE uEye_ camera or open a virtual multicast camera.
     ///
@@ -228,3 +229,6 @@ unsafe extern "C" {
         cbSizeOfParams: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/multicast.rs"));
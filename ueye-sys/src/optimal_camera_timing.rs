@@ -4,7 +4,12 @@
 
 use crate::types::{double, void, HCAM, INT, UINT};
 
+/// Determine the optimal pixel clock once.
+pub const IS_BEST_PCLK_RUN_ONCE: INT = 0;
+
 /// Enumeration of commands for [`is_OptimalCameraTiming`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
 pub enum IS_OPTIMAL_CAMERA_TIMING_CMD {
     /// Get optimal camera timing (pixel clock).
     IS_OPTIMAL_CAMERA_TIMING_CMD_GET_PIXELCLOCK = 0x00000001,
@@ -14,23 +19,27 @@ pub enum IS_OPTIMAL_CAMERA_TIMING_CMD {
 }
 
 /// I/O structure of the optimal camera parameters.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
 pub struct IS_OPTIMAL_CAMERA_TIMING {
-    s32Mode: INT,
+    /// Mode of the measurement, [`IS_BEST_PCLK_RUN_ONCE`].
+    pub s32Mode: INT,
 
     /// The period (in milliseconds) during which no transfer error may occur.
     ///
     /// The adjustable range is between `4` and `20` seconds.
     /// The higher the value you set for this parameter, the more stable the determined pixel clock
     /// value will be. This, in turn, increases the runtime of the function correspondingly.
-    s32TimeoutFineTuning: INT,
+    pub s32TimeoutFineTuning: INT,
 
     /// Pixel clock frequency (in MHz).
-    ps32PixelClock: *mut INT,
+    pub ps32PixelClock: *mut INT,
 
     /// Frame rate (in FPS).
-    pdFramerate: *mut double,
+    pub pdFramerate: *mut double,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Generic interface to the optimal camera timing functionality.
     pub fn is_OptimalCameraTiming(
@@ -40,3 +49,6 @@ unsafe extern "C" {
         u32SizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/optimal_camera_timing.rs"));
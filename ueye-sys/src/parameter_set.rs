@@ -66,6 +66,7 @@ pub enum PARAMETERSET_CMD {
     IS_PARAMETERSET_CMD_ERASE_HW_PARAMETERSET               = 7
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Saves the current camera parameters to a file or to the user memory of the camera and
     /// loads the parameter set from a file or the user memory.
@@ -94,3 +95,6 @@ unsafe extern "C" {
     /// [`is_ParameterSet`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_parameterset.html)
     pub fn is_ParameterSet(hCam: HIDS, nCommand: PARAMETERSET_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/parameter_set.rs"));
@@ -119,6 +119,7 @@ pub enum PERSISTENT_MEMORY_CMD {
     IS_PERSISTENT_MEMORY_GET_SIZE_USER_PROTECTED = 9,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
 
     /// Read or write the non-volatile user memory of the camera.
@@ -146,3 +147,6 @@ unsafe extern "C" {
     ) -> INT;
 
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/persistent_memory.rs"));
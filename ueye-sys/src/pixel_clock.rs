@@ -52,6 +52,7 @@ pub enum PIXELCLOCK_CMD {
     IS_PIXELCLOCK_CMD_SET           = 6
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Returns the adjustable pixel clock range and sets the pixel clock.
     ///
@@ -92,3 +93,6 @@ unsafe extern "C" {
     /// [`is_PixelClock`](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_pixelclock.html)
     pub fn is_PixelClock(hCam: HIDS, nCommand: PIXELCLOCK_CMD, pParam: *mut void, cbSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/pixel_clock.rs"));
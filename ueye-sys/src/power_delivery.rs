@@ -107,17 +107,18 @@ impl Default for IS_POWER_DELIVERY_PROFILES {
 impl IS_POWER_DELIVERY_PROFILES {
     pub const fn voltage(&self) -> f32 {
         match *self {
-            IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_INVALID | _ => 0.0,
             IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_5V_LOW_POWER
             | IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_5V_HIGH_POWER => 5.0,
             IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_9V => 9.0,
             IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_12V => 12.0,
             IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_14V8 => 14.8,
             IS_POWER_DELIVERY_PROFILES::IS_POWER_DELIVERY_PROFILE_15V => 15.0,
+            _ => 0.0,
         }
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Query which USB Power Delivery profiles are supported by the camera in combination with the
     /// host PC and set the appropriate profile.
@@ -143,3 +144,6 @@ unsafe extern "C" {
         cbSizeOfParams: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/power_delivery.rs"));
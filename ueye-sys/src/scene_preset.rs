@@ -50,6 +50,7 @@ bitflags! {
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// **Obsolete:** Configure scene presets.
     ///
@@ -66,3 +67,6 @@ unsafe extern "C" {
         nSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/scene_preset.rs"));
@@ -334,6 +334,7 @@ bitflags! {
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Configure and activate the sequencer mode.
     ///
@@ -364,3 +365,6 @@ unsafe extern "C" {
         cbSizeOfParams: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/sequencer.rs"));
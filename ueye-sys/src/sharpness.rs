@@ -81,6 +81,7 @@ bitflags! {
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Enhance or reduce the image sharpness if supported by your _uEye_ model.
     ///
@@ -113,3 +114,6 @@ unsafe extern "C" {
         nSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/sharpness.rs"));
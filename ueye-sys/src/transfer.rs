@@ -124,6 +124,7 @@ pub enum TRANSFER_CMD {
     TRANSFER_CMD_GET_IMAGE_DESTINATION_CAPABILITIES = 5002,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Adjust the latency of the image data transfer of GigE _uEye_ cameras.
     ///
@@ -153,3 +154,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/transfer.rs"));
@@ -135,6 +135,7 @@ pub enum TRIGGER_CMD {
     IS_TRIGGER_CMD_SET_LINE_PRESCALER = 12,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Activates the burst trigger mode in GigE and USB 3 _uEye_ cameras.
     ///
@@ -166,3 +167,6 @@ unsafe extern "C" {
         cbSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/trigger.rs"));
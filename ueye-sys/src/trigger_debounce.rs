@@ -128,6 +128,7 @@ pub enum TRIGGER_DEBOUNCE_CMD {
     TRIGGER_DEBOUNCE_CMD_GET_DELAY_TIME_DEFAULT = 9,
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Suppress disturbances at the trigger input when you are running a _uEye_ camera in
     /// trigger mode.
@@ -161,3 +162,6 @@ unsafe extern "C" {
         nSizeOfParam: UINT,
     ) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/trigger_debounce.rs"));
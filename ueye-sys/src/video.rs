@@ -17,6 +17,7 @@ pub const IS_FORCE_VIDEO_STOP: UINT = 0x4000;
 pub const IS_FORCE_VIDEO_START: UINT = 0x4000;
 pub const IS_USE_NEXT_MEM: UINT = 0x8000;
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Stops live mode or cancels a hardware triggered image capture in case the exposure has
     /// not yet started.
@@ -234,3 +235,6 @@ unsafe extern "C" {
     /// [is_HasVideoStarted](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_hasvideostarted.html)
     pub fn is_HasVideoStarted(hCam: HIDS, pbo: *mut BOOL) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/video.rs"));
@@ -78,6 +78,7 @@ bitflags! {
     }
 }
 
+#[cfg(not(any(feature = "dlopen", feature = "mock")))]
 unsafe extern "C" {
     /// Control the zoom function of the lens or sensor (digital zoom) if supported by your camera.
     ///
@@ -102,3 +103,6 @@ unsafe extern "C" {
     /// [is_Zoom](https://www.1stvision.com/cameras/IDS/IDS-manuals/uEye_Manual/is_zoom.html)
     pub fn is_Zoom(hCam: HIDS, nCommand: ZOOM_CMD, pParam: *mut void, nSizeOfParam: UINT) -> INT;
 }
+
+#[cfg(any(feature = "dlopen", feature = "mock"))]
+include!(concat!(env!("OUT_DIR"), "/shims/zoom.rs"));